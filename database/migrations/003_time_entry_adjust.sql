@@ -0,0 +1,88 @@
+-- 時間区間の訂正イベント（adjust）対応
+
+-- CHECK制約を変更するため time_entry_events を再作成する
+DROP VIEW IF EXISTS time_entries_view;
+
+CREATE TABLE time_entry_events_new (
+  id INTEGER PRIMARY KEY,
+  task_id INTEGER NOT NULL,
+  event_type TEXT NOT NULL CHECK(event_type IN ('start','stop','annotate','adjust')),
+  at TEXT NOT NULL,
+  start_event_id INTEGER,
+  payload TEXT,
+  FOREIGN KEY(task_id) REFERENCES tasks(id)
+);
+
+INSERT INTO time_entry_events_new (id, task_id, event_type, at, start_event_id, payload)
+SELECT id, task_id, event_type, at, start_event_id, payload
+FROM time_entry_events;
+
+DROP TABLE time_entry_events;
+ALTER TABLE time_entry_events_new RENAME TO time_entry_events;
+
+CREATE INDEX IF NOT EXISTS idx_time_entry_events_task_at ON time_entry_events(task_id, at);
+CREATE INDEX IF NOT EXISTS idx_time_entry_events_start_event ON time_entry_events(start_event_id, event_type);
+
+-- 時間エントリビュー（訂正イベントを反映）
+-- 開始/停止の対応付けは元のイベント時刻で行い、最新の adjust で区間の開始・終了を上書きする
+CREATE VIEW time_entries_view AS
+WITH starts AS (
+  SELECT
+    id AS start_event_id,
+    task_id,
+    at AS start_time,
+    LEAD(at) OVER (PARTITION BY task_id ORDER BY at, id) AS next_start_time
+  FROM time_entry_events
+  WHERE event_type = 'start'
+), paired AS (
+  SELECT
+    s.task_id,
+    s.start_event_id,
+    s.start_time,
+    s.next_start_time,
+    (
+      SELECT st.at
+      FROM time_entry_events st
+      WHERE st.task_id = s.task_id
+        AND st.event_type = 'stop'
+        AND (st.at > s.start_time OR (st.at = s.start_time AND st.id > s.start_event_id))
+        AND (s.next_start_time IS NULL OR st.at < s.next_start_time)
+      ORDER BY st.at, st.id
+      LIMIT 1
+    ) AS stop_time
+  FROM starts s
+), latest_adjust AS (
+  SELECT
+    a.start_event_id,
+    json_extract(a.payload, '$.start_time') AS start_time,
+    json_extract(a.payload, '$.end_time') AS end_time
+  FROM time_entry_events a
+  WHERE a.event_type = 'adjust'
+    AND a.id = (
+      SELECT MAX(a2.id)
+      FROM time_entry_events a2
+      WHERE a2.event_type = 'adjust'
+        AND a2.start_event_id = a.start_event_id
+    )
+), adjusted AS (
+  SELECT
+    p.task_id,
+    p.start_event_id,
+    COALESCE(la.start_time, p.start_time) AS start_time,
+    COALESCE(la.end_time, p.stop_time, p.next_start_time) AS end_time,
+    p.next_start_time
+  FROM paired p
+  LEFT JOIN latest_adjust la ON la.start_event_id = p.start_event_id
+)
+SELECT
+  task_id,
+  start_event_id,
+  start_time,
+  end_time,
+  CASE
+    WHEN end_time IS NOT NULL
+    THEN CAST(ROUND((julianday(end_time) - julianday(start_time)) * 86400) AS INTEGER)
+    ELSE NULL
+  END AS duration_in_seconds,
+  next_start_time
+FROM adjusted;
@@ -0,0 +1,113 @@
+-- 注釈イベント（annotate）を時間区間のメモとして公開する
+
+DROP VIEW IF EXISTS time_entries_view;
+DROP VIEW IF EXISTS time_entries_all_view;
+
+-- 無効化済みを含む全時間エントリビュー
+-- 無効化された区間も開始/停止の対応付けには参加させ、最新の void/unvoid で is_voided を判定する
+CREATE VIEW time_entries_all_view AS
+WITH starts AS (
+  SELECT
+    id AS start_event_id,
+    task_id,
+    at AS start_time,
+    LEAD(at) OVER (PARTITION BY task_id ORDER BY at, id) AS next_start_time
+  FROM time_entry_events
+  WHERE event_type = 'start'
+), paired AS (
+  SELECT
+    s.task_id,
+    s.start_event_id,
+    s.start_time,
+    s.next_start_time,
+    (
+      SELECT st.at
+      FROM time_entry_events st
+      WHERE st.task_id = s.task_id
+        AND st.event_type = 'stop'
+        AND (st.at > s.start_time OR (st.at = s.start_time AND st.id > s.start_event_id))
+        AND (s.next_start_time IS NULL OR st.at < s.next_start_time)
+      ORDER BY st.at, st.id
+      LIMIT 1
+    ) AS stop_time
+  FROM starts s
+), latest_adjust AS (
+  SELECT
+    a.start_event_id,
+    json_extract(a.payload, '$.start_time') AS start_time,
+    json_extract(a.payload, '$.end_time') AS end_time
+  FROM time_entry_events a
+  WHERE a.event_type = 'adjust'
+    AND a.id = (
+      SELECT MAX(a2.id)
+      FROM time_entry_events a2
+      WHERE a2.event_type = 'adjust'
+        AND a2.start_event_id = a.start_event_id
+    )
+), latest_void AS (
+  SELECT
+    v.start_event_id,
+    v.event_type
+  FROM time_entry_events v
+  WHERE v.event_type IN ('void', 'unvoid')
+    AND v.id = (
+      SELECT MAX(v2.id)
+      FROM time_entry_events v2
+      WHERE v2.event_type IN ('void', 'unvoid')
+        AND v2.start_event_id = v.start_event_id
+    )
+), entry_notes AS (
+  -- 注釈は発生順に改行区切りで連結する（集約関数内のORDER BYは使えないため並べ替え済みの副問い合わせから集約）
+  SELECT
+    n.start_event_id,
+    group_concat(n.payload, char(10)) AS notes
+  FROM (
+    SELECT start_event_id, payload
+    FROM time_entry_events
+    WHERE event_type = 'annotate'
+      AND start_event_id IS NOT NULL
+      AND payload IS NOT NULL
+    ORDER BY start_event_id, at, id
+  ) n
+  GROUP BY n.start_event_id
+), adjusted AS (
+  SELECT
+    p.task_id,
+    p.start_event_id,
+    COALESCE(la.start_time, p.start_time) AS start_time,
+    COALESCE(la.end_time, p.stop_time, p.next_start_time) AS end_time,
+    p.next_start_time,
+    CASE WHEN lv.event_type = 'void' THEN 1 ELSE 0 END AS is_voided,
+    en.notes
+  FROM paired p
+  LEFT JOIN latest_adjust la ON la.start_event_id = p.start_event_id
+  LEFT JOIN latest_void lv ON lv.start_event_id = p.start_event_id
+  LEFT JOIN entry_notes en ON en.start_event_id = p.start_event_id
+)
+SELECT
+  task_id,
+  start_event_id,
+  start_time,
+  end_time,
+  CASE
+    WHEN end_time IS NOT NULL
+    THEN CAST(ROUND((julianday(end_time) - julianday(start_time)) * 86400) AS INTEGER)
+    ELSE NULL
+  END AS duration_in_seconds,
+  next_start_time,
+  is_voided,
+  notes
+FROM adjusted;
+
+-- 時間エントリビュー（無効化された区間を除外）
+CREATE VIEW time_entries_view AS
+SELECT
+  task_id,
+  start_event_id,
+  start_time,
+  end_time,
+  duration_in_seconds,
+  next_start_time,
+  notes
+FROM time_entries_all_view
+WHERE is_voided = 0;
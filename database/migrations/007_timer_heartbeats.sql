@@ -0,0 +1,9 @@
+-- 実行中タイマーのハートビート
+-- 計測中に定期的に更新し、起動時に最終ハートビートが古い区間をクラッシュ/スリープ後の復旧対象として検出する
+-- 運用上の状態であり、区間そのものは変更しない（停止は通常の stop イベントで行う）
+
+CREATE TABLE IF NOT EXISTS timer_heartbeats (
+  start_event_id INTEGER PRIMARY KEY,
+  at TEXT NOT NULL,
+  FOREIGN KEY(start_event_id) REFERENCES time_entry_events(id)
+);
@@ -0,0 +1,202 @@
+-- 時間区間の一時停止/再開イベント（pause / resume）対応
+
+-- CHECK制約を変更するため time_entry_events を再作成する
+-- timer_heartbeats は time_entry_events を参照するため、再作成の間は退避しておく
+DROP VIEW IF EXISTS time_entries_view;
+DROP VIEW IF EXISTS time_entries_all_view;
+
+CREATE TEMP TABLE timer_heartbeats_backup AS SELECT start_event_id, at FROM timer_heartbeats;
+DELETE FROM timer_heartbeats;
+
+CREATE TABLE time_entry_events_new (
+  id INTEGER PRIMARY KEY,
+  task_id INTEGER NOT NULL,
+  event_type TEXT NOT NULL CHECK(event_type IN ('start','stop','annotate','adjust','void','unvoid','pause','resume')),
+  at TEXT NOT NULL,
+  start_event_id INTEGER,
+  payload TEXT,
+  FOREIGN KEY(task_id) REFERENCES tasks(id)
+);
+
+INSERT INTO time_entry_events_new (id, task_id, event_type, at, start_event_id, payload)
+SELECT id, task_id, event_type, at, start_event_id, payload
+FROM time_entry_events;
+
+DROP TABLE time_entry_events;
+ALTER TABLE time_entry_events_new RENAME TO time_entry_events;
+
+CREATE INDEX IF NOT EXISTS idx_time_entry_events_task_at ON time_entry_events(task_id, at);
+CREATE INDEX IF NOT EXISTS idx_time_entry_events_start_event ON time_entry_events(start_event_id, event_type);
+
+INSERT INTO timer_heartbeats (start_event_id, at)
+SELECT start_event_id, at FROM timer_heartbeats_backup;
+DROP TABLE timer_heartbeats_backup;
+
+-- 無効化済みを含む全時間エントリビュー
+-- 無効化された区間も開始/停止の対応付けには参加させ、最新の void/unvoid で is_voided を判定する
+-- 一時停止していた時間（区間内に切り詰め）は duration_in_seconds から除外する
+CREATE VIEW time_entries_all_view AS
+WITH starts AS (
+  SELECT
+    id AS start_event_id,
+    task_id,
+    at AS start_time,
+    LEAD(at) OVER (PARTITION BY task_id ORDER BY at, id) AS next_start_time
+  FROM time_entry_events
+  WHERE event_type = 'start'
+), paired AS (
+  SELECT
+    s.task_id,
+    s.start_event_id,
+    s.start_time,
+    s.next_start_time,
+    (
+      SELECT st.at
+      FROM time_entry_events st
+      WHERE st.task_id = s.task_id
+        AND st.event_type = 'stop'
+        AND (
+          st.at > s.start_time
+          OR (
+            st.at = s.start_time AND st.id > s.start_event_id
+            AND (st.start_event_id IS NULL OR st.start_event_id = s.start_event_id)
+          )
+        )
+        AND (
+          s.next_start_time IS NULL
+          OR st.at < s.next_start_time
+          OR (st.at = s.next_start_time AND st.start_event_id = s.start_event_id)
+        )
+      ORDER BY st.at, st.id
+      LIMIT 1
+    ) AS stop_time
+  FROM starts s
+), latest_adjust AS (
+  SELECT
+    a.start_event_id,
+    json_extract(a.payload, '$.start_time') AS start_time,
+    json_extract(a.payload, '$.end_time') AS end_time
+  FROM time_entry_events a
+  WHERE a.event_type = 'adjust'
+    AND a.id = (
+      SELECT MAX(a2.id)
+      FROM time_entry_events a2
+      WHERE a2.event_type = 'adjust'
+        AND a2.start_event_id = a.start_event_id
+    )
+), latest_void AS (
+  SELECT
+    v.start_event_id,
+    v.event_type
+  FROM time_entry_events v
+  WHERE v.event_type IN ('void', 'unvoid')
+    AND v.id = (
+      SELECT MAX(v2.id)
+      FROM time_entry_events v2
+      WHERE v2.event_type IN ('void', 'unvoid')
+        AND v2.start_event_id = v.start_event_id
+    )
+), entry_notes AS (
+  -- 注釈は発生順に改行区切りで連結する（集約関数内のORDER BYは使えないため並べ替え済みの副問い合わせから集約）
+  SELECT
+    n.start_event_id,
+    group_concat(n.payload, char(10)) AS notes
+  FROM (
+    SELECT start_event_id, payload
+    FROM time_entry_events
+    WHERE event_type = 'annotate'
+      AND start_event_id IS NOT NULL
+      AND payload IS NOT NULL
+    ORDER BY start_event_id, at, id
+  ) n
+  GROUP BY n.start_event_id
+), pause_spans AS (
+  -- 一時停止を同じ区間の次の再開と対応付ける（再開がなければ一時停止中）
+  SELECT
+    pz.start_event_id,
+    pz.at AS pause_time,
+    (
+      SELECT r.at
+      FROM time_entry_events r
+      WHERE r.event_type = 'resume'
+        AND r.start_event_id = pz.start_event_id
+        AND (r.at > pz.at OR (r.at = pz.at AND r.id > pz.id))
+      ORDER BY r.at, r.id
+      LIMIT 1
+    ) AS resume_time
+  FROM time_entry_events pz
+  WHERE pz.event_type = 'pause'
+    AND pz.start_event_id IS NOT NULL
+), adjusted AS (
+  SELECT
+    p.task_id,
+    p.start_event_id,
+    COALESCE(la.start_time, p.start_time) AS start_time,
+    COALESCE(la.end_time, p.stop_time, p.next_start_time) AS end_time,
+    p.next_start_time,
+    CASE WHEN lv.event_type = 'void' THEN 1 ELSE 0 END AS is_voided,
+    en.notes
+  FROM paired p
+  LEFT JOIN latest_adjust la ON la.start_event_id = p.start_event_id
+  LEFT JOIN latest_void lv ON lv.start_event_id = p.start_event_id
+  LEFT JOIN entry_notes en ON en.start_event_id = p.start_event_id
+), clamped_pauses AS (
+  -- 一時停止区間を時間区間内に切り詰める（停止済みの区間で再開がなければ停止時刻まで）
+  SELECT
+    a.start_event_id,
+    MAX(ps.pause_time, a.start_time) AS pause_from,
+    CASE
+      WHEN ps.resume_time IS NULL THEN a.end_time
+      WHEN a.end_time IS NULL THEN ps.resume_time
+      ELSE MIN(ps.resume_time, a.end_time)
+    END AS pause_to,
+    CASE WHEN ps.resume_time IS NULL AND a.end_time IS NULL THEN ps.pause_time END AS open_pause_time
+  FROM adjusted a
+  JOIN pause_spans ps ON ps.start_event_id = a.start_event_id
+), entry_pauses AS (
+  SELECT
+    start_event_id,
+    SUM(
+      CASE
+        WHEN pause_to IS NOT NULL AND pause_to > pause_from
+        THEN CAST(ROUND((julianday(pause_to) - julianday(pause_from)) * 86400) AS INTEGER)
+        ELSE 0
+      END
+    ) AS paused_seconds,
+    MAX(open_pause_time) AS paused_at
+  FROM clamped_pauses
+  GROUP BY start_event_id
+)
+SELECT
+  a.task_id,
+  a.start_event_id,
+  a.start_time,
+  a.end_time,
+  CASE
+    WHEN a.end_time IS NOT NULL
+    THEN CAST(ROUND((julianday(a.end_time) - julianday(a.start_time)) * 86400) AS INTEGER)
+      - COALESCE(ep.paused_seconds, 0)
+    ELSE NULL
+  END AS duration_in_seconds,
+  a.next_start_time,
+  a.is_voided,
+  a.notes,
+  COALESCE(ep.paused_seconds, 0) AS paused_seconds,
+  ep.paused_at
+FROM adjusted a
+LEFT JOIN entry_pauses ep ON ep.start_event_id = a.start_event_id;
+
+-- 時間エントリビュー（無効化された区間を除外）
+CREATE VIEW time_entries_view AS
+SELECT
+  task_id,
+  start_event_id,
+  start_time,
+  end_time,
+  duration_in_seconds,
+  next_start_time,
+  notes,
+  paused_seconds,
+  paused_at
+FROM time_entries_all_view
+WHERE is_voided = 0;
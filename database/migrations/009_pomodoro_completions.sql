@@ -0,0 +1,13 @@
+-- 完了したポモドーロ（作業フェーズ）の記録
+-- セッションの進行状態はアプリケーション内で保持し、完了した作業フェーズのみを永続化する
+
+CREATE TABLE IF NOT EXISTS pomodoro_completions (
+  id INTEGER PRIMARY KEY,
+  task_id INTEGER NOT NULL,
+  started_at TEXT NOT NULL,
+  completed_at TEXT NOT NULL,
+  work_seconds INTEGER NOT NULL,
+  FOREIGN KEY(task_id) REFERENCES tasks(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pomodoro_completions_task ON pomodoro_completions(task_id);
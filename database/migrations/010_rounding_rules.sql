@@ -0,0 +1,14 @@
+-- 作業時間の丸め規則（請求用の集計にのみ使い、time_entry_events は変更しない）
+-- project_id が NULL の行はワークスペース全体の既定、それ以外はプロジェクトごとの上書き
+
+CREATE TABLE IF NOT EXISTS rounding_rules (
+  id INTEGER PRIMARY KEY,
+  project_id INTEGER,
+  mode TEXT NOT NULL CHECK(mode IN ('none','up','down','nearest')),
+  increment_seconds INTEGER NOT NULL CHECK(increment_seconds >= 0),
+  scope TEXT NOT NULL CHECK(scope IN ('entry','day')),
+  updated_at TEXT NOT NULL,
+  FOREIGN KEY(project_id) REFERENCES projects(id)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_rounding_rules_project ON rounding_rules(IFNULL(project_id, 0));
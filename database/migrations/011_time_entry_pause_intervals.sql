@@ -0,0 +1,36 @@
+-- 時間区間ごとの一時停止していた区間
+-- 日・週ごとの集計で一時停止の時間を実際に一時停止していた日から差し引くために使う
+-- time_entries_all_view の clamped_pauses と同じく区間内に切り詰め、一時停止中（終わりのない）区間は含めない
+
+CREATE VIEW IF NOT EXISTS time_entry_pauses_view AS
+WITH pause_spans AS (
+  SELECT
+    pz.start_event_id,
+    pz.at AS pause_time,
+    (
+      SELECT r.at
+      FROM time_entry_events r
+      WHERE r.event_type = 'resume'
+        AND r.start_event_id = pz.start_event_id
+        AND (r.at > pz.at OR (r.at = pz.at AND r.id > pz.id))
+      ORDER BY r.at, r.id
+      LIMIT 1
+    ) AS resume_time
+  FROM time_entry_events pz
+  WHERE pz.event_type = 'pause'
+    AND pz.start_event_id IS NOT NULL
+), clamped AS (
+  SELECT
+    e.start_event_id,
+    MAX(ps.pause_time, e.start_time) AS pause_from,
+    CASE
+      WHEN ps.resume_time IS NULL THEN e.end_time
+      WHEN e.end_time IS NULL THEN ps.resume_time
+      ELSE MIN(ps.resume_time, e.end_time)
+    END AS pause_to
+  FROM time_entries_all_view e
+  JOIN pause_spans ps ON ps.start_event_id = e.start_event_id
+)
+SELECT start_event_id, pause_from, pause_to
+FROM clamped
+WHERE pause_to IS NOT NULL AND pause_to > pause_from;
@@ -0,0 +1,42 @@
+use crate::application::services::BackupSummary;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// バックアップファイルのパスを解析
+pub fn parse_backup_path(path: &str) -> anyhow::Result<PathBuf> {
+    if path.trim().is_empty() {
+        return Err(anyhow::anyhow!("Backup path is required"));
+    }
+    Ok(PathBuf::from(path))
+}
+
+/// テーブルごとの行数レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupTableResponse {
+    pub name: String,
+    pub row_count: usize,
+}
+
+/// バックアップの書き出し・取り込み結果レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupResponse {
+    pub path: String,
+    pub schema_version: i64,
+    pub tables: Vec<BackupTableResponse>,
+    pub total_rows: usize,
+}
+
+impl From<BackupSummary> for BackupResponse {
+    fn from(summary: BackupSummary) -> Self {
+        Self {
+            path: summary.path.display().to_string(),
+            schema_version: summary.schema_version,
+            total_rows: summary.table_rows.iter().map(|(_, count)| count).sum(),
+            tables: summary
+                .table_rows
+                .into_iter()
+                .map(|(name, row_count)| BackupTableResponse { name, row_count })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use super::time_entry_dto::{format_duration_seconds, parse_date};
+use crate::application::services::{
+    CsvFormat, ExportEntryFilter, ExportSummary, ExportTimeEntriesCsvCommand, ExportTimeEntriesIcsCommand,
+};
+use crate::domain::value_objects::{TagId, TaskId};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// 時間エントリのCSVエクスポートリクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportTimeEntriesCsvRequest {
+    pub start_date: String,       // YYYY-MM-DD形式
+    pub end_date: Option<String>, // YYYY-MM-DD形式（この日を含む、未指定時はstart_dateと同日）
+    pub project_id: Option<i64>,
+    pub task_id: Option<i64>,
+    pub tag_id: Option<i64>,
+    pub path: String,
+    pub delimiter: Option<char>,       // 未指定時は ","
+    pub always_quote: Option<bool>,    // 未指定時は必要なフィールドのみ引用符で囲む
+    pub escape_formulas: Option<bool>, // 未指定時は数式として解釈されるフィールドをエスケープする
+    pub timezone: Option<String>,      // IANAタイムゾーン名（未指定時は設定のカレンダー）
+}
+
+/// 時間エントリのiCalendarエクスポートリクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportTimeEntriesIcsRequest {
+    pub start_date: String,       // YYYY-MM-DD形式
+    pub end_date: Option<String>, // YYYY-MM-DD形式（この日を含む、未指定時はstart_dateと同日）
+    pub project_id: Option<i64>,
+    pub task_id: Option<i64>,
+    pub tag_id: Option<i64>,
+    pub path: String,
+    pub include_running: Option<bool>, // 未指定時は実行中のエントリを除外する
+}
+
+/// 期間と絞り込み条件を解析
+fn entry_filter(
+    start_date: &str,
+    end_date: Option<&str>,
+    project_id: Option<i64>,
+    task_id: Option<i64>,
+    tag_id: Option<i64>,
+) -> anyhow::Result<ExportEntryFilter> {
+    let start_date = parse_date(start_date)?;
+    Ok(ExportEntryFilter {
+        start_date,
+        end_date: end_date.map(parse_date).transpose()?.unwrap_or(start_date),
+        project_id,
+        task_id: task_id.map(TaskId::new).transpose()?,
+        tag_id: tag_id.map(TagId::new).transpose()?,
+    })
+}
+
+fn parse_export_path(path: String) -> anyhow::Result<PathBuf> {
+    if path.trim().is_empty() {
+        return Err(anyhow::anyhow!("Export path is required"));
+    }
+    Ok(PathBuf::from(path))
+}
+
+impl ExportTimeEntriesCsvRequest {
+    pub fn to_command(self) -> anyhow::Result<ExportTimeEntriesCsvCommand> {
+        Ok(ExportTimeEntriesCsvCommand {
+            filter: entry_filter(
+                &self.start_date,
+                self.end_date.as_deref(),
+                self.project_id,
+                self.task_id,
+                self.tag_id,
+            )?,
+            path: parse_export_path(self.path)?,
+            format: CsvFormat::new(
+                self.delimiter.unwrap_or(','),
+                self.always_quote.unwrap_or(false),
+                self.escape_formulas.unwrap_or(true),
+            )?,
+            timezone: self
+                .timezone
+                .map(|name| name.parse::<Tz>().map_err(|_| anyhow::anyhow!("Invalid timezone: {}", name)))
+                .transpose()?,
+        })
+    }
+}
+
+impl ExportTimeEntriesIcsRequest {
+    pub fn to_command(self) -> anyhow::Result<ExportTimeEntriesIcsCommand> {
+        Ok(ExportTimeEntriesIcsCommand {
+            filter: entry_filter(
+                &self.start_date,
+                self.end_date.as_deref(),
+                self.project_id,
+                self.task_id,
+                self.tag_id,
+            )?,
+            path: parse_export_path(self.path)?,
+            include_running: self.include_running.unwrap_or(false),
+        })
+    }
+}
+
+/// エクスポート結果レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportResponse {
+    pub path: String,
+    pub row_count: usize,
+    pub total_seconds: i64,
+    pub total_duration_formatted: String, // HH:MM:SS形式
+}
+
+impl From<ExportSummary> for ExportResponse {
+    fn from(summary: ExportSummary) -> Self {
+        Self {
+            path: summary.path.display().to_string(),
+            row_count: summary.row_count,
+            total_seconds: summary.total_seconds,
+            total_duration_formatted: format_duration_seconds(summary.total_seconds),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn CSVエクスポートリクエストがコマンドに変換されること() {
+        let request = ExportTimeEntriesCsvRequest {
+            start_date: "2025-03-01".to_string(),
+            end_date: Some("2025-03-31".to_string()),
+            project_id: Some(2),
+            task_id: None,
+            tag_id: None,
+            path: "/tmp/march.csv".to_string(),
+            delimiter: Some(';'),
+            always_quote: None,
+            escape_formulas: None,
+            timezone: Some("Asia/Tokyo".to_string()),
+        };
+        let command = request.clone().to_command().unwrap();
+
+        assert_eq!(command.filter.start_date, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+        assert_eq!(command.filter.end_date, NaiveDate::from_ymd_opt(2025, 3, 31).unwrap());
+        assert_eq!(command.format, CsvFormat::new(';', false, true).unwrap());
+        assert_eq!(command.timezone, Some(Tz::Asia__Tokyo));
+
+        let invalid = ExportTimeEntriesCsvRequest {
+            timezone: Some("Mars/Olympus".to_string()),
+            ..request.clone()
+        };
+        assert!(invalid.to_command().is_err());
+        let invalid = ExportTimeEntriesCsvRequest { path: " ".to_string(), ..request };
+        assert!(invalid.to_command().is_err());
+    }
+}
@@ -0,0 +1,215 @@
+use crate::application::services::{CsvColumnMapping, ImportReport, ImportTimeEntriesCsvCommand, ImportTogglCsvCommand};
+use chrono::format::{Item, StrftimeItems};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Togglの詳細レポートCSVの取り込みリクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportTogglCsvRequest {
+    pub path: String,
+    pub timezone: Option<String>, // IANAタイムゾーン名（未指定時は設定のカレンダー）
+    pub dry_run: Option<bool>,    // 未指定時は取り込む
+}
+
+/// 汎用CSVの列の対応付け（ヘッダー行の列名）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvColumnMappingDto {
+    pub project: String,
+    pub task: String,
+    pub start: String,              // 開始日時（日付と時刻が別の列の場合は日付）の列
+    pub start_time: Option<String>, // 開始時刻の列
+    pub end: Option<String>,        // 終了日時の列（未指定でend_timeがある場合は開始日の列）
+    pub end_time: Option<String>,   // 終了時刻の列
+    pub duration: Option<String>,   // 所要時間（HH:MM[:SS] または時間単位の小数）の列
+    pub notes: Option<String>,
+    pub tags: Option<String>, // カンマ区切り
+}
+
+impl From<CsvColumnMappingDto> for CsvColumnMapping {
+    fn from(dto: CsvColumnMappingDto) -> Self {
+        Self {
+            project: dto.project,
+            task: dto.task,
+            start: dto.start,
+            start_time: dto.start_time,
+            end: dto.end,
+            end_time: dto.end_time,
+            duration: dto.duration,
+            notes: dto.notes,
+            tags: dto.tags,
+        }
+    }
+}
+
+/// 汎用CSVの取り込みリクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportTimeEntriesCsvRequest {
+    pub path: String,
+    pub mapping: CsvColumnMappingDto,
+    pub delimiter: Option<char>,               // 未指定時は ","
+    pub datetime_formats: Option<Vec<String>>, // strftime形式（未指定時は YYYY-MM-DD HH:MM[:SS]）
+    pub timezone: Option<String>,              // IANAタイムゾーン名（未指定時は設定のカレンダー）
+    pub dry_run: Option<bool>,                 // 未指定時は取り込む
+}
+
+/// 取り込むファイルのパスを解析
+fn parse_import_path(path: String) -> anyhow::Result<PathBuf> {
+    if path.trim().is_empty() {
+        return Err(anyhow::anyhow!("Import path is required"));
+    }
+    Ok(PathBuf::from(path))
+}
+
+/// タイムゾーン名を解析
+fn parse_timezone(timezone: Option<String>) -> anyhow::Result<Option<Tz>> {
+    timezone
+        .map(|name| name.parse::<Tz>().map_err(|_| anyhow::anyhow!("Invalid timezone: {}", name)))
+        .transpose()
+}
+
+impl ImportTogglCsvRequest {
+    pub fn to_command(self) -> anyhow::Result<ImportTogglCsvCommand> {
+        Ok(ImportTogglCsvCommand {
+            path: parse_import_path(self.path)?,
+            timezone: parse_timezone(self.timezone)?,
+            dry_run: self.dry_run.unwrap_or(false),
+        })
+    }
+}
+
+impl ImportTimeEntriesCsvRequest {
+    pub fn to_command(self) -> anyhow::Result<ImportTimeEntriesCsvCommand> {
+        let datetime_formats = self.datetime_formats.unwrap_or_default();
+        if let Some(format) = datetime_formats
+            .iter()
+            .find(|format| format.trim().is_empty() || StrftimeItems::new(format).any(|item| item == Item::Error))
+        {
+            return Err(anyhow::anyhow!("Invalid date time format: {}", format));
+        }
+
+        Ok(ImportTimeEntriesCsvCommand {
+            path: parse_import_path(self.path)?,
+            mapping: self.mapping.into(),
+            delimiter: self.delimiter.unwrap_or(','),
+            datetime_formats,
+            timezone: parse_timezone(self.timezone)?,
+            dry_run: self.dry_run.unwrap_or(false),
+        })
+    }
+}
+
+/// 作成した（dry-run の場合は作成する）タスクのレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedTaskResponse {
+    pub project_name: String,
+    pub task_name: String,
+}
+
+/// 取り込まなかった行のレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedRowResponse {
+    pub line: usize,
+    pub message: String,
+}
+
+/// 取り込み結果レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportResponse {
+    pub path: String,
+    pub dry_run: bool,
+    pub row_count: usize,
+    pub imported_count: usize,
+    pub imported_seconds: i64,
+    pub created_projects: Vec<String>,
+    pub created_tasks: Vec<ImportedTaskResponse>,
+    pub created_tags: Vec<String>,
+    pub skipped_rows: Vec<SkippedRowResponse>,
+}
+
+impl From<ImportReport> for ImportResponse {
+    fn from(report: ImportReport) -> Self {
+        Self {
+            path: report.path.display().to_string(),
+            dry_run: report.dry_run,
+            row_count: report.row_count,
+            imported_count: report.imported_count,
+            imported_seconds: report.imported_seconds,
+            created_projects: report.created_projects,
+            created_tasks: report
+                .created_tasks
+                .into_iter()
+                .map(|(project_name, task_name)| ImportedTaskResponse { project_name, task_name })
+                .collect(),
+            created_tags: report.created_tags,
+            skipped_rows: report
+                .skipped_rows
+                .into_iter()
+                .map(|row| SkippedRowResponse {
+                    line: row.line,
+                    message: row.message,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn Toggl取り込みリクエストがコマンドに変換されること() {
+        let request = ImportTogglCsvRequest {
+            path: "/tmp/toggl.csv".to_string(),
+            timezone: Some("Europe/Berlin".to_string()),
+            dry_run: None,
+        };
+        let command = request.clone().to_command().unwrap();
+
+        assert_eq!(command.path, PathBuf::from("/tmp/toggl.csv"));
+        assert_eq!(command.timezone, Some(Tz::Europe__Berlin));
+        assert!(!command.dry_run);
+
+        let invalid = ImportTogglCsvRequest {
+            timezone: Some("Mars/Olympus".to_string()),
+            ..request.clone()
+        };
+        assert!(invalid.to_command().is_err());
+        let invalid = ImportTogglCsvRequest { path: "".to_string(), ..request };
+        assert!(invalid.to_command().is_err());
+    }
+
+    #[test]
+    fn 日時の書式が不正な汎用CSV取り込みリクエストはエラーになること() {
+        let request = ImportTimeEntriesCsvRequest {
+            path: "/tmp/harvest.csv".to_string(),
+            mapping: CsvColumnMappingDto {
+                project: "Project".to_string(),
+                task: "Task".to_string(),
+                start: "Date".to_string(),
+                start_time: Some("Started At".to_string()),
+                end: None,
+                end_time: None,
+                duration: Some("Hours".to_string()),
+                notes: Some("Notes".to_string()),
+                tags: None,
+            },
+            delimiter: None,
+            datetime_formats: Some(vec!["%Y-%m-%d %H:%M".to_string()]),
+            timezone: None,
+            dry_run: Some(true),
+        };
+        let command = request.clone().to_command().unwrap();
+        assert_eq!(command.delimiter, ',');
+        assert_eq!(command.mapping.duration.as_deref(), Some("Hours"));
+        assert!(command.dry_run);
+
+        let invalid = ImportTimeEntriesCsvRequest {
+            datetime_formats: Some(vec!["%Y-%m-%d %Q".to_string()]),
+            ..request
+        };
+        assert!(invalid.to_command().is_err());
+    }
+}
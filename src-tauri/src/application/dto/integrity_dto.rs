@@ -0,0 +1,150 @@
+use super::time_entry_dto::format_datetime;
+use super::TimeEntryEventResponse;
+use crate::application::use_cases::{CheckIntegrityCommand, RepairTimeEntriesCommand, RepairTimeEntriesResult};
+use crate::domain::entities::RepairReason;
+use crate::domain::services::{IntegrityReport, TimeEntryAnomaly};
+use serde::{Deserialize, Serialize};
+
+/// 整合性チェックリクエスト
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CheckTimeEntryIntegrityRequest {
+    pub max_running_seconds: Option<i64>, // 長時間実行とみなす秒数（未指定時は12時間）
+}
+
+impl CheckTimeEntryIntegrityRequest {
+    pub fn to_command(self) -> anyhow::Result<CheckIntegrityCommand> {
+        Ok(CheckIntegrityCommand {
+            max_running_seconds: self.max_running_seconds,
+        })
+    }
+}
+
+/// 整合性補正リクエスト
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepairTimeEntriesRequest {
+    pub reasons: Option<Vec<String>>, // implicit_stop / backfill_start / auto_cutoff（未指定時は全て）
+    pub max_running_seconds: Option<i64>,
+}
+
+impl RepairTimeEntriesRequest {
+    pub fn to_command(self) -> anyhow::Result<RepairTimeEntriesCommand> {
+        let reasons = match self.reasons {
+            Some(reasons) => reasons
+                .iter()
+                .map(|s| s.parse::<RepairReason>())
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            None => vec![
+                RepairReason::ImplicitStop,
+                RepairReason::BackfillStart,
+                RepairReason::AutoCutoff,
+            ],
+        };
+
+        Ok(RepairTimeEntriesCommand {
+            reasons,
+            max_running_seconds: self.max_running_seconds,
+        })
+    }
+}
+
+/// 整合性異常レスポンス
+/// start_time / end_time は補正後に想定される区間（孤立STOPで補完できない場合 start_time はNone）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntryAnomalyResponse {
+    pub kind: String,
+    pub task_id: i64,
+    pub event_id: i64, // 異常の起点となるイベント（開始または停止）
+    pub start_time: Option<String>,
+    pub end_time: String,
+    pub repair_reason: Option<String>,
+}
+
+impl From<TimeEntryAnomaly> for TimeEntryAnomalyResponse {
+    fn from(anomaly: TimeEntryAnomaly) -> Self {
+        let (event_id, start_time, end_time) = match &anomaly {
+            TimeEntryAnomaly::DuplicateStart { start_event_id, start_time, next_start_time, .. } => {
+                (*start_event_id, Some(*start_time), *next_start_time)
+            }
+            TimeEntryAnomaly::OrphanStop { stop_event_id, stop_time, backfill_start_time, .. } => {
+                (*stop_event_id, *backfill_start_time, *stop_time)
+            }
+            TimeEntryAnomaly::ReversedInterval { start_event_id, start_time, end_time, .. } => {
+                (*start_event_id, Some(*start_time), *end_time)
+            }
+            TimeEntryAnomaly::LongRunning { start_event_id, start_time, cutoff_time, .. } => {
+                (*start_event_id, Some(*start_time), *cutoff_time)
+            }
+        };
+
+        Self {
+            kind: anomaly.kind().to_string(),
+            task_id: i64::from(anomaly.task_id()),
+            event_id,
+            start_time: start_time.map(format_datetime),
+            end_time: format_datetime(end_time),
+            repair_reason: anomaly.repair_reason().map(|r| r.as_str().to_string()),
+        }
+    }
+}
+
+/// 整合性チェック結果レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityReportResponse {
+    pub checked_at: String, // ISO 8601形式
+    pub event_count: usize,
+    pub repairable_count: usize,
+    pub anomalies: Vec<TimeEntryAnomalyResponse>,
+}
+
+impl From<IntegrityReport> for IntegrityReportResponse {
+    fn from(report: IntegrityReport) -> Self {
+        Self {
+            checked_at: format_datetime(report.checked_at),
+            event_count: report.event_count,
+            repairable_count: report.repairable_count(),
+            anomalies: report.anomalies.into_iter().map(TimeEntryAnomalyResponse::from).collect(),
+        }
+    }
+}
+
+/// 整合性補正結果レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepairTimeEntriesResponse {
+    pub repair_events: Vec<TimeEntryEventResponse>,
+    pub report: IntegrityReportResponse,
+}
+
+impl From<RepairTimeEntriesResult> for RepairTimeEntriesResponse {
+    fn from(result: RepairTimeEntriesResult) -> Self {
+        Self {
+            repair_events: result.repair_events.into_iter().map(TimeEntryEventResponse::from).collect(),
+            report: IntegrityReportResponse::from(result.report),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn 補正理由の指定が変換されること() {
+        let command = RepairTimeEntriesRequest {
+            reasons: Some(vec!["auto_cutoff".to_string()]),
+            max_running_seconds: Some(3600),
+        }.to_command().unwrap();
+        assert_eq!(command.reasons, vec![RepairReason::AutoCutoff]);
+        assert_eq!(command.max_running_seconds, Some(3600));
+
+        // 未指定時は全ての理由
+        let command = RepairTimeEntriesRequest::default().to_command().unwrap();
+        assert_eq!(command.reasons.len(), 3);
+
+        let result = RepairTimeEntriesRequest {
+            reasons: Some(vec!["unknown".to_string()]),
+            max_running_seconds: None,
+        }.to_command();
+        assert!(result.is_err());
+    }
+}
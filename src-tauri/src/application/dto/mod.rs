@@ -3,8 +3,30 @@
 pub mod project_dto;
 pub mod task_dto;
 pub mod time_entry_dto;
+pub mod tag_dto;
+pub mod integrity_dto;
+pub mod recovery_dto;
+pub mod pomodoro_dto;
+pub mod report_dto;
+pub mod timesheet_dto;
+pub mod timeline_dto;
+pub mod rounding_dto;
+pub mod export_dto;
+pub mod backup_dto;
+pub mod import_dto;
 
 pub use project_dto::*;
 pub use task_dto::*;
 pub use time_entry_dto::*;
+pub use tag_dto::*;
+pub use integrity_dto::*;
+pub use recovery_dto::*;
+pub use pomodoro_dto::*;
+pub use report_dto::*;
+pub use timesheet_dto::*;
+pub use timeline_dto::*;
+pub use rounding_dto::*;
+pub use export_dto::*;
+pub use backup_dto::*;
+pub use import_dto::*;
 
@@ -0,0 +1,123 @@
+use super::time_entry_dto::format_datetime;
+use crate::application::use_cases::StartPomodoroCommand;
+use crate::domain::entities::{PomodoroPhaseChange, PomodoroSession};
+use crate::domain::value_objects::TaskId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// ポモドーロ開始リクエスト（未指定の長さは既定の設定を使う）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartPomodoroRequest {
+    pub task_id: i64,
+    pub work_seconds: Option<i64>,
+    pub short_break_seconds: Option<i64>,
+    pub long_break_seconds: Option<i64>,
+    pub long_break_interval: Option<u32>,
+}
+
+impl StartPomodoroRequest {
+    pub fn to_command(self) -> anyhow::Result<StartPomodoroCommand> {
+        Ok(StartPomodoroCommand {
+            task_id: TaskId::new(self.task_id)?,
+            work_seconds: self.work_seconds,
+            short_break_seconds: self.short_break_seconds,
+            long_break_seconds: self.long_break_seconds,
+            long_break_interval: self.long_break_interval,
+        })
+    }
+}
+
+/// ポモドーロセッションレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PomodoroSessionResponse {
+    pub task_id: i64,
+    pub phase: String, // work / short_break / long_break
+    pub is_paused: bool,
+    pub phase_started_at: String,      // ISO 8601形式
+    pub phase_ends_at: Option<String>, // ISO 8601形式（一時停止中はNone）
+    pub remaining_seconds: i64,
+    pub completed_count: u32,
+    pub work_seconds: i64,
+    pub short_break_seconds: i64,
+    pub long_break_seconds: i64,
+    pub long_break_interval: u32,
+}
+
+impl PomodoroSessionResponse {
+    pub fn new(session: &PomodoroSession, now: DateTime<Utc>) -> Self {
+        let settings = session.settings();
+        Self {
+            task_id: i64::from(session.task_id()),
+            phase: session.phase().as_str().to_string(),
+            is_paused: session.is_paused(),
+            phase_started_at: format_datetime(session.phase_started_at()),
+            phase_ends_at: session.phase_ends_at(now).map(format_datetime),
+            remaining_seconds: session.remaining_seconds(now),
+            completed_count: session.completed_count(),
+            work_seconds: settings.work_seconds(),
+            short_break_seconds: settings.short_break_seconds(),
+            long_break_seconds: settings.long_break_seconds(),
+            long_break_interval: settings.long_break_interval(),
+        }
+    }
+}
+
+/// ポモドーロのフェーズ遷移レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PomodoroPhaseChangeResponse {
+    pub task_id: i64,
+    pub from_phase: String,
+    pub to_phase: String,
+    pub completed: bool,
+    pub completed_count: u32,
+    pub at: String, // ISO 8601形式
+}
+
+impl From<PomodoroPhaseChange> for PomodoroPhaseChangeResponse {
+    fn from(change: PomodoroPhaseChange) -> Self {
+        Self {
+            task_id: i64::from(change.task_id),
+            from_phase: change.from.as_str().to_string(),
+            to_phase: change.to.as_str().to_string(),
+            completed: change.completed,
+            completed_count: change.completed_count,
+            at: format_datetime(change.at),
+        }
+    }
+}
+
+/// ポモドーロセッション終了レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PomodoroEndedResponse {
+    pub task_id: i64,
+    pub reason: String,
+}
+
+impl PomodoroEndedResponse {
+    pub fn new(task_id: TaskId, reason: String) -> Self {
+        Self {
+            task_id: i64::from(task_id),
+            reason,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::PomodoroSettings;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn セッションレスポンスに残り時間と終了予定が含まれること() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let session = PomodoroSession::start(TaskId::new(1).unwrap(), PomodoroSettings::default(), start);
+
+        let response = PomodoroSessionResponse::new(&session, start + Duration::minutes(10));
+        assert_eq!(response.phase, "work");
+        assert_eq!(response.remaining_seconds, 15 * 60);
+        assert_eq!(response.phase_ends_at, Some("2024-01-01T09:25:00Z".to_string()));
+        assert_eq!(response.long_break_interval, 4);
+    }
+}
@@ -0,0 +1,84 @@
+use super::time_entry_dto::{format_datetime, parse_datetime};
+use super::TimeEntryResponse;
+use crate::application::use_cases::ResolveRecoveryCommand;
+use crate::domain::services::{PendingRecovery, RecoveryResolution};
+use serde::{Deserialize, Serialize};
+
+/// 復旧解決リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolveRecoveryRequest {
+    pub start_event_id: i64,
+    pub action: String,            // keep / stop_at_last_heartbeat / stop_at
+    pub stop_time: Option<String>, // ISO 8601形式（action が stop_at の場合に必須）
+}
+
+impl ResolveRecoveryRequest {
+    pub fn to_command(self) -> anyhow::Result<ResolveRecoveryCommand> {
+        let resolution = match self.action.as_str() {
+            "keep" => RecoveryResolution::Keep,
+            "stop_at_last_heartbeat" => RecoveryResolution::StopAtLastHeartbeat,
+            "stop_at" => {
+                let stop_time = self
+                    .stop_time
+                    .ok_or_else(|| anyhow::anyhow!("Stop time is required"))?;
+                RecoveryResolution::StopAt(parse_datetime(&stop_time)?)
+            }
+            action => return Err(anyhow::anyhow!("Invalid recovery action: {}", action)),
+        };
+
+        Ok(ResolveRecoveryCommand {
+            start_event_id: self.start_event_id,
+            resolution,
+        })
+    }
+}
+
+/// 復旧待ち区間レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingRecoveryResponse {
+    pub entry: TimeEntryResponse,
+    pub last_heartbeat: String, // ISO 8601形式
+    pub detected_at: String,    // ISO 8601形式
+}
+
+impl From<PendingRecovery> for PendingRecoveryResponse {
+    fn from(recovery: PendingRecovery) -> Self {
+        Self {
+            entry: TimeEntryResponse::from(recovery.entry),
+            last_heartbeat: format_datetime(recovery.last_heartbeat),
+            detected_at: format_datetime(recovery.detected_at),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn request(action: &str, stop_time: Option<&str>) -> ResolveRecoveryRequest {
+        ResolveRecoveryRequest {
+            start_event_id: 1,
+            action: action.to_string(),
+            stop_time: stop_time.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn 復旧方法が変換されること() {
+        assert_eq!(request("keep", None).to_command().unwrap().resolution, RecoveryResolution::Keep);
+        assert_eq!(
+            request("stop_at_last_heartbeat", None).to_command().unwrap().resolution,
+            RecoveryResolution::StopAtLastHeartbeat
+        );
+        assert_eq!(
+            request("stop_at", Some("2024-01-01T18:00:00Z")).to_command().unwrap().resolution,
+            RecoveryResolution::StopAt(Utc.with_ymd_and_hms(2024, 1, 1, 18, 0, 0).unwrap())
+        );
+
+        // 停止時刻の指定漏れや不明な方法はエラー
+        assert!(request("stop_at", None).to_command().is_err());
+        assert!(request("discard", None).to_command().is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use super::time_entry_dto::{format_datetime, format_duration_seconds, parse_datetime};
+use crate::application::services::{Report, ReportGroup};
+use crate::domain::repositories::{ReportDimension, ReportGranularity, ReportKey, ReportQuery};
+use crate::domain::services::LocalCalendar;
+use crate::domain::value_objects::{ProjectId, TagId, TaskId};
+use serde::{Deserialize, Serialize};
+
+/// レポート作成リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateReportRequest {
+    pub start_time: String,               // ISO 8601形式（この時刻を含む）
+    pub end_time: String,                 // ISO 8601形式（この時刻を含まない）
+    pub granularity: String,              // day / week / month
+    pub group_by: Option<Vec<String>>,    // project / task / tag（指定順に入れ子にする）
+    pub project_ids: Option<Vec<i64>>,
+    pub task_ids: Option<Vec<i64>>,
+    pub tag_ids: Option<Vec<i64>>,
+    pub include_archived: Option<bool>,
+}
+
+impl GenerateReportRequest {
+    /// 集計条件に変換（日・週・月の区切りは calendar で判定する）
+    pub fn to_query(self, calendar: LocalCalendar) -> anyhow::Result<ReportQuery> {
+        Ok(ReportQuery {
+            start: parse_datetime(&self.start_time)?,
+            end: parse_datetime(&self.end_time)?,
+            granularity: self.granularity.parse::<ReportGranularity>()?,
+            group_by: self
+                .group_by
+                .unwrap_or_default()
+                .iter()
+                .map(|dimension| dimension.parse::<ReportDimension>())
+                .collect::<anyhow::Result<_>>()?,
+            project_ids: self
+                .project_ids
+                .unwrap_or_default()
+                .into_iter()
+                .map(ProjectId::new)
+                .collect::<anyhow::Result<_>>()?,
+            task_ids: self
+                .task_ids
+                .unwrap_or_default()
+                .into_iter()
+                .map(TaskId::new)
+                .collect::<anyhow::Result<_>>()?,
+            tag_ids: self
+                .tag_ids
+                .unwrap_or_default()
+                .into_iter()
+                .map(TagId::new)
+                .collect::<anyhow::Result<_>>()?,
+            include_archived: self.include_archived.unwrap_or(false),
+            calendar,
+        })
+    }
+}
+
+/// レポートのグループレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportGroupResponse {
+    pub dimension: String,            // day / week / month / project / task / tag
+    pub period_start: Option<String>, // YYYY-MM-DD形式（期間のグループのみ）
+    pub id: Option<i64>,              // プロジェクト/タスク/タグのID（タグなしはNone）
+    pub name: Option<String>,
+    pub total_seconds: i64,
+    pub total_duration_formatted: String, // HH:MM:SS形式
+    pub rounded_seconds: i64,             // 丸め設定を適用した合計
+    pub rounded_duration_formatted: String, // HH:MM:SS形式
+    pub entry_count: usize,
+    pub children: Vec<ReportGroupResponse>,
+}
+
+/// レポートレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportResponse {
+    pub start_time: String, // ISO 8601形式
+    pub end_time: String,   // ISO 8601形式
+    pub granularity: String,
+    pub group_by: Vec<String>,
+    pub total_seconds: i64,
+    pub total_duration_formatted: String, // HH:MM:SS形式
+    pub rounded_seconds: i64,             // 丸め設定を適用した合計
+    pub rounded_duration_formatted: String, // HH:MM:SS形式
+    pub entry_count: usize,
+    pub groups: Vec<ReportGroupResponse>,
+}
+
+impl ReportGroupResponse {
+    fn new(group: ReportGroup, granularity: ReportGranularity) -> Self {
+        let (dimension, period_start, id) = match group.key {
+            ReportKey::Period(date) => (granularity.as_str(), Some(date.format("%Y-%m-%d").to_string()), None),
+            ReportKey::Project(project_id) => (ReportDimension::Project.as_str(), None, Some(i64::from(project_id))),
+            ReportKey::Task(task_id) => (ReportDimension::Task.as_str(), None, Some(i64::from(task_id))),
+            ReportKey::Tag(tag_id) => (ReportDimension::Tag.as_str(), None, tag_id.map(i64::from)),
+        };
+
+        Self {
+            dimension: dimension.to_string(),
+            period_start,
+            id,
+            name: group.name,
+            total_seconds: group.total_seconds,
+            total_duration_formatted: format_duration_seconds(group.total_seconds),
+            rounded_seconds: group.rounded_seconds,
+            rounded_duration_formatted: format_duration_seconds(group.rounded_seconds),
+            entry_count: group.entry_count,
+            children: group
+                .children
+                .into_iter()
+                .map(|child| Self::new(child, granularity))
+                .collect(),
+        }
+    }
+}
+
+impl From<Report> for ReportResponse {
+    fn from(report: Report) -> Self {
+        let granularity = report.query.granularity;
+
+        Self {
+            start_time: format_datetime(report.query.start),
+            end_time: format_datetime(report.query.end),
+            granularity: granularity.as_str().to_string(),
+            group_by: report.query.group_by.iter().map(|d| d.as_str().to_string()).collect(),
+            total_seconds: report.total_seconds,
+            total_duration_formatted: format_duration_seconds(report.total_seconds),
+            rounded_seconds: report.rounded_seconds,
+            rounded_duration_formatted: format_duration_seconds(report.rounded_seconds),
+            entry_count: report.entry_count,
+            groups: report
+                .groups
+                .into_iter()
+                .map(|group| ReportGroupResponse::new(group, granularity))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::Weekday;
+
+    fn request(granularity: &str, group_by: &[&str]) -> GenerateReportRequest {
+        GenerateReportRequest {
+            start_time: "2025-03-01T00:00:00Z".to_string(),
+            end_time: "2025-04-01T00:00:00Z".to_string(),
+            granularity: granularity.to_string(),
+            group_by: Some(group_by.iter().map(|s| s.to_string()).collect()),
+            project_ids: None,
+            task_ids: Some(vec![1]),
+            tag_ids: None,
+            include_archived: None,
+        }
+    }
+
+    #[test]
+    fn レポート作成リクエストが集計条件に変換されること() {
+        let calendar = LocalCalendar::from_name("Asia/Tokyo", Weekday::Sun).unwrap();
+        let query = request("week", &["project", "tag"]).to_query(calendar).unwrap();
+
+        assert_eq!(query.granularity, ReportGranularity::Week);
+        assert_eq!(query.group_by, vec![ReportDimension::Project, ReportDimension::Tag]);
+        assert_eq!(query.task_ids, vec![TaskId::new(1).unwrap()]);
+        assert!(query.project_ids.is_empty());
+        assert!(!query.include_archived);
+        assert_eq!(query.calendar, calendar);
+
+        // 不明な単位や軸はエラー
+        assert!(request("year", &[]).to_query(calendar).is_err());
+        assert!(request("day", &["client"]).to_query(calendar).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use crate::application::use_cases::SetRoundingRuleCommand;
+use crate::domain::services::RoundingPolicy;
+use crate::domain::value_objects::{ProjectId, RoundingMode, RoundingRule, RoundingScope};
+use serde::{Deserialize, Serialize};
+
+/// 丸め規則DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundingRuleDto {
+    pub mode: String, // "none" | "up" | "down" | "nearest"
+    pub increment_seconds: i64,
+    pub scope: String, // "entry" | "day"
+}
+
+impl RoundingRuleDto {
+    pub fn to_rule(&self) -> anyhow::Result<RoundingRule> {
+        RoundingRule::new(
+            self.mode.parse::<RoundingMode>()?,
+            self.increment_seconds,
+            self.scope.parse::<RoundingScope>()?,
+        )
+    }
+}
+
+impl From<RoundingRule> for RoundingRuleDto {
+    fn from(rule: RoundingRule) -> Self {
+        Self {
+            mode: rule.mode().as_str().to_string(),
+            increment_seconds: rule.increment_seconds(),
+            scope: rule.scope().as_str().to_string(),
+        }
+    }
+}
+
+/// 丸め規則の設定リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetRoundingRuleRequest {
+    pub project_id: Option<i64>,       // 未指定の場合はワークスペース全体の既定
+    pub rule: Option<RoundingRuleDto>, // プロジェクトで未指定の場合は上書きを解除
+}
+
+impl SetRoundingRuleRequest {
+    pub fn to_command(self) -> anyhow::Result<SetRoundingRuleCommand> {
+        Ok(SetRoundingRuleCommand {
+            project_id: self.project_id.map(ProjectId::new).transpose()?,
+            rule: self.rule.as_ref().map(RoundingRuleDto::to_rule).transpose()?,
+        })
+    }
+}
+
+/// プロジェクトごとの丸め規則レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectRoundingRuleResponse {
+    pub project_id: i64,
+    pub rule: RoundingRuleDto,
+}
+
+/// 丸め設定レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundingSettingsResponse {
+    pub default_rule: RoundingRuleDto,
+    pub project_rules: Vec<ProjectRoundingRuleResponse>,
+}
+
+impl From<RoundingPolicy> for RoundingSettingsResponse {
+    fn from(policy: RoundingPolicy) -> Self {
+        Self {
+            default_rule: RoundingRuleDto::from(policy.default_rule),
+            project_rules: policy
+                .project_rules
+                .into_iter()
+                .map(|(project_id, rule)| ProjectRoundingRuleResponse {
+                    project_id: project_id.into(),
+                    rule: RoundingRuleDto::from(rule),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn 丸め規則の設定リクエストがコマンドに変換されること() {
+        let request = SetRoundingRuleRequest {
+            project_id: Some(2),
+            rule: Some(RoundingRuleDto {
+                mode: "up".to_string(),
+                increment_seconds: 900,
+                scope: "day".to_string(),
+            }),
+        };
+        let command = request.clone().to_command().unwrap();
+
+        assert_eq!(command.project_id, Some(ProjectId::new(2).unwrap()));
+        assert_eq!(command.rule, Some(RoundingRule::new(RoundingMode::Up, 900, RoundingScope::Day).unwrap()));
+
+        let invalid = SetRoundingRuleRequest {
+            project_id: None,
+            rule: Some(RoundingRuleDto {
+                mode: "ceil".to_string(),
+                ..request.rule.unwrap()
+            }),
+        };
+        assert!(invalid.to_command().is_err());
+
+        let response = RoundingSettingsResponse::from(RoundingPolicy::default());
+        assert_eq!(response.default_rule.mode, "none");
+        assert!(response.project_rules.is_empty());
+    }
+}
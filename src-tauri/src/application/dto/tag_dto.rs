@@ -0,0 +1,92 @@
+use crate::domain::entities::Tag;
+use crate::domain::value_objects::{TagId, TaskId};
+use serde::{Deserialize, Serialize};
+
+/// タスクへのタグ付与リクエストDTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddTagToTaskRequest {
+    pub task_id: i64,
+    pub tag_name: String,
+}
+
+impl AddTagToTaskRequest {
+    pub fn to_command(&self) -> anyhow::Result<crate::application::use_cases::AddTagToTaskCommand> {
+        let task_id = TaskId::new(self.task_id)?;
+        Ok(crate::application::use_cases::AddTagToTaskCommand {
+            task_id,
+            tag_name: self.tag_name.clone(),
+            at: None,
+        })
+    }
+}
+
+/// タスクからのタグ剥奪リクエストDTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveTagFromTaskRequest {
+    pub task_id: i64,
+    pub tag_id: i64,
+}
+
+impl RemoveTagFromTaskRequest {
+    pub fn to_command(&self) -> anyhow::Result<crate::application::use_cases::RemoveTagFromTaskCommand> {
+        let task_id = TaskId::new(self.task_id)?;
+        let tag_id = TagId::new(self.tag_id)?;
+        Ok(crate::application::use_cases::RemoveTagFromTaskCommand {
+            task_id,
+            tag_id,
+        })
+    }
+}
+
+/// タグレスポンスDTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagDto {
+    pub id: i64,
+    pub name: String,
+}
+
+impl From<Tag> for TagDto {
+    fn from(tag: Tag) -> Self {
+        Self {
+            id: tag.id().value(),
+            name: tag.name().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ドメインからタグDTO変換が正しく動作すること() {
+        let tag = Tag::new(TagId::new(3).unwrap(), "meeting".to_string()).unwrap();
+        let dto = TagDto::from(tag);
+
+        assert_eq!(dto.id, 3);
+        assert_eq!(dto.name, "meeting");
+    }
+
+    #[test]
+    fn タグ付与リクエストからコマンド変換が正しく動作すること() {
+        let request = AddTagToTaskRequest {
+            task_id: 1,
+            tag_name: "#client-a".to_string(),
+        };
+
+        let command = request.to_command().unwrap();
+        assert_eq!(command.task_id.value(), 1);
+        assert_eq!(command.tag_name, "#client-a");
+    }
+
+    #[test]
+    fn 不正なIDのタグ剥奪リクエストが失敗すること() {
+        let request = RemoveTagFromTaskRequest {
+            task_id: 1,
+            tag_id: 0,
+        };
+
+        assert!(request.to_command().is_err());
+    }
+}
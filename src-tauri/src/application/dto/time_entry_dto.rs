@@ -1,18 +1,36 @@
 use crate::domain::entities::time_entry::{TimeEntry, TimeEntryEvent};
-use crate::domain::value_objects::TaskId;
-use chrono::{DateTime, Utc};
+use crate::domain::repositories::TimeEntryCursor;
+use crate::domain::services::LocalCalendar;
+use crate::domain::value_objects::{TagId, TaskId};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 /// タイマー開始リクエスト
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StartTimerRequest {
     pub task_id: i64,
+    pub at: Option<String>, // ISO 8601形式（未指定の場合は現在時刻）
 }
 
 /// タイマー停止リクエスト
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StopTimerRequest {
     pub task_id: i64,
+    pub at: Option<String>, // ISO 8601形式（未指定の場合は現在時刻）
+}
+
+/// タイマー一時停止リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PauseTimerRequest {
+    pub task_id: i64,
+    pub at: Option<String>, // ISO 8601形式（未指定の場合は現在時刻）
+}
+
+/// タイマー再開リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResumeTimerRequest {
+    pub task_id: i64,
+    pub at: Option<String>, // ISO 8601形式（未指定の場合は現在時刻）
 }
 
 /// 手動エントリ追加リクエスト
@@ -24,6 +42,32 @@ pub struct AddManualEntryRequest {
     pub note: Option<String>,
 }
 
+/// 時間エントリ訂正リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTimeEntryRequest {
+    pub start_event_id: i64,
+    pub start_time: Option<String>, // ISO 8601形式
+    pub end_time: Option<String>,   // ISO 8601形式
+}
+
+/// 時間エントリへのメモ追記リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnotateTimeEntryRequest {
+    pub start_event_id: i64,
+    pub note: String,
+}
+
+/// 期間指定の時間エントリ取得リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTimeEntriesRequest {
+    pub start_date: String,         // YYYY-MM-DD形式
+    pub end_date: Option<String>,   // YYYY-MM-DD形式（この日を含む、未指定時はstart_dateと同日）
+    pub project_id: Option<i64>,
+    pub task_id: Option<i64>,
+    pub cursor: Option<String>,     // 前ページのnext_cursor
+    pub limit: Option<usize>,
+}
+
 /// タイムエントリイベントレスポンス
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeEntryEventResponse {
@@ -46,23 +90,51 @@ pub struct TimeEntryResponse {
     pub elapsed_duration: String, // HH:MM:SS形式
     pub is_running: bool,
     pub is_completed: bool,
+    pub notes: Option<String>, // 注釈を発生順に改行区切りで連結
+    pub is_paused: bool,
+    pub paused_seconds: i64, // 一時停止していた秒数（duration_in_seconds からは除外済み）
+}
+
+/// タイムエントリページレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntryPageResponse {
+    pub entries: Vec<TimeEntryResponse>,
+    pub next_cursor: Option<String>,
 }
 
 /// タイマー状態レスポンス
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimerStatusResponse {
     pub is_running: bool,
+    pub is_paused: bool,
+    pub paused_at: Option<String>, // ISO 8601形式（一時停止中のみ）
     pub current_entry: Option<TimeEntryResponse>,
     pub elapsed_seconds: Option<i64>,
     pub elapsed_duration: Option<String>, // HH:MM:SS形式
 }
 
 /// 現在のタイマーレスポンス
+/// task_id / elapsed_* は最後に開始したタイマー、timers は実行中の全タイマー
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrentTimerResponse {
     pub task_id: Option<i64>,
     pub elapsed_seconds: Option<i64>,
     pub elapsed_duration: Option<String>,
+    pub timers: Vec<RunningTimerResponse>,
+}
+
+/// 実行中タイマーレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunningTimerResponse {
+    pub task_id: i64,
+    pub elapsed_seconds: Option<i64>,
+    pub elapsed_duration: Option<String>,
+}
+
+/// 時間エントリ変更通知レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntriesChangedResponse {
+    pub task_id: i64,
 }
 
 /// タスク作業時間サマリーレスポンス
@@ -71,8 +143,11 @@ pub struct TaskTimeSummaryResponse {
     pub task_id: i64,
     pub total_duration_seconds: i64,
     pub total_duration_formatted: String, // HH:MM:SS形式
+    pub rounded_duration_seconds: i64,    // 丸め設定を適用した合計
+    pub rounded_duration_formatted: String, // HH:MM:SS形式
     pub entry_count: usize,
     pub is_running: bool,
+    pub completed_pomodoros: usize,
 }
 
 /// プロジェクト作業時間サマリーレスポンス
@@ -81,6 +156,25 @@ pub struct ProjectTimeSummaryResponse {
     pub project_id: i64,
     pub total_duration_seconds: i64,
     pub total_duration_formatted: String, // HH:MM:SS形式
+    pub rounded_duration_seconds: i64,    // 丸め設定を適用した合計
+    pub rounded_duration_formatted: String, // HH:MM:SS形式
+    pub entry_count: usize,
+}
+
+/// タグ作業時間サマリーリクエスト（期間指定は任意）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagTimeSummaryRequest {
+    pub tag_id: i64,
+    pub start_time: Option<String>, // ISO 8601形式
+    pub end_time: Option<String>,   // ISO 8601形式
+}
+
+/// タグ作業時間サマリーレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagTimeSummaryResponse {
+    pub tag_id: i64,
+    pub total_duration_seconds: i64,
+    pub total_duration_formatted: String, // HH:MM:SS形式
     pub entry_count: usize,
 }
 
@@ -110,6 +204,9 @@ impl From<TimeEntry> for TimeEntryResponse {
             elapsed_duration: entry.elapsed_duration(),
             is_running: entry.is_running(),
             is_completed: entry.is_completed(),
+            notes: entry.notes().map(str::to_string),
+            is_paused: entry.is_paused(),
+            paused_seconds: entry.paused_seconds(),
         }
     }
 }
@@ -117,14 +214,32 @@ impl From<TimeEntry> for TimeEntryResponse {
 impl StartTimerRequest {
     pub fn to_command(self) -> anyhow::Result<crate::application::use_cases::StartTimerCommand> {
         let task_id = TaskId::new(self.task_id)?;
-        Ok(crate::application::use_cases::StartTimerCommand { task_id })
+        let at = self.at.as_deref().map(parse_datetime).transpose()?;
+        Ok(crate::application::use_cases::StartTimerCommand { task_id, at })
     }
 }
 
 impl StopTimerRequest {
     pub fn to_command(self) -> anyhow::Result<crate::application::use_cases::StopTimerCommand> {
         let task_id = TaskId::new(self.task_id)?;
-        Ok(crate::application::use_cases::StopTimerCommand { task_id })
+        let at = self.at.as_deref().map(parse_datetime).transpose()?;
+        Ok(crate::application::use_cases::StopTimerCommand { task_id, at })
+    }
+}
+
+impl PauseTimerRequest {
+    pub fn to_command(self) -> anyhow::Result<crate::application::use_cases::PauseTimerCommand> {
+        let task_id = TaskId::new(self.task_id)?;
+        let at = self.at.as_deref().map(parse_datetime).transpose()?;
+        Ok(crate::application::use_cases::PauseTimerCommand { task_id, at })
+    }
+}
+
+impl ResumeTimerRequest {
+    pub fn to_command(self) -> anyhow::Result<crate::application::use_cases::ResumeTimerCommand> {
+        let task_id = TaskId::new(self.task_id)?;
+        let at = self.at.as_deref().map(parse_datetime).transpose()?;
+        Ok(crate::application::use_cases::ResumeTimerCommand { task_id, at })
     }
 }
 
@@ -143,17 +258,120 @@ impl AddManualEntryRequest {
     }
 }
 
+impl UpdateTimeEntryRequest {
+    pub fn to_command(self) -> anyhow::Result<crate::application::use_cases::UpdateTimeEntryCommand> {
+        let start_time = self.start_time.as_deref().map(parse_datetime).transpose()?;
+        let end_time = self.end_time.as_deref().map(parse_datetime).transpose()?;
+
+        Ok(crate::application::use_cases::UpdateTimeEntryCommand {
+            start_event_id: self.start_event_id,
+            start_time,
+            end_time,
+        })
+    }
+}
+
+impl AnnotateTimeEntryRequest {
+    pub fn to_command(self) -> anyhow::Result<crate::application::use_cases::AnnotateTimeEntryCommand> {
+        Ok(crate::application::use_cases::AnnotateTimeEntryCommand {
+            start_event_id: self.start_event_id,
+            note: self.note,
+        })
+    }
+}
+
+impl GetTimeEntriesRequest {
+    /// ページサイズの既定値
+    pub const DEFAULT_LIMIT: usize = 200;
+    /// ページサイズの上限
+    pub const MAX_LIMIT: usize = 1000;
+
+    /// 期間のコマンドに変換（日付は calendar のローカル日付として扱う）
+    pub fn to_command(&self, calendar: &LocalCalendar) -> anyhow::Result<crate::application::use_cases::GetTimeEntriesCommand> {
+        let start_date = parse_date(&self.start_date)?;
+        let end_date = match &self.end_date {
+            Some(end_date) => parse_date(end_date)?,
+            None => start_date,
+        };
+        if end_date < start_date {
+            return Err(anyhow::anyhow!("End date must not be before start date"));
+        }
+
+        let limit = self.limit.unwrap_or(Self::DEFAULT_LIMIT);
+        if limit == 0 || limit > Self::MAX_LIMIT {
+            return Err(anyhow::anyhow!("Limit must be between 1 and {}", Self::MAX_LIMIT));
+        }
+
+        Ok(crate::application::use_cases::GetTimeEntriesCommand {
+            start: calendar.start_of_day(start_date),
+            end: calendar.start_of_day(end_date + chrono::Duration::days(1)),
+            project_id: self.project_id,
+            task_id: self.task_id.map(TaskId::new).transpose()?,
+            cursor: self.cursor.as_deref().map(decode_cursor).transpose()?,
+            limit: Some(limit),
+        })
+    }
+}
+
+impl From<crate::application::use_cases::TimeEntryPage> for TimeEntryPageResponse {
+    fn from(page: crate::application::use_cases::TimeEntryPage) -> Self {
+        Self {
+            entries: page.entries.into_iter().map(TimeEntryResponse::from).collect(),
+            next_cursor: page.next_cursor.map(|cursor| encode_cursor(&cursor)),
+        }
+    }
+}
+
+impl TagTimeSummaryRequest {
+    pub fn tag_id(&self) -> anyhow::Result<TagId> {
+        TagId::new(self.tag_id)
+    }
+
+    /// 集計期間を取得（未指定の場合は全期間）
+    pub fn period(&self) -> anyhow::Result<Option<(DateTime<Utc>, DateTime<Utc>)>> {
+        match (&self.start_time, &self.end_time) {
+            (Some(start), Some(end)) => Ok(Some((parse_datetime(start)?, parse_datetime(end)?))),
+            (None, None) => Ok(None),
+            _ => Err(anyhow::anyhow!("Both start_time and end_time must be specified")),
+        }
+    }
+}
+
 // ユーティリティ関数
 
-fn format_datetime(dt: DateTime<Utc>) -> String {
+pub(super) fn format_datetime(dt: DateTime<Utc>) -> String {
     dt.format("%Y-%m-%dT%H:%M:%SZ").to_string()
 }
 
-fn parse_datetime(s: &str) -> anyhow::Result<DateTime<Utc>> {
+pub(super) fn parse_datetime(s: &str) -> anyhow::Result<DateTime<Utc>> {
     Ok(DateTime::parse_from_rfc3339(s)?.with_timezone(&Utc))
 }
 
-fn format_duration_seconds(seconds: i64) -> String {
+/// YYYY-MM-DD形式の日付を解析
+pub(super) fn parse_date(s: &str) -> anyhow::Result<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|e| anyhow::anyhow!("Invalid date '{}': {}", s, e))
+}
+
+/// ページングカーソルを文字列化（`開始時刻|開始イベントID`）
+fn encode_cursor(cursor: &TimeEntryCursor) -> String {
+    format!("{}|{}", format_datetime(cursor.start_time), cursor.start_event_id)
+}
+
+fn decode_cursor(s: &str) -> anyhow::Result<TimeEntryCursor> {
+    let (start_time, start_event_id) = s
+        .split_once('|')
+        .ok_or_else(|| anyhow::anyhow!("Invalid cursor: {}", s))?;
+
+    Ok(TimeEntryCursor {
+        start_time: parse_datetime(start_time)?,
+        start_event_id: start_event_id
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid cursor: {}", s))?,
+    })
+}
+
+pub(super) fn format_duration_seconds(seconds: i64) -> String {
     let hours = seconds / 3600;
     let minutes = (seconds % 3600) / 60;
     let secs = seconds % 60;
@@ -167,9 +385,12 @@ impl TimerStatusResponse {
         elapsed_seconds: Option<i64>,
     ) -> Self {
         let elapsed_duration = elapsed_seconds.map(format_duration_seconds);
+        let paused_at = current_entry.as_ref().and_then(|e| e.paused_at());
         
         Self {
             is_running,
+            is_paused: paused_at.is_some(),
+            paused_at: paused_at.map(format_datetime),
             current_entry: current_entry.map(TimeEntryResponse::from),
             elapsed_seconds,
             elapsed_duration,
@@ -179,12 +400,49 @@ impl TimerStatusResponse {
 
 impl CurrentTimerResponse {
     pub fn new(task_id: Option<TaskId>, elapsed_seconds: Option<i64>) -> Self {
-        let elapsed_duration = elapsed_seconds.map(format_duration_seconds);
-        
+        let timers = task_id
+            .map(|task_id| vec![RunningTimerResponse::new(task_id, elapsed_seconds)])
+            .unwrap_or_default();
+        Self::from_timers(timers)
+    }
+
+    /// 実行中の全タイマーから作成（先頭を代表のタイマーとする）
+    pub fn from_timers(timers: Vec<RunningTimerResponse>) -> Self {
+        let first = timers.first();
+
+        Self {
+            task_id: first.map(|t| t.task_id),
+            elapsed_seconds: first.and_then(|t| t.elapsed_seconds),
+            elapsed_duration: first.and_then(|t| t.elapsed_duration.clone()),
+            timers,
+        }
+    }
+
+    /// 実行中の時間エントリから作成
+    pub fn from_entries(entries: &[TimeEntry]) -> Self {
+        Self::from_timers(
+            entries
+                .iter()
+                .map(|entry| RunningTimerResponse::new(entry.task_id(), Some(entry.elapsed_seconds())))
+                .collect(),
+        )
+    }
+}
+
+impl RunningTimerResponse {
+    pub fn new(task_id: TaskId, elapsed_seconds: Option<i64>) -> Self {
         Self {
-            task_id: task_id.map(i64::from),
+            task_id: i64::from(task_id),
             elapsed_seconds,
-            elapsed_duration,
+            elapsed_duration: elapsed_seconds.map(format_duration_seconds),
+        }
+    }
+}
+
+impl EntriesChangedResponse {
+    pub fn new(task_id: TaskId) -> Self {
+        Self {
+            task_id: i64::from(task_id),
         }
     }
 }
@@ -193,15 +451,20 @@ impl TaskTimeSummaryResponse {
     pub fn new(
         task_id: TaskId,
         total_duration_seconds: i64,
+        rounded_duration_seconds: i64,
         entry_count: usize,
         is_running: bool,
+        completed_pomodoros: usize,
     ) -> Self {
         Self {
             task_id: i64::from(task_id),
             total_duration_seconds,
             total_duration_formatted: format_duration_seconds(total_duration_seconds),
+            rounded_duration_seconds,
+            rounded_duration_formatted: format_duration_seconds(rounded_duration_seconds),
             entry_count,
             is_running,
+            completed_pomodoros,
         }
     }
 }
@@ -210,12 +473,30 @@ impl ProjectTimeSummaryResponse {
     pub fn new(
         project_id: i64,
         total_duration_seconds: i64,
+        rounded_duration_seconds: i64,
         entry_count: usize,
     ) -> Self {
         Self {
             project_id,
             total_duration_seconds,
             total_duration_formatted: format_duration_seconds(total_duration_seconds),
+            rounded_duration_seconds,
+            rounded_duration_formatted: format_duration_seconds(rounded_duration_seconds),
+            entry_count,
+        }
+    }
+}
+
+impl TagTimeSummaryResponse {
+    pub fn new(
+        tag_id: TagId,
+        total_duration_seconds: i64,
+        entry_count: usize,
+    ) -> Self {
+        Self {
+            tag_id: i64::from(tag_id),
+            total_duration_seconds,
+            total_duration_formatted: format_duration_seconds(total_duration_seconds),
             entry_count,
         }
     }
@@ -278,13 +559,21 @@ mod tests {
 
     #[test]
     fn リクエスト変換が正しく動作すること() {
-        let start_request = StartTimerRequest { task_id: 1 };
+        let start_request = StartTimerRequest { task_id: 1, at: None };
         let command = start_request.to_command().unwrap();
         assert_eq!(i64::from(command.task_id), 1);
 
-        let stop_request = StopTimerRequest { task_id: 1 };
+        let stop_request = StopTimerRequest { task_id: 1, at: None };
         let command = stop_request.to_command().unwrap();
         assert_eq!(i64::from(command.task_id), 1);
+        assert!(command.at.is_none());
+
+        // 開始・停止時刻を指定できる
+        let start_request = StartTimerRequest { task_id: 1, at: Some("2024-01-01T09:00:00Z".to_string()) };
+        let command = start_request.to_command().unwrap();
+        assert_eq!(command.at, Some(Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap()));
+        let stop_request = StopTimerRequest { task_id: 1, at: Some("invalid".to_string()) };
+        assert!(stop_request.to_command().is_err());
 
         let manual_request = AddManualEntryRequest {
             task_id: 1,
@@ -319,6 +608,26 @@ mod tests {
         assert_eq!(response.elapsed_duration, Some("01:01:01".to_string()));
     }
 
+    #[test]
+    fn 一時停止中のタイマー状態レスポンスが作成されること() {
+        let start_time = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let paused_at = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
+        let entry = TimeEntry::new(TaskId::new(1).unwrap(), 10, start_time, None).with_pause(600, Some(paused_at));
+        let elapsed_seconds = entry.elapsed_seconds();
+
+        let response = TimerStatusResponse::new(true, Some(entry), Some(elapsed_seconds));
+        assert!(response.is_paused);
+        assert_eq!(response.paused_at, Some("2024-01-01T10:00:00Z".to_string()));
+        assert_eq!(response.elapsed_duration, Some("00:50:00".to_string()));
+        let current_entry = response.current_entry.unwrap();
+        assert!(current_entry.is_paused);
+        assert_eq!(current_entry.paused_seconds, 600);
+
+        let response = TimerStatusResponse::new(true, None, Some(60));
+        assert!(!response.is_paused);
+        assert!(response.paused_at.is_none());
+    }
+
     #[test]
     fn 現在のタイマーレスポンス作成が正しく動作すること() {
         let task_id = TaskId::new(1).unwrap();
@@ -327,29 +636,42 @@ mod tests {
         assert_eq!(response.task_id, Some(1));
         assert_eq!(response.elapsed_seconds, Some(3600));
         assert_eq!(response.elapsed_duration, Some("01:00:00".to_string()));
+        assert_eq!(response.timers.len(), 1);
+
+        // 同時実行中の全タイマーを含む
+        let response = CurrentTimerResponse::from_timers(vec![
+            RunningTimerResponse::new(TaskId::new(2).unwrap(), Some(60)),
+            RunningTimerResponse::new(task_id, Some(3600)),
+        ]);
+        assert_eq!(response.task_id, Some(2));
+        assert_eq!(response.timers.iter().map(|t| t.task_id).collect::<Vec<_>>(), vec![2, 1]);
+        assert!(CurrentTimerResponse::from_timers(Vec::new()).task_id.is_none());
     }
 
     #[test]
     fn サマリーレスポンス作成が正しく動作すること() {
         let task_id = TaskId::new(1).unwrap();
-        let task_summary = TaskTimeSummaryResponse::new(task_id, 7200, 2, true);
+        let task_summary = TaskTimeSummaryResponse::new(task_id, 7200, 7200 + 900, 2, true, 3);
         
         assert_eq!(task_summary.task_id, 1);
         assert_eq!(task_summary.total_duration_seconds, 7200);
         assert_eq!(task_summary.total_duration_formatted, "02:00:00");
+        assert_eq!(task_summary.rounded_duration_formatted, "02:15:00");
         assert_eq!(task_summary.entry_count, 2);
         assert!(task_summary.is_running);
+        assert_eq!(task_summary.completed_pomodoros, 3);
 
-        let project_summary = ProjectTimeSummaryResponse::new(1, 14400, 4);
+        let project_summary = ProjectTimeSummaryResponse::new(1, 14400, 14400, 4);
         assert_eq!(project_summary.project_id, 1);
         assert_eq!(project_summary.total_duration_seconds, 14400);
         assert_eq!(project_summary.total_duration_formatted, "04:00:00");
+        assert_eq!(project_summary.rounded_duration_seconds, 14400);
         assert_eq!(project_summary.entry_count, 4);
     }
 
     #[test]
     fn 無効なリクエスト変換が失敗すること() {
-        let invalid_request = StartTimerRequest { task_id: 0 };
+        let invalid_request = StartTimerRequest { task_id: 0, at: None };
         assert!(invalid_request.to_command().is_err());
 
         let invalid_manual_request = AddManualEntryRequest {
@@ -360,4 +682,102 @@ mod tests {
         };
         assert!(invalid_manual_request.to_command().is_err());
     }
+    #[test]
+    fn タグサマリーリクエストの期間変換が正しく動作すること() {
+        let request = TagTimeSummaryRequest {
+            tag_id: 1,
+            start_time: Some("2024-01-01T00:00:00Z".to_string()),
+            end_time: Some("2024-01-08T00:00:00Z".to_string()),
+        };
+        let (start, end) = request.period().unwrap().unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2024, 1, 8, 0, 0, 0).unwrap());
+
+        let all_time = TagTimeSummaryRequest {
+            tag_id: 1,
+            start_time: None,
+            end_time: None,
+        };
+        assert!(all_time.period().unwrap().is_none());
+
+        let half_open = TagTimeSummaryRequest {
+            tag_id: 1,
+            start_time: Some("2024-01-01T00:00:00Z".to_string()),
+            end_time: None,
+        };
+        assert!(half_open.period().is_err());
+
+        let tag_summary = TagTimeSummaryResponse::new(TagId::new(2).unwrap(), 5400, 3);
+        assert_eq!(tag_summary.tag_id, 2);
+        assert_eq!(tag_summary.total_duration_formatted, "01:30:00");
+    }
+    #[test]
+    fn 期間指定リクエストからコマンド変換が正しく動作すること() {
+        let request = GetTimeEntriesRequest {
+            start_date: "2024-01-01".to_string(),
+            end_date: Some("2024-01-07".to_string()),
+            project_id: Some(1),
+            task_id: None,
+            cursor: None,
+            limit: None,
+        };
+
+        let command = request.to_command(&LocalCalendar::utc()).unwrap();
+        assert_eq!(command.start, Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(command.end, Utc.with_ymd_and_hms(2024, 1, 8, 0, 0, 0).unwrap());
+        assert_eq!(command.project_id, Some(1));
+        assert_eq!(command.limit, Some(GetTimeEntriesRequest::DEFAULT_LIMIT));
+
+        let single_day = GetTimeEntriesRequest {
+            end_date: None,
+            ..request.clone()
+        };
+        let command = single_day.to_command(&LocalCalendar::utc()).unwrap();
+        assert_eq!(command.end, Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap());
+
+        let reversed = GetTimeEntriesRequest {
+            start_date: "2024-01-07".to_string(),
+            end_date: Some("2024-01-01".to_string()),
+            ..request.clone()
+        };
+        assert!(reversed.to_command(&LocalCalendar::utc()).is_err());
+
+        // 日付はカレンダーのタイムゾーンのローカル日付として扱う
+        let tokyo = LocalCalendar::from_name("Asia/Tokyo", chrono::Weekday::Mon).unwrap();
+        let command = request.to_command(&tokyo).unwrap();
+        assert_eq!(command.start, Utc.with_ymd_and_hms(2023, 12, 31, 15, 0, 0).unwrap());
+        assert_eq!(command.end, Utc.with_ymd_and_hms(2024, 1, 7, 15, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn ページングカーソルの往復変換が正しく動作すること() {
+        let cursor = TimeEntryCursor {
+            start_time: Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+            start_event_id: 42,
+        };
+
+        let encoded = encode_cursor(&cursor);
+        assert_eq!(decode_cursor(&encoded).unwrap(), cursor);
+        assert!(decode_cursor("invalid").is_err());
+    }
+    #[test]
+    fn 時間エントリ訂正リクエスト変換が正しく動作すること() {
+        let request = UpdateTimeEntryRequest {
+            start_event_id: 10,
+            start_time: None,
+            end_time: Some("2024-01-01T11:00:00Z".to_string()),
+        };
+
+        let command = request.to_command().unwrap();
+        assert_eq!(command.start_event_id, 10);
+        assert!(command.start_time.is_none());
+        assert_eq!(command.end_time, Some(Utc.with_ymd_and_hms(2024, 1, 1, 11, 0, 0).unwrap()));
+
+        let invalid_request = UpdateTimeEntryRequest {
+            start_event_id: 10,
+            start_time: Some("invalid-date".to_string()),
+            end_time: None,
+        };
+        assert!(invalid_request.to_command().is_err());
+    }
 }
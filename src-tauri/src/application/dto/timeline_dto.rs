@@ -0,0 +1,150 @@
+use super::time_entry_dto::{format_datetime, format_duration_seconds, parse_date};
+use crate::application::services::{Timeline, TimelineDay, TimelineGap, TimelineSpan};
+use crate::domain::repositories::TimelineEntry;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// タイムライン取得リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTimelineRequest {
+    pub date: String,         // YYYY-MM-DD形式（ローカル日付）
+    pub span: Option<String>, // day / week（省略時は day）
+}
+
+impl GetTimelineRequest {
+    pub fn to_query(self) -> anyhow::Result<(NaiveDate, TimelineSpan)> {
+        let span = self.span.as_deref().map(str::parse::<TimelineSpan>).transpose()?;
+        Ok((parse_date(&self.date)?, span.unwrap_or(TimelineSpan::Day)))
+    }
+}
+
+/// タイムラインの時間区間レスポンス（タスク・プロジェクト名付き）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEntryResponse {
+    pub start_event_id: i64,
+    pub task_id: i64,
+    pub task_name: String,
+    pub project_id: i64,
+    pub project_name: String,
+    pub start_time: String,       // ISO 8601形式
+    pub end_time: Option<String>, // ISO 8601形式
+    pub duration_in_seconds: Option<i64>,
+    pub elapsed_duration: String, // HH:MM:SS形式
+    pub is_running: bool,
+    pub is_paused: bool,
+    pub notes: Option<String>,
+}
+
+/// 空き時間レスポンス（start_time / end_time はそのまま手動エントリの追加に使える）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineGapResponse {
+    pub start_time: String, // ISO 8601形式
+    pub end_time: String,   // ISO 8601形式
+    pub duration_seconds: i64,
+    pub duration_formatted: String, // HH:MM:SS形式
+}
+
+/// 1日分のタイムラインレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineDayResponse {
+    pub date: String, // YYYY-MM-DD形式
+    pub entries: Vec<TimelineEntryResponse>,
+    pub gaps: Vec<TimelineGapResponse>,
+    pub tracked_seconds: i64,
+    pub tracked_duration_formatted: String, // HH:MM:SS形式
+}
+
+/// タイムラインレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineResponse {
+    pub span: String,
+    pub start_date: String, // YYYY-MM-DD形式
+    pub days: Vec<TimelineDayResponse>,
+}
+
+impl From<TimelineEntry> for TimelineEntryResponse {
+    fn from(item: TimelineEntry) -> Self {
+        let entry = item.entry;
+        Self {
+            start_event_id: entry.start_event_id(),
+            task_id: i64::from(entry.task_id()),
+            task_name: item.task_name,
+            project_id: i64::from(item.project_id),
+            project_name: item.project_name,
+            start_time: format_datetime(entry.start_time()),
+            end_time: entry.end_time().map(format_datetime),
+            duration_in_seconds: entry.duration_in_seconds(),
+            elapsed_duration: entry.elapsed_duration(),
+            is_running: entry.is_running(),
+            is_paused: entry.is_paused(),
+            notes: entry.notes().map(str::to_string),
+        }
+    }
+}
+
+impl From<TimelineGap> for TimelineGapResponse {
+    fn from(gap: TimelineGap) -> Self {
+        Self {
+            start_time: format_datetime(gap.start_time),
+            end_time: format_datetime(gap.end_time),
+            duration_seconds: gap.duration_seconds(),
+            duration_formatted: format_duration_seconds(gap.duration_seconds()),
+        }
+    }
+}
+
+impl From<TimelineDay> for TimelineDayResponse {
+    fn from(day: TimelineDay) -> Self {
+        Self {
+            date: day.date.format("%Y-%m-%d").to_string(),
+            entries: day.entries.into_iter().map(TimelineEntryResponse::from).collect(),
+            gaps: day.gaps.into_iter().map(TimelineGapResponse::from).collect(),
+            tracked_seconds: day.tracked_seconds,
+            tracked_duration_formatted: format_duration_seconds(day.tracked_seconds),
+        }
+    }
+}
+
+impl From<Timeline> for TimelineResponse {
+    fn from(timeline: Timeline) -> Self {
+        Self {
+            span: timeline.span.as_str().to_string(),
+            start_date: timeline.start_date.format("%Y-%m-%d").to_string(),
+            days: timeline.days.into_iter().map(TimelineDayResponse::from).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn タイムライン取得リクエストが変換されること() {
+        let request = |date: &str, span: Option<&str>| GetTimelineRequest {
+            date: date.to_string(),
+            span: span.map(str::to_string),
+        };
+        let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+
+        assert_eq!(request("2025-03-10", None).to_query().unwrap(), (date, TimelineSpan::Day));
+        assert_eq!(request("2025-03-10", Some("week")).to_query().unwrap(), (date, TimelineSpan::Week));
+        assert!(request("2025-03-10", Some("month")).to_query().is_err());
+        assert!(request("03/10/2025", None).to_query().is_err());
+    }
+
+    #[test]
+    fn 空き時間が手動エントリに使える形式になること() {
+        let response = TimelineGapResponse::from(TimelineGap {
+            start_time: Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap(),
+            end_time: Utc.with_ymd_and_hms(2025, 3, 10, 13, 30, 0).unwrap(),
+        });
+
+        assert_eq!(response.start_time, "2025-03-10T12:00:00Z");
+        assert_eq!(response.end_time, "2025-03-10T13:30:00Z");
+        assert_eq!(response.duration_seconds, 5400);
+        assert_eq!(response.duration_formatted, "01:30:00");
+    }
+}
@@ -0,0 +1,122 @@
+use super::time_entry_dto::{format_duration_seconds, parse_date};
+use crate::application::use_cases::{SetTimesheetCellCommand, Timesheet, TimesheetRow};
+use crate::domain::value_objects::TaskId;
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// タイムシートの週の指定（YYYY-MM-DD形式、週の途中の日付でもよい）を解析
+pub fn parse_timesheet_week(week_start: &str) -> anyhow::Result<NaiveDate> {
+    parse_date(week_start)
+}
+
+/// タイムシートのセル設定リクエスト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetTimesheetCellRequest {
+    pub task_id: i64,
+    pub day: String, // YYYY-MM-DD形式（ローカル日付）
+    pub duration_seconds: i64,
+}
+
+impl SetTimesheetCellRequest {
+    pub fn to_command(self) -> anyhow::Result<SetTimesheetCellCommand> {
+        Ok(SetTimesheetCellCommand {
+            task_id: TaskId::new(self.task_id)?,
+            date: parse_date(&self.day)?,
+            duration_seconds: self.duration_seconds,
+        })
+    }
+}
+
+/// タイムシートの行レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimesheetRowResponse {
+    pub task_id: i64,
+    pub task_name: String,
+    pub project_id: i64,
+    pub daily_seconds: Vec<i64>, // days と同じ並び
+    pub total_seconds: i64,
+    pub total_duration_formatted: String, // HH:MM:SS形式
+}
+
+/// タイムシートレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimesheetResponse {
+    pub week_start: String, // YYYY-MM-DD形式
+    pub days: Vec<String>,  // YYYY-MM-DD形式の7日分
+    pub rows: Vec<TimesheetRowResponse>,
+    pub daily_totals: Vec<i64>,
+    pub total_seconds: i64,
+    pub total_duration_formatted: String, // HH:MM:SS形式
+}
+
+impl From<TimesheetRow> for TimesheetRowResponse {
+    fn from(row: TimesheetRow) -> Self {
+        Self {
+            task_id: row.task_id.into(),
+            task_name: row.task_name,
+            project_id: row.project_id.into(),
+            daily_seconds: row.daily_seconds,
+            total_seconds: row.total_seconds,
+            total_duration_formatted: format_duration_seconds(row.total_seconds),
+        }
+    }
+}
+
+impl From<Timesheet> for TimesheetResponse {
+    fn from(timesheet: Timesheet) -> Self {
+        Self {
+            week_start: timesheet.week_start.format("%Y-%m-%d").to_string(),
+            days: (0..timesheet.daily_totals.len() as i64)
+                .map(|i| (timesheet.week_start + Duration::days(i)).format("%Y-%m-%d").to_string())
+                .collect(),
+            rows: timesheet.rows.into_iter().map(TimesheetRowResponse::from).collect(),
+            daily_totals: timesheet.daily_totals,
+            total_seconds: timesheet.total_seconds,
+            total_duration_formatted: format_duration_seconds(timesheet.total_seconds),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ProjectId;
+
+    #[test]
+    fn セル設定リクエストがコマンドに変換されること() {
+        let request = SetTimesheetCellRequest {
+            task_id: 3,
+            day: "2025-03-05".to_string(),
+            duration_seconds: 5400,
+        };
+        let command = request.clone().to_command().unwrap();
+
+        assert_eq!(command.task_id, TaskId::new(3).unwrap());
+        assert_eq!(command.date, NaiveDate::from_ymd_opt(2025, 3, 5).unwrap());
+        assert_eq!(command.duration_seconds, 5400);
+        assert!(SetTimesheetCellRequest { day: "2025/03/05".to_string(), ..request }.to_command().is_err());
+    }
+
+    #[test]
+    fn タイムシートレスポンスに週の日付が含まれること() {
+        let response = TimesheetResponse::from(Timesheet {
+            week_start: NaiveDate::from_ymd_opt(2025, 3, 29).unwrap(),
+            rows: vec![TimesheetRow {
+                task_id: TaskId::new(1).unwrap(),
+                task_name: "設計".to_string(),
+                project_id: ProjectId::new(1).unwrap(),
+                daily_seconds: vec![0, 3600, 0, 0, 0, 0, 1800],
+                total_seconds: 5400,
+            }],
+            daily_totals: vec![0, 3600, 0, 0, 0, 0, 1800],
+            total_seconds: 5400,
+        });
+
+        assert_eq!(response.week_start, "2025-03-29");
+        assert_eq!(response.days.first().unwrap(), "2025-03-29");
+        assert_eq!(response.days.last().unwrap(), "2025-04-04");
+        assert_eq!(response.rows[0].total_duration_formatted, "01:30:00");
+        assert_eq!(response.total_duration_formatted, "01:30:00");
+    }
+}
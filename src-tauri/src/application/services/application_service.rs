@@ -1,16 +1,41 @@
-use crate::application::use_cases::{ProjectUseCases, TaskUseCases, TimeTrackingUseCases};
-use crate::infrastructure::config::Config;
+use crate::application::use_cases::{IntegrityUseCases, PomodoroUseCases, ProjectUseCases, RecoveryUseCases, RoundingUseCases, TagUseCases, TaskUseCases, TimeTrackingUseCases, TimesheetUseCases};
+use crate::application::services::{
+    AppNotification, AutoCutoffWorker, BackupService, BackupServiceImpl, DeferredNotifier, EventBus, ExportService,
+    ExportServiceImpl, HeartbeatWorker, ImportService, ImportServiceImpl, NotificationSubscriber, Notifier,
+    PomodoroWorker, ReportService, ReportServiceImpl, TimelineService, TimelineServiceImpl, TimerTickWorker,
+};
+use crate::domain::entities::PomodoroSettings;
+use crate::domain::services::{AutoCutoffPolicy, Clock, LocalCalendar, SystemClock, WorkingHours};
+use crate::infrastructure::config::{AutoCutoffConfig, Config, PomodoroConfig, RecoveryConfig, TimerConfig};
 use crate::infrastructure::database::DatabaseConnection;
-use crate::infrastructure::repositories::{SqliteProjectRepository, SqliteTaskRepository, SqliteTimeEntryRepository};
+use crate::infrastructure::repositories::{SqliteBackupRepository, SqlitePomodoroRepository, SqliteProjectRepository, SqliteReportRepository, SqliteRoundingRepository, SqliteTagRepository, SqliteTimelineRepository, SqliteTaskRepository, SqliteTimeEntryRepository};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
 /// アプリケーションサービス - 依存性の注入とライフサイクル管理
 pub struct ApplicationService {
     db: Arc<Mutex<DatabaseConnection>>,
-    project_use_cases: Box<dyn ProjectUseCases>,
-    task_use_cases: Box<dyn TaskUseCases>,
-    time_tracking_use_cases: Box<dyn TimeTrackingUseCases>,
+    project_use_cases: Arc<dyn ProjectUseCases>,
+    task_use_cases: Arc<dyn TaskUseCases>,
+    time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>,
+    tag_use_cases: Arc<dyn TagUseCases>,
+    integrity_use_cases: Box<dyn IntegrityUseCases>,
+    recovery_use_cases: Arc<dyn RecoveryUseCases>,
+    pomodoro_use_cases: Arc<dyn PomodoroUseCases>,
+    report_service: Box<dyn ReportService>,
+    timesheet_use_cases: Box<dyn TimesheetUseCases>,
+    timeline_service: Box<dyn TimelineService>,
+    rounding_use_cases: Box<dyn RoundingUseCases>,
+    export_service: Box<dyn ExportService>,
+    backup_service: Box<dyn BackupService>,
+    import_service: Box<dyn ImportService>,
+    auto_cutoff_config: AutoCutoffConfig,
+    recovery_config: RecoveryConfig,
+    pomodoro_config: PomodoroConfig,
+    timer_config: TimerConfig,
+    calendar: LocalCalendar,
+    notifier: Arc<DeferredNotifier>,
+    event_bus: Arc<EventBus>,
 }
 
 impl ApplicationService {
@@ -52,6 +77,15 @@ impl ApplicationService {
         let time_entry_repo = SqliteTimeEntryRepository::new(db_arc.clone());
         tracing::debug!("ApplicationService::new: Time entry repository created");
         
+        let tag_repo = SqliteTagRepository::new(db_arc.clone());
+        tracing::debug!("ApplicationService::new: Tag repository created");
+
+        let pomodoro_repo = SqlitePomodoroRepository::new(db_arc.clone());
+        tracing::debug!("ApplicationService::new: Pomodoro repository created");
+
+        let report_repo = SqliteReportRepository::new(db_arc.clone());
+        tracing::debug!("ApplicationService::new: Report repository created");
+        
         // ドメインサービスを作成
         tracing::debug!("ApplicationService::new: Creating domain services");
         let project_service = crate::domain::services::ProjectManagementServiceImpl::new(
@@ -62,29 +96,179 @@ impl ApplicationService {
         
         let time_tracking_service = crate::domain::services::TimeTrackingServiceImpl::new(
             time_entry_repo.clone(),
-        );
+        )
+        .with_exclusivity(config.timer.exclusivity);
         tracing::debug!("ApplicationService::new: Time tracking service created");
+
+        let integrity_service = crate::domain::services::TimeEntryIntegrityServiceImpl::new(
+            time_entry_repo.clone(),
+        );
+        tracing::debug!("ApplicationService::new: Time entry integrity service created");
+
+        let recovery_service = crate::domain::services::TimerRecoveryServiceImpl::new(
+            time_entry_repo.clone(),
+        );
+        tracing::debug!("ApplicationService::new: Timer recovery service created");
         
+        // ユースケースが発行するドメインイベントのバス（タイマーや時間エントリの変更はフロントエンドへ通知する）
+        // 通知先は起動後に attach_notifier で設定する
+        let event_bus = Arc::new(EventBus::new());
+        let notifier = Arc::new(DeferredNotifier::new());
+        event_bus.subscribe(Arc::new(NotificationSubscriber::new(notifier.clone())));
+
         // ユースケースを作成
         tracing::debug!("ApplicationService::new: Creating use cases");
-        let project_use_cases = Box::new(
+        let project_use_cases = Arc::new(
             crate::application::use_cases::ProjectUseCasesImpl::new(project_repo.clone(), project_service)
-        ) as Box<dyn ProjectUseCases>;
+                .with_event_bus(event_bus.clone())
+        ) as Arc<dyn ProjectUseCases>;
         tracing::debug!("ApplicationService::new: Project use cases created");
         
-        let task_use_cases = Box::new(
+        let task_use_cases = Arc::new(
             crate::application::use_cases::TaskUseCasesImpl::new(task_repo.clone(), project_repo.clone())
-        ) as Box<dyn TaskUseCases>;
+                .with_event_bus(event_bus.clone())
+        ) as Arc<dyn TaskUseCases>;
         tracing::debug!("ApplicationService::new: Task use cases created");
         
-        let time_tracking_use_cases = Box::new(
+        let time_tracking_use_cases = Arc::new(
             crate::application::use_cases::TimeTrackingUseCasesImpl::new(
                 time_entry_repo,
-                task_repo,
+                task_repo.clone(),
                 time_tracking_service,
             )
-        ) as Box<dyn TimeTrackingUseCases>;
+            .with_event_bus(event_bus.clone())
+        ) as Arc<dyn TimeTrackingUseCases>;
         tracing::debug!("ApplicationService::new: Time tracking use cases created");
+        
+        let tag_use_cases = Arc::new(
+            crate::application::use_cases::TagUseCasesImpl::new(tag_repo, task_repo)
+                .with_event_bus(event_bus.clone())
+        ) as Arc<dyn TagUseCases>;
+        tracing::debug!("ApplicationService::new: Tag use cases created");
+
+        let integrity_use_cases = Box::new(
+            crate::application::use_cases::IntegrityUseCasesImpl::new(integrity_service)
+                .with_event_bus(event_bus.clone())
+        ) as Box<dyn IntegrityUseCases>;
+        tracing::debug!("ApplicationService::new: Integrity use cases created");
+
+        let recovery_use_cases = Arc::new(
+            crate::application::use_cases::RecoveryUseCasesImpl::new(
+                recovery_service,
+                Arc::new(SystemClock),
+                config.recovery.stale_after_seconds,
+            )?
+            .with_event_bus(event_bus.clone())
+        ) as Arc<dyn RecoveryUseCases>;
+        tracing::debug!("ApplicationService::new: Recovery use cases created");
+
+        let pomodoro_settings = PomodoroSettings::new(
+            config.pomodoro.work_seconds,
+            config.pomodoro.short_break_seconds,
+            config.pomodoro.long_break_seconds,
+            config.pomodoro.long_break_interval,
+        )?;
+        let pomodoro_use_cases = Arc::new(
+            crate::application::use_cases::PomodoroUseCasesImpl::new(
+                pomodoro_repo,
+                time_tracking_use_cases.clone(),
+                Arc::new(SystemClock),
+                pomodoro_settings,
+            )
+            .with_event_bus(event_bus.clone())
+        ) as Arc<dyn PomodoroUseCases>;
+        tracing::debug!("ApplicationService::new: Pomodoro use cases created");
+
+        let report_service = Box::new(
+            ReportServiceImpl::new(report_repo, SqliteRoundingRepository::new(db_arc.clone()), Arc::new(SystemClock))
+        ) as Box<dyn ReportService>;
+        tracing::debug!("ApplicationService::new: Report service created");
+
+        let calendar = LocalCalendar::from_name(&config.calendar.timezone, config.calendar.week_start)?;
+        tracing::debug!("ApplicationService::new: Calendar created - config: {:?}", config.calendar);
+
+        let timesheet_use_cases = Box::new(
+            crate::application::use_cases::TimesheetUseCasesImpl::new(
+                SqliteTimeEntryRepository::new(db_arc.clone()),
+                SqliteTaskRepository::new(db_arc.clone()),
+                time_tracking_use_cases.clone(),
+                Arc::new(SystemClock),
+                calendar,
+                config.timeline.working_hours_start,
+            )
+        ) as Box<dyn TimesheetUseCases>;
+        tracing::debug!("ApplicationService::new: Timesheet use cases created");
+
+        let working_hours = WorkingHours::new(
+            config.timeline.working_hours_start,
+            config.timeline.working_hours_end,
+            config.timeline.working_days.clone(),
+        )?;
+        let timeline_service = Box::new(
+            TimelineServiceImpl::new(
+                SqliteTimelineRepository::new(db_arc.clone()),
+                Arc::new(SystemClock),
+                calendar,
+                working_hours,
+                config.timeline.min_gap_seconds,
+            )
+        ) as Box<dyn TimelineService>;
+        tracing::debug!("ApplicationService::new: Timeline service created");
+
+        let rounding_use_cases = Box::new(
+            crate::application::use_cases::RoundingUseCasesImpl::new(
+                SqliteRoundingRepository::new(db_arc.clone()),
+                SqliteTimeEntryRepository::new(db_arc.clone()),
+                SqliteTaskRepository::new(db_arc.clone()),
+                SqliteProjectRepository::new(db_arc.clone()),
+                Arc::new(SystemClock),
+                calendar,
+            )
+        ) as Box<dyn RoundingUseCases>;
+        tracing::debug!("ApplicationService::new: Rounding use cases created");
+
+        let export_service = Box::new(
+            ExportServiceImpl::new(
+                SqliteTimeEntryRepository::new(db_arc.clone()),
+                SqliteTaskRepository::new(db_arc.clone()),
+                SqliteProjectRepository::new(db_arc.clone()),
+                SqliteTagRepository::new(db_arc.clone()),
+                Arc::new(SystemClock),
+                calendar,
+            )
+        ) as Box<dyn ExportService>;
+        tracing::debug!("ApplicationService::new: Export service created");
+
+        let backup_service = Box::new(
+            BackupServiceImpl::new(SqliteBackupRepository::new(db_arc.clone()), Arc::new(SystemClock))
+        ) as Box<dyn BackupService>;
+        tracing::debug!("ApplicationService::new: Backup service created");
+
+        let import_service = Box::new(
+            ImportServiceImpl::new(
+                project_use_cases.clone(),
+                task_use_cases.clone(),
+                tag_use_cases.clone(),
+                time_tracking_use_cases.clone(),
+                calendar,
+            )
+        ) as Box<dyn ImportService>;
+        tracing::debug!("ApplicationService::new: Import service created");
+
+        // 前回終了時に実行中だったタイマーの照合（ハートビートが途絶していれば復旧待ちにする）
+        match recovery_use_cases.check_heartbeats().await {
+            Ok(detected) if !detected.is_empty() => {
+                tracing::warn!("ApplicationService::new: {} running timer(s) pending recovery", detected.len());
+                // 通知先は起動後に設定されるため、それまで DeferredNotifier が保持する
+                for recovery in detected {
+                    notifier.notify(AppNotification::TimerRecoveryPending(recovery));
+                }
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::error!("ApplicationService::new: Failed to reconcile running timers: {}", e);
+            }
+        }
 
         tracing::info!("ApplicationService::new: All components created successfully, creating ApplicationService instance");
         
@@ -93,6 +277,24 @@ impl ApplicationService {
             project_use_cases,
             task_use_cases,
             time_tracking_use_cases,
+            tag_use_cases,
+            integrity_use_cases,
+            recovery_use_cases,
+            pomodoro_use_cases,
+            report_service,
+            timesheet_use_cases,
+            timeline_service,
+            rounding_use_cases,
+            export_service,
+            backup_service,
+            import_service,
+            auto_cutoff_config: config.auto_cutoff,
+            recovery_config: config.recovery,
+            pomodoro_config: config.pomodoro,
+            timer_config: config.timer,
+            calendar,
+            notifier,
+            event_bus,
         };
         
         tracing::info!("ApplicationService::new: Application service initialization completed successfully");
@@ -114,6 +316,115 @@ impl ApplicationService {
         self.time_tracking_use_cases.as_ref()
     }
 
+    /// タグユースケースを取得
+    pub fn tag_use_cases(&self) -> &dyn TagUseCases {
+        self.tag_use_cases.as_ref()
+    }
+
+    /// 整合性ユースケースを取得
+    pub fn integrity_use_cases(&self) -> &dyn IntegrityUseCases {
+        self.integrity_use_cases.as_ref()
+    }
+
+    /// タイマー復旧ユースケースを取得
+    pub fn recovery_use_cases(&self) -> &dyn RecoveryUseCases {
+        self.recovery_use_cases.as_ref()
+    }
+
+    /// ポモドーロユースケースを取得
+    pub fn pomodoro_use_cases(&self) -> &dyn PomodoroUseCases {
+        self.pomodoro_use_cases.as_ref()
+    }
+
+    /// レポートサービスを取得
+    pub fn report_service(&self) -> &dyn ReportService {
+        self.report_service.as_ref()
+    }
+
+    /// タイムシートユースケースを取得
+    pub fn timesheet_use_cases(&self) -> &dyn TimesheetUseCases {
+        self.timesheet_use_cases.as_ref()
+    }
+
+    /// タイムラインサービスを取得
+    pub fn timeline_service(&self) -> &dyn TimelineService {
+        self.timeline_service.as_ref()
+    }
+
+    /// 丸め設定ユースケースを取得
+    pub fn rounding_use_cases(&self) -> &dyn RoundingUseCases {
+        self.rounding_use_cases.as_ref()
+    }
+
+    /// エクスポートサービスを取得
+    pub fn export_service(&self) -> &dyn ExportService {
+        self.export_service.as_ref()
+    }
+
+    /// バックアップサービスを取得
+    pub fn backup_service(&self) -> &dyn BackupService {
+        self.backup_service.as_ref()
+    }
+
+    /// 取り込みサービスを取得
+    pub fn import_service(&self) -> &dyn ImportService {
+        self.import_service.as_ref()
+    }
+
+    /// 日・週の区切りに使うカレンダーを取得
+    pub fn calendar(&self) -> LocalCalendar {
+        self.calendar
+    }
+
+    /// ドメインイベントのバスを取得（購読者の登録に使う）
+    pub fn event_bus(&self) -> Arc<EventBus> {
+        self.event_bus.clone()
+    }
+
+    /// ユースケースからの通知先を設定する（設定前の通知は保持しておき、設定時に送る）
+    pub fn attach_notifier(&self, notifier: Arc<dyn Notifier>) {
+        self.notifier.set(notifier);
+    }
+
+    /// 長時間実行タイマーの自動停止タスクを起動する（設定で無効な場合は起動しない）
+    pub fn start_auto_cutoff(
+        &self,
+        notifier: Arc<dyn Notifier>,
+        clock: Arc<dyn Clock>,
+    ) -> anyhow::Result<Option<tokio::task::JoinHandle<()>>> {
+        let config = &self.auto_cutoff_config;
+        let policy = AutoCutoffPolicy::new(config.max_timer_seconds, config.daily_cutoff_time, self.calendar)?;
+        if !policy.is_enabled() {
+            tracing::info!("ApplicationService::start_auto_cutoff: Auto cutoff is disabled");
+            return Ok(None);
+        }
+
+        tracing::info!("ApplicationService::start_auto_cutoff: Starting auto cutoff task - config: {:?}", config);
+        let worker = AutoCutoffWorker::new(self.time_tracking_use_cases.clone(), policy, clock, notifier);
+        Ok(Some(worker.spawn(std::time::Duration::from_secs(config.check_interval_seconds.max(1)))))
+    }
+
+    /// 実行中タイマーのハートビート記録タスクを起動する
+    pub fn start_heartbeat(&self, notifier: Arc<dyn Notifier>) -> tokio::task::JoinHandle<()> {
+        let interval = std::time::Duration::from_secs(self.recovery_config.heartbeat_interval_seconds.max(1));
+        tracing::info!("ApplicationService::start_heartbeat: Starting heartbeat task - config: {:?}", self.recovery_config);
+        HeartbeatWorker::new(self.recovery_use_cases.clone(), notifier).spawn(interval)
+    }
+
+    /// ポモドーロのフェーズを進めるタスクを起動する
+    pub fn start_pomodoro(&self, notifier: Arc<dyn Notifier>) -> tokio::task::JoinHandle<()> {
+        let interval = std::time::Duration::from_secs(self.pomodoro_config.tick_interval_seconds.max(1));
+        tracing::info!("ApplicationService::start_pomodoro: Starting pomodoro task - config: {:?}", self.pomodoro_config);
+        PomodoroWorker::new(self.pomodoro_use_cases.clone(), notifier).spawn(interval)
+    }
+
+    /// 実行中タイマーの経過時間を通知するタスクを起動する
+    pub fn start_timer_tick(&self, notifier: Arc<dyn Notifier>) -> tokio::task::JoinHandle<()> {
+        let interval = std::time::Duration::from_secs(self.timer_config.tick_interval_seconds.max(1));
+        tracing::info!("ApplicationService::start_timer_tick: Starting timer tick task - config: {:?}", self.timer_config);
+        TimerTickWorker::new(self.time_tracking_use_cases.clone(), notifier).spawn(interval)
+    }
+
     /// データベース接続を取得
     pub fn database(&self) -> Arc<Mutex<DatabaseConnection>> {
         self.db.clone()
@@ -134,5 +445,6 @@ mod tests {
         let _project_use_cases = app_service.project_use_cases();
         let _task_use_cases = app_service.task_use_cases();
         let _time_tracking_use_cases = app_service.time_tracking_use_cases();
+        let _tag_use_cases = app_service.tag_use_cases();
     }
 }
@@ -0,0 +1,117 @@
+use crate::application::services::{AppNotification, Notifier};
+use crate::application::use_cases::TimeTrackingUseCases;
+use crate::domain::entities::TimeEntryEvent;
+use crate::domain::services::{AutoCutoffPolicy, Clock};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 長時間実行タイマーの自動停止を定期実行するバックグラウンドタスク
+pub struct AutoCutoffWorker {
+    time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>,
+    policy: AutoCutoffPolicy,
+    clock: Arc<dyn Clock>,
+    notifier: Arc<dyn Notifier>,
+}
+
+impl AutoCutoffWorker {
+    pub fn new(
+        time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>,
+        policy: AutoCutoffPolicy,
+        clock: Arc<dyn Clock>,
+        notifier: Arc<dyn Notifier>,
+    ) -> Self {
+        Self {
+            time_tracking_use_cases,
+            policy,
+            clock,
+            notifier,
+        }
+    }
+
+    /// 1回分の確認を行い、自動停止したイベントを通知する
+    pub async fn run_once(&self) -> anyhow::Result<Vec<TimeEntryEvent>> {
+        let now = self.clock.now();
+        let stop_events = self
+            .time_tracking_use_cases
+            .apply_auto_cutoff(&self.policy, now)
+            .await?;
+
+        for event in &stop_events {
+            tracing::info!(
+                task_id = %event.task_id(),
+                start_event_id = ?event.start_event_id(),
+                at = %event.at(),
+                "Timer stopped by auto cutoff"
+            );
+            self.notifier.notify(AppNotification::TimerAutoCutoff(event.clone()));
+        }
+
+        Ok(stop_events)
+    }
+
+    /// 指定間隔で確認を繰り返すタスクを起動する
+    pub fn spawn(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.run_once().await {
+                    tracing::error!(error = %e, "Failed to apply auto cutoff");
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::application::services::notifier::tests::RecordingNotifier;
+    use crate::application::use_cases::{StartTimerCommand, TimeTrackingUseCasesImpl};
+    use crate::domain::entities::{RepairReason, Task};
+    use crate::domain::repositories::task_tests::InMemoryTaskRepository;
+    use crate::domain::repositories::time_entry_tests::InMemoryTimeEntryRepository;
+    use crate::domain::repositories::TaskRepository;
+    use crate::domain::services::clock::tests::FixedClock;
+    use crate::domain::services::{LocalCalendar, TimeTrackingServiceImpl};
+    use crate::domain::value_objects::{ProjectId, TaskId};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn 打ち切り時刻を過ぎると自動停止して通知すること() {
+        let time_entry_repo = InMemoryTimeEntryRepository::new();
+        let task_repo = InMemoryTaskRepository::new();
+        let task_id = TaskId::new(1).unwrap();
+        task_repo
+            .save(&Task::new(task_id, ProjectId::new(1).unwrap(), "Test Task".to_string()).unwrap())
+            .await
+            .unwrap();
+        let use_cases: Arc<dyn TimeTrackingUseCases> = Arc::new(TimeTrackingUseCasesImpl::new(
+            time_entry_repo.clone(),
+            task_repo,
+            TimeTrackingServiceImpl::new(time_entry_repo),
+        ));
+
+        let start_event = use_cases.start_timer(StartTimerCommand { task_id, at: None }).await.unwrap();
+        let clock = Arc::new(FixedClock::new(Utc::now()));
+        let notifier = Arc::new(RecordingNotifier::new());
+        let policy = AutoCutoffPolicy::new(Some(60 * 60), None, LocalCalendar::utc()).unwrap();
+        let worker = AutoCutoffWorker::new(use_cases.clone(), policy, clock.clone(), notifier.clone());
+
+        // 閾値到達前は停止しない
+        assert!(worker.run_once().await.unwrap().is_empty());
+        assert!(notifier.notifications().is_empty());
+
+        clock.advance(chrono::Duration::hours(2));
+        let stopped = worker.run_once().await.unwrap();
+        assert_eq!(stopped.len(), 1);
+        assert_eq!(stopped[0].repair_reason(), Some(RepairReason::AutoCutoff));
+        assert_eq!(
+            stopped[0].at().timestamp(),
+            (start_event.at() + chrono::Duration::hours(1)).timestamp()
+        );
+        assert_eq!(notifier.notifications(), vec![AppNotification::TimerAutoCutoff(stopped[0].clone())]);
+        assert!(use_cases.get_current_timer().await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,159 @@
+use crate::domain::repositories::{Backup, BackupRepository, BACKUP_FORMAT};
+use crate::domain::services::Clock;
+use async_trait::async_trait;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// バックアップの書き出し・取り込み結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupSummary {
+    pub path: PathBuf,
+    pub schema_version: i64,
+    /// テーブルごとの行数（バックアップ文書の順序）
+    pub table_rows: Vec<(String, usize)>,
+}
+
+impl BackupSummary {
+    fn new(path: PathBuf, backup: &Backup) -> Self {
+        Self {
+            path,
+            schema_version: backup.schema_version,
+            table_rows: backup
+                .tables
+                .iter()
+                .map(|table| (table.name.clone(), table.rows.len()))
+                .collect(),
+        }
+    }
+}
+
+/// バックアップサービス
+#[async_trait]
+pub trait BackupService: Send + Sync {
+    /// 全テーブルの全行をバージョン付きのJSON文書として書き出す
+    async fn export_backup(&self, path: PathBuf) -> anyhow::Result<BackupSummary>;
+
+    /// JSON文書を空のデータベースに復元する（一部だけ取り込むことはない）
+    async fn import_backup(&self, path: PathBuf) -> anyhow::Result<BackupSummary>;
+}
+
+/// バックアップサービス実装
+pub struct BackupServiceImpl<B: BackupRepository> {
+    repository: B,
+    clock: Arc<dyn Clock>,
+}
+
+impl<B: BackupRepository> BackupServiceImpl<B> {
+    pub fn new(repository: B, clock: Arc<dyn Clock>) -> Self {
+        Self { repository, clock }
+    }
+}
+
+#[async_trait]
+impl<B: BackupRepository> BackupService for BackupServiceImpl<B> {
+    async fn export_backup(&self, path: PathBuf) -> anyhow::Result<BackupSummary> {
+        let backup = Backup {
+            format: BACKUP_FORMAT.to_string(),
+            schema_version: self.repository.schema_version().await?,
+            exported_at: self.clock.now(),
+            tables: self.repository.export_tables().await?,
+        };
+
+        tokio::fs::write(&path, serde_json::to_string_pretty(&backup)?)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", path.display(), e))?;
+        tracing::info!("BackupService::export_backup: wrote schema version {} to {}", backup.schema_version, path.display());
+
+        Ok(BackupSummary::new(path, &backup))
+    }
+
+    async fn import_backup(&self, path: PathBuf) -> anyhow::Result<BackupSummary> {
+        let content = tokio::fs::read_to_string(&path)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+        let backup: Backup = serde_json::from_str(&content).map_err(|e| anyhow::anyhow!("Invalid backup file: {}", e))?;
+        if backup.format != BACKUP_FORMAT {
+            return Err(anyhow::anyhow!("Unsupported backup format: {}", backup.format));
+        }
+
+        self.repository
+            .import_tables(backup.schema_version, &backup.tables)
+            .await?;
+        tracing::info!("BackupService::import_backup: restored schema version {} from {}", backup.schema_version, path.display());
+
+        Ok(BackupSummary::new(path, &backup))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::BackupTable;
+    use crate::domain::services::clock::tests::FixedClock;
+    use chrono::{TimeZone, Utc};
+    use tokio::sync::Mutex;
+
+    // 取り込んだテーブルをそのまま保持するリポジトリ
+    #[derive(Default)]
+    struct StubBackupRepository {
+        tables: Mutex<Vec<BackupTable>>,
+    }
+
+    #[async_trait]
+    impl BackupRepository for StubBackupRepository {
+        async fn schema_version(&self) -> anyhow::Result<i64> {
+            Ok(10)
+        }
+
+        async fn export_tables(&self) -> anyhow::Result<Vec<BackupTable>> {
+            Ok(self.tables.lock().await.clone())
+        }
+
+        async fn import_tables(&self, schema_version: i64, tables: &[BackupTable]) -> anyhow::Result<()> {
+            assert_eq!(schema_version, 10);
+            *self.tables.lock().await = tables.to_vec();
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn 書き出した文書を取り込めること() {
+        let source = StubBackupRepository::default();
+        *source.tables.lock().await = vec![BackupTable {
+            name: "projects".to_string(),
+            rows: vec![serde_json::json!({ "id": 1 }).as_object().unwrap().clone()],
+        }];
+        let clock = Arc::new(FixedClock::new(Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap()));
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("backup.json");
+
+        let exported = BackupServiceImpl::new(source, clock.clone())
+            .export_backup(path.clone())
+            .await
+            .unwrap();
+        assert_eq!(exported.table_rows, vec![("projects".to_string(), 1)]);
+        let document: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(document["format"], BACKUP_FORMAT);
+        assert_eq!(document["schema_version"], 10);
+
+        let service = BackupServiceImpl::new(StubBackupRepository::default(), clock);
+        let imported = service.import_backup(path).await.unwrap();
+        assert_eq!(imported, exported);
+        assert_eq!(service.repository.export_tables().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn バックアップ以外の文書は取り込まないこと() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("other.json");
+        std::fs::write(&path, r#"{"format":"other","schema_version":10,"exported_at":"2025-03-01T00:00:00Z","tables":[]}"#).unwrap();
+        let service = BackupServiceImpl::new(
+            StubBackupRepository::default(),
+            Arc::new(FixedClock::new(Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap())),
+        );
+
+        assert!(service.import_backup(path).await.is_err());
+        assert!(service.import_backup(dir.path().join("missing.json")).await.is_err());
+    }
+}
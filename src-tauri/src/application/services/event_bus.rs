@@ -0,0 +1,73 @@
+use crate::domain::events::DomainEvent;
+use std::sync::{Arc, RwLock};
+
+/// ドメインイベントの購読者
+pub trait DomainEventSubscriber: Send + Sync {
+    fn handle(&self, event: &DomainEvent);
+}
+
+impl<F> DomainEventSubscriber for F
+where
+    F: Fn(&DomainEvent) + Send + Sync,
+{
+    fn handle(&self, event: &DomainEvent) {
+        self(event)
+    }
+}
+
+/// プロセス内のドメインイベントバス
+///
+/// 発行されたイベントは購読者に登録順で同期的に配信する。
+/// 時間のかかる処理を行う購読者は、自身でタスクを起動して処理すること。
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: RwLock<Vec<Arc<dyn DomainEventSubscriber>>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 購読者を登録する
+    pub fn subscribe(&self, subscriber: Arc<dyn DomainEventSubscriber>) {
+        self.subscribers.write().unwrap().push(subscriber);
+    }
+
+    /// イベントを発行する
+    pub fn publish(&self, event: DomainEvent) {
+        // 配信中に購読者が登録されてもデッドロックしないよう、一覧を複製してから配信する
+        let subscribers = self.subscribers.read().unwrap().clone();
+        for subscriber in subscribers {
+            subscriber.handle(&event);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ProjectId;
+    use std::sync::Mutex;
+
+    #[test]
+    fn 発行したイベントが全ての購読者に配信されること() {
+        let bus = EventBus::new();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        for name in ["first", "second"] {
+            let received = received.clone();
+            bus.subscribe(Arc::new(move |event: &DomainEvent| {
+                received.lock().unwrap().push((name, event.clone()));
+            }));
+        }
+
+        let event = DomainEvent::ProjectArchived {
+            project_id: ProjectId::new(1).unwrap(),
+            with_tasks: false,
+        };
+        bus.publish(event.clone());
+
+        assert_eq!(*received.lock().unwrap(), vec![("first", event.clone()), ("second", event)]);
+    }
+}
@@ -0,0 +1,642 @@
+use crate::domain::entities::time_entry::TimeEntry;
+use crate::domain::entities::TaskTagEvent;
+use crate::domain::repositories::{ProjectRepository, TagRepository, TaskRepository, TimeEntryQuery, TimeEntryRepository};
+use crate::domain::services::{Clock, LocalCalendar};
+use crate::domain::value_objects::{TagId, TaskId};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// CSVの書式（区切り文字とエスケープの方法）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvFormat {
+    delimiter: char,
+    /// 全てのフィールドを引用符で囲む（false の場合は必要なフィールドのみ）
+    always_quote: bool,
+    /// `=` `+` `-` `@` で始まるフィールドの先頭に `'` を付け、表計算ソフトで数式として解釈されないようにする
+    escape_formulas: bool,
+}
+
+impl CsvFormat {
+    pub fn new(delimiter: char, always_quote: bool, escape_formulas: bool) -> anyhow::Result<Self> {
+        if matches!(delimiter, '"' | '\'' | '\r' | '\n') {
+            return Err(anyhow::anyhow!("Invalid CSV delimiter: {:?}", delimiter));
+        }
+        Ok(Self {
+            delimiter,
+            always_quote,
+            escape_formulas,
+        })
+    }
+
+    /// 1行分のフィールドをCSVの行（改行は CRLF）に変換
+    pub fn format_row<S: AsRef<str>>(&self, fields: &[S]) -> String {
+        let mut line = fields
+            .iter()
+            .map(|field| self.format_field(field.as_ref()))
+            .collect::<Vec<_>>()
+            .join(&self.delimiter.to_string());
+        line.push_str("\r\n");
+        line
+    }
+
+    fn format_field(&self, value: &str) -> String {
+        let value = if self.escape_formulas && value.starts_with(['=', '+', '-', '@']) {
+            format!("'{}", value)
+        } else {
+            value.to_string()
+        };
+
+        let needs_quote = self.always_quote || value.contains([self.delimiter, '"', '\r', '\n']);
+        if needs_quote {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value
+        }
+    }
+}
+
+impl Default for CsvFormat {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            always_quote: false,
+            escape_formulas: true,
+        }
+    }
+}
+
+/// エクスポートする時間エントリの条件
+#[derive(Debug, Clone)]
+pub struct ExportEntryFilter {
+    /// 期間（ローカル日付、両端を含む）
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub project_id: Option<i64>,
+    pub task_id: Option<TaskId>,
+    /// エントリ開始時点で付与されていたタグで絞り込む
+    pub tag_id: Option<TagId>,
+}
+
+/// 時間エントリのCSVエクスポートコマンド
+#[derive(Debug, Clone)]
+pub struct ExportTimeEntriesCsvCommand {
+    pub filter: ExportEntryFilter,
+    pub path: PathBuf,
+    pub format: CsvFormat,
+    /// 日付の区切りと時刻の表示に使うタイムゾーン（未指定の場合は設定のカレンダー）
+    pub timezone: Option<Tz>,
+}
+
+/// 時間エントリのiCalendarエクスポートコマンド
+#[derive(Debug, Clone)]
+pub struct ExportTimeEntriesIcsCommand {
+    pub filter: ExportEntryFilter,
+    pub path: PathBuf,
+    /// 実行中のエントリを現在時刻までの仮の予定（TENTATIVE）として含める（false の場合は除外）
+    pub include_running: bool,
+}
+
+/// エクスポート結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportSummary {
+    pub path: PathBuf,
+    pub row_count: usize,
+    pub total_seconds: i64,
+}
+
+/// エクスポートサービス
+#[async_trait]
+pub trait ExportService: Send + Sync {
+    /// 期間内に開始した時間エントリをCSVファイルに書き出す（開始時刻順）
+    async fn export_time_entries_csv(&self, command: ExportTimeEntriesCsvCommand) -> anyhow::Result<ExportSummary>;
+
+    /// 期間内に開始した時間エントリをiCalendarファイルのVEVENTとして書き出す（UIDは開始イベントIDから決まる）
+    async fn export_time_entries_ics(&self, command: ExportTimeEntriesIcsCommand) -> anyhow::Result<ExportSummary>;
+}
+
+/// エクスポートサービス実装
+pub struct ExportServiceImpl<T, K, P, G>
+where
+    T: TimeEntryRepository,
+    K: TaskRepository,
+    P: ProjectRepository,
+    G: TagRepository,
+{
+    time_entry_repository: T,
+    task_repository: K,
+    project_repository: P,
+    tag_repository: G,
+    clock: Arc<dyn Clock>,
+    calendar: LocalCalendar,
+}
+
+/// CSVの各行に付けるタスクの情報
+struct TaskLabels {
+    task_name: String,
+    project_name: String,
+}
+
+impl<T, K, P, G> ExportServiceImpl<T, K, P, G>
+where
+    T: TimeEntryRepository,
+    K: TaskRepository,
+    P: ProjectRepository,
+    G: TagRepository,
+{
+    /// CSVの列
+    pub const CSV_HEADER: [&'static str; 9] = [
+        "date",
+        "project",
+        "task",
+        "tags",
+        "start",
+        "end",
+        "duration_seconds",
+        "duration_hours",
+        "notes",
+    ];
+
+    pub fn new(
+        time_entry_repository: T,
+        task_repository: K,
+        project_repository: P,
+        tag_repository: G,
+        clock: Arc<dyn Clock>,
+        calendar: LocalCalendar,
+    ) -> Self {
+        Self {
+            time_entry_repository,
+            task_repository,
+            project_repository,
+            tag_repository,
+            clock,
+            calendar,
+        }
+    }
+
+    /// 期間・条件に一致する時間エントリを開始時刻順に取得
+    async fn find_entries(&self, filter: &ExportEntryFilter, calendar: &LocalCalendar) -> anyhow::Result<Vec<TimeEntry>> {
+        if filter.end_date < filter.start_date {
+            return Err(anyhow::anyhow!("End date must not be before start date"));
+        }
+
+        let start = calendar.start_of_day(filter.start_date);
+        let end = calendar.start_of_day(filter.end_date + Duration::days(1));
+        let mut query = TimeEntryQuery::new(start, end);
+        query.project_id = filter.project_id;
+        query.task_id = filter.task_id;
+        let mut entries = self.time_entry_repository.find_entries_by_query(&query).await?;
+
+        if let Some(tag_id) = filter.tag_id {
+            let tagged: HashSet<i64> = self
+                .time_entry_repository
+                .find_entries_by_period_and_tag(tag_id, start, end)
+                .await?
+                .iter()
+                .map(TimeEntry::start_event_id)
+                .collect();
+            entries.retain(|entry| tagged.contains(&entry.start_event_id()));
+        }
+
+        entries.reverse();
+        Ok(entries)
+    }
+
+    async fn load_labels(&self, task_id: TaskId) -> anyhow::Result<TaskLabels> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {}", i64::from(task_id)))?;
+        let project_name = self
+            .project_repository
+            .find_by_id(task.project_id())
+            .await?
+            .map(|project| project.name().to_string())
+            .unwrap_or_default();
+
+        Ok(TaskLabels {
+            task_name: task.name().to_string(),
+            project_name,
+        })
+    }
+
+    /// タスクのタグイベントを取得（取得済みのものは再利用する）
+    async fn tag_events_for<'a>(
+        &self,
+        tag_events: &'a mut HashMap<TaskId, Vec<TaskTagEvent>>,
+        task_id: TaskId,
+    ) -> anyhow::Result<&'a [TaskTagEvent]> {
+        Ok(match tag_events.entry(task_id) {
+            Entry::Occupied(occupied) => occupied.into_mut(),
+            Entry::Vacant(vacant) => vacant.insert(self.tag_repository.find_events_by_task(task_id).await?),
+        })
+    }
+
+    /// タスクの情報を取得（取得済みのものは再利用する）
+    async fn labels_for<'a>(
+        &self,
+        labels: &'a mut HashMap<TaskId, TaskLabels>,
+        task_id: TaskId,
+    ) -> anyhow::Result<&'a TaskLabels> {
+        Ok(match labels.entry(task_id) {
+            Entry::Occupied(occupied) => occupied.into_mut(),
+            Entry::Vacant(vacant) => vacant.insert(self.load_labels(task_id).await?),
+        })
+    }
+}
+
+async fn write_export(path: &Path, content: String) -> anyhow::Result<()> {
+    tokio::fs::write(path, content)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", path.display(), e))
+}
+
+/// 指定時刻の時点で付与されていたタグ（名前順、カンマ区切り）
+///
+/// タグリポジトリの `find_by_task_at` と同じく、時刻までの最新のイベントが付与のタグとする。
+fn tags_at(events: &[TaskTagEvent], at: DateTime<Utc>, tag_names: &HashMap<TagId, String>) -> String {
+    let mut latest: HashMap<TagId, bool> = HashMap::new();
+    for event in events.iter().take_while(|event| event.at() <= at) {
+        latest.insert(event.tag_id(), event.is_add());
+    }
+    let mut names: Vec<&str> = latest
+        .into_iter()
+        .filter(|(_, added)| *added)
+        .filter_map(|(tag_id, _)| tag_names.get(&tag_id).map(String::as_str))
+        .collect();
+    names.sort_unstable();
+    names.join(", ")
+}
+
+/// ローカル時刻の表示（YYYY-MM-DD HH:MM:SS形式）
+fn format_local(at: DateTime<Utc>, timezone: Tz) -> String {
+    at.with_timezone(&timezone).format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// iCalendarの日時（UTC、YYYYMMDDTHHMMSSZ形式）
+fn ics_datetime(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// iCalendarのTEXT値のエスケープ（RFC 5545 3.3.11）
+fn ics_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// iCalendarの1行（75オクテットを超える場合は折り返す、UTF-8の文字の途中では折り返さない）
+fn ics_line(line: &str) -> String {
+    let mut result = String::with_capacity(line.len() + 2);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            result.push_str("\r\n ");
+            width = 1;
+        }
+        result.push(c);
+        width += c.len_utf8();
+    }
+    result.push_str("\r\n");
+    result
+}
+
+#[async_trait]
+impl<T, K, P, G> ExportService for ExportServiceImpl<T, K, P, G>
+where
+    T: TimeEntryRepository,
+    K: TaskRepository,
+    P: ProjectRepository,
+    G: TagRepository,
+{
+    async fn export_time_entries_csv(&self, command: ExportTimeEntriesCsvCommand) -> anyhow::Result<ExportSummary> {
+        let calendar = match command.timezone {
+            Some(timezone) => LocalCalendar::new(timezone, self.calendar.week_start()),
+            None => self.calendar,
+        };
+        let entries = self.find_entries(&command.filter, &calendar).await?;
+        let now = self.clock.now();
+
+        // タグはエントリごとに問い合わせず、タスクごとのイベントから開始時点のものを求める
+        let tag_names: HashMap<TagId, String> = self
+            .tag_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|tag| (tag.id(), tag.name().to_string()))
+            .collect();
+        let mut tag_events: HashMap<TaskId, Vec<TaskTagEvent>> = HashMap::new();
+        let mut labels: HashMap<TaskId, TaskLabels> = HashMap::new();
+        let mut csv = command.format.format_row(&Self::CSV_HEADER);
+        let mut total_seconds = 0;
+        for entry in &entries {
+            let tags = tags_at(
+                self.tag_events_for(&mut tag_events, entry.task_id()).await?,
+                entry.start_time(),
+                &tag_names,
+            );
+            let label = self.labels_for(&mut labels, entry.task_id()).await?;
+
+            // 実行中のエントリは終了時刻を空欄にし、現在時刻までの時間を出力する
+            let seconds = entry
+                .duration_in_seconds()
+                .unwrap_or_else(|| entry.seconds_within(entry.start_time(), now, now));
+            total_seconds += seconds;
+
+            csv.push_str(&command.format.format_row(&[
+                calendar.date_of(entry.start_time()).format("%Y-%m-%d").to_string(),
+                label.project_name.clone(),
+                label.task_name.clone(),
+                tags,
+                format_local(entry.start_time(), calendar.timezone()),
+                entry
+                    .end_time()
+                    .map(|end| format_local(end, calendar.timezone()))
+                    .unwrap_or_default(),
+                seconds.to_string(),
+                format!("{:.2}", seconds as f64 / 3600.0),
+                entry.notes().unwrap_or_default().to_string(),
+            ]));
+        }
+
+        write_export(&command.path, csv).await?;
+        tracing::info!(
+            "ExportService::export_time_entries_csv: wrote {} rows to {}",
+            entries.len(),
+            command.path.display()
+        );
+
+        Ok(ExportSummary {
+            path: command.path,
+            row_count: entries.len(),
+            total_seconds,
+        })
+    }
+
+    async fn export_time_entries_ics(&self, command: ExportTimeEntriesIcsCommand) -> anyhow::Result<ExportSummary> {
+        let entries = self.find_entries(&command.filter, &self.calendar).await?;
+        let now = self.clock.now();
+
+        let mut labels: HashMap<TaskId, TaskLabels> = HashMap::new();
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//Time Tracker Go//Time Entries//EN".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+        ];
+        let mut row_count = 0;
+        let mut total_seconds = 0;
+        for entry in entries.iter().filter(|entry| command.include_running || entry.is_completed()) {
+            let label = self.labels_for(&mut labels, entry.task_id()).await?;
+            let summary = if label.project_name.is_empty() {
+                label.task_name.clone()
+            } else {
+                format!("{} ({})", label.task_name, label.project_name)
+            };
+            total_seconds += entry
+                .duration_in_seconds()
+                .unwrap_or_else(|| entry.seconds_within(entry.start_time(), now, now));
+            row_count += 1;
+
+            // 再取り込み時に同じ予定として更新されるよう、UIDは開始イベントIDだけから決める
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:time-entry-{}@time-tracker-go", entry.start_event_id()));
+            lines.push(format!("DTSTAMP:{}", ics_datetime(now)));
+            lines.push(format!("DTSTART:{}", ics_datetime(entry.start_time())));
+            lines.push(format!(
+                "DTEND:{}",
+                ics_datetime(entry.end_time().or(entry.paused_at()).unwrap_or(now))
+            ));
+            lines.push(format!("SUMMARY:{}", ics_escape(&summary)));
+            if let Some(notes) = entry.notes() {
+                lines.push(format!("DESCRIPTION:{}", ics_escape(notes)));
+            }
+            lines.push(format!("STATUS:{}", if entry.is_running() { "TENTATIVE" } else { "CONFIRMED" }));
+            lines.push("END:VEVENT".to_string());
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        write_export(&command.path, lines.iter().map(|line| ics_line(line)).collect()).await?;
+        tracing::info!(
+            "ExportService::export_time_entries_ics: wrote {} events to {}",
+            row_count,
+            command.path.display()
+        );
+
+        Ok(ExportSummary {
+            path: command.path,
+            row_count,
+            total_seconds,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::time_entry::TimeEntryEvent;
+    use crate::domain::entities::{Project, Tag, Task, TaskTagEvent};
+    use crate::domain::repositories::tag_tests::InMemoryTagRepository;
+    use crate::domain::repositories::task_tests::InMemoryTaskRepository;
+    use crate::domain::repositories::tests::InMemoryProjectRepository;
+    use crate::domain::repositories::time_entry_tests::InMemoryTimeEntryRepository;
+    use crate::domain::services::clock::tests::FixedClock;
+    use crate::domain::value_objects::ProjectId;
+    use chrono::TimeZone;
+
+    #[test]
+    fn 区切り文字や引用符を含むフィールドがエスケープされること() {
+        let format = CsvFormat::default();
+        assert_eq!(
+            format.format_row(&["plain", "a,b", "say \"hi\"", "line1\nline2", "=SUM(A1)"]),
+            "plain,\"a,b\",\"say \"\"hi\"\"\",\"line1\nline2\",'=SUM(A1)\r\n"
+        );
+
+        let format = CsvFormat::new(';', true, false).unwrap();
+        assert_eq!(format.format_row(&["a,b", "-1"]), "\"a,b\";\"-1\"\r\n");
+        assert!(CsvFormat::new('"', false, false).is_err());
+    }
+
+    type TestService = ExportServiceImpl<
+        InMemoryTimeEntryRepository,
+        InMemoryTaskRepository,
+        InMemoryProjectRepository,
+        InMemoryTagRepository,
+    >;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, day, hour, minute, 0).unwrap()
+    }
+
+    fn filter(start_day: u32, end_day: u32) -> ExportEntryFilter {
+        ExportEntryFilter {
+            start_date: NaiveDate::from_ymd_opt(2025, 3, start_day).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2025, 3, end_day).unwrap(),
+            project_id: None,
+            task_id: None,
+            tag_id: None,
+        }
+    }
+
+    // 3/10 23:30〜3/11 01:00 (UTC、注釈付き)、3/11 10:00〜（実行中）、3/12 09:00〜10:00 のエントリ
+    async fn setup() -> TestService {
+        let project_repo = InMemoryProjectRepository::new();
+        let task_repo = InMemoryTaskRepository::new();
+        let tag_repo = InMemoryTagRepository::new();
+        let time_entry_repo = InMemoryTimeEntryRepository::new();
+        let project_id = ProjectId::new(1).unwrap();
+        let task_id = TaskId::new(1).unwrap();
+        project_repo
+            .save(&Project::new(project_id, "Client, Inc.".to_string()).unwrap())
+            .await
+            .unwrap();
+        task_repo
+            .save(&Task::new(task_id, project_id, "Design".to_string()).unwrap())
+            .await
+            .unwrap();
+        let tag_id = TagId::new(1).unwrap();
+        tag_repo.save(&Tag::new(tag_id, "billable".to_string()).unwrap()).await.unwrap();
+        tag_repo
+            .save_task_tag_event(&TaskTagEvent::add(task_id, tag_id).with_at(at(1, 0, 0)))
+            .await
+            .unwrap();
+
+        for (start, end) in [(at(10, 23, 30), Some(at(11, 1, 0))), (at(11, 10, 0), None), (at(12, 9, 0), Some(at(12, 10, 0)))] {
+            let start_event = time_entry_repo
+                .save_event(&TimeEntryEvent::start_at(task_id, start))
+                .await
+                .unwrap();
+            if let Some(end) = end {
+                time_entry_repo
+                    .save_event(&TimeEntryEvent::stop_at(task_id, start_event.id().unwrap(), end))
+                    .await
+                    .unwrap();
+            }
+        }
+        time_entry_repo
+            .save_event(&TimeEntryEvent::annotate(task_id, 1, "Kickoff, \"draft\"".to_string()))
+            .await
+            .unwrap();
+
+        ExportServiceImpl::new(
+            time_entry_repo,
+            task_repo,
+            project_repo,
+            tag_repo,
+            Arc::new(FixedClock::new(at(11, 10, 30))),
+            LocalCalendar::utc(),
+        )
+    }
+
+    #[tokio::test]
+    async fn 期間内の時間エントリがローカル時刻でCSVに書き出されること() {
+        let service = setup().await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("entries.csv");
+        let summary = service
+            .export_time_entries_csv(ExportTimeEntriesCsvCommand {
+                filter: filter(11, 11),
+                path: path.clone(),
+                format: CsvFormat::default(),
+                timezone: Some(Tz::Asia__Tokyo),
+            })
+            .await
+            .unwrap();
+
+        // 東京時間では 3/11 08:30 開始と 3/11 19:00 開始のエントリが期間に含まれる
+        assert_eq!(summary.row_count, 2);
+        assert_eq!(summary.total_seconds, 5400 + 1800);
+        let csv = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<_> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], "date,project,task,tags,start,end,duration_seconds,duration_hours,notes");
+        assert_eq!(
+            lines[1],
+            "2025-03-11,\"Client, Inc.\",Design,billable,2025-03-11 08:30:00,2025-03-11 10:00:00,5400,1.50,\"Kickoff, \"\"draft\"\"\""
+        );
+        assert_eq!(lines[2], "2025-03-11,\"Client, Inc.\",Design,billable,2025-03-11 19:00:00,,1800,0.50,");
+    }
+
+    #[tokio::test]
+    async fn タグは各エントリの開始時点で付与されていたものが書き出されること() {
+        let service = setup().await;
+        // 3/11 10:00 開始のエントリより後、3/12 09:00 開始のエントリより前にタグを外す
+        service
+            .tag_repository
+            .save_task_tag_event(
+                &TaskTagEvent::remove(TaskId::new(1).unwrap(), TagId::new(1).unwrap()).with_at(at(11, 12, 0)),
+            )
+            .await
+            .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("entries.csv");
+        service
+            .export_time_entries_csv(ExportTimeEntriesCsvCommand {
+                filter: filter(10, 12),
+                path: path.clone(),
+                format: CsvFormat::default(),
+                timezone: None,
+            })
+            .await
+            .unwrap();
+
+        let csv = std::fs::read_to_string(&path).unwrap();
+        let tags: Vec<_> = csv
+            .split("\r\n")
+            .skip(1)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.split(',').nth(4).unwrap().to_string())
+            .collect();
+        assert_eq!(tags, vec!["billable", "billable", ""]);
+    }
+
+    #[test]
+    fn iCalendarの値がエスケープされ長い行が折り返されること() {
+        assert_eq!(ics_escape("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+
+        let line = format!("SUMMARY:{}", "設計".repeat(20));
+        let folded = ics_line(&line);
+        assert!(folded.split("\r\n").all(|part| part.len() <= 75));
+        assert_eq!(folded.replace("\r\n ", ""), format!("{}\r\n", line));
+    }
+
+    #[tokio::test]
+    async fn 時間エントリがUIDの安定したVEVENTとして書き出されること() {
+        let service = setup().await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("entries.ics");
+        let command = ExportTimeEntriesIcsCommand {
+            filter: filter(10, 11),
+            path: path.clone(),
+            include_running: false,
+        };
+
+        let summary = service.export_time_entries_ics(command.clone()).await.unwrap();
+        assert_eq!(summary.row_count, 1);
+        let ics = std::fs::read_to_string(&path).unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains(
+            "UID:time-entry-1@time-tracker-go\r\nDTSTAMP:20250311T103000Z\r\nDTSTART:20250310T233000Z\r\nDTEND:20250311T010000Z\r\n\
+             SUMMARY:Design (Client\\, Inc.)\r\nDESCRIPTION:Kickoff\\, \"draft\"\r\nSTATUS:CONFIRMED\r\n"
+        ));
+
+        // 実行中のエントリは現在時刻までの仮の予定になる
+        let summary = service
+            .export_time_entries_ics(ExportTimeEntriesIcsCommand { include_running: true, ..command })
+            .await
+            .unwrap();
+        assert_eq!(summary.row_count, 2);
+        let ics = std::fs::read_to_string(&path).unwrap();
+        assert!(ics.contains("UID:time-entry-3@time-tracker-go"));
+        assert!(ics.contains("DTEND:20250311T103000Z\r\nSUMMARY:Design (Client\\, Inc.)\r\nSTATUS:TENTATIVE\r\n"));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::application::services::{AppNotification, Notifier};
+use crate::application::use_cases::RecoveryUseCases;
+use crate::domain::services::PendingRecovery;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 実行中タイマーのハートビートを定期記録するバックグラウンドタスク
+/// スリープ復帰などで途絶を検出した場合はフロントエンドに通知する
+pub struct HeartbeatWorker {
+    recovery_use_cases: Arc<dyn RecoveryUseCases>,
+    notifier: Arc<dyn Notifier>,
+}
+
+impl HeartbeatWorker {
+    pub fn new(recovery_use_cases: Arc<dyn RecoveryUseCases>, notifier: Arc<dyn Notifier>) -> Self {
+        Self {
+            recovery_use_cases,
+            notifier,
+        }
+    }
+
+    /// 1回分のハートビートを記録し、新たに検出した復旧待ちの区間を通知する
+    pub async fn run_once(&self) -> anyhow::Result<Vec<PendingRecovery>> {
+        let detected = self.recovery_use_cases.check_heartbeats().await?;
+        for recovery in &detected {
+            self.notifier.notify(AppNotification::TimerRecoveryPending(recovery.clone()));
+        }
+        Ok(detected)
+    }
+
+    /// 指定間隔でハートビートを記録するタスクを起動する
+    pub fn spawn(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.run_once().await {
+                    tracing::error!(error = %e, "Failed to record timer heartbeat");
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::application::services::notifier::tests::RecordingNotifier;
+    use crate::application::use_cases::RecoveryUseCasesImpl;
+    use crate::domain::entities::TimeEntryEvent;
+    use crate::domain::repositories::time_entry_tests::InMemoryTimeEntryRepository;
+    use crate::domain::repositories::TimeEntryRepository;
+    use crate::domain::services::clock::tests::FixedClock;
+    use crate::domain::services::TimerRecoveryServiceImpl;
+    use crate::domain::value_objects::TaskId;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn スリープ復帰でハートビートの途絶を通知すること() {
+        let repository = InMemoryTimeEntryRepository::new();
+        let now = Utc::now();
+        let clock = Arc::new(FixedClock::new(now));
+        let use_cases: Arc<dyn RecoveryUseCases> = Arc::new(
+            RecoveryUseCasesImpl::new(TimerRecoveryServiceImpl::new(repository.clone()), clock.clone(), 5 * 60).unwrap(),
+        );
+        repository
+            .save_event(&TimeEntryEvent::start_at(TaskId::new(1).unwrap(), now))
+            .await
+            .unwrap();
+        let notifier = Arc::new(RecordingNotifier::new());
+        let worker = HeartbeatWorker::new(use_cases, notifier.clone());
+
+        assert!(worker.run_once().await.unwrap().is_empty());
+        assert!(notifier.notifications().is_empty());
+
+        // スリープ中はハートビートが記録されない
+        clock.advance(chrono::Duration::hours(1));
+        let detected = worker.run_once().await.unwrap();
+        assert_eq!(detected.len(), 1);
+        assert_eq!(notifier.notifications(), vec![AppNotification::TimerRecoveryPending(detected[0].clone())]);
+    }
+}
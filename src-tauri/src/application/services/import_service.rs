@@ -0,0 +1,1014 @@
+use crate::application::use_cases::{
+    AddManualEntryCommand, AddTagToTaskCommand, CreateProjectCommand, CreateTaskCommand, ProjectUseCases, TagUseCases,
+    TaskUseCases, TimeTrackingUseCases,
+};
+use crate::domain::entities::{Project, Tag, Task};
+use crate::domain::services::LocalCalendar;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Togglでプロジェクトが空の行を取り込むプロジェクト名
+pub const TOGGL_NO_PROJECT: &str = "(no project)";
+
+/// Togglでタスク・説明がどちらも空の行を取り込むタスク名
+pub const TOGGL_NO_DESCRIPTION: &str = "(no description)";
+
+/// 汎用CSV取り込みで日時の書式が指定されなかった場合の書式
+pub const DEFAULT_DATETIME_FORMATS: [&str; 3] = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S"];
+
+/// CSVの1レコード
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRecord {
+    /// レコードが始まる行番号（1始まり）
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+impl CsvRecord {
+    /// 指定列の値（前後の空白を除く、列がなければ空文字）
+    fn field(&self, index: usize) -> &str {
+        self.fields.get(index).map(|field| field.trim()).unwrap_or_default()
+    }
+}
+
+/// CSVをレコードに分割する（RFC 4180、引用符内の区切り文字・改行と `""` に対応）
+///
+/// 先頭のBOMと空行は無視する。
+pub fn parse_csv(content: &str, delimiter: char) -> anyhow::Result<Vec<CsvRecord>> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\r' | '\n' => {
+                fields.push(std::mem::take(&mut field));
+                if fields.len() > 1 || !fields[0].is_empty() {
+                    records.push(CsvRecord {
+                        line: record_line,
+                        fields: std::mem::take(&mut fields),
+                    });
+                }
+                fields.clear();
+                line += 1;
+                record_line = line;
+            }
+            c if c == delimiter => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(anyhow::anyhow!("Line {}: unterminated quoted field", record_line));
+    }
+    if !fields.is_empty() || !field.is_empty() {
+        fields.push(field);
+        records.push(CsvRecord {
+            line: record_line,
+            fields,
+        });
+    }
+
+    Ok(records)
+}
+
+/// ヘッダー行から列の位置を探す（大文字・小文字と前後の空白は区別しない）
+fn column_index(header: &CsvRecord, name: &str) -> Option<usize> {
+    header
+        .fields
+        .iter()
+        .position(|field| field.trim().eq_ignore_ascii_case(name))
+}
+
+/// 取り込む1行分の時間エントリ
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ImportRow {
+    project: String,
+    task: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    note: Option<String>,
+    tags: Vec<String>,
+}
+
+impl ImportRow {
+    /// 行を検証して作成する
+    ///
+    /// 取り込み時にプロジェクト・タスクを作成してから行が失敗しないよう、
+    /// プロジェクト・タスク・タグの作成で検証される名前の規則もここで確認する。
+    fn new(
+        project: &str,
+        task: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> anyhow::Result<Self> {
+        if project.is_empty() {
+            return Err(anyhow::anyhow!("Project name is required"));
+        }
+        if task.is_empty() {
+            return Err(anyhow::anyhow!("Task name is required"));
+        }
+        if project.len() > 255 {
+            return Err(anyhow::anyhow!("Project name cannot exceed 255 characters"));
+        }
+        if task.len() > 255 {
+            return Err(anyhow::anyhow!("Task name cannot exceed 255 characters"));
+        }
+        if tags.iter().any(|tag| tag.chars().count() > 255) {
+            return Err(anyhow::anyhow!("Tag name cannot exceed 255 characters"));
+        }
+        if start >= end {
+            return Err(anyhow::anyhow!("Start time must be before end time"));
+        }
+        Ok(Self {
+            project: project.to_string(),
+            task: task.to_string(),
+            start,
+            end,
+            note,
+            tags,
+        })
+    }
+}
+
+/// 取り込まなかった行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedRow {
+    /// CSVの行番号（1始まり）
+    pub line: usize,
+    pub message: String,
+}
+
+/// 取り込み結果（dry-run の場合は取り込んだ場合の結果）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportReport {
+    pub path: PathBuf,
+    pub dry_run: bool,
+    /// ヘッダーを除いた行数
+    pub row_count: usize,
+    pub imported_count: usize,
+    pub imported_seconds: i64,
+    pub created_projects: Vec<String>,
+    /// 作成したタスク（プロジェクト名, タスク名）
+    pub created_tasks: Vec<(String, String)>,
+    pub created_tags: Vec<String>,
+    /// 不正な行・既存のエントリと重なる行など、取り込まなかった行
+    pub skipped_rows: Vec<SkippedRow>,
+}
+
+/// Togglの詳細レポートCSVの取り込みコマンド
+#[derive(Debug, Clone)]
+pub struct ImportTogglCsvCommand {
+    pub path: PathBuf,
+    /// CSVの日時のタイムゾーン（Togglのプロフィールの設定、未指定の場合は設定のカレンダー）
+    pub timezone: Option<Tz>,
+    /// 何も書き込まずに、作成されるプロジェクト・タスク・タグと取り込めない行だけを報告する
+    pub dry_run: bool,
+}
+
+/// 汎用CSV取り込みの列の対応付け（ヘッダー行の列名で指定する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvColumnMapping {
+    pub project: String,
+    pub task: String,
+    /// 開始日時の列（日付と時刻が別の列の場合は日付の列）
+    pub start: String,
+    /// 開始時刻の列（`start` と空白でつないで日時の書式で解釈する）
+    pub start_time: Option<String>,
+    /// 終了日時の列（未指定で `end_time` がある場合は開始日の列を使う）
+    pub end: Option<String>,
+    pub end_time: Option<String>,
+    /// 所要時間の列（`HH:MM[:SS]` または時間単位の小数、終了日時が空の行に使う）
+    pub duration: Option<String>,
+    pub notes: Option<String>,
+    /// カンマ区切りのタグの列
+    pub tags: Option<String>,
+}
+
+/// 汎用CSVの取り込みコマンド
+#[derive(Debug, Clone)]
+pub struct ImportTimeEntriesCsvCommand {
+    pub path: PathBuf,
+    pub mapping: CsvColumnMapping,
+    pub delimiter: char,
+    /// 日時の書式（chrono の strftime 形式、先頭から順に試す。日付だけの書式はその日の0時とする）
+    /// 空の場合は `DEFAULT_DATETIME_FORMATS`。オフセット付きのRFC 3339形式はそのまま解釈する
+    pub datetime_formats: Vec<String>,
+    /// オフセットのない日時のタイムゾーン（未指定の場合は設定のカレンダー）
+    pub timezone: Option<Tz>,
+    pub dry_run: bool,
+}
+
+/// 取り込みサービス
+#[async_trait]
+pub trait ImportService: Send + Sync {
+    /// Togglの詳細レポートCSVを手動エントリとして取り込む
+    ///
+    /// 存在しないプロジェクト・タスクは作成し、行のタグはタスクに付与する。
+    /// 既存のエントリと重なる行や不正な行は取り込まずに報告する。
+    async fn import_toggl_csv(&self, command: ImportTogglCsvCommand) -> anyhow::Result<ImportReport>;
+
+    /// 列の対応付けに従ってCSVを手動エントリとして取り込む
+    ///
+    /// 行ごとに解釈・検証し、取り込めない行は行番号と理由を報告して残りの行を取り込む。
+    async fn import_time_entries_csv(&self, command: ImportTimeEntriesCsvCommand) -> anyhow::Result<ImportReport>;
+}
+
+/// Togglの詳細レポートCSVの列の位置
+struct TogglColumns {
+    project: usize,
+    task: Option<usize>,
+    description: usize,
+    start_date: usize,
+    start_time: usize,
+    end_date: usize,
+    end_time: usize,
+    tags: Option<usize>,
+}
+
+impl TogglColumns {
+    fn from_header(header: &CsvRecord) -> anyhow::Result<Self> {
+        let required = |name: &str| {
+            column_index(header, name).ok_or_else(|| anyhow::anyhow!("Missing column in Toggl CSV: {}", name))
+        };
+        Ok(Self {
+            project: required("Project")?,
+            task: column_index(header, "Task"),
+            description: required("Description")?,
+            start_date: required("Start date")?,
+            start_time: required("Start time")?,
+            end_date: required("End date")?,
+            end_time: required("End time")?,
+            tags: column_index(header, "Tags"),
+        })
+    }
+
+    /// 1行をエントリに変換する（タスク列が空の場合は説明をタスク名にする）
+    fn parse_row(&self, record: &CsvRecord, calendar: &LocalCalendar) -> anyhow::Result<ImportRow> {
+        let local = |date_index: usize, time_index: usize, label: &str| -> anyhow::Result<DateTime<Utc>> {
+            let date = NaiveDate::parse_from_str(record.field(date_index), "%Y-%m-%d")
+                .map_err(|_| anyhow::anyhow!("Invalid {} date: {}", label, record.field(date_index)))?;
+            let time = NaiveTime::parse_from_str(record.field(time_index), "%H:%M:%S")
+                .map_err(|_| anyhow::anyhow!("Invalid {} time: {}", label, record.field(time_index)))?;
+            Ok(calendar.at_local_time(date, time))
+        };
+        let start = local(self.start_date, self.start_time, "start")?;
+        let end = local(self.end_date, self.end_time, "end")?;
+
+        let project = match record.field(self.project) {
+            "" => TOGGL_NO_PROJECT,
+            project => project,
+        };
+        let task = self.task.map(|index| record.field(index)).unwrap_or_default();
+        let description = record.field(self.description);
+        let (task, note) = match (task, description) {
+            ("", "") => (TOGGL_NO_DESCRIPTION, None),
+            ("", description) => (description, None),
+            (task, "") => (task, None),
+            (task, description) => (task, Some(description.to_string())),
+        };
+
+        ImportRow::new(
+            project,
+            task,
+            start,
+            end,
+            note,
+            self.tags
+                .map(|index| split_tags(record.field(index)))
+                .unwrap_or_default(),
+        )
+    }
+}
+
+/// 汎用CSVの列の位置
+struct MappedColumns {
+    project: usize,
+    task: usize,
+    start: usize,
+    start_time: Option<usize>,
+    end: Option<usize>,
+    end_time: Option<usize>,
+    duration: Option<usize>,
+    notes: Option<usize>,
+    tags: Option<usize>,
+}
+
+impl MappedColumns {
+    fn from_header(header: &CsvRecord, mapping: &CsvColumnMapping) -> anyhow::Result<Self> {
+        let find = |name: &str| column_index(header, name).ok_or_else(|| anyhow::anyhow!("Column not found in CSV: {}", name));
+        let optional = |name: &Option<String>| name.as_deref().map(find).transpose();
+
+        let columns = Self {
+            project: find(&mapping.project)?,
+            task: find(&mapping.task)?,
+            start: find(&mapping.start)?,
+            start_time: optional(&mapping.start_time)?,
+            end: optional(&mapping.end)?,
+            end_time: optional(&mapping.end_time)?,
+            duration: optional(&mapping.duration)?,
+            notes: optional(&mapping.notes)?,
+            tags: optional(&mapping.tags)?,
+        };
+        if columns.end.is_none() && columns.end_time.is_none() && columns.duration.is_none() {
+            return Err(anyhow::anyhow!("Either an end or a duration column is required"));
+        }
+        Ok(columns)
+    }
+
+    fn parse_row(&self, record: &CsvRecord, formats: &[String], calendar: &LocalCalendar) -> anyhow::Result<ImportRow> {
+        let start = parse_datetime_field(record, self.start, self.start_time, formats, calendar)?
+            .ok_or_else(|| anyhow::anyhow!("Start time is required"))?;
+        // 終了時刻だけの列の場合は開始日の列と組み合わせる
+        let end = if self.end.is_some() || self.end_time.is_some() {
+            parse_datetime_field(record, self.end.unwrap_or(self.start), self.end_time, formats, calendar)?
+        } else {
+            None
+        };
+        let end = match (end, self.duration.map(|index| record.field(index))) {
+            (Some(end), _) => end,
+            (None, Some(duration)) if !duration.is_empty() => start + parse_duration(duration)?,
+            _ => return Err(anyhow::anyhow!("End time or duration is required")),
+        };
+
+        ImportRow::new(
+            record.field(self.project),
+            record.field(self.task),
+            start,
+            end,
+            self.notes
+                .map(|index| record.field(index))
+                .filter(|notes| !notes.is_empty())
+                .map(str::to_string),
+            self.tags
+                .map(|index| split_tags(record.field(index)))
+                .unwrap_or_default(),
+        )
+    }
+}
+
+/// 日付（と時刻）の列から日時を解釈する（空欄の場合は `None`）
+fn parse_datetime_field(
+    record: &CsvRecord,
+    date_index: usize,
+    time_index: Option<usize>,
+    formats: &[String],
+    calendar: &LocalCalendar,
+) -> anyhow::Result<Option<DateTime<Utc>>> {
+    let value = match time_index.map(|index| record.field(index)) {
+        Some("") => return Ok(None),
+        Some(time) => format!("{} {}", record.field(date_index), time),
+        None => record.field(date_index).to_string(),
+    };
+    if value.is_empty() {
+        return Ok(None);
+    }
+    parse_datetime(&value, formats, calendar).map(Some)
+}
+
+/// 日時を書式の順に解釈する（オフセット付きのRFC 3339形式はタイムゾーンによらずそのまま解釈する）
+fn parse_datetime(value: &str, formats: &[String], calendar: &LocalCalendar) -> anyhow::Result<DateTime<Utc>> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    for format in formats {
+        if let Ok(local) = NaiveDateTime::parse_from_str(value, format) {
+            return Ok(calendar.at_local_time(local.date(), local.time()));
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(value, format) {
+            return Ok(calendar.start_of_day(date));
+        }
+    }
+    Err(anyhow::anyhow!("Invalid date time: {}", value))
+}
+
+/// 所要時間を解釈する（`HH:MM`、`HH:MM:SS`、または時間単位の小数）
+fn parse_duration(value: &str) -> anyhow::Result<Duration> {
+    let invalid = || anyhow::anyhow!("Invalid duration: {}", value);
+    let seconds = if value.contains(':') {
+        let parts = value
+            .split(':')
+            .map(|part| part.trim().parse::<i64>().map_err(|_| invalid()))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        match parts[..] {
+            [hours, minutes] => hours * 3600 + minutes * 60,
+            [hours, minutes, seconds] => hours * 3600 + minutes * 60 + seconds,
+            _ => return Err(invalid()),
+        }
+    } else {
+        let hours = value.parse::<f64>().map_err(|_| invalid())?;
+        if !hours.is_finite() {
+            return Err(invalid());
+        }
+        (hours * 3600.0).round() as i64
+    };
+    Ok(Duration::seconds(seconds))
+}
+
+/// カンマ区切りのタグを正規化して分割する
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(',')
+        .map(Tag::normalize_name)
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+/// プロジェクト名とタスク名の組
+type TaskKey = (String, String);
+
+/// 時間区間（開始, 終了、実行中は終了なし）
+type Interval = (DateTime<Utc>, Option<DateTime<Utc>>);
+
+/// 取り込み中に解決したプロジェクト・タスク・タグと、タスクごとの区間
+///
+/// dry-run で作成予定のプロジェクト・タスクは `None` として保持する。
+struct ImportPlan {
+    projects: HashMap<String, Option<Project>>,
+    tasks: HashMap<TaskKey, Option<Task>>,
+    intervals: HashMap<TaskKey, Vec<Interval>>,
+    tags: HashSet<String>,
+    report: ImportReport,
+}
+
+/// 取り込みサービス実装
+pub struct ImportServiceImpl {
+    project_use_cases: Arc<dyn ProjectUseCases>,
+    task_use_cases: Arc<dyn TaskUseCases>,
+    tag_use_cases: Arc<dyn TagUseCases>,
+    time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>,
+    calendar: LocalCalendar,
+}
+
+impl ImportServiceImpl {
+    pub fn new(
+        project_use_cases: Arc<dyn ProjectUseCases>,
+        task_use_cases: Arc<dyn TaskUseCases>,
+        tag_use_cases: Arc<dyn TagUseCases>,
+        time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>,
+        calendar: LocalCalendar,
+    ) -> Self {
+        Self {
+            project_use_cases,
+            task_use_cases,
+            tag_use_cases,
+            time_tracking_use_cases,
+            calendar,
+        }
+    }
+
+    /// 取り込む日時の解釈に使うカレンダー
+    fn calendar_for(&self, timezone: Option<Tz>) -> LocalCalendar {
+        match timezone {
+            Some(timezone) => LocalCalendar::new(timezone, self.calendar.week_start()),
+            None => self.calendar,
+        }
+    }
+
+    /// 既存のプロジェクト・タスク・タグを読み込む
+    async fn load_plan(&self, path: PathBuf, dry_run: bool) -> anyhow::Result<ImportPlan> {
+        let mut projects = HashMap::new();
+        let mut tasks: HashMap<TaskKey, Option<Task>> = HashMap::new();
+        for project in self.project_use_cases.get_all_projects().await? {
+            for task in self.task_use_cases.get_tasks_by_project(project.id()).await? {
+                // 同名のタスクが複数ある場合はアクティブなものに取り込む
+                match tasks.entry((project.name().to_string(), task.name().to_string())) {
+                    Entry::Vacant(vacant) => {
+                        vacant.insert(Some(task));
+                    }
+                    Entry::Occupied(mut occupied) => {
+                        if occupied.get().as_ref().is_some_and(Task::is_archived) {
+                            occupied.insert(Some(task));
+                        }
+                    }
+                }
+            }
+            projects.insert(project.name().to_string(), Some(project));
+        }
+        let tags = self
+            .tag_use_cases
+            .list_tags()
+            .await?
+            .iter()
+            .map(|tag| tag.name().to_string())
+            .collect();
+
+        Ok(ImportPlan {
+            projects,
+            tasks,
+            intervals: HashMap::new(),
+            tags,
+            report: ImportReport {
+                path,
+                dry_run,
+                row_count: 0,
+                imported_count: 0,
+                imported_seconds: 0,
+                created_projects: Vec::new(),
+                created_tasks: Vec::new(),
+                created_tags: Vec::new(),
+                skipped_rows: Vec::new(),
+            },
+        })
+    }
+
+    /// 行を順に取り込み、取り込めない行は理由とともに報告する
+    async fn import_rows(
+        &self,
+        path: PathBuf,
+        rows: Vec<(usize, anyhow::Result<ImportRow>)>,
+        dry_run: bool,
+    ) -> anyhow::Result<ImportReport> {
+        let mut plan = self.load_plan(path, dry_run).await?;
+        for (line, row) in rows {
+            plan.report.row_count += 1;
+            let result = match row {
+                Ok(row) => self.import_row(&mut plan, row, dry_run).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                plan.report.skipped_rows.push(SkippedRow {
+                    line,
+                    message: e.to_string(),
+                });
+            }
+        }
+        Ok(plan.report)
+    }
+
+    async fn import_row(&self, plan: &mut ImportPlan, row: ImportRow, dry_run: bool) -> anyhow::Result<()> {
+        let project = match plan.projects.get(&row.project) {
+            Some(Some(project)) if project.is_archived() => {
+                return Err(anyhow::anyhow!("Project '{}' is archived", row.project));
+            }
+            Some(project) => project.clone(),
+            None => None,
+        };
+        let key = (row.project.clone(), row.task.clone());
+        let task = match plan.tasks.get(&key) {
+            Some(Some(task)) if task.is_archived() => {
+                return Err(anyhow::anyhow!("Task '{}' is archived", row.task));
+            }
+            Some(task) => task.clone(),
+            None => None,
+        };
+
+        // add_manual_entry と同じく、同じタスクの既存の区間（先に取り込んだ行を含む）と重なる行は取り込まない
+        let intervals = match plan.intervals.entry(key.clone()) {
+            Entry::Occupied(occupied) => occupied.into_mut(),
+            Entry::Vacant(vacant) => {
+                let existing = match &task {
+                    Some(task) => self
+                        .time_tracking_use_cases
+                        .get_task_entries(task.id())
+                        .await?
+                        .iter()
+                        .map(|entry| (entry.start_time(), entry.end_time()))
+                        .collect(),
+                    None => Vec::new(),
+                };
+                vacant.insert(existing)
+            }
+        };
+        if intervals
+            .iter()
+            .any(|(start, end)| *start < row.end && end.is_none_or(|end| end > row.start))
+        {
+            return Err(anyhow::anyhow!("Time entry overlaps with existing entries"));
+        }
+
+        if !dry_run {
+            let project_id = match &project {
+                Some(project) => project.id(),
+                None => {
+                    let created = self
+                        .project_use_cases
+                        .create_project(CreateProjectCommand { name: row.project.clone() })
+                        .await?;
+                    let id = created.id();
+                    plan.projects.insert(row.project.clone(), Some(created));
+                    plan.report.created_projects.push(row.project.clone());
+                    id
+                }
+            };
+            let task_id = match &task {
+                Some(task) => task.id(),
+                None => {
+                    let created = self
+                        .task_use_cases
+                        .create_task(CreateTaskCommand {
+                            project_id,
+                            name: row.task.clone(),
+                        })
+                        .await?;
+                    let id = created.id();
+                    plan.tasks.insert(key.clone(), Some(created));
+                    plan.report.created_tasks.push(key.clone());
+                    id
+                }
+            };
+
+            self.time_tracking_use_cases
+                .add_manual_entry(AddManualEntryCommand {
+                    task_id,
+                    start_time: row.start,
+                    end_time: row.end,
+                    note: row.note.clone(),
+                })
+                .await?;
+            // Togglのタグはエントリ単位だが、このアプリのタグはタスク単位のためタスクに付与する
+            for tag in &row.tags {
+                self.tag_use_cases
+                    .add_tag_to_task(AddTagToTaskCommand {
+                        task_id,
+                        tag_name: tag.clone(),
+                        // タグの付与はエントリの開始時点で判定されるため、取り込んだエントリの開始時刻で付与する
+                        at: Some(row.start),
+                    })
+                    .await?;
+            }
+        } else {
+            if project.is_none() && !plan.projects.contains_key(&row.project) {
+                plan.projects.insert(row.project.clone(), None);
+                plan.report.created_projects.push(row.project.clone());
+            }
+            if task.is_none() && !plan.tasks.contains_key(&key) {
+                plan.tasks.insert(key.clone(), None);
+                plan.report.created_tasks.push(key.clone());
+            }
+        }
+
+        for tag in &row.tags {
+            if plan.tags.insert(tag.clone()) {
+                plan.report.created_tags.push(tag.clone());
+            }
+        }
+        if let Some(intervals) = plan.intervals.get_mut(&key) {
+            intervals.push((row.start, Some(row.end)));
+        }
+        plan.report.imported_count += 1;
+        plan.report.imported_seconds += (row.end - row.start).num_seconds();
+
+        Ok(())
+    }
+}
+
+async fn read_import(path: &Path) -> anyhow::Result<String> {
+    tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))
+}
+
+#[async_trait]
+impl ImportService for ImportServiceImpl {
+    async fn import_toggl_csv(&self, command: ImportTogglCsvCommand) -> anyhow::Result<ImportReport> {
+        let calendar = self.calendar_for(command.timezone);
+        let records = parse_csv(&read_import(&command.path).await?, ',')?;
+        let (header, records) = records
+            .split_first()
+            .ok_or_else(|| anyhow::anyhow!("Toggl CSV is empty"))?;
+        let columns = TogglColumns::from_header(header)?;
+
+        let rows = records
+            .iter()
+            .map(|record| (record.line, columns.parse_row(record, &calendar)))
+            .collect();
+        let report = self.import_rows(command.path, rows, command.dry_run).await?;
+        tracing::info!(
+            "ImportService::import_toggl_csv: imported {} of {} rows from {} (dry run: {})",
+            report.imported_count,
+            report.row_count,
+            report.path.display(),
+            report.dry_run
+        );
+
+        Ok(report)
+    }
+
+    async fn import_time_entries_csv(&self, command: ImportTimeEntriesCsvCommand) -> anyhow::Result<ImportReport> {
+        if matches!(command.delimiter, '"' | '\r' | '\n') {
+            return Err(anyhow::anyhow!("Invalid CSV delimiter: {:?}", command.delimiter));
+        }
+        let calendar = self.calendar_for(command.timezone);
+        let formats = if command.datetime_formats.is_empty() {
+            DEFAULT_DATETIME_FORMATS.iter().map(|format| format.to_string()).collect()
+        } else {
+            command.datetime_formats
+        };
+        let records = parse_csv(&read_import(&command.path).await?, command.delimiter)?;
+        let (header, records) = records
+            .split_first()
+            .ok_or_else(|| anyhow::anyhow!("CSV is empty"))?;
+        let columns = MappedColumns::from_header(header, &command.mapping)?;
+
+        let rows = records
+            .iter()
+            .map(|record| (record.line, columns.parse_row(record, &formats, &calendar)))
+            .collect();
+        let report = self.import_rows(command.path, rows, command.dry_run).await?;
+        tracing::info!(
+            "ImportService::import_time_entries_csv: imported {} of {} rows from {} (dry run: {})",
+            report.imported_count,
+            report.row_count,
+            report.path.display(),
+            report.dry_run
+        );
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::{ProjectUseCasesImpl, TagUseCasesImpl, TaskUseCasesImpl, TimeTrackingUseCasesImpl};
+    use crate::domain::repositories::tag_tests::InMemoryTagRepository;
+    use crate::domain::repositories::task_tests::InMemoryTaskRepository;
+    use crate::domain::repositories::tests::InMemoryProjectRepository;
+    use crate::domain::repositories::time_entry_tests::InMemoryTimeEntryRepository;
+    use crate::domain::services::{ProjectManagementServiceImpl, TimeTrackingServiceImpl};
+    use chrono::{TimeZone, Weekday};
+
+    const TOGGL_CSV: &str = "\u{feff}User,Email,Client,Project,Task,Description,Billable,Start date,Start time,End date,End time,Duration,Tags,Amount ()\r\n\
+        Alice,alice@example.com,Acme,Website,Design,\"Header, footer\",Yes,2025-03-03,09:00:00,2025-03-03,10:30:00,01:30:00,\"billable, #client\",\r\n\
+        Alice,alice@example.com,Acme,Website,,Standup,No,2025-03-03,10:00:00,2025-03-03,10:15:00,00:15:00,,\r\n\
+        Alice,alice@example.com,,,,,No,2025-03-03,11:00:00,2025-03-03,12:00:00,01:00:00,,\r\n\
+        Alice,alice@example.com,Acme,Website,Design,,Yes,2025-03-03,10:00:00,2025-03-03,11:00:00,01:00:00,,\r\n\
+        Alice,alice@example.com,Acme,Website,Design,,Yes,2025-03-03,13:00:00,2025-03-03,12:00:00,-01:00:00,,\r\n";
+
+    fn setup() -> (ImportServiceImpl, Arc<dyn TimeTrackingUseCases>, Arc<dyn ProjectUseCases>) {
+        let project_repo = InMemoryProjectRepository::new();
+        let task_repo = InMemoryTaskRepository::new();
+        let tag_repo = InMemoryTagRepository::new();
+        let time_entry_repo = InMemoryTimeEntryRepository::new().with_tag_repository(&tag_repo);
+        let project_use_cases: Arc<dyn ProjectUseCases> = Arc::new(ProjectUseCasesImpl::new(
+            project_repo.clone(),
+            ProjectManagementServiceImpl::new(project_repo.clone(), task_repo.clone()),
+        ));
+        let time_tracking_use_cases: Arc<dyn TimeTrackingUseCases> = Arc::new(TimeTrackingUseCasesImpl::new(
+            time_entry_repo.clone(),
+            task_repo.clone(),
+            TimeTrackingServiceImpl::new(time_entry_repo),
+        ));
+        let service = ImportServiceImpl::new(
+            project_use_cases.clone(),
+            Arc::new(TaskUseCasesImpl::new(task_repo.clone(), project_repo)),
+            Arc::new(TagUseCasesImpl::new(tag_repo, task_repo)),
+            time_tracking_use_cases.clone(),
+            LocalCalendar::from_name("Asia/Tokyo", Weekday::Mon).unwrap(),
+        );
+        (service, time_tracking_use_cases, project_use_cases)
+    }
+
+    #[test]
+    fn 引用符内の区切り文字と改行を含むCSVを分割できること() {
+        let records = parse_csv("a,\"b,c\",\"say \"\"hi\"\"\"\r\n\r\n\"multi\nline\",x\n", ',').unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].line, 1);
+        assert_eq!(records[0].fields, vec!["a", "b,c", "say \"hi\""]);
+        assert_eq!(records[1].line, 3);
+        assert_eq!(records[1].fields, vec!["multi\nline", "x"]);
+        assert!(parse_csv("a,\"b\n", ',').is_err());
+    }
+
+    #[tokio::test]
+    async fn dry_runでは何も書き込まずに作成予定と重なる行を報告すること() {
+        let (service, time_tracking_use_cases, project_use_cases) = setup();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("toggl.csv");
+        std::fs::write(&path, TOGGL_CSV).unwrap();
+
+        let report = service
+            .import_toggl_csv(ImportTogglCsvCommand {
+                path,
+                timezone: None,
+                dry_run: true,
+            })
+            .await
+            .unwrap();
+
+        assert!(report.dry_run);
+        assert_eq!(report.row_count, 5);
+        assert_eq!(report.imported_count, 3);
+        assert_eq!(report.imported_seconds, 90 * 60 + 15 * 60 + 60 * 60);
+        assert_eq!(report.created_projects, vec!["Website", TOGGL_NO_PROJECT]);
+        assert_eq!(
+            report.created_tasks,
+            vec![
+                ("Website".to_string(), "Design".to_string()),
+                ("Website".to_string(), "Standup".to_string()),
+                (TOGGL_NO_PROJECT.to_string(), TOGGL_NO_DESCRIPTION.to_string()),
+            ]
+        );
+        assert_eq!(report.created_tags, vec!["billable", "client"]);
+        assert_eq!(
+            report.skipped_rows,
+            vec![
+                SkippedRow { line: 5, message: "Time entry overlaps with existing entries".to_string() },
+                SkippedRow { line: 6, message: "Start time must be before end time".to_string() },
+            ]
+        );
+        assert!(project_use_cases.get_all_projects().await.unwrap().is_empty());
+        assert!(time_tracking_use_cases.get_recent_entries(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn 元の時刻で取り込み再取り込みでは全行が重なること() {
+        let (service, time_tracking_use_cases, _) = setup();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("toggl.csv");
+        std::fs::write(&path, TOGGL_CSV).unwrap();
+        let command = ImportTogglCsvCommand {
+            path,
+            timezone: Some(Tz::UTC),
+            dry_run: false,
+        };
+
+        let report = service.import_toggl_csv(command.clone()).await.unwrap();
+        assert_eq!(report.imported_count, 3);
+        assert_eq!(report.created_projects.len(), 2);
+        assert_eq!(report.created_tasks.len(), 3);
+
+        let entries = time_tracking_use_cases.get_recent_entries(10).await.unwrap();
+        assert_eq!(entries.len(), 3);
+        let design = entries
+            .iter()
+            .find(|entry| entry.notes() == Some("Header, footer"))
+            .unwrap();
+        assert_eq!(design.start_time(), Utc.with_ymd_and_hms(2025, 3, 3, 9, 0, 0).unwrap());
+        assert_eq!(design.end_time(), Some(design.start_time() + Duration::minutes(90)));
+        let tags = service.tag_use_cases.get_task_tags(design.task_id()).await.unwrap();
+        assert_eq!(tags.iter().map(|tag| tag.name()).collect::<Vec<_>>(), vec!["billable", "client"]);
+        // タグは取り込んだエントリの開始時点で付与されているため、タグ別の絞り込みに含まれる
+        let tagged = time_tracking_use_cases.get_tag_entries(tags[0].id()).await.unwrap();
+        assert_eq!(
+            tagged.iter().map(|entry| entry.start_event_id()).collect::<Vec<_>>(),
+            vec![design.start_event_id()]
+        );
+
+        let again = service.import_toggl_csv(command).await.unwrap();
+        assert_eq!(again.imported_count, 0);
+        assert_eq!(again.skipped_rows.len(), 5);
+        assert!(again.created_projects.is_empty() && again.created_tasks.is_empty() && again.created_tags.is_empty());
+    }
+
+    #[test]
+    fn 所要時間を時分秒と小数の時間で解釈できること() {
+        assert_eq!(parse_duration("01:30").unwrap(), Duration::minutes(90));
+        assert_eq!(parse_duration("25:00:30").unwrap(), Duration::seconds(25 * 3600 + 30));
+        assert_eq!(parse_duration("0.75").unwrap(), Duration::minutes(45));
+        assert!(parse_duration("1:2:3:4").is_err());
+        assert!(parse_duration("abc").is_err());
+    }
+
+    #[tokio::test]
+    async fn 列の対応付けに従って取り込み取り込めない行を報告すること() {
+        let (service, time_tracking_use_cases, _) = setup();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("clockify.csv");
+        std::fs::write(
+            &path,
+            "Project;Description;Start Date;Start Time;End Time;Duration (decimal);Tags;Note\n\
+             Website;Design;03/03/2025;09:00 AM;10:30 AM;;billable;Mockups\n\
+             Website;Design;03/03/2025;10:00 AM;;;;\n\
+             Website;Design;03/03/2025;10:00 AM;11:00 AM;;;\n\
+             Website;Review;03/03/2025;01:00 PM;;0.75;;\n\
+             Website;;03/03/2025;02:00 PM;03:00 PM;;;\n\
+             Website;Review;2025-03-03;02:00 PM;03:00 PM;;;\n",
+        )
+        .unwrap();
+        let mapping = CsvColumnMapping {
+            project: "Project".to_string(),
+            task: "Description".to_string(),
+            start: "Start Date".to_string(),
+            start_time: Some("Start Time".to_string()),
+            end: None,
+            end_time: Some("End Time".to_string()),
+            duration: Some("Duration (decimal)".to_string()),
+            notes: Some("Note".to_string()),
+            tags: Some("Tags".to_string()),
+        };
+        let command = ImportTimeEntriesCsvCommand {
+            path,
+            mapping: mapping.clone(),
+            delimiter: ';',
+            datetime_formats: vec!["%m/%d/%Y %I:%M %p".to_string()],
+            timezone: Some(Tz::UTC),
+            dry_run: false,
+        };
+
+        let report = service.import_time_entries_csv(command.clone()).await.unwrap();
+        assert_eq!(report.row_count, 6);
+        assert_eq!(report.imported_count, 2);
+        assert_eq!(
+            report.skipped_rows,
+            vec![
+                SkippedRow { line: 3, message: "End time or duration is required".to_string() },
+                SkippedRow { line: 4, message: "Time entry overlaps with existing entries".to_string() },
+                SkippedRow { line: 6, message: "Task name is required".to_string() },
+                SkippedRow { line: 7, message: "Invalid date time: 2025-03-03 02:00 PM".to_string() },
+            ]
+        );
+        let mut entries = time_tracking_use_cases.get_recent_entries(10).await.unwrap();
+        entries.sort_by_key(|entry| entry.start_time());
+        assert_eq!(entries[0].notes(), Some("Mockups"));
+        assert_eq!(entries[0].end_time(), Some(Utc.with_ymd_and_hms(2025, 3, 3, 10, 30, 0).unwrap()));
+        assert_eq!(entries[1].start_time(), Utc.with_ymd_and_hms(2025, 3, 3, 13, 0, 0).unwrap());
+        assert_eq!(entries[1].end_time(), Some(Utc.with_ymd_and_hms(2025, 3, 3, 13, 45, 0).unwrap()));
+
+        // 対応付けの誤りは行ごとではなく取り込み全体のエラーにする
+        let missing_column = ImportTimeEntriesCsvCommand {
+            mapping: CsvColumnMapping { notes: Some("Memo".to_string()), ..mapping.clone() },
+            ..command.clone()
+        };
+        assert!(service.import_time_entries_csv(missing_column).await.is_err());
+        let no_end = ImportTimeEntriesCsvCommand {
+            mapping: CsvColumnMapping { end_time: None, duration: None, ..mapping },
+            ..command
+        };
+        assert!(service.import_time_entries_csv(no_end).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn 作成できない名前の行ではプロジェクトもタスクも作成しないこと() {
+        let (service, time_tracking_use_cases, project_use_cases) = setup();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("entries.csv");
+        std::fs::write(
+            &path,
+            format!(
+                "Project,Task,Start,End,Tags\n\
+                 Website,{},2025-03-03 09:00,2025-03-03 10:00,\n\
+                 Intranet,Design,2025-03-03 09:00,2025-03-03 10:00,{}\n",
+                "a".repeat(256),
+                "b".repeat(256)
+            ),
+        )
+        .unwrap();
+        let command = ImportTimeEntriesCsvCommand {
+            path,
+            mapping: CsvColumnMapping {
+                project: "Project".to_string(),
+                task: "Task".to_string(),
+                start: "Start".to_string(),
+                start_time: None,
+                end: Some("End".to_string()),
+                end_time: None,
+                duration: None,
+                notes: None,
+                tags: Some("Tags".to_string()),
+            },
+            delimiter: ',',
+            datetime_formats: Vec::new(),
+            timezone: Some(Tz::UTC),
+            dry_run: false,
+        };
+
+        let report = service.import_time_entries_csv(command).await.unwrap();
+
+        assert_eq!(report.imported_count, 0);
+        assert_eq!(
+            report.skipped_rows,
+            vec![
+                SkippedRow { line: 2, message: "Task name cannot exceed 255 characters".to_string() },
+                SkippedRow { line: 3, message: "Tag name cannot exceed 255 characters".to_string() },
+            ]
+        );
+        assert!(report.created_projects.is_empty());
+        assert!(report.created_tasks.is_empty());
+        assert!(project_use_cases.get_all_projects().await.unwrap().is_empty());
+        assert!(time_tracking_use_cases.get_recent_entries(10).await.unwrap().is_empty());
+    }
+}
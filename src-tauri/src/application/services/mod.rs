@@ -1,6 +1,28 @@
 // アプリケーションサービス - ユースケースを調整し、外部システムとの統合を管理
 
 pub mod application_service;
+pub mod auto_cutoff_worker;
+pub mod backup_service;
+pub mod event_bus;
+pub mod export_service;
+pub mod heartbeat_worker;
+pub mod import_service;
+pub mod notifier;
+pub mod pomodoro_worker;
+pub mod report_service;
+pub mod timeline_service;
+pub mod timer_tick_worker;
 
 pub use application_service::*;
+pub use auto_cutoff_worker::*;
+pub use backup_service::*;
+pub use event_bus::*;
+pub use export_service::*;
+pub use heartbeat_worker::*;
+pub use import_service::*;
+pub use pomodoro_worker::*;
+pub use report_service::*;
+pub use timeline_service::*;
+pub use timer_tick_worker::*;
+pub use notifier::{AppNotification, DeferredNotifier, NoopNotifier, NotificationSubscriber, Notifier};
 
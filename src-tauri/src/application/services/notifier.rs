@@ -0,0 +1,165 @@
+use crate::application::services::DomainEventSubscriber;
+use crate::domain::entities::{PomodoroPhaseChange, TimeEntry, TimeEntryEvent};
+use crate::domain::events::DomainEvent;
+use crate::domain::services::PendingRecovery;
+use crate::domain::value_objects::TaskId;
+use std::sync::{Arc, Mutex};
+
+/// フロントエンドへの通知
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppNotification {
+    /// 長時間実行タイマーを自動停止した（自動停止イベントを保持）
+    TimerAutoCutoff(TimeEntryEvent),
+    /// ハートビートの途絶した実行中タイマーを検出した
+    TimerRecoveryPending(PendingRecovery),
+    /// ポモドーロのフェーズが切り替わった
+    PomodoroPhaseChanged(PomodoroPhaseChange),
+    /// ポモドーロセッションを終了した（タイマーを操作できなかった理由を保持）
+    PomodoroEnded { task_id: TaskId, reason: String },
+    /// タイマーを開始した（開始した実行中の時間エントリを保持）
+    TimerStarted(TimeEntry),
+    /// タイマーを停止した（停止した時間エントリを保持）
+    TimerStopped(TimeEntry),
+    /// 実行中タイマーの定期通知（実行中の時間エントリを保持）
+    TimerTick(Vec<TimeEntry>),
+    /// タスクの時間エントリが変更された
+    EntriesChanged(TaskId),
+}
+
+/// フロントエンドへの通知手段（Tauriイベント等の実装をプレゼンテーション層から注入する）
+pub trait Notifier: Send + Sync {
+    fn notify(&self, notification: AppNotification);
+}
+
+/// 何も通知しない通知手段
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopNotifier;
+
+impl Notifier for NoopNotifier {
+    fn notify(&self, _notification: AppNotification) {}
+}
+
+/// 後から通知手段を差し替えられる通知手段
+///
+/// Tauriの AppHandle はアプリケーション起動後にしか得られないため、
+/// 起動時に作成するユースケースにはこれを渡しておき、起動後に実際の通知手段を設定する。
+/// 設定されるまでの通知（起動時に検出した復旧待ちのタイマー等）は保持し、設定時にまとめて送る。
+#[derive(Default)]
+pub struct DeferredNotifier {
+    state: Mutex<DeferredState>,
+}
+
+#[derive(Default)]
+struct DeferredState {
+    notifier: Option<Arc<dyn Notifier>>,
+    pending: Vec<AppNotification>,
+}
+
+impl DeferredNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 通知先を設定し、保持していた通知を送る
+    pub fn set(&self, notifier: Arc<dyn Notifier>) {
+        let pending = {
+            let mut state = self.state.lock().unwrap();
+            state.notifier = Some(notifier.clone());
+            std::mem::take(&mut state.pending)
+        };
+        for notification in pending {
+            notifier.notify(notification);
+        }
+    }
+}
+
+impl Notifier for DeferredNotifier {
+    fn notify(&self, notification: AppNotification) {
+        let notifier = {
+            let mut state = self.state.lock().unwrap();
+            match &state.notifier {
+                Some(notifier) => notifier.clone(),
+                None => {
+                    state.pending.push(notification);
+                    return;
+                }
+            }
+        };
+        notifier.notify(notification);
+    }
+}
+
+/// タイマーや時間エントリのドメインイベントをフロントエンドへの通知に変換する購読者
+pub struct NotificationSubscriber {
+    notifier: Arc<dyn Notifier>,
+}
+
+impl NotificationSubscriber {
+    pub fn new(notifier: Arc<dyn Notifier>) -> Self {
+        Self { notifier }
+    }
+}
+
+impl DomainEventSubscriber for NotificationSubscriber {
+    fn handle(&self, event: &DomainEvent) {
+        match event {
+            DomainEvent::TimerStarted(entry) => self.notifier.notify(AppNotification::TimerStarted(entry.clone())),
+            DomainEvent::TimerStopped(entry) => self.notifier.notify(AppNotification::TimerStopped(entry.clone())),
+            DomainEvent::PomodoroEnded { task_id, reason } => self.notifier.notify(AppNotification::PomodoroEnded {
+                task_id: *task_id,
+                reason: reason.clone(),
+            }),
+            _ => {}
+        }
+        if let Some(task_id) = event.entries_changed_task() {
+            self.notifier.notify(AppNotification::EntriesChanged(task_id));
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+pub mod tests {
+    use super::*;
+
+    // テスト用の通知記録
+    #[derive(Debug, Default)]
+    pub struct RecordingNotifier {
+        notifications: Mutex<Vec<AppNotification>>,
+    }
+
+    impl RecordingNotifier {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn notifications(&self) -> Vec<AppNotification> {
+            self.notifications.lock().unwrap().clone()
+        }
+    }
+
+    impl Notifier for RecordingNotifier {
+        fn notify(&self, notification: AppNotification) {
+            self.notifications.lock().unwrap().push(notification);
+        }
+    }
+
+    #[test]
+    fn 通知先の設定前の通知が設定時に送られること() {
+        let deferred = DeferredNotifier::new();
+        let task_id = TaskId::new(1).unwrap();
+        deferred.notify(AppNotification::EntriesChanged(task_id));
+
+        let notifier = Arc::new(RecordingNotifier::new());
+        deferred.set(notifier.clone());
+        deferred.notify(AppNotification::EntriesChanged(TaskId::new(2).unwrap()));
+
+        assert_eq!(
+            notifier.notifications(),
+            vec![
+                AppNotification::EntriesChanged(task_id),
+                AppNotification::EntriesChanged(TaskId::new(2).unwrap()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use crate::application::services::{AppNotification, Notifier};
+use crate::application::use_cases::PomodoroUseCases;
+use crate::domain::entities::PomodoroPhaseChange;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// ポモドーロのフェーズ終了を監視し、次のフェーズに進めるバックグラウンドタスク
+pub struct PomodoroWorker {
+    pomodoro_use_cases: Arc<dyn PomodoroUseCases>,
+    notifier: Arc<dyn Notifier>,
+}
+
+impl PomodoroWorker {
+    pub fn new(pomodoro_use_cases: Arc<dyn PomodoroUseCases>, notifier: Arc<dyn Notifier>) -> Self {
+        Self {
+            pomodoro_use_cases,
+            notifier,
+        }
+    }
+
+    /// 1回分の確認を行い、フェーズが切り替わった場合は通知する
+    pub async fn run_once(&self) -> anyhow::Result<Option<PomodoroPhaseChange>> {
+        let change = self.pomodoro_use_cases.tick().await?;
+        if let Some(change) = &change {
+            tracing::info!(
+                task_id = %change.task_id,
+                from = change.from.as_str(),
+                to = change.to.as_str(),
+                "Pomodoro phase changed"
+            );
+            self.notifier.notify(AppNotification::PomodoroPhaseChanged(change.clone()));
+        }
+        Ok(change)
+    }
+
+    /// 指定間隔でフェーズ終了を確認するタスクを起動する
+    pub fn spawn(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.run_once().await {
+                    tracing::error!(error = %e, "Failed to advance pomodoro phase");
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,235 @@
+use crate::domain::repositories::{ReportKey, ReportQuery, ReportRepository, ReportRow, RoundingRepository};
+use crate::domain::services::Clock;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// レポートのグループ（期間・プロジェクト・タスク・タグ）ごとの合計
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportGroup {
+    pub key: ReportKey,
+    pub name: Option<String>,
+    pub total_seconds: i64,
+    /// 丸め設定を適用した合計
+    pub rounded_seconds: i64,
+    pub entry_count: usize,
+    /// 次の軸でのグループ（最後の軸では空）
+    pub children: Vec<ReportGroup>,
+}
+
+/// レポート（期間ごとのグループを頂点とした入れ子の合計）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub query: ReportQuery,
+    pub total_seconds: i64,
+    pub rounded_seconds: i64,
+    /// 期間ごとの件数の合計（期間をまたぐエントリは各期間で数える）
+    pub entry_count: usize,
+    pub groups: Vec<ReportGroup>,
+}
+
+/// レポートサービス
+#[async_trait]
+pub trait ReportService: Send + Sync {
+    /// 集計条件に従ってレポートを作成
+    async fn generate_report(&self, query: ReportQuery) -> anyhow::Result<Report>;
+}
+
+/// レポートサービス実装
+pub struct ReportServiceImpl<R: ReportRepository, U: RoundingRepository> {
+    repository: R,
+    rounding_repository: U,
+    clock: Arc<dyn Clock>,
+}
+
+impl<R: ReportRepository, U: RoundingRepository> ReportServiceImpl<R, U> {
+    pub fn new(repository: R, rounding_repository: U, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            repository,
+            rounding_repository,
+            clock,
+        }
+    }
+
+    /// 集計行を親のグループの下に追加する
+    fn insert_row(groups: &mut Vec<ReportGroup>, keys: &[ReportKey], row: &ReportRow) {
+        let depth = row.keys.len() - keys.len();
+        let Some((key, rest)) = keys.split_first() else {
+            return;
+        };
+
+        let index = match groups.iter().position(|group| &group.key == key) {
+            Some(index) => index,
+            // 親のグループは集計済みのはずなので、最後の軸でのみ新しいグループを作る
+            None if rest.is_empty() => {
+                groups.push(ReportGroup {
+                    key: key.clone(),
+                    name: row.names.get(depth).cloned().flatten(),
+                    total_seconds: row.total_seconds,
+                    rounded_seconds: row.rounded_seconds,
+                    entry_count: row.entry_count,
+                    children: Vec::new(),
+                });
+                return;
+            }
+            None => return,
+        };
+
+        if !rest.is_empty() {
+            Self::insert_row(&mut groups[index].children, rest, row);
+        }
+    }
+
+    /// 期間以外のグループを合計の大きい順に並べる
+    fn sort_children(groups: &mut [ReportGroup]) {
+        for group in groups.iter_mut() {
+            group.children.sort_by_key(|group| std::cmp::Reverse(group.total_seconds));
+            Self::sort_children(&mut group.children);
+        }
+    }
+}
+
+#[async_trait]
+impl<R: ReportRepository, U: RoundingRepository> ReportService for ReportServiceImpl<R, U> {
+    async fn generate_report(&self, query: ReportQuery) -> anyhow::Result<Report> {
+        query.validate()?;
+        let now = self.clock.now();
+        let rounding = self.rounding_repository.find_policy().await?;
+
+        // 軸ごとに集計する（タグは1エントリが複数のグループに属するため、親の合計は子の合計から求めない）
+        let mut groups = Vec::new();
+        for depth in 0..=query.group_by.len() {
+            let rows = self.repository.aggregate(&query, &query.group_by[..depth], &rounding, now).await?;
+            for row in &rows {
+                Self::insert_row(&mut groups, &row.keys, row);
+            }
+        }
+        Self::sort_children(&mut groups);
+
+        Ok(Report {
+            total_seconds: groups.iter().map(|group| group.total_seconds).sum(),
+            rounded_seconds: groups.iter().map(|group| group.rounded_seconds).sum(),
+            entry_count: groups.iter().map(|group| group.entry_count).sum(),
+            groups,
+            query,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::rounding_tests::InMemoryRoundingRepository;
+    use crate::domain::repositories::{ReportDimension, ReportGranularity};
+    use crate::domain::services::clock::tests::FixedClock;
+    use crate::domain::services::{LocalCalendar, RoundingPolicy};
+    use crate::domain::value_objects::{TagId, TaskId};
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+    // 軸の数ごとに決まった集計行を返すリポジトリ
+    struct StubReportRepository {
+        rows_by_depth: Vec<Vec<ReportRow>>,
+    }
+
+    #[async_trait]
+    impl ReportRepository for StubReportRepository {
+        async fn aggregate(
+            &self,
+            _query: &ReportQuery,
+            dimensions: &[ReportDimension],
+            _rounding: &RoundingPolicy,
+            _now: DateTime<Utc>,
+        ) -> anyhow::Result<Vec<ReportRow>> {
+            Ok(self.rows_by_depth[dimensions.len()].clone())
+        }
+    }
+
+    fn row(keys: Vec<ReportKey>, total_seconds: i64, entry_count: usize) -> ReportRow {
+        let names = keys.iter().map(|_| None).collect();
+        // 15分単位の切り上げを適用したものとする
+        let rounded_seconds = (total_seconds + 899) / 900 * 900;
+        ReportRow { keys, names, total_seconds, rounded_seconds, entry_count }
+    }
+
+    #[tokio::test]
+    async fn 集計行が入れ子のグループになること() {
+        let period = ReportKey::Period(NaiveDate::from_ymd_opt(2025, 3, 3).unwrap());
+        let task = |id| ReportKey::Task(TaskId::new(id).unwrap());
+        let tag = |id: Option<i64>| ReportKey::Tag(id.map(|id| TagId::new(id).unwrap()));
+        let repository = StubReportRepository {
+            rows_by_depth: vec![
+                vec![row(vec![period.clone()], 5400, 2)],
+                vec![
+                    row(vec![period.clone(), task(1)], 1800, 1),
+                    row(vec![period.clone(), task(2)], 3600, 1),
+                ],
+                vec![
+                    row(vec![period.clone(), task(1), tag(None)], 1800, 1),
+                    row(vec![period.clone(), task(2), tag(Some(1))], 3600, 1),
+                    row(vec![period.clone(), task(2), tag(Some(2))], 3600, 1),
+                ],
+            ],
+        };
+        let clock = Arc::new(FixedClock::new(Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap()));
+        let service = ReportServiceImpl::new(repository, InMemoryRoundingRepository::new(), clock);
+
+        let report = service
+            .generate_report(ReportQuery {
+                start: Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+                end: Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+                granularity: ReportGranularity::Week,
+                group_by: vec![ReportDimension::Task, ReportDimension::Tag],
+                project_ids: Vec::new(),
+                task_ids: Vec::new(),
+                tag_ids: Vec::new(),
+                include_archived: false,
+                calendar: LocalCalendar::utc(),
+            })
+            .await
+            .unwrap();
+
+        // 複数タグのエントリがあっても親の合計は重複しない
+        assert_eq!(report.total_seconds, 5400);
+        assert_eq!(report.rounded_seconds, 5400);
+        assert_eq!(report.groups.len(), 1);
+        let tasks = &report.groups[0].children;
+        assert_eq!(tasks.iter().map(|g| (g.key.clone(), g.total_seconds)).collect::<Vec<_>>(), vec![
+            (task(2), 3600),
+            (task(1), 1800),
+        ]);
+        assert_eq!(tasks[0].children.len(), 2);
+        assert_eq!(tasks[1].children[0].key, tag(None));
+    }
+
+    #[tokio::test]
+    async fn 不正な集計条件はエラーになること() {
+        let clock = Arc::new(FixedClock::new(Utc::now()));
+        let service = ReportServiceImpl::new(
+            StubReportRepository { rows_by_depth: Vec::new() },
+            InMemoryRoundingRepository::new(),
+            clock,
+        );
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        let query = ReportQuery {
+            start,
+            end: start,
+            granularity: ReportGranularity::Day,
+            group_by: Vec::new(),
+            project_ids: Vec::new(),
+            task_ids: Vec::new(),
+            tag_ids: Vec::new(),
+            include_archived: false,
+            calendar: LocalCalendar::utc(),
+        };
+
+        assert!(service.generate_report(query.clone()).await.is_err());
+        assert!(service
+            .generate_report(ReportQuery {
+                end: start + chrono::Duration::days(1),
+                group_by: vec![ReportDimension::Tag, ReportDimension::Tag],
+                ..query
+            })
+            .await
+            .is_err());
+    }
+}
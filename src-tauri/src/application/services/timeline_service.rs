@@ -0,0 +1,283 @@
+use crate::domain::repositories::{TimelineEntry, TimelineRepository};
+use crate::domain::services::{free_intervals, Clock, LocalCalendar, WorkingHours};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// タイムラインの表示範囲
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineSpan {
+    Day,
+    /// カレンダーの週の開始曜日から始まる7日
+    Week,
+}
+
+impl TimelineSpan {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimelineSpan::Day => "day",
+            TimelineSpan::Week => "week",
+        }
+    }
+}
+
+impl FromStr for TimelineSpan {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "day" => Ok(TimelineSpan::Day),
+            "week" => Ok(TimelineSpan::Week),
+            _ => Err(anyhow::anyhow!("Invalid timeline span: {}", s)),
+        }
+    }
+}
+
+/// 稼働時間のうち、どのタスクの時間区間にも含まれない空き時間
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimelineGap {
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+}
+
+impl TimelineGap {
+    pub fn duration_seconds(&self) -> i64 {
+        (self.end_time - self.start_time).num_seconds()
+    }
+}
+
+/// 1日分のタイムライン
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineDay {
+    pub date: NaiveDate,
+    /// その日に重複する時間区間（開始時刻順、日をまたぐ区間は両方の日に含まれる）
+    pub entries: Vec<TimelineEntry>,
+    /// 稼働時間内の空き時間（開始時刻順、現在時刻より後は含まない）
+    pub gaps: Vec<TimelineGap>,
+    /// その日に含まれる作業時間の合計（秒、同時実行の区間はそれぞれ数える）
+    pub tracked_seconds: i64,
+}
+
+/// タイムライン
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline {
+    pub span: TimelineSpan,
+    pub start_date: NaiveDate,
+    pub days: Vec<TimelineDay>,
+}
+
+/// タイムラインサービス
+#[async_trait]
+pub trait TimelineService: Send + Sync {
+    /// 指定日（週の場合は指定日を含む週）の全タスクの時間区間と空き時間を取得
+    async fn get_timeline(&self, date: NaiveDate, span: TimelineSpan) -> anyhow::Result<Timeline>;
+}
+
+/// タイムラインサービス実装
+pub struct TimelineServiceImpl<R: TimelineRepository> {
+    repository: R,
+    clock: Arc<dyn Clock>,
+    calendar: LocalCalendar,
+    working_hours: WorkingHours,
+    /// これより短い空き時間は返さない（秒）
+    min_gap_seconds: i64,
+}
+
+impl<R: TimelineRepository> TimelineServiceImpl<R> {
+    pub fn new(
+        repository: R,
+        clock: Arc<dyn Clock>,
+        calendar: LocalCalendar,
+        working_hours: WorkingHours,
+        min_gap_seconds: i64,
+    ) -> Self {
+        Self {
+            repository,
+            clock,
+            calendar,
+            working_hours,
+            min_gap_seconds,
+        }
+    }
+
+    fn build_day(&self, date: NaiveDate, entries: &[TimelineEntry], now: DateTime<Utc>) -> TimelineDay {
+        let day_start = self.calendar.start_of_day(date);
+        let day_end = self.calendar.start_of_day(date + Duration::days(1));
+        let entries: Vec<_> = entries
+            .iter()
+            .filter(|item| {
+                item.entry.start_time() < day_end && item.entry.end_time().is_none_or(|end| end > day_start)
+            })
+            .cloned()
+            .collect();
+
+        // 実行中の区間は現在時刻まで（一時停止中なら一時停止した時刻まで）埋まっているとみなす
+        let gaps = match self.working_hours.range_on(&self.calendar, date) {
+            Some((start, end)) if start < now => free_intervals(
+                start,
+                end.min(now),
+                entries
+                    .iter()
+                    .map(|item| {
+                        let end_time = item.entry.end_time().or(item.entry.paused_at()).unwrap_or(now);
+                        (item.entry.start_time(), end_time)
+                    }),
+            )
+            .into_iter()
+            .map(|(start_time, end_time)| TimelineGap { start_time, end_time })
+            .filter(|gap| gap.duration_seconds() >= self.min_gap_seconds)
+            .collect(),
+            _ => Vec::new(),
+        };
+
+        TimelineDay {
+            date,
+            tracked_seconds: entries
+                .iter()
+                .map(|item| item.entry.seconds_within(day_start, day_end, now))
+                .sum(),
+            entries,
+            gaps,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: TimelineRepository> TimelineService for TimelineServiceImpl<R> {
+    async fn get_timeline(&self, date: NaiveDate, span: TimelineSpan) -> anyhow::Result<Timeline> {
+        let (start_date, day_count) = match span {
+            TimelineSpan::Day => (date, 1),
+            TimelineSpan::Week => (self.calendar.start_of_week(date), 7),
+        };
+        let now = self.clock.now();
+
+        let entries = self
+            .repository
+            .find_entries_overlapping_period(
+                self.calendar.start_of_day(start_date),
+                self.calendar.start_of_day(start_date + Duration::days(day_count)),
+            )
+            .await?;
+
+        Ok(Timeline {
+            span,
+            start_date,
+            days: (0..day_count)
+                .map(|i| self.build_day(start_date + Duration::days(i), &entries, now))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::time_entry::TimeEntry;
+    use crate::domain::services::clock::tests::FixedClock;
+    use crate::domain::value_objects::{ProjectId, TaskId};
+    use chrono::{NaiveTime, TimeZone, Weekday};
+
+    // 期間に関係なく決まった区間を返すリポジトリ
+    struct StubTimelineRepository {
+        entries: Vec<TimelineEntry>,
+    }
+
+    #[async_trait]
+    impl TimelineRepository for StubTimelineRepository {
+        async fn find_entries_overlapping_period(
+            &self,
+            start: DateTime<Utc>,
+            end: DateTime<Utc>,
+        ) -> anyhow::Result<Vec<TimelineEntry>> {
+            Ok(self
+                .entries
+                .iter()
+                .filter(|item| item.entry.start_time() < end && item.entry.end_time().is_none_or(|e| e > start))
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, day, hour, minute, 0).unwrap()
+    }
+
+    fn item(id: i64, start: DateTime<Utc>, end: Option<DateTime<Utc>>) -> TimelineEntry {
+        TimelineEntry {
+            entry: TimeEntry::new(TaskId::new(1).unwrap(), id, start, end),
+            task_name: "Design".to_string(),
+            project_id: ProjectId::new(1).unwrap(),
+            project_name: "Project".to_string(),
+        }
+    }
+
+    fn service(entries: Vec<TimelineEntry>, now: DateTime<Utc>) -> TimelineServiceImpl<StubTimelineRepository> {
+        let working_hours = WorkingHours::new(
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+            vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+        )
+        .unwrap();
+        TimelineServiceImpl::new(
+            StubTimelineRepository { entries },
+            Arc::new(FixedClock::new(now)),
+            LocalCalendar::utc(),
+            working_hours,
+            60,
+        )
+    }
+
+    #[tokio::test]
+    async fn 稼働時間内の空き時間が求まること() {
+        // 2025-03-10 は月曜日。短い空き（11:00:00〜11:00:30）は返さず、実行中の区間は現在時刻まで埋まっているとみなす
+        let entries = vec![
+            item(1, at(9, 23, 0), Some(at(10, 9, 30))),
+            item(2, at(10, 10, 0), Some(at(10, 11, 0))),
+            item(3, at(10, 11, 0) + Duration::seconds(30), Some(at(10, 12, 0))),
+            item(4, at(10, 15, 0), None),
+        ];
+        let timeline = service(entries, at(10, 16, 0))
+            .get_timeline(NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(), TimelineSpan::Day)
+            .await
+            .unwrap();
+
+        assert_eq!(timeline.days.len(), 1);
+        let day = &timeline.days[0];
+        assert_eq!(day.entries.iter().map(|e| e.entry.start_event_id()).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(day.gaps, vec![
+            TimelineGap { start_time: at(10, 9, 30), end_time: at(10, 10, 0) },
+            TimelineGap { start_time: at(10, 12, 0), end_time: at(10, 15, 0) },
+        ]);
+        // 前日から続く区間はその日に含まれる部分だけを数える
+        assert_eq!(day.tracked_seconds, (9 * 60 + 30) * 60 + 3600 + 3570 + 3600);
+    }
+
+    #[tokio::test]
+    async fn 週のタイムラインは稼働日だけに空き時間があること() {
+        let entries = vec![item(1, at(11, 9, 0), Some(at(11, 18, 0)))];
+        let timeline = service(entries, at(20, 0, 0))
+            .get_timeline(NaiveDate::from_ymd_opt(2025, 3, 12).unwrap(), TimelineSpan::Week)
+            .await
+            .unwrap();
+
+        assert_eq!(timeline.start_date, NaiveDate::from_ymd_opt(2025, 3, 10).unwrap());
+        let gaps: Vec<_> = timeline.days.iter().map(|day| day.gaps.len()).collect();
+        assert_eq!(gaps, vec![1, 0, 1, 1, 1, 0, 0]);
+        assert_eq!(timeline.days[1].entries.len(), 1);
+        assert_eq!(timeline.days[1].tracked_seconds, 9 * 3600);
+    }
+
+    #[tokio::test]
+    async fn 一時停止中の区間は一時停止した時刻以降が空き時間になること() {
+        let mut paused = item(1, at(10, 9, 0), None);
+        paused.entry = paused.entry.with_pause(0, Some(at(10, 10, 0)));
+        let timeline = service(vec![paused], at(10, 12, 0))
+            .get_timeline(NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(), TimelineSpan::Day)
+            .await
+            .unwrap();
+
+        assert_eq!(timeline.days[0].gaps, vec![TimelineGap { start_time: at(10, 10, 0), end_time: at(10, 12, 0) }]);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::application::services::{AppNotification, Notifier};
+use crate::application::use_cases::TimeTrackingUseCases;
+use crate::domain::entities::TimeEntry;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 実行中タイマーの経過時間を定期的にフロントエンドへ通知するバックグラウンドタスク
+pub struct TimerTickWorker {
+    time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>,
+    notifier: Arc<dyn Notifier>,
+}
+
+impl TimerTickWorker {
+    pub fn new(time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>, notifier: Arc<dyn Notifier>) -> Self {
+        Self {
+            time_tracking_use_cases,
+            notifier,
+        }
+    }
+
+    /// 1回分の通知を行う（実行中のタイマーがない場合は通知しない）
+    pub async fn run_once(&self) -> anyhow::Result<Vec<TimeEntry>> {
+        let entries = self.time_tracking_use_cases.get_running_entries().await?;
+        if !entries.is_empty() {
+            self.notifier.notify(AppNotification::TimerTick(entries.clone()));
+        }
+        Ok(entries)
+    }
+
+    /// 指定間隔で経過時間を通知するタスクを起動する
+    pub fn spawn(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.run_once().await {
+                    tracing::error!(error = %e, "Failed to notify running timers");
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::application::services::notifier::tests::RecordingNotifier;
+    use crate::application::use_cases::{StartTimerCommand, TimeTrackingUseCasesImpl};
+    use crate::domain::entities::Task;
+    use crate::domain::repositories::task_tests::InMemoryTaskRepository;
+    use crate::domain::repositories::time_entry_tests::InMemoryTimeEntryRepository;
+    use crate::domain::repositories::TaskRepository;
+    use crate::domain::services::TimeTrackingServiceImpl;
+    use crate::domain::value_objects::{ProjectId, TaskId};
+
+    #[tokio::test]
+    async fn 実行中のタイマーがある間だけ通知すること() {
+        let time_entry_repo = InMemoryTimeEntryRepository::new();
+        let task_repo = InMemoryTaskRepository::new();
+        let task_id = TaskId::new(1).unwrap();
+        task_repo
+            .save(&Task::new(task_id, ProjectId::new(1).unwrap(), "Test Task".to_string()).unwrap())
+            .await
+            .unwrap();
+        let use_cases: Arc<dyn TimeTrackingUseCases> = Arc::new(TimeTrackingUseCasesImpl::new(
+            time_entry_repo.clone(),
+            task_repo,
+            TimeTrackingServiceImpl::new(time_entry_repo),
+        ));
+        let notifier = Arc::new(RecordingNotifier::new());
+        let worker = TimerTickWorker::new(use_cases.clone(), notifier.clone());
+
+        assert!(worker.run_once().await.unwrap().is_empty());
+        assert!(notifier.notifications().is_empty());
+
+        use_cases.start_timer(StartTimerCommand { task_id, at: None }).await.unwrap();
+        let entries = worker.run_once().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(notifier.notifications(), vec![AppNotification::TimerTick(entries)]);
+    }
+}
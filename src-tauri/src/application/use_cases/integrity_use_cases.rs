@@ -0,0 +1,199 @@
+use crate::application::services::EventBus;
+use crate::domain::entities::time_entry::{RepairReason, TimeEntryEvent};
+use crate::domain::events::DomainEvent;
+use crate::domain::services::{IntegrityCheckOptions, IntegrityReport, TimeEntryIntegrityService};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// 整合性チェックコマンド
+#[derive(Debug, Clone, Default)]
+pub struct CheckIntegrityCommand {
+    pub max_running_seconds: Option<i64>,
+}
+
+/// 整合性補正コマンド
+#[derive(Debug, Clone)]
+pub struct RepairTimeEntriesCommand {
+    pub reasons: Vec<RepairReason>,
+    pub max_running_seconds: Option<i64>,
+}
+
+/// 整合性補正結果
+#[derive(Debug, Clone)]
+pub struct RepairTimeEntriesResult {
+    /// 追加した補正イベント
+    pub repair_events: Vec<TimeEntryEvent>,
+    /// 補正後の整合性チェック結果
+    pub report: IntegrityReport,
+}
+
+/// 整合性ユースケーストレイト
+#[async_trait]
+pub trait IntegrityUseCases: Send + Sync {
+    /// 時間区間のイベントログを検査する
+    async fn check_time_entry_integrity(&self, command: CheckIntegrityCommand) -> anyhow::Result<IntegrityReport>;
+
+    /// 補正可能な異常に補正イベントを追加する（INSERT-only）
+    async fn repair_time_entries(&self, command: RepairTimeEntriesCommand) -> anyhow::Result<RepairTimeEntriesResult>;
+}
+
+/// 整合性ユースケース実装
+pub struct IntegrityUseCasesImpl<S: TimeEntryIntegrityService> {
+    integrity_service: S,
+    event_bus: Arc<EventBus>,
+}
+
+impl<S: TimeEntryIntegrityService> IntegrityUseCasesImpl<S> {
+    pub fn new(integrity_service: S) -> Self {
+        Self {
+            integrity_service,
+            event_bus: Arc::new(EventBus::new()),
+        }
+    }
+
+    /// ドメインイベントの発行先を設定
+    pub fn with_event_bus(mut self, event_bus: Arc<EventBus>) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+
+    /// 補正イベントごとに変更された時間エントリを発行する（停止の補正は停止した時間エントリ）
+    async fn publish_repair_events(&self, events: &[TimeEntryEvent]) {
+        for event in events {
+            if !event.is_stop() {
+                self.event_bus.publish(DomainEvent::EntryAdded { task_id: event.task_id() });
+                continue;
+            }
+            let Some(start_event_id) = event.start_event_id() else { continue };
+            match self.integrity_service.find_entry(start_event_id).await {
+                Ok(Some(entry)) if entry.is_completed() => {
+                    self.event_bus.publish(DomainEvent::TimerStopped(entry));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(start_event_id, error = %e, "Failed to load repaired time entry for domain event");
+                }
+            }
+        }
+    }
+
+    fn options(max_running_seconds: Option<i64>) -> anyhow::Result<IntegrityCheckOptions> {
+        let options = IntegrityCheckOptions::new(Utc::now());
+        match max_running_seconds {
+            Some(seconds) if seconds <= 0 => Err(anyhow::anyhow!("Max running seconds must be positive")),
+            Some(seconds) => Ok(options.with_max_running_seconds(seconds)),
+            None => Ok(options),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: TimeEntryIntegrityService> IntegrityUseCases for IntegrityUseCasesImpl<S> {
+    async fn check_time_entry_integrity(&self, command: CheckIntegrityCommand) -> anyhow::Result<IntegrityReport> {
+        let options = Self::options(command.max_running_seconds)?;
+        self.integrity_service.check(&options).await
+    }
+
+    async fn repair_time_entries(&self, command: RepairTimeEntriesCommand) -> anyhow::Result<RepairTimeEntriesResult> {
+        if command.reasons.is_empty() {
+            return Err(anyhow::anyhow!("No repair reasons specified"));
+        }
+
+        let options = Self::options(command.max_running_seconds)?;
+        let repair_events = self.integrity_service.repair(&options, &command.reasons).await?;
+        self.publish_repair_events(&repair_events).await;
+        let report = self.integrity_service.check(&options).await?;
+
+        tracing::info!(repaired = repair_events.len(), remaining = report.anomalies.len(), "Time entry repair completed");
+
+        Ok(RepairTimeEntriesResult { repair_events, report })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::application::services::notifier::tests::RecordingNotifier;
+    use crate::application::services::{AppNotification, NotificationSubscriber};
+    use crate::domain::repositories::time_entry_repository::tests::InMemoryTimeEntryRepository;
+    use crate::domain::repositories::TimeEntryRepository;
+    use crate::domain::services::TimeEntryIntegrityServiceImpl;
+    use crate::domain::value_objects::TaskId;
+
+    fn setup_use_cases() -> (IntegrityUseCasesImpl<TimeEntryIntegrityServiceImpl<InMemoryTimeEntryRepository>>, InMemoryTimeEntryRepository) {
+        let repository = InMemoryTimeEntryRepository::new();
+        let use_cases = IntegrityUseCasesImpl::new(TimeEntryIntegrityServiceImpl::new(repository.clone()));
+        (use_cases, repository)
+    }
+
+    #[tokio::test]
+    async fn 整合性チェックと補正が正しく動作すること() {
+        let (use_cases, repository) = setup_use_cases();
+        let task_id = TaskId::new(1).unwrap();
+        let now = Utc::now();
+        repository.save_event(&TimeEntryEvent::start_at(task_id, now - chrono::Duration::hours(3))).await.unwrap();
+        repository.save_event(&TimeEntryEvent::start_at(task_id, now - chrono::Duration::hours(2))).await.unwrap();
+
+        let report = use_cases.check_time_entry_integrity(CheckIntegrityCommand::default()).await.unwrap();
+        assert_eq!(report.anomalies.len(), 1);
+        assert_eq!(report.anomalies[0].kind(), "duplicate_start");
+
+        let result = use_cases.repair_time_entries(RepairTimeEntriesCommand {
+            reasons: vec![RepairReason::ImplicitStop],
+            max_running_seconds: None,
+        }).await.unwrap();
+        assert_eq!(result.repair_events.len(), 1);
+        assert!(result.report.is_clean());
+
+        // 閾値を下げると実行中の区間が長時間実行として検出される
+        let report = use_cases.check_time_entry_integrity(CheckIntegrityCommand {
+            max_running_seconds: Some(60 * 60),
+        }).await.unwrap();
+        assert_eq!(report.anomalies[0].kind(), "long_running");
+    }
+
+    #[tokio::test]
+    async fn 不正な補正条件が拒否されること() {
+        let (use_cases, _) = setup_use_cases();
+
+        let result = use_cases.check_time_entry_integrity(CheckIntegrityCommand {
+            max_running_seconds: Some(0),
+        }).await;
+        assert!(result.unwrap_err().to_string().contains("Max running seconds must be positive"));
+
+        let result = use_cases.repair_time_entries(RepairTimeEntriesCommand {
+            reasons: vec![],
+            max_running_seconds: None,
+        }).await;
+        assert!(result.unwrap_err().to_string().contains("No repair reasons specified"));
+    }
+
+    #[tokio::test]
+    async fn 補正で停止した区間が通知されること() {
+        let (use_cases, repository) = setup_use_cases();
+        let notifier = Arc::new(RecordingNotifier::new());
+        let event_bus = Arc::new(EventBus::new());
+        event_bus.subscribe(Arc::new(NotificationSubscriber::new(notifier.clone())));
+        let use_cases = use_cases.with_event_bus(event_bus);
+
+        let task_id = TaskId::new(1).unwrap();
+        let now = Utc::now();
+        let first = repository.save_event(&TimeEntryEvent::start_at(task_id, now - chrono::Duration::hours(3))).await.unwrap();
+        repository.save_event(&TimeEntryEvent::start_at(task_id, now - chrono::Duration::hours(2))).await.unwrap();
+
+        use_cases.repair_time_entries(RepairTimeEntriesCommand {
+            reasons: vec![RepairReason::ImplicitStop],
+            max_running_seconds: None,
+        }).await.unwrap();
+
+        let notifications = notifier.notifications();
+        assert_eq!(notifications.len(), 2);
+        assert!(matches!(
+            &notifications[0],
+            AppNotification::TimerStopped(entry) if entry.start_event_id() == first.id().unwrap() && entry.is_completed()
+        ));
+        assert_eq!(notifications[1], AppNotification::EntriesChanged(task_id));
+    }
+}
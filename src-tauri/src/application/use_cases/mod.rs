@@ -3,8 +3,20 @@
 pub mod project_use_cases;
 pub mod task_use_cases;
 pub mod time_tracking_use_cases;
+pub mod tag_use_cases;
+pub mod integrity_use_cases;
+pub mod recovery_use_cases;
+pub mod pomodoro_use_cases;
+pub mod timesheet_use_cases;
+pub mod rounding_use_cases;
 
 pub use project_use_cases::*;
 pub use task_use_cases::*;
 pub use time_tracking_use_cases::*;
+pub use tag_use_cases::*;
+pub use integrity_use_cases::*;
+pub use recovery_use_cases::*;
+pub use pomodoro_use_cases::*;
+pub use timesheet_use_cases::*;
+pub use rounding_use_cases::*;
 
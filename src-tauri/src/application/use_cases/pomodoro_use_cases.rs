@@ -0,0 +1,452 @@
+use crate::application::services::EventBus;
+use crate::application::use_cases::{
+    PauseTimerCommand, ResumeTimerCommand, StartTimerCommand, StopTimerCommand, TimeTrackingUseCases,
+};
+use crate::domain::entities::{PomodoroCompletion, PomodoroPhaseChange, PomodoroSession, PomodoroSettings};
+use crate::domain::events::DomainEvent;
+use crate::domain::repositories::PomodoroRepository;
+use crate::domain::services::Clock;
+use crate::domain::value_objects::TaskId;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// ポモドーロ開始コマンド（未指定の長さは既定の設定を使う）
+#[derive(Debug, Clone)]
+pub struct StartPomodoroCommand {
+    pub task_id: TaskId,
+    pub work_seconds: Option<i64>,
+    pub short_break_seconds: Option<i64>,
+    pub long_break_seconds: Option<i64>,
+    pub long_break_interval: Option<u32>,
+}
+
+/// ポモドーロユースケーストレイト
+#[async_trait]
+pub trait PomodoroUseCases: Send + Sync {
+    /// ポモドーロセッションを開始する（作業フェーズのタイマーを開始）
+    async fn start_pomodoro(&self, command: StartPomodoroCommand) -> anyhow::Result<PomodoroSession>;
+
+    /// ポモドーロセッションを終了する（作業フェーズ中ならタイマーを停止）
+    async fn stop_pomodoro(&self) -> anyhow::Result<Option<PomodoroSession>>;
+
+    /// ポモドーロセッションを一時停止する
+    async fn pause_pomodoro(&self) -> anyhow::Result<PomodoroSession>;
+
+    /// 一時停止中のポモドーロセッションを再開する
+    async fn resume_pomodoro(&self) -> anyhow::Result<PomodoroSession>;
+
+    /// 現在のフェーズをスキップして次のフェーズに進む（スキップした作業は完了数に数えない）
+    async fn skip_pomodoro_phase(&self) -> anyhow::Result<PomodoroPhaseChange>;
+
+    /// 現在のポモドーロセッションを取得
+    async fn get_pomodoro_session(&self) -> anyhow::Result<Option<PomodoroSession>>;
+
+    /// 終了時刻に達したフェーズを次のフェーズに進める
+    ///
+    /// タイマーを操作できない場合（タスクのアーカイブ等）はセッションを終了する。
+    async fn tick(&self) -> anyhow::Result<Option<PomodoroPhaseChange>>;
+
+    /// 指定タスクの完了したポモドーロ数を取得
+    async fn count_completed_pomodoros(&self, task_id: TaskId) -> anyhow::Result<usize>;
+}
+
+/// ポモドーロユースケース実装
+pub struct PomodoroUseCasesImpl<P: PomodoroRepository> {
+    pomodoro_repository: P,
+    time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>,
+    clock: Arc<dyn Clock>,
+    default_settings: PomodoroSettings,
+    // タイマー操作とフェーズ遷移を直列化するため、操作中はロックを保持する
+    session: Mutex<Option<PomodoroSession>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl<P: PomodoroRepository> PomodoroUseCasesImpl<P> {
+    pub fn new(
+        pomodoro_repository: P,
+        time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>,
+        clock: Arc<dyn Clock>,
+        default_settings: PomodoroSettings,
+    ) -> Self {
+        Self {
+            pomodoro_repository,
+            time_tracking_use_cases,
+            clock,
+            default_settings,
+            session: Mutex::new(None),
+            event_bus: Arc::new(EventBus::new()),
+        }
+    }
+
+    /// ドメインイベントの発行先を設定
+    pub fn with_event_bus(mut self, event_bus: Arc<EventBus>) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+
+    /// フェーズを進め、作業フェーズの出入りに合わせてタイマーを停止・開始する
+    ///
+    /// 完了したフェーズは予定終了時刻で区切るが、次のフェーズ（作業ならタイマー）は現在時刻から始める。
+    /// スリープ等で複数のフェーズ分遅れても進めるのは1フェーズだけで、不在の間の作業や完了は記録しない。
+    /// タイマー操作に失敗した場合はセッションを元に戻す。
+    async fn advance(&self, session: &mut PomodoroSession, completed: bool) -> anyhow::Result<PomodoroPhaseChange> {
+        let now = self.clock.now();
+        let ended_at = if completed {
+            session.scheduled_phase_end().min(now)
+        } else {
+            now
+        };
+        let original = session.clone();
+        let change = session.advance(now, completed);
+
+        let result = if change.from.is_work() {
+            let command = StopTimerCommand { task_id: change.task_id, at: Some(ended_at) };
+            self.time_tracking_use_cases.stop_timer(command).await.map(|_| ())
+        } else {
+            let command = StartTimerCommand { task_id: change.task_id, at: Some(now) };
+            self.time_tracking_use_cases.start_timer(command).await.map(|_| ())
+        };
+        if let Err(e) = result {
+            *session = original;
+            return Err(e);
+        }
+
+        if change.from.is_work() && change.completed {
+            let completion = PomodoroCompletion::new(
+                change.task_id,
+                change.from_started_at,
+                ended_at,
+                session.settings().work_seconds(),
+            );
+            let saved = self.pomodoro_repository.save_completion(&completion).await?;
+            self.event_bus.publish(DomainEvent::PomodoroCompleted(saved));
+        }
+
+        Ok(change)
+    }
+}
+
+#[async_trait]
+impl<P: PomodoroRepository> PomodoroUseCases for PomodoroUseCasesImpl<P> {
+    async fn start_pomodoro(&self, command: StartPomodoroCommand) -> anyhow::Result<PomodoroSession> {
+        let mut current = self.session.lock().await;
+        if current.is_some() {
+            return Err(anyhow::anyhow!("Pomodoro session is already running"));
+        }
+
+        let defaults = self.default_settings;
+        let settings = PomodoroSettings::new(
+            command.work_seconds.unwrap_or(defaults.work_seconds()),
+            command.short_break_seconds.unwrap_or(defaults.short_break_seconds()),
+            command.long_break_seconds.unwrap_or(defaults.long_break_seconds()),
+            command.long_break_interval.unwrap_or(defaults.long_break_interval()),
+        )?;
+
+        let now = self.clock.now();
+        self.time_tracking_use_cases
+            .start_timer(StartTimerCommand { task_id: command.task_id, at: Some(now) })
+            .await?;
+
+        let session = PomodoroSession::start(command.task_id, settings, now);
+        *current = Some(session.clone());
+        Ok(session)
+    }
+
+    async fn stop_pomodoro(&self) -> anyhow::Result<Option<PomodoroSession>> {
+        let mut current = self.session.lock().await;
+        let Some(session) = current.take() else {
+            return Ok(None);
+        };
+
+        if session.phase().is_work() {
+            let command = StopTimerCommand { task_id: session.task_id(), at: None };
+            if let Err(e) = self.time_tracking_use_cases.stop_timer(command).await {
+                tracing::warn!(error = %e, task_id = %session.task_id(), "Failed to stop timer for pomodoro");
+            }
+        }
+
+        Ok(Some(session))
+    }
+
+    async fn pause_pomodoro(&self) -> anyhow::Result<PomodoroSession> {
+        let mut current = self.session.lock().await;
+        let session = current
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("No pomodoro session"))?;
+        let original = session.clone();
+        let now = self.clock.now();
+        session.pause(now)?;
+
+        // タイマーを一時停止できない場合はセッションを元に戻す
+        if session.phase().is_work() {
+            let command = PauseTimerCommand { task_id: session.task_id(), at: Some(now) };
+            if let Err(e) = self.time_tracking_use_cases.pause_timer(command).await {
+                *session = original;
+                return Err(e);
+            }
+        }
+
+        Ok(session.clone())
+    }
+
+    async fn resume_pomodoro(&self) -> anyhow::Result<PomodoroSession> {
+        let mut current = self.session.lock().await;
+        let session = current
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("No pomodoro session"))?;
+        let original = session.clone();
+        let now = self.clock.now();
+        session.resume(now)?;
+
+        // タイマーを再開できない場合はセッションを元に戻す
+        if session.phase().is_work() {
+            let command = ResumeTimerCommand { task_id: session.task_id(), at: Some(now) };
+            if let Err(e) = self.time_tracking_use_cases.resume_timer(command).await {
+                *session = original;
+                return Err(e);
+            }
+        }
+
+        Ok(session.clone())
+    }
+
+    async fn skip_pomodoro_phase(&self) -> anyhow::Result<PomodoroPhaseChange> {
+        let mut current = self.session.lock().await;
+        let session = current
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("No pomodoro session"))?;
+
+        self.advance(session, false).await
+    }
+
+    async fn get_pomodoro_session(&self) -> anyhow::Result<Option<PomodoroSession>> {
+        Ok(self.session.lock().await.clone())
+    }
+
+    async fn tick(&self) -> anyhow::Result<Option<PomodoroPhaseChange>> {
+        let mut current = self.session.lock().await;
+        let Some(session) = current.as_mut() else {
+            return Ok(None);
+        };
+        if !session.is_phase_complete(self.clock.now()) {
+            return Ok(None);
+        }
+
+        match self.advance(session, true).await {
+            Ok(change) => Ok(Some(change)),
+            Err(e) => {
+                // 失敗したフェーズを残すと確認のたびに失敗し続けるため、セッションを終了して通知する
+                let task_id = session.task_id();
+                *current = None;
+                self.event_bus.publish(DomainEvent::PomodoroEnded { task_id, reason: e.to_string() });
+                Err(e)
+            }
+        }
+    }
+
+    async fn count_completed_pomodoros(&self, task_id: TaskId) -> anyhow::Result<usize> {
+        self.pomodoro_repository.count_completions_by_task(task_id).await
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::application::services::notifier::tests::RecordingNotifier;
+    use crate::application::services::{AppNotification, NotificationSubscriber};
+    use crate::application::use_cases::TimeTrackingUseCasesImpl;
+    use crate::domain::entities::{PomodoroPhase, Task};
+    use crate::domain::repositories::pomodoro_tests::InMemoryPomodoroRepository;
+    use crate::domain::repositories::{task_tests::InMemoryTaskRepository, time_entry_tests::InMemoryTimeEntryRepository};
+    use crate::domain::repositories::TaskRepository;
+    use crate::domain::services::clock::tests::FixedClock;
+    use crate::domain::services::TimeTrackingServiceImpl;
+    use crate::domain::value_objects::ProjectId;
+    use chrono::{Duration, Utc};
+
+    async fn setup_use_cases() -> (
+        PomodoroUseCasesImpl<InMemoryPomodoroRepository>,
+        Arc<dyn TimeTrackingUseCases>,
+        Arc<FixedClock>,
+        TaskId,
+        InMemoryTaskRepository,
+    ) {
+        let time_entry_repo = InMemoryTimeEntryRepository::new();
+        let task_repo = InMemoryTaskRepository::new();
+        let task_id = TaskId::new(1).unwrap();
+        let task = Task::new(task_id, ProjectId::new(1).unwrap(), "Test Task".to_string()).unwrap();
+        task_repo.save(&task).await.unwrap();
+
+        let time_tracking_use_cases: Arc<dyn TimeTrackingUseCases> = Arc::new(TimeTrackingUseCasesImpl::new(
+            time_entry_repo.clone(),
+            task_repo.clone(),
+            TimeTrackingServiceImpl::new(time_entry_repo),
+        ));
+        // タイマーの記録時刻は現在時刻より後にできないため、過去の時刻から進める
+        let clock = Arc::new(FixedClock::new(Utc::now() - Duration::hours(3)));
+        let use_cases = PomodoroUseCasesImpl::new(
+            InMemoryPomodoroRepository::new(),
+            time_tracking_use_cases.clone(),
+            clock.clone(),
+            PomodoroSettings::default(),
+        );
+
+        (use_cases, time_tracking_use_cases, clock, task_id, task_repo)
+    }
+
+    fn start_command(task_id: TaskId) -> StartPomodoroCommand {
+        StartPomodoroCommand {
+            task_id,
+            work_seconds: None,
+            short_break_seconds: None,
+            long_break_seconds: None,
+            long_break_interval: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn 作業フェーズに合わせてタイマーが開始停止されること() {
+        let (use_cases, time_tracking, clock, task_id, _) = setup_use_cases().await;
+
+        use_cases.start_pomodoro(start_command(task_id)).await.unwrap();
+        assert!(time_tracking.get_timer_status(task_id).await.unwrap().is_running);
+        assert!(use_cases.start_pomodoro(start_command(task_id)).await.is_err());
+
+        // 終了時刻前は進まない
+        clock.advance(Duration::minutes(24));
+        assert!(use_cases.tick().await.unwrap().is_none());
+
+        clock.advance(Duration::minutes(1));
+        let change = use_cases.tick().await.unwrap().unwrap();
+        assert_eq!((change.from, change.to), (PomodoroPhase::Work, PomodoroPhase::ShortBreak));
+        assert!(!time_tracking.get_timer_status(task_id).await.unwrap().is_running);
+        assert_eq!(use_cases.count_completed_pomodoros(task_id).await.unwrap(), 1);
+
+        clock.advance(Duration::minutes(5));
+        let change = use_cases.tick().await.unwrap().unwrap();
+        assert_eq!(change.to, PomodoroPhase::Work);
+        assert!(time_tracking.get_timer_status(task_id).await.unwrap().is_running);
+
+        use_cases.stop_pomodoro().await.unwrap().unwrap();
+        assert!(!time_tracking.get_timer_status(task_id).await.unwrap().is_running);
+        assert!(use_cases.get_pomodoro_session().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn 一時停止とスキップがタイマーに反映されること() {
+        let (use_cases, time_tracking, clock, task_id, _) = setup_use_cases().await;
+        let command = StartPomodoroCommand {
+            work_seconds: Some(50 * 60),
+            ..start_command(task_id)
+        };
+        let session = use_cases.start_pomodoro(command).await.unwrap();
+        assert_eq!(session.settings().work_seconds(), 50 * 60);
+
+        clock.advance(Duration::minutes(10));
+        use_cases.pause_pomodoro().await.unwrap();
+        let status = time_tracking.get_timer_status(task_id).await.unwrap();
+        assert!(status.current_entry.unwrap().is_paused());
+
+        // 一時停止中は終了時刻に達しない
+        clock.advance(Duration::hours(1));
+        assert!(use_cases.tick().await.unwrap().is_none());
+
+        let session = use_cases.resume_pomodoro().await.unwrap();
+        assert_eq!(session.remaining_seconds(clock.now()), 40 * 60);
+        assert!(!time_tracking.get_timer_status(task_id).await.unwrap().current_entry.unwrap().is_paused());
+
+        let change = use_cases.skip_pomodoro_phase().await.unwrap();
+        assert!(!change.completed);
+        assert_eq!(change.to, PomodoroPhase::ShortBreak);
+        assert!(!time_tracking.get_timer_status(task_id).await.unwrap().is_running);
+        assert_eq!(use_cases.count_completed_pomodoros(task_id).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn 遅れて進めた場合は予定終了時刻で作業を区切り次のフェーズは現在時刻から始めること() {
+        let (use_cases, time_tracking, clock, task_id, _) = setup_use_cases().await;
+        let started_at = clock.now();
+        use_cases.start_pomodoro(start_command(task_id)).await.unwrap();
+
+        // スリープ等で作業と休憩の終了時刻を過ぎてから進める
+        clock.advance(Duration::minutes(40));
+        let work_end = started_at + Duration::minutes(25);
+        let change = use_cases.tick().await.unwrap().unwrap();
+        assert_eq!((change.from, change.to), (PomodoroPhase::Work, PomodoroPhase::ShortBreak));
+        assert_eq!(change.at, clock.now());
+
+        let entries = time_tracking.get_task_entries(task_id).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].end_time(), Some(work_end));
+        assert_eq!(use_cases.count_completed_pomodoros(task_id).await.unwrap(), 1);
+
+        // 過ぎた休憩をまとめて進めず、休憩は現在時刻から始まる
+        assert!(use_cases.tick().await.unwrap().is_none());
+        assert!(!time_tracking.get_timer_status(task_id).await.unwrap().is_running);
+
+        // 不在の間の作業は記録せず、次の作業は休憩の終了時刻から計測される
+        clock.advance(Duration::hours(2));
+        let change = use_cases.tick().await.unwrap().unwrap();
+        assert_eq!(change.to, PomodoroPhase::Work);
+        assert_eq!(change.at, clock.now());
+        let status = time_tracking.get_timer_status(task_id).await.unwrap();
+        assert_eq!(status.current_entry.unwrap().start_time(), clock.now());
+        assert!(use_cases.tick().await.unwrap().is_none());
+        assert_eq!(use_cases.count_completed_pomodoros(task_id).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn タイマーを開始できない場合はセッションを終了して通知すること() {
+        let (use_cases, time_tracking, clock, task_id, task_repo) = setup_use_cases().await;
+        let notifier = Arc::new(RecordingNotifier::new());
+        let event_bus = Arc::new(EventBus::new());
+        event_bus.subscribe(Arc::new(NotificationSubscriber::new(notifier.clone())));
+        let use_cases = use_cases.with_event_bus(event_bus);
+        use_cases.start_pomodoro(start_command(task_id)).await.unwrap();
+        clock.advance(Duration::minutes(25));
+        use_cases.tick().await.unwrap().unwrap();
+
+        let task = task_repo.find_by_id(task_id).await.unwrap().unwrap();
+        task_repo.save(&task.archive()).await.unwrap();
+        clock.advance(Duration::minutes(5));
+
+        assert!(use_cases.tick().await.is_err());
+        assert!(use_cases.get_pomodoro_session().await.unwrap().is_none());
+        assert!(!time_tracking.get_timer_status(task_id).await.unwrap().is_running);
+        assert!(notifier
+            .notifications()
+            .iter()
+            .any(|notification| matches!(notification, AppNotification::PomodoroEnded { task_id: id, .. } if *id == task_id)));
+
+        // 終了後は失敗し続けない
+        clock.advance(Duration::seconds(1));
+        assert!(use_cases.tick().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn タイマーを一時停止再開できない場合はセッションを変えないこと() {
+        let (use_cases, time_tracking, clock, task_id, _) = setup_use_cases().await;
+        use_cases.start_pomodoro(start_command(task_id)).await.unwrap();
+
+        // ポモドーロの外でタイマーが停止された
+        clock.advance(Duration::minutes(5));
+        time_tracking
+            .stop_timer(StopTimerCommand { task_id, at: Some(clock.now()) })
+            .await
+            .unwrap();
+        assert!(use_cases.pause_pomodoro().await.is_err());
+        assert!(!use_cases.get_pomodoro_session().await.unwrap().unwrap().is_paused());
+
+        // ポモドーロの外でタイマーが再開された
+        time_tracking
+            .start_timer(StartTimerCommand { task_id, at: Some(clock.now()) })
+            .await
+            .unwrap();
+        use_cases.pause_pomodoro().await.unwrap();
+        time_tracking.resume_timer(ResumeTimerCommand { task_id, at: None }).await.unwrap();
+        assert!(use_cases.resume_pomodoro().await.is_err());
+        assert!(use_cases.get_pomodoro_session().await.unwrap().unwrap().is_paused());
+    }
+}
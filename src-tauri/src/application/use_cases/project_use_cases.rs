@@ -1,8 +1,11 @@
+use crate::application::services::EventBus;
 use crate::domain::entities::Project;
+use crate::domain::events::DomainEvent;
 use crate::domain::repositories::ProjectRepository;
 use crate::domain::services::ProjectManagementService;
 use crate::domain::value_objects::ProjectId;
 use async_trait::async_trait;
+use std::sync::Arc;
 
 /// プロジェクト作成コマンド
 #[derive(Debug, Clone)]
@@ -62,11 +65,22 @@ pub trait ProjectUseCases: Send + Sync {
 pub struct ProjectUseCasesImpl<R: ProjectRepository, S: ProjectManagementService> {
     repository: R,
     service: S,
+    event_bus: Arc<EventBus>,
 }
 
 impl<R: ProjectRepository, S: ProjectManagementService> ProjectUseCasesImpl<R, S> {
     pub fn new(repository: R, service: S) -> Self {
-        Self { repository, service }
+        Self {
+            repository,
+            service,
+            event_bus: Arc::new(EventBus::new()),
+        }
+    }
+
+    /// ドメインイベントの発行先を設定
+    pub fn with_event_bus(mut self, event_bus: Arc<EventBus>) -> Self {
+        self.event_bus = event_bus;
+        self
     }
 }
 
@@ -86,6 +100,7 @@ impl<R: ProjectRepository, S: ProjectManagementService> ProjectUseCases for Proj
         
         // 保存
         self.repository.save(&project).await?;
+        self.event_bus.publish(DomainEvent::ProjectCreated { project_id: id });
         
         Ok(project)
     }
@@ -112,6 +127,7 @@ impl<R: ProjectRepository, S: ProjectManagementService> ProjectUseCases for Proj
         
         // 保存
         self.repository.save(&updated_project).await?;
+        self.event_bus.publish(DomainEvent::ProjectRenamed { project_id: command.id });
         
         Ok(updated_project)
     }
@@ -141,6 +157,10 @@ impl<R: ProjectRepository, S: ProjectManagementService> ProjectUseCases for Proj
             let archived_project = existing_project.archive();
             self.repository.save(&archived_project).await?;
         }
+        self.event_bus.publish(DomainEvent::ProjectArchived {
+            project_id: command.id,
+            with_tasks: command.force,
+        });
 
         Ok(())
     }
@@ -155,6 +175,7 @@ impl<R: ProjectRepository, S: ProjectManagementService> ProjectUseCases for Proj
         
         // 保存
         self.repository.save(&restored_project).await?;
+        self.event_bus.publish(DomainEvent::ProjectRestored { project_id: command.id });
         
         Ok(restored_project)
     }
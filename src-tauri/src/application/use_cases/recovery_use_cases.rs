@@ -0,0 +1,325 @@
+use crate::application::services::EventBus;
+use crate::domain::entities::time_entry::TimeEntryEvent;
+use crate::domain::events::DomainEvent;
+use crate::domain::services::{Clock, PendingRecovery, RecoveryResolution, TimerRecoveryService};
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+/// 復旧解決コマンド
+#[derive(Debug, Clone)]
+pub struct ResolveRecoveryCommand {
+    pub start_event_id: i64,
+    pub resolution: RecoveryResolution,
+}
+
+/// タイマー復旧ユースケーストレイト
+#[async_trait]
+pub trait RecoveryUseCases: Send + Sync {
+    /// ハートビートを確認する
+    /// 最終ハートビートが古い実行中区間を復旧待ちとして保持し、それ以外の実行中区間にハートビートを記録する
+    /// 戻り値は新たに検出した復旧待ちの区間
+    async fn check_heartbeats(&self) -> anyhow::Result<Vec<PendingRecovery>>;
+
+    /// 復旧待ちの区間を取得（既に停止された区間は除く）
+    async fn get_pending_recoveries(&self) -> anyhow::Result<Vec<PendingRecovery>>;
+
+    /// 復旧待ちの区間を解決する
+    async fn resolve_recovery(&self, command: ResolveRecoveryCommand) -> anyhow::Result<Option<TimeEntryEvent>>;
+}
+
+/// タイマー復旧ユースケース実装
+pub struct RecoveryUseCasesImpl<S: TimerRecoveryService> {
+    recovery_service: S,
+    clock: Arc<dyn Clock>,
+    stale_after_seconds: i64,
+    // 解決されるまで最終ハートビートを保持するため、復旧待ちの区間はハートビート記録から除外する
+    pending: Mutex<Vec<PendingRecovery>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl<S: TimerRecoveryService> RecoveryUseCasesImpl<S> {
+    pub fn new(recovery_service: S, clock: Arc<dyn Clock>, stale_after_seconds: i64) -> anyhow::Result<Self> {
+        if stale_after_seconds <= 0 {
+            return Err(anyhow::anyhow!("Stale after seconds must be positive"));
+        }
+
+        Ok(Self {
+            recovery_service,
+            clock,
+            stale_after_seconds,
+            pending: Mutex::new(Vec::new()),
+            event_bus: Arc::new(EventBus::new()),
+        })
+    }
+
+    /// ドメインイベントの発行先を設定
+    pub fn with_event_bus(mut self, event_bus: Arc<EventBus>) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+
+    /// 停止した時間エントリを発行する
+    async fn publish_stopped(&self, start_event_id: i64) {
+        match self.recovery_service.find_entry(start_event_id).await {
+            Ok(Some(entry)) if entry.is_completed() => {
+                self.event_bus.publish(DomainEvent::TimerStopped(entry));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!(start_event_id, error = %e, "Failed to load stopped time entry for domain event");
+            }
+        }
+    }
+
+    fn pending_start_event_ids(&self) -> Vec<i64> {
+        self.pending.lock().unwrap().iter().map(|r| r.start_event_id()).collect()
+    }
+
+    fn remove_pending(&self, start_event_id: i64) {
+        self.pending.lock().unwrap().retain(|r| r.start_event_id() != start_event_id);
+    }
+}
+
+#[async_trait]
+impl<S: TimerRecoveryService> RecoveryUseCases for RecoveryUseCasesImpl<S> {
+    async fn check_heartbeats(&self) -> anyhow::Result<Vec<PendingRecovery>> {
+        let now = self.clock.now();
+        let pending_ids = self.pending_start_event_ids();
+
+        let detected: Vec<PendingRecovery> = self
+            .recovery_service
+            .find_stale_entries(now, self.stale_after_seconds)
+            .await?
+            .into_iter()
+            .filter(|r| !pending_ids.contains(&r.start_event_id()))
+            .collect();
+
+        for recovery in &detected {
+            tracing::warn!(
+                task_id = %recovery.entry.task_id(),
+                start_event_id = recovery.start_event_id(),
+                last_heartbeat = %recovery.last_heartbeat,
+                "Stale timer heartbeat detected"
+            );
+        }
+
+        let excluded_ids = {
+            let mut pending = self.pending.lock().unwrap();
+            pending.extend(detected.iter().cloned());
+            pending.iter().map(|r| r.start_event_id()).collect::<Vec<_>>()
+        };
+        self.recovery_service.record_heartbeats(now, &excluded_ids).await?;
+
+        Ok(detected)
+    }
+
+    async fn get_pending_recoveries(&self) -> anyhow::Result<Vec<PendingRecovery>> {
+        let pending = self.pending.lock().unwrap().clone();
+        let mut recoveries = Vec::new();
+
+        for recovery in pending {
+            if self.recovery_service.is_running(recovery.start_event_id()).await? {
+                recoveries.push(recovery);
+            } else {
+                // 通常の停止や自動停止で既に閉じられた区間は復旧対象から外す
+                self.remove_pending(recovery.start_event_id());
+            }
+        }
+
+        Ok(recoveries)
+    }
+
+    async fn resolve_recovery(&self, command: ResolveRecoveryCommand) -> anyhow::Result<Option<TimeEntryEvent>> {
+        let recovery = self
+            .pending
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.start_event_id() == command.start_event_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Pending recovery not found"))?;
+
+        let result = self
+            .recovery_service
+            .resolve(&recovery, command.resolution, self.clock.now())
+            .await;
+        if result.is_ok() || !self.recovery_service.is_running(recovery.start_event_id()).await? {
+            self.remove_pending(recovery.start_event_id());
+        }
+        if let Ok(Some(_)) = &result {
+            self.publish_stopped(recovery.start_event_id()).await;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::application::services::notifier::tests::RecordingNotifier;
+    use crate::application::services::{AppNotification, NotificationSubscriber};
+    use crate::domain::repositories::time_entry_repository::tests::InMemoryTimeEntryRepository;
+    use crate::domain::repositories::TimeEntryRepository;
+    use crate::domain::services::clock::tests::FixedClock;
+    use crate::domain::services::{TimeTrackingService, TimeTrackingServiceImpl, TimerRecoveryServiceImpl};
+    use crate::domain::value_objects::TaskId;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    type TestUseCases = RecoveryUseCasesImpl<TimerRecoveryServiceImpl<InMemoryTimeEntryRepository>>;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, hour, minute, 0).unwrap()
+    }
+
+    fn setup_use_cases() -> (TestUseCases, InMemoryTimeEntryRepository, Arc<FixedClock>) {
+        let repository = InMemoryTimeEntryRepository::new();
+        let clock = Arc::new(FixedClock::new(at(9, 0)));
+        let use_cases = RecoveryUseCasesImpl::new(
+            TimerRecoveryServiceImpl::new(repository.clone()),
+            clock.clone(),
+            5 * 60,
+        )
+        .unwrap();
+        (use_cases, repository, clock)
+    }
+
+    #[tokio::test]
+    async fn ハートビートが途絶えた区間が復旧待ちになること() {
+        let (use_cases, repository, clock) = setup_use_cases();
+        let task_id = TaskId::new(1).unwrap();
+        let start = repository.save_event(&TimeEntryEvent::start_at(task_id, at(9, 0))).await.unwrap();
+        let start_event_id = start.id().unwrap();
+
+        // 計測中は定期的にハートビートが記録される
+        clock.set(at(9, 1));
+        assert!(use_cases.check_heartbeats().await.unwrap().is_empty());
+        assert_eq!(repository.find_heartbeat(start_event_id).await.unwrap(), Some(at(9, 1)));
+
+        // クラッシュ後の再起動でハートビートの途絶を検出する
+        clock.set(at(18, 0));
+        let detected = use_cases.check_heartbeats().await.unwrap();
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].last_heartbeat, at(9, 1));
+
+        // 復旧待ちの区間は最終ハートビートを保持し、再検出もしない
+        clock.advance(Duration::minutes(10));
+        assert!(use_cases.check_heartbeats().await.unwrap().is_empty());
+        assert_eq!(repository.find_heartbeat(start_event_id).await.unwrap(), Some(at(9, 1)));
+        assert_eq!(use_cases.get_pending_recoveries().await.unwrap().len(), 1);
+
+        let stop_event = use_cases
+            .resolve_recovery(ResolveRecoveryCommand {
+                start_event_id,
+                resolution: RecoveryResolution::StopAtLastHeartbeat,
+            })
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stop_event.at(), at(9, 1));
+        assert!(use_cases.get_pending_recoveries().await.unwrap().is_empty());
+
+        // 解決済みの区間は再度解決できない
+        let result = use_cases
+            .resolve_recovery(ResolveRecoveryCommand {
+                start_event_id,
+                resolution: RecoveryResolution::Keep,
+            })
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn 停止済みの区間は復旧待ちから除かれること() {
+        let (use_cases, repository, clock) = setup_use_cases();
+        let task_id = TaskId::new(1).unwrap();
+        let start = repository.save_event(&TimeEntryEvent::start_at(task_id, at(9, 0))).await.unwrap();
+
+        clock.set(at(12, 0));
+        assert_eq!(use_cases.check_heartbeats().await.unwrap().len(), 1);
+
+        repository
+            .save_event(&TimeEntryEvent::stop_at(task_id, start.id().unwrap(), at(12, 0)))
+            .await
+            .unwrap();
+        assert!(use_cases.get_pending_recoveries().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn 継続を選ぶとハートビートが再開されること() {
+        let (use_cases, repository, clock) = setup_use_cases();
+        let start = repository
+            .save_event(&TimeEntryEvent::start_at(TaskId::new(1).unwrap(), at(9, 0)))
+            .await
+            .unwrap();
+        let start_event_id = start.id().unwrap();
+
+        clock.set(at(12, 0));
+        use_cases.check_heartbeats().await.unwrap();
+        let result = use_cases
+            .resolve_recovery(ResolveRecoveryCommand {
+                start_event_id,
+                resolution: RecoveryResolution::Keep,
+            })
+            .await
+            .unwrap();
+        assert!(result.is_none());
+
+        clock.advance(Duration::minutes(1));
+        assert!(use_cases.check_heartbeats().await.unwrap().is_empty());
+        assert_eq!(repository.find_heartbeat(start_event_id).await.unwrap(), Some(at(12, 1)));
+    }
+
+    #[tokio::test]
+    async fn 遡って開始したタイマーが復旧待ちにならないこと() {
+        let repository = InMemoryTimeEntryRepository::new();
+        let now = Utc::now();
+        let use_cases = RecoveryUseCasesImpl::new(
+            TimerRecoveryServiceImpl::new(repository.clone()),
+            Arc::new(FixedClock::new(now)),
+            5 * 60,
+        )
+        .unwrap();
+        let time_tracking = TimeTrackingServiceImpl::new(repository.clone());
+
+        time_tracking
+            .start_timer(TaskId::new(1).unwrap(), Some(now - Duration::hours(1)))
+            .await
+            .unwrap();
+
+        assert!(use_cases.check_heartbeats().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn 復旧で停止した区間が通知されること() {
+        let (use_cases, repository, clock) = setup_use_cases();
+        let notifier = Arc::new(RecordingNotifier::new());
+        let event_bus = Arc::new(EventBus::new());
+        event_bus.subscribe(Arc::new(NotificationSubscriber::new(notifier.clone())));
+        let use_cases = use_cases.with_event_bus(event_bus);
+
+        let task_id = TaskId::new(1).unwrap();
+        let start = repository.save_event(&TimeEntryEvent::start_at(task_id, at(9, 0))).await.unwrap();
+        let start_event_id = start.id().unwrap();
+        clock.set(at(12, 0));
+        use_cases.check_heartbeats().await.unwrap();
+        assert!(notifier.notifications().is_empty());
+
+        use_cases
+            .resolve_recovery(ResolveRecoveryCommand {
+                start_event_id,
+                resolution: RecoveryResolution::StopAt(at(10, 0)),
+            })
+            .await
+            .unwrap();
+
+        let notifications = notifier.notifications();
+        assert_eq!(notifications.len(), 2);
+        assert!(matches!(
+            &notifications[0],
+            AppNotification::TimerStopped(entry) if entry.start_event_id() == start_event_id && entry.end_time() == Some(at(10, 0))
+        ));
+        assert_eq!(notifications[1], AppNotification::EntriesChanged(task_id));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::domain::repositories::{ProjectRepository, RoundingRepository, TaskRepository, TimeEntryRepository};
+use crate::domain::services::{Clock, LocalCalendar, RoundingPolicy};
+use crate::domain::value_objects::{ProjectId, RoundingRule, TaskId};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// 丸め規則の設定コマンド
+#[derive(Debug, Clone)]
+pub struct SetRoundingRuleCommand {
+    /// None の場合はワークスペース全体の既定
+    pub project_id: Option<ProjectId>,
+    /// プロジェクトで None の場合は上書きを解除して既定に戻す（既定で None の場合は丸めない）
+    pub rule: Option<RoundingRule>,
+}
+
+/// 丸め設定ユースケーストレイト
+#[async_trait]
+pub trait RoundingUseCases: Send + Sync {
+    /// 丸め設定を取得
+    async fn get_rounding_policy(&self) -> anyhow::Result<RoundingPolicy>;
+
+    /// 丸め規則を設定し、更新後の丸め設定を返す
+    async fn set_rounding_rule(&self, command: SetRoundingRuleCommand) -> anyhow::Result<RoundingPolicy>;
+
+    /// タスクの丸めた合計作業時間を取得（秒、タスクのプロジェクトの規則を適用）
+    async fn get_task_rounded_duration(&self, task_id: TaskId) -> anyhow::Result<i64>;
+
+    /// プロジェクトの丸めた合計作業時間を取得（秒）
+    async fn get_project_rounded_duration(&self, project_id: ProjectId) -> anyhow::Result<i64>;
+}
+
+/// 丸め設定ユースケース実装
+pub struct RoundingUseCasesImpl<U, T, K, P>
+where
+    U: RoundingRepository,
+    T: TimeEntryRepository,
+    K: TaskRepository,
+    P: ProjectRepository,
+{
+    rounding_repository: U,
+    time_entry_repository: T,
+    task_repository: K,
+    project_repository: P,
+    clock: Arc<dyn Clock>,
+    calendar: LocalCalendar,
+}
+
+impl<U, T, K, P> RoundingUseCasesImpl<U, T, K, P>
+where
+    U: RoundingRepository,
+    T: TimeEntryRepository,
+    K: TaskRepository,
+    P: ProjectRepository,
+{
+    pub fn new(
+        rounding_repository: U,
+        time_entry_repository: T,
+        task_repository: K,
+        project_repository: P,
+        clock: Arc<dyn Clock>,
+        calendar: LocalCalendar,
+    ) -> Self {
+        Self {
+            rounding_repository,
+            time_entry_repository,
+            task_repository,
+            project_repository,
+            clock,
+            calendar,
+        }
+    }
+}
+
+#[async_trait]
+impl<U, T, K, P> RoundingUseCases for RoundingUseCasesImpl<U, T, K, P>
+where
+    U: RoundingRepository,
+    T: TimeEntryRepository,
+    K: TaskRepository,
+    P: ProjectRepository,
+{
+    async fn get_rounding_policy(&self) -> anyhow::Result<RoundingPolicy> {
+        self.rounding_repository.find_policy().await
+    }
+
+    async fn set_rounding_rule(&self, command: SetRoundingRuleCommand) -> anyhow::Result<RoundingPolicy> {
+        match (command.project_id, command.rule) {
+            (None, rule) => {
+                self.rounding_repository
+                    .save_rule(None, rule.unwrap_or_default())
+                    .await?;
+            }
+            (Some(project_id), rule) => {
+                if !self.project_repository.exists(project_id).await? {
+                    return Err(anyhow::anyhow!("Project not found"));
+                }
+                match rule {
+                    Some(rule) => self.rounding_repository.save_rule(Some(project_id), rule).await?,
+                    None => self.rounding_repository.delete_project_rule(project_id).await?,
+                }
+            }
+        }
+
+        self.rounding_repository.find_policy().await
+    }
+
+    async fn get_task_rounded_duration(&self, task_id: TaskId) -> anyhow::Result<i64> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+        let rule = self.rounding_repository.find_policy().await?.rule_for(task.project_id());
+        let entries = self.time_entry_repository.find_entries_by_task(task_id).await?;
+
+        Ok(RoundingPolicy::round_entries(rule, &entries, &self.calendar, self.clock.now()))
+    }
+
+    async fn get_project_rounded_duration(&self, project_id: ProjectId) -> anyhow::Result<i64> {
+        let rule = self.rounding_repository.find_policy().await?.rule_for(project_id);
+        let entries = self
+            .time_entry_repository
+            .find_entries_by_project(i64::from(project_id))
+            .await?;
+
+        Ok(RoundingPolicy::round_entries(rule, &entries, &self.calendar, self.clock.now()))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::time_entry::TimeEntryEvent;
+    use crate::domain::entities::{Project, Task};
+    use crate::domain::repositories::rounding_tests::InMemoryRoundingRepository;
+    use crate::domain::repositories::task_tests::InMemoryTaskRepository;
+    use crate::domain::repositories::tests::InMemoryProjectRepository;
+    use crate::domain::repositories::time_entry_tests::InMemoryTimeEntryRepository;
+    use crate::domain::services::clock::tests::FixedClock;
+    use crate::domain::value_objects::{RoundingMode, RoundingScope};
+    use chrono::{DateTime, TimeZone, Utc};
+
+    type TestUseCases = RoundingUseCasesImpl<
+        InMemoryRoundingRepository,
+        InMemoryTimeEntryRepository,
+        InMemoryTaskRepository,
+        InMemoryProjectRepository,
+    >;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 10, hour, minute, 0).unwrap()
+    }
+
+    async fn setup() -> TestUseCases {
+        let project_repo = InMemoryProjectRepository::new();
+        let task_repo = InMemoryTaskRepository::new();
+        let time_entry_repo = InMemoryTimeEntryRepository::new();
+        let project_id = ProjectId::new(1).unwrap();
+        let task_id = TaskId::new(1).unwrap();
+        project_repo
+            .save(&Project::new(project_id, "Client".to_string()).unwrap())
+            .await
+            .unwrap();
+        task_repo
+            .save(&Task::new(task_id, project_id, "Design".to_string()).unwrap())
+            .await
+            .unwrap();
+
+        // 10分のエントリを2件
+        for (start, end) in [(at(9, 0), at(9, 10)), (at(10, 0), at(10, 10))] {
+            let start_event = time_entry_repo
+                .save_event(&TimeEntryEvent::start_at(task_id, start))
+                .await
+                .unwrap();
+            time_entry_repo
+                .save_event(&TimeEntryEvent::stop_at(task_id, start_event.id().unwrap(), end))
+                .await
+                .unwrap();
+        }
+
+        RoundingUseCasesImpl::new(
+            InMemoryRoundingRepository::new(),
+            time_entry_repo,
+            task_repo,
+            project_repo,
+            Arc::new(FixedClock::new(at(18, 0))),
+            LocalCalendar::utc(),
+        )
+    }
+
+    #[tokio::test]
+    async fn 既定とプロジェクトの規則で丸めた合計が求まること() {
+        let use_cases = setup().await;
+        let task_id = TaskId::new(1).unwrap();
+        let project_id = ProjectId::new(1).unwrap();
+
+        // 設定がなければ丸めない
+        assert_eq!(use_cases.get_task_rounded_duration(task_id).await.unwrap(), 20 * 60);
+
+        use_cases
+            .set_rounding_rule(SetRoundingRuleCommand {
+                project_id: None,
+                rule: Some(RoundingRule::new(RoundingMode::Up, 15 * 60, RoundingScope::Entry).unwrap()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(use_cases.get_task_rounded_duration(task_id).await.unwrap(), 30 * 60);
+
+        let policy = use_cases
+            .set_rounding_rule(SetRoundingRuleCommand {
+                project_id: Some(project_id),
+                rule: Some(RoundingRule::new(RoundingMode::Up, 6 * 60, RoundingScope::Day).unwrap()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(policy.project_rules.len(), 1);
+        // 日単位では1日の合計（20分）を丸める
+        assert_eq!(use_cases.get_task_rounded_duration(task_id).await.unwrap(), 24 * 60);
+
+        // 上書きを解除すると既定に戻る
+        use_cases
+            .set_rounding_rule(SetRoundingRuleCommand { project_id: Some(project_id), rule: None })
+            .await
+            .unwrap();
+        assert_eq!(use_cases.get_task_rounded_duration(task_id).await.unwrap(), 30 * 60);
+    }
+
+    #[tokio::test]
+    async fn 存在しないプロジェクトには規則を設定できないこと() {
+        let use_cases = setup().await;
+
+        let result = use_cases
+            .set_rounding_rule(SetRoundingRuleCommand {
+                project_id: Some(ProjectId::new(99).unwrap()),
+                rule: Some(RoundingRule::none()),
+            })
+            .await;
+        assert!(result.is_err());
+        assert!(use_cases.get_task_rounded_duration(TaskId::new(99).unwrap()).await.is_err());
+    }
+}
@@ -0,0 +1,261 @@
+use crate::application::services::EventBus;
+use crate::domain::entities::{Tag, TaskTagEvent};
+use crate::domain::events::DomainEvent;
+use crate::domain::repositories::{TagRepository, TaskRepository};
+use crate::domain::value_objects::{TagId, TaskId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+/// タスクへのタグ付与コマンド
+#[derive(Debug, Clone)]
+pub struct AddTagToTaskCommand {
+    pub task_id: TaskId,
+    pub tag_name: String,
+    /// 付与日時（未指定の場合は現在時刻、過去のエントリにタグを付けるときに遡って指定する）
+    pub at: Option<DateTime<Utc>>,
+}
+
+/// タスクからのタグ剥奪コマンド
+#[derive(Debug, Clone)]
+pub struct RemoveTagFromTaskCommand {
+    pub task_id: TaskId,
+    pub tag_id: TagId,
+}
+
+/// タグユースケーストレイト
+#[async_trait]
+pub trait TagUseCases: Send + Sync {
+    /// タスクにタグを付与する（タグが存在しない場合は作成）
+    async fn add_tag_to_task(&self, command: AddTagToTaskCommand) -> anyhow::Result<Tag>;
+
+    /// タスクからタグを剥奪する
+    async fn remove_tag_from_task(&self, command: RemoveTagFromTaskCommand) -> anyhow::Result<()>;
+
+    /// タスクに現在付与されているタグを取得する
+    async fn get_task_tags(&self, task_id: TaskId) -> anyhow::Result<Vec<Tag>>;
+
+    /// 全てのタグを取得する
+    async fn list_tags(&self) -> anyhow::Result<Vec<Tag>>;
+
+    /// タグを取得する
+    async fn get_tag(&self, id: TagId) -> anyhow::Result<Option<Tag>>;
+}
+
+/// タグユースケース実装
+pub struct TagUseCasesImpl<G: TagRepository, T: TaskRepository> {
+    tag_repository: G,
+    task_repository: T,
+    event_bus: Arc<EventBus>,
+}
+
+impl<G: TagRepository, T: TaskRepository> TagUseCasesImpl<G, T> {
+    pub fn new(tag_repository: G, task_repository: T) -> Self {
+        Self {
+            tag_repository,
+            task_repository,
+            event_bus: Arc::new(EventBus::new()),
+        }
+    }
+
+    /// ドメインイベントの発行先を設定
+    pub fn with_event_bus(mut self, event_bus: Arc<EventBus>) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+}
+
+#[async_trait]
+impl<G: TagRepository, T: TaskRepository> TagUseCases for TagUseCasesImpl<G, T> {
+    async fn add_tag_to_task(&self, command: AddTagToTaskCommand) -> anyhow::Result<Tag> {
+        // タスクの存在確認
+        let task = self.task_repository.find_by_id(command.task_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+
+        // アーカイブ済みタスクにはタグを付与できない
+        if task.is_archived() {
+            return Err(anyhow::anyhow!("Cannot add tag to archived task"));
+        }
+
+        // タグを名前で検索し、存在しなければ作成
+        let tag_name = Tag::normalize_name(&command.tag_name);
+        let tag = match self.tag_repository.find_by_name(&tag_name).await? {
+            Some(tag) => tag,
+            None => {
+                let id = self.tag_repository.next_id().await?;
+                let tag = Tag::new(id, tag_name)?;
+                self.tag_repository.save(&tag).await?;
+                tag
+            }
+        };
+
+        // 付与日時の時点で既に付与済みの場合はイベントを追加しない
+        let attached_tags = match command.at {
+            Some(at) => self.tag_repository.find_by_task_at(command.task_id, at).await?,
+            None => self.tag_repository.find_by_task(command.task_id).await?,
+        };
+        if attached_tags.iter().any(|t| t.id() == tag.id()) {
+            return Ok(tag);
+        }
+
+        let event = match command.at {
+            Some(at) => TaskTagEvent::add_at(command.task_id, tag.id(), at),
+            None => TaskTagEvent::add(command.task_id, tag.id()),
+        };
+        self.tag_repository.save_task_tag_event(&event).await?;
+        self.event_bus.publish(DomainEvent::TagAddedToTask { task_id: command.task_id, tag_id: tag.id() });
+
+        Ok(tag)
+    }
+
+    async fn remove_tag_from_task(&self, command: RemoveTagFromTaskCommand) -> anyhow::Result<()> {
+        // タスクの存在確認
+        let _task = self.task_repository.find_by_id(command.task_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+
+        // 付与されていないタグは剥奪できない
+        let current_tags = self.tag_repository.find_by_task(command.task_id).await?;
+        if !current_tags.iter().any(|t| t.id() == command.tag_id) {
+            return Err(anyhow::anyhow!("Tag is not assigned to task"));
+        }
+
+        let event = TaskTagEvent::remove(command.task_id, command.tag_id);
+        self.tag_repository.save_task_tag_event(&event).await?;
+        self.event_bus.publish(DomainEvent::TagRemovedFromTask { task_id: command.task_id, tag_id: command.tag_id });
+
+        Ok(())
+    }
+
+    async fn get_task_tags(&self, task_id: TaskId) -> anyhow::Result<Vec<Tag>> {
+        // タスクの存在確認
+        let _task = self.task_repository.find_by_id(task_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+
+        self.tag_repository.find_by_task(task_id).await
+    }
+
+    async fn list_tags(&self) -> anyhow::Result<Vec<Tag>> {
+        self.tag_repository.find_all().await
+    }
+
+    async fn get_tag(&self, id: TagId) -> anyhow::Result<Option<Tag>> {
+        self.tag_repository.find_by_id(id).await
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::Task;
+    use crate::domain::repositories::{tag_tests::InMemoryTagRepository, task_tests::InMemoryTaskRepository};
+    use crate::domain::value_objects::ProjectId;
+
+    async fn setup_use_cases() -> (TagUseCasesImpl<InMemoryTagRepository, InMemoryTaskRepository>, TaskId) {
+        let tag_repo = InMemoryTagRepository::new();
+        let task_repo = InMemoryTaskRepository::new();
+
+        // テスト用タスクを作成
+        let task_id = TaskId::new(1).unwrap();
+        let task = Task::new(task_id, ProjectId::new(1).unwrap(), "Test Task".to_string()).unwrap();
+        task_repo.save(&task).await.unwrap();
+
+        (TagUseCasesImpl::new(tag_repo, task_repo), task_id)
+    }
+
+    #[tokio::test]
+    async fn タスクへのタグ付与が成功すること() {
+        let (use_cases, task_id) = setup_use_cases().await;
+
+        let tag = use_cases.add_tag_to_task(AddTagToTaskCommand {
+            task_id,
+            tag_name: "#meeting".to_string(),
+            at: None,
+        }).await.unwrap();
+
+        assert_eq!(tag.name(), "meeting");
+
+        let tags = use_cases.get_task_tags(task_id).await.unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].id(), tag.id());
+    }
+
+    #[tokio::test]
+    async fn 同じタグの再付与で重複しないこと() {
+        let (use_cases, task_id) = setup_use_cases().await;
+        let command = AddTagToTaskCommand {
+            task_id,
+            tag_name: "meeting".to_string(),
+            at: None,
+        };
+
+        let first = use_cases.add_tag_to_task(command.clone()).await.unwrap();
+        let second = use_cases.add_tag_to_task(command).await.unwrap();
+
+        assert_eq!(first.id(), second.id());
+        assert_eq!(use_cases.list_tags().await.unwrap().len(), 1);
+        assert_eq!(use_cases.get_task_tags(task_id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn タスクからのタグ剥奪が成功すること() {
+        let (use_cases, task_id) = setup_use_cases().await;
+        let tag = use_cases.add_tag_to_task(AddTagToTaskCommand {
+            task_id,
+            tag_name: "client-a".to_string(),
+            at: None,
+        }).await.unwrap();
+
+        use_cases.remove_tag_from_task(RemoveTagFromTaskCommand {
+            task_id,
+            tag_id: tag.id(),
+        }).await.unwrap();
+
+        assert!(use_cases.get_task_tags(task_id).await.unwrap().is_empty());
+        // タグマスタ自体は残る
+        assert_eq!(use_cases.list_tags().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn 付与されていないタグの剥奪が失敗すること() {
+        let (use_cases, task_id) = setup_use_cases().await;
+
+        let result = use_cases.remove_tag_from_task(RemoveTagFromTaskCommand {
+            task_id,
+            tag_id: TagId::new(99).unwrap(),
+        }).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Tag is not assigned to task"));
+    }
+
+    #[tokio::test]
+    async fn 存在しないタスクへのタグ付与が失敗すること() {
+        let (use_cases, _) = setup_use_cases().await;
+
+        let result = use_cases.add_tag_to_task(AddTagToTaskCommand {
+            task_id: TaskId::new(999).unwrap(),
+            tag_name: "meeting".to_string(),
+            at: None,
+        }).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Task not found"));
+    }
+
+    #[tokio::test]
+    async fn アーカイブタスクへのタグ付与が失敗すること() {
+        let (use_cases, task_id) = setup_use_cases().await;
+        let task = use_cases.task_repository.find_by_id(task_id).await.unwrap().unwrap();
+        use_cases.task_repository.save(&task.archive()).await.unwrap();
+
+        let result = use_cases.add_tag_to_task(AddTagToTaskCommand {
+            task_id,
+            tag_name: "meeting".to_string(),
+            at: None,
+        }).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Cannot add tag to archived task"));
+    }
+}
@@ -1,7 +1,10 @@
+use crate::application::services::EventBus;
 use crate::domain::entities::Task;
+use crate::domain::events::DomainEvent;
 use crate::domain::repositories::{TaskRepository, ProjectRepository};
 use crate::domain::value_objects::{ProjectId, TaskId};
 use async_trait::async_trait;
+use std::sync::Arc;
 
 /// タスク作成コマンド
 #[derive(Debug, Clone)]
@@ -68,6 +71,7 @@ pub trait TaskUseCases: Send + Sync {
 pub struct TaskUseCasesImpl<T: TaskRepository, P: ProjectRepository> {
     task_repository: T,
     project_repository: P,
+    event_bus: Arc<EventBus>,
 }
 
 impl<T: TaskRepository, P: ProjectRepository> TaskUseCasesImpl<T, P> {
@@ -75,8 +79,15 @@ impl<T: TaskRepository, P: ProjectRepository> TaskUseCasesImpl<T, P> {
         Self {
             task_repository,
             project_repository,
+            event_bus: Arc::new(EventBus::new()),
         }
     }
+
+    /// ドメインイベントの発行先を設定
+    pub fn with_event_bus(mut self, event_bus: Arc<EventBus>) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
 }
 
 #[async_trait]
@@ -99,6 +110,7 @@ impl<T: TaskRepository, P: ProjectRepository> TaskUseCases for TaskUseCasesImpl<
         
         // 保存
         self.task_repository.save(&task).await?;
+        self.event_bus.publish(DomainEvent::TaskCreated { task_id: id, project_id: command.project_id });
         
         Ok(task)
     }
@@ -113,6 +125,9 @@ impl<T: TaskRepository, P: ProjectRepository> TaskUseCases for TaskUseCasesImpl<
             return Err(anyhow::anyhow!("Cannot update archived task"));
         }
 
+        let from_project_id = existing_task.project_id();
+        let renamed = command.name.is_some();
+
         // 名前を更新
         if let Some(name) = command.name {
             existing_task = existing_task.change_name(name)?;
@@ -134,6 +149,17 @@ impl<T: TaskRepository, P: ProjectRepository> TaskUseCases for TaskUseCasesImpl<
 
         // 保存
         self.task_repository.save(&existing_task).await?;
+
+        if renamed {
+            self.event_bus.publish(DomainEvent::TaskRenamed { task_id: command.id });
+        }
+        if existing_task.project_id() != from_project_id {
+            self.event_bus.publish(DomainEvent::TaskMoved {
+                task_id: command.id,
+                from_project_id,
+                to_project_id: existing_task.project_id(),
+            });
+        }
         
         Ok(existing_task)
     }
@@ -151,6 +177,7 @@ impl<T: TaskRepository, P: ProjectRepository> TaskUseCases for TaskUseCasesImpl<
         // タスクをアーカイブ
         let archived_task = existing_task.archive();
         self.task_repository.save(&archived_task).await?;
+        self.event_bus.publish(DomainEvent::TaskArchived { task_id: command.id });
 
         Ok(())
     }
@@ -173,6 +200,7 @@ impl<T: TaskRepository, P: ProjectRepository> TaskUseCases for TaskUseCasesImpl<
         
         // 保存
         self.task_repository.save(&restored_task).await?;
+        self.event_bus.publish(DomainEvent::TaskRestored { task_id: command.id });
         
         Ok(restored_task)
     }
@@ -327,6 +355,31 @@ mod tests {
         assert_eq!(moved_task.project_id(), new_project_id);
     }
 
+    #[tokio::test]
+    async fn タスクのプロジェクト移動でイベントが発行されること() {
+        let (use_cases, project_id) = setup_use_cases().await;
+        let event_bus = Arc::new(EventBus::new());
+        let published = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let received = published.clone();
+        event_bus.subscribe(Arc::new(move |event: &DomainEvent| received.lock().unwrap().push(event.clone())));
+        let use_cases = use_cases.with_event_bus(event_bus);
+
+        let new_project_id = ProjectId::new(2).unwrap();
+        let new_project = Project::new(new_project_id, "New Project".to_string()).unwrap();
+        use_cases.project_repository.save(&new_project).await.unwrap();
+        let task = use_cases.create_task(CreateTaskCommand {
+            project_id,
+            name: "Test Task".to_string(),
+        }).await.unwrap();
+
+        use_cases.move_task_to_project(task.id(), new_project_id).await.unwrap();
+
+        assert_eq!(*published.lock().unwrap(), vec![
+            DomainEvent::TaskCreated { task_id: task.id(), project_id },
+            DomainEvent::TaskMoved { task_id: task.id(), from_project_id: project_id, to_project_id: new_project_id },
+        ]);
+    }
+
     #[tokio::test]
     async fn タスクアーカイブが成功すること() {
         let (use_cases, project_id) = setup_use_cases().await;
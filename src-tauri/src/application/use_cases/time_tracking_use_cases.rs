@@ -1,20 +1,43 @@
+use crate::application::services::EventBus;
 use crate::domain::entities::time_entry::{TimeEntry, TimeEntryEvent};
-use crate::domain::repositories::{TaskRepository, TimeEntryRepository};
-use crate::domain::services::TimeTrackingService;
-use crate::domain::value_objects::TaskId;
+use crate::domain::events::DomainEvent;
+use crate::domain::repositories::{TaskRepository, TimeEntryCursor, TimeEntryQuery, TimeEntryRepository};
+use crate::domain::services::{AutoCutoffPolicy, TimeTrackingService};
+use crate::domain::value_objects::{TagId, TaskId};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use std::sync::Arc;
 
 /// タイマー開始コマンド
 #[derive(Debug, Clone)]
 pub struct StartTimerCommand {
     pub task_id: TaskId,
+    /// 開始時刻（未指定の場合は現在時刻、過去に遡って開始できる）
+    pub at: Option<DateTime<Utc>>,
 }
 
 /// タイマー停止コマンド
 #[derive(Debug, Clone)]
 pub struct StopTimerCommand {
     pub task_id: TaskId,
+    /// 停止時刻（未指定の場合は現在時刻）
+    pub at: Option<DateTime<Utc>>,
+}
+
+/// タイマー一時停止コマンド
+#[derive(Debug, Clone)]
+pub struct PauseTimerCommand {
+    pub task_id: TaskId,
+    /// 一時停止時刻（未指定の場合は現在時刻）
+    pub at: Option<DateTime<Utc>>,
+}
+
+/// タイマー再開コマンド
+#[derive(Debug, Clone)]
+pub struct ResumeTimerCommand {
+    pub task_id: TaskId,
+    /// 再開時刻（未指定の場合は現在時刻）
+    pub at: Option<DateTime<Utc>>,
 }
 
 /// 手動時間エントリ追加コマンド
@@ -26,6 +49,39 @@ pub struct AddManualEntryCommand {
     pub note: Option<String>,
 }
 
+/// 時間エントリ訂正コマンド（指定した側のみ変更）
+#[derive(Debug, Clone)]
+pub struct UpdateTimeEntryCommand {
+    pub start_event_id: i64,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+/// 時間エントリへのメモ追記コマンド
+#[derive(Debug, Clone)]
+pub struct AnnotateTimeEntryCommand {
+    pub start_event_id: i64,
+    pub note: String,
+}
+
+/// 期間指定の時間エントリ取得コマンド
+#[derive(Debug, Clone)]
+pub struct GetTimeEntriesCommand {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub project_id: Option<i64>,
+    pub task_id: Option<TaskId>,
+    pub cursor: Option<TimeEntryCursor>,
+    pub limit: Option<usize>,
+}
+
+/// 時間エントリのページ
+#[derive(Debug, Clone)]
+pub struct TimeEntryPage {
+    pub entries: Vec<TimeEntry>,
+    pub next_cursor: Option<TimeEntryCursor>,
+}
+
 /// タイムトラッキングユースケーストレイト
 #[async_trait]
 pub trait TimeTrackingUseCases: Send + Sync {
@@ -35,15 +91,39 @@ pub trait TimeTrackingUseCases: Send + Sync {
     /// タイマーを停止する
     async fn stop_timer(&self, command: StopTimerCommand) -> anyhow::Result<Option<TimeEntryEvent>>;
 
-    /// 現在実行中のタスクを取得
+    /// タイマーを一時停止する（同じ時間エントリのまま計測を止める）
+    async fn pause_timer(&self, command: PauseTimerCommand) -> anyhow::Result<TimeEntryEvent>;
+
+    /// 一時停止中のタイマーを再開する
+    async fn resume_timer(&self, command: ResumeTimerCommand) -> anyhow::Result<TimeEntryEvent>;
+
+    /// 現在実行中のタスクを取得（複数実行中の場合は最後に開始したタスク）
     async fn get_current_timer(&self) -> anyhow::Result<Option<TaskId>>;
 
+    /// 現在実行中のタスクを全て取得（同時実行モードでは複数件）
+    async fn get_current_timers(&self) -> anyhow::Result<Vec<TaskId>>;
+
+    /// 実行中の時間エントリ一覧を取得
+    async fn get_running_entries(&self) -> anyhow::Result<Vec<TimeEntry>>;
+
     /// 指定タスクのタイマー状態を取得
     async fn get_timer_status(&self, task_id: TaskId) -> anyhow::Result<TimerStatus>;
 
     /// 手動で時間エントリを追加
     async fn add_manual_entry(&self, command: AddManualEntryCommand) -> anyhow::Result<()>;
 
+    /// 既存の時間エントリの開始・終了を訂正
+    async fn update_time_entry(&self, command: UpdateTimeEntryCommand) -> anyhow::Result<TimeEntry>;
+
+    /// 時間エントリを削除（無効化イベントを追加し集計から除外）
+    async fn delete_time_entry(&self, start_event_id: i64) -> anyhow::Result<TimeEntryEvent>;
+
+    /// 削除した時間エントリを復元（無効化を取り消し）
+    async fn restore_time_entry(&self, start_event_id: i64) -> anyhow::Result<TimeEntry>;
+
+    /// 時間エントリにメモを追記する（実行中・終了済みのどちらも可）
+    async fn annotate_time_entry(&self, command: AnnotateTimeEntryCommand) -> anyhow::Result<TimeEntry>;
+
     /// 指定タスクの時間エントリ一覧を取得
     async fn get_task_entries(&self, task_id: TaskId) -> anyhow::Result<Vec<TimeEntry>>;
 
@@ -56,14 +136,38 @@ pub trait TimeTrackingUseCases: Send + Sync {
     /// 最近の時間エントリを取得
     async fn get_recent_entries(&self, limit: usize) -> anyhow::Result<Vec<TimeEntry>>;
 
+    /// 期間・プロジェクト・タスクを指定して時間エントリを取得（カーソルページング）
+    async fn get_entries_by_period(&self, command: GetTimeEntriesCommand) -> anyhow::Result<TimeEntryPage>;
+
     /// 指定タスクの合計作業時間を取得
     async fn get_task_total_duration(&self, task_id: TaskId) -> anyhow::Result<i64>;
 
     /// 指定プロジェクトの合計作業時間を取得
     async fn get_project_total_duration(&self, project_id: i64) -> anyhow::Result<i64>;
 
+    /// 指定タグの時間エントリを取得
+    async fn get_tag_entries(&self, tag_id: TagId) -> anyhow::Result<Vec<TimeEntry>>;
+
+    /// 指定期間・指定タグの時間エントリを取得
+    async fn get_tag_entries_by_period(
+        &self,
+        tag_id: TagId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimeEntry>>;
+
+    /// 指定タグの合計作業時間を取得
+    async fn get_tag_total_duration(&self, tag_id: TagId) -> anyhow::Result<i64>;
+
     /// 全ての実行中タイマーを停止
     async fn stop_all_timers(&self) -> anyhow::Result<Vec<TimeEntryEvent>>;
+
+    /// 打ち切り時刻を過ぎた実行中タイマーを自動停止
+    async fn apply_auto_cutoff(
+        &self,
+        policy: &AutoCutoffPolicy,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimeEntryEvent>>;
 }
 
 /// タイマーの状態情報
@@ -79,6 +183,7 @@ pub struct TimeTrackingUseCasesImpl<T: TimeEntryRepository, K: TaskRepository, S
     time_entry_repository: T,
     task_repository: K,
     time_tracking_service: S,
+    event_bus: Arc<EventBus>,
 }
 
 impl<T: TimeEntryRepository, K: TaskRepository, S: TimeTrackingService> 
@@ -89,6 +194,33 @@ impl<T: TimeEntryRepository, K: TaskRepository, S: TimeTrackingService>
             time_entry_repository,
             task_repository,
             time_tracking_service,
+            event_bus: Arc::new(EventBus::new()),
+        }
+    }
+
+    /// ドメインイベントの発行先を設定
+    pub fn with_event_bus(mut self, event_bus: Arc<EventBus>) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+
+    /// 停止した時間エントリを発行する（まだ実行中の場合は発行しない。停止自体は保存済みなので取得に失敗しても処理は継続する）
+    async fn publish_stopped(&self, start_event_id: i64) {
+        match self.time_entry_repository.find_entry_by_start_event_id(start_event_id).await {
+            Ok(Some(entry)) if entry.is_completed() => {
+                self.event_bus.publish(DomainEvent::TimerStopped(entry));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!(start_event_id, error = %e, "Failed to load stopped time entry for domain event");
+            }
+        }
+    }
+
+    /// 停止イベントごとに停止した時間エントリを発行する
+    async fn publish_stop_events(&self, events: &[TimeEntryEvent]) {
+        for start_event_id in events.iter().filter_map(|e| e.start_event_id()) {
+            self.publish_stopped(start_event_id).await;
         }
     }
 }
@@ -107,8 +239,18 @@ impl<T: TimeEntryRepository, K: TaskRepository, S: TimeTrackingService>
             return Err(anyhow::anyhow!("Cannot start timer for archived task"));
         }
 
+        // 開始に伴って停止される区間を発行するため、開始前に実行中の区間を控えておく
+        let running_before = self.time_entry_repository.find_running_entries().await?;
+
         // タイマーを開始
-        let event = self.time_tracking_service.start_timer(command.task_id).await?;
+        let event = self.time_tracking_service.start_timer(command.task_id, command.at).await?;
+
+        for entry in running_before {
+            self.publish_stopped(entry.start_event_id()).await;
+        }
+        if let Some(entry) = self.time_entry_repository.find_running_entry_by_task(command.task_id).await? {
+            self.event_bus.publish(DomainEvent::TimerStarted(entry));
+        }
         
         Ok(event)
     }
@@ -119,13 +261,52 @@ impl<T: TimeEntryRepository, K: TaskRepository, S: TimeTrackingService>
             .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
 
         // タイマーを停止
-        let event = self.time_tracking_service.stop_timer(command.task_id).await?;
+        let event = self.time_tracking_service.stop_timer(command.task_id, command.at).await?;
+        if let Some(start_event_id) = event.as_ref().and_then(|e| e.start_event_id()) {
+            self.publish_stopped(start_event_id).await;
+        }
         
         Ok(event)
     }
 
+    async fn pause_timer(&self, command: PauseTimerCommand) -> anyhow::Result<TimeEntryEvent> {
+        // タスクの存在確認
+        let _task = self.task_repository.find_by_id(command.task_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+
+        let event = self.time_tracking_service.pause_timer(command.task_id, command.at).await?;
+        self.event_bus.publish(DomainEvent::TimerPaused { task_id: command.task_id });
+
+        Ok(event)
+    }
+
+    async fn resume_timer(&self, command: ResumeTimerCommand) -> anyhow::Result<TimeEntryEvent> {
+        // タスクの存在確認
+        let task = self.task_repository.find_by_id(command.task_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+
+        // アーカイブ済みタスクではタイマーを再開できない
+        if task.is_archived() {
+            return Err(anyhow::anyhow!("Cannot resume timer for archived task"));
+        }
+
+        let event = self.time_tracking_service.resume_timer(command.task_id, command.at).await?;
+        self.event_bus.publish(DomainEvent::TimerResumed { task_id: command.task_id });
+
+        Ok(event)
+    }
+
     async fn get_current_timer(&self) -> anyhow::Result<Option<TaskId>> {
-        self.time_tracking_service.get_running_task().await
+        let running_tasks = self.time_tracking_service.get_running_tasks().await?;
+        Ok(running_tasks.first().copied())
+    }
+
+    async fn get_current_timers(&self) -> anyhow::Result<Vec<TaskId>> {
+        self.time_tracking_service.get_running_tasks().await
+    }
+
+    async fn get_running_entries(&self) -> anyhow::Result<Vec<TimeEntry>> {
+        self.time_entry_repository.find_running_entries().await
     }
 
     async fn get_timer_status(&self, task_id: TaskId) -> anyhow::Result<TimerStatus> {
@@ -169,10 +350,111 @@ impl<T: TimeEntryRepository, K: TaskRepository, S: TimeTrackingService>
             command.end_time,
             command.note,
         ).await?;
+        self.event_bus.publish(DomainEvent::EntryAdded { task_id: command.task_id });
 
         Ok(())
     }
 
+    async fn update_time_entry(&self, command: UpdateTimeEntryCommand) -> anyhow::Result<TimeEntry> {
+        // 時間エントリの存在確認
+        let entry = self.time_entry_repository
+            .find_entry_by_start_event_id(command.start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry not found"))?;
+
+        // アーカイブ済みタスクの時間エントリは訂正できない
+        let task = self.task_repository.find_by_id(entry.task_id()).await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+        if task.is_archived() {
+            return Err(anyhow::anyhow!("Cannot update entry for archived task"));
+        }
+
+        // 訂正イベントを追加
+        self.time_tracking_service.adjust_entry(
+            command.start_event_id,
+            command.start_time,
+            command.end_time,
+        ).await?;
+
+        let corrected = self.time_entry_repository
+            .find_entry_by_start_event_id(command.start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry not found"))?;
+        self.event_bus.publish(DomainEvent::EntryCorrected(corrected.clone()));
+
+        Ok(corrected)
+    }
+
+    async fn delete_time_entry(&self, start_event_id: i64) -> anyhow::Result<TimeEntryEvent> {
+        // 時間エントリの存在確認
+        let entry = self.time_entry_repository
+            .find_entry_by_start_event_id(start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry not found"))?;
+
+        // アーカイブ済みタスクの時間エントリは削除できない
+        let task = self.task_repository.find_by_id(entry.task_id()).await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+        if task.is_archived() {
+            return Err(anyhow::anyhow!("Cannot delete entry for archived task"));
+        }
+
+        let event = self.time_tracking_service.void_entry(start_event_id).await?;
+        self.event_bus.publish(DomainEvent::EntryDeleted { task_id: entry.task_id(), start_event_id });
+
+        Ok(event)
+    }
+
+    async fn restore_time_entry(&self, start_event_id: i64) -> anyhow::Result<TimeEntry> {
+        // 無効化された時間エントリの存在確認
+        let entry = self.time_entry_repository
+            .find_voided_entry_by_start_event_id(start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry is not voided"))?;
+
+        // アーカイブ済みタスクの時間エントリは復元できない
+        let task = self.task_repository.find_by_id(entry.task_id()).await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+        if task.is_archived() {
+            return Err(anyhow::anyhow!("Cannot restore entry for archived task"));
+        }
+
+        self.time_tracking_service.unvoid_entry(start_event_id).await?;
+
+        let restored = self.time_entry_repository
+            .find_entry_by_start_event_id(start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry not found"))?;
+        self.event_bus.publish(DomainEvent::EntryRestored(restored.clone()));
+
+        Ok(restored)
+    }
+
+    async fn annotate_time_entry(&self, command: AnnotateTimeEntryCommand) -> anyhow::Result<TimeEntry> {
+        // 時間エントリの存在確認
+        let entry = self.time_entry_repository
+            .find_entry_by_start_event_id(command.start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry not found"))?;
+
+        // アーカイブ済みタスクの時間エントリにはメモを追記できない
+        let task = self.task_repository.find_by_id(entry.task_id()).await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+        if task.is_archived() {
+            return Err(anyhow::anyhow!("Cannot annotate entry for archived task"));
+        }
+
+        self.time_tracking_service.annotate_entry(command.start_event_id, command.note).await?;
+
+        let annotated = self.time_entry_repository
+            .find_entry_by_start_event_id(command.start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry not found"))?;
+        self.event_bus.publish(DomainEvent::EntryAnnotated(annotated.clone()));
+
+        Ok(annotated)
+    }
+
     async fn get_task_entries(&self, task_id: TaskId) -> anyhow::Result<Vec<TimeEntry>> {
         // タスクの存在確認
         let _task = self.task_repository.find_by_id(task_id).await?
@@ -239,6 +521,36 @@ impl<T: TimeEntryRepository, K: TaskRepository, S: TimeTrackingService>
         }
     }
 
+    async fn get_entries_by_period(&self, command: GetTimeEntriesCommand) -> anyhow::Result<TimeEntryPage> {
+        if command.start >= command.end {
+            return Err(anyhow::anyhow!("Start time must be before end time"));
+        }
+        if command.limit == Some(0) {
+            return Err(anyhow::anyhow!("Limit must be positive"));
+        }
+
+        // 次ページの有無を判定するため1件多く取得する
+        let query = TimeEntryQuery {
+            start: command.start,
+            end: command.end,
+            project_id: command.project_id,
+            task_id: command.task_id,
+            after: command.cursor,
+            limit: command.limit.map(|limit| limit + 1),
+        };
+        let mut entries = self.time_entry_repository.find_entries_by_query(&query).await?;
+
+        let next_cursor = match command.limit {
+            Some(limit) if entries.len() > limit => {
+                entries.truncate(limit);
+                entries.last().map(TimeEntryCursor::from_entry)
+            }
+            _ => None,
+        };
+
+        Ok(TimeEntryPage { entries, next_cursor })
+    }
+
     async fn get_task_total_duration(&self, task_id: TaskId) -> anyhow::Result<i64> {
         // タスクの存在確認
         let _task = self.task_repository.find_by_id(task_id).await?
@@ -253,8 +565,52 @@ impl<T: TimeEntryRepository, K: TaskRepository, S: TimeTrackingService>
             .await
     }
 
+    async fn get_tag_entries(&self, tag_id: TagId) -> anyhow::Result<Vec<TimeEntry>> {
+        self.time_entry_repository
+            .find_entries_by_tag(tag_id)
+            .await
+    }
+
+    async fn get_tag_entries_by_period(
+        &self,
+        tag_id: TagId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimeEntry>> {
+        if start >= end {
+            return Err(anyhow::anyhow!("Start time must be before end time"));
+        }
+
+        self.time_entry_repository
+            .find_entries_by_period_and_tag(tag_id, start, end)
+            .await
+    }
+
+    async fn get_tag_total_duration(&self, tag_id: TagId) -> anyhow::Result<i64> {
+        self.time_entry_repository
+            .sum_duration_by_tag(tag_id)
+            .await
+    }
+
     async fn stop_all_timers(&self) -> anyhow::Result<Vec<TimeEntryEvent>> {
-        self.time_tracking_service.stop_all_timers().await
+        let events = self.time_tracking_service.stop_all_timers().await?;
+        self.publish_stop_events(&events).await;
+
+        Ok(events)
+    }
+
+    async fn apply_auto_cutoff(
+        &self,
+        policy: &AutoCutoffPolicy,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimeEntryEvent>> {
+        if !policy.is_enabled() {
+            return Ok(Vec::new());
+        }
+        let events = self.time_tracking_service.apply_auto_cutoff(policy, now).await?;
+        self.publish_stop_events(&events).await;
+
+        Ok(events)
     }
 }
 
@@ -262,10 +618,14 @@ impl<T: TimeEntryRepository, K: TaskRepository, S: TimeTrackingService>
 #[allow(non_snake_case)]
 mod tests {
     use super::*;
-    use crate::domain::entities::{Project, Task};
+    use crate::application::services::notifier::tests::RecordingNotifier;
+    use crate::application::services::{AppNotification, NotificationSubscriber};
+    use crate::domain::entities::{Project, Tag, Task, TaskTagEvent};
     use crate::domain::repositories::{time_entry_tests::InMemoryTimeEntryRepository, task_tests::InMemoryTaskRepository};
+    use crate::domain::repositories::{tag_tests::InMemoryTagRepository, TagRepository};
     use crate::domain::services::TimeTrackingServiceImpl;
     use crate::domain::value_objects::ProjectId;
+    use chrono::TimeZone;
 
     async fn setup_use_cases() -> (
         TimeTrackingUseCasesImpl<
@@ -275,8 +635,8 @@ mod tests {
         >,
         TaskId,
     ) {
-        let time_entry_repo = InMemoryTimeEntryRepository::new();
         let task_repo = InMemoryTaskRepository::new();
+        let time_entry_repo = InMemoryTimeEntryRepository::new().with_task_repository(&task_repo);
         let time_tracking_service = TimeTrackingServiceImpl::new(time_entry_repo.clone());
 
         // テスト用プロジェクトとタスクを作成
@@ -301,7 +661,7 @@ mod tests {
     async fn タイマー開始が正しく動作すること() {
         let (use_cases, task_id) = setup_use_cases().await;
 
-        let command = StartTimerCommand { task_id };
+        let command = StartTimerCommand { task_id, at: None };
         let event = use_cases.start_timer(command).await.unwrap();
 
         assert_eq!(event.task_id(), task_id);
@@ -319,11 +679,11 @@ mod tests {
         let (use_cases, task_id) = setup_use_cases().await;
 
         // タイマーを開始
-        let start_command = StartTimerCommand { task_id };
+        let start_command = StartTimerCommand { task_id, at: None };
         use_cases.start_timer(start_command).await.unwrap();
 
         // タイマーを停止
-        let stop_command = StopTimerCommand { task_id };
+        let stop_command = StopTimerCommand { task_id, at: None };
         let stop_event = use_cases.stop_timer(stop_command).await.unwrap();
 
         assert!(stop_event.is_some());
@@ -343,7 +703,7 @@ mod tests {
         let (use_cases, _) = setup_use_cases().await;
         let invalid_task_id = TaskId::new(999).unwrap();
 
-        let command = StartTimerCommand { task_id: invalid_task_id };
+        let command = StartTimerCommand { task_id: invalid_task_id, at: None };
         let result = use_cases.start_timer(command).await;
 
         assert!(result.is_err());
@@ -359,7 +719,7 @@ mod tests {
         let archived_task = task.archive();
         use_cases.task_repository.save(&archived_task).await.unwrap();
 
-        let command = StartTimerCommand { task_id };
+        let command = StartTimerCommand { task_id, at: None };
         let result = use_cases.start_timer(command).await;
 
         assert!(result.is_err());
@@ -396,10 +756,10 @@ mod tests {
         let (use_cases, task_id) = setup_use_cases().await;
 
         // タイマーで1つのエントリを作成
-        let start_command = StartTimerCommand { task_id };
+        let start_command = StartTimerCommand { task_id, at: None };
         use_cases.start_timer(start_command).await.unwrap();
 
-        let stop_command = StopTimerCommand { task_id };
+        let stop_command = StopTimerCommand { task_id, at: None };
         use_cases.stop_timer(stop_command).await.unwrap();
 
         // エントリを取得
@@ -439,7 +799,7 @@ mod tests {
         assert!(current.is_none());
 
         // タイマーを開始
-        let start_command = StartTimerCommand { task_id };
+        let start_command = StartTimerCommand { task_id, at: None };
         use_cases.start_timer(start_command).await.unwrap();
 
         // 現在のタイマーを確認
@@ -447,7 +807,7 @@ mod tests {
         assert_eq!(current, Some(task_id));
 
         // タイマーを停止
-        let stop_command = StopTimerCommand { task_id };
+        let stop_command = StopTimerCommand { task_id, at: None };
         use_cases.stop_timer(stop_command).await.unwrap();
 
         // 再び実行中のタイマーなし
@@ -460,7 +820,7 @@ mod tests {
         let (use_cases, task_id) = setup_use_cases().await;
 
         // タイマーを開始
-        let start_command = StartTimerCommand { task_id };
+        let start_command = StartTimerCommand { task_id, at: None };
         use_cases.start_timer(start_command).await.unwrap();
 
         // 全タイマーを停止
@@ -472,4 +832,239 @@ mod tests {
         let current = use_cases.get_current_timer().await.unwrap();
         assert!(current.is_none());
     }
+    #[tokio::test]
+    async fn タイマーの開始と停止が通知されること() {
+        let (use_cases, task_id) = setup_use_cases().await;
+        let notifier = Arc::new(RecordingNotifier::new());
+        let event_bus = Arc::new(EventBus::new());
+        event_bus.subscribe(Arc::new(NotificationSubscriber::new(notifier.clone())));
+        let use_cases = use_cases.with_event_bus(event_bus);
+
+        use_cases.start_timer(StartTimerCommand { task_id, at: None }).await.unwrap();
+        let notifications = notifier.notifications();
+        assert_eq!(notifications.len(), 2);
+        assert!(matches!(&notifications[0], AppNotification::TimerStarted(entry) if entry.task_id() == task_id && entry.is_running()));
+        assert_eq!(notifications[1], AppNotification::EntriesChanged(task_id));
+
+        // 同じタスクで再開始すると、暗黙に停止した区間も通知される
+        use_cases.start_timer(StartTimerCommand { task_id, at: None }).await.unwrap();
+        let notifications = notifier.notifications();
+        assert_eq!(notifications.len(), 6);
+        assert!(matches!(&notifications[2], AppNotification::TimerStopped(entry) if entry.is_completed()));
+        assert!(matches!(&notifications[4], AppNotification::TimerStarted(_)));
+
+        use_cases.stop_timer(StopTimerCommand { task_id, at: None }).await.unwrap();
+        let notifications = notifier.notifications();
+        assert_eq!(notifications.len(), 8);
+        assert!(matches!(&notifications[6], AppNotification::TimerStopped(entry) if entry.task_id() == task_id));
+        assert_eq!(notifications[7], AppNotification::EntriesChanged(task_id));
+    }
+
+    #[tokio::test]
+    async fn 期間指定の時間エントリ取得がページングされること() {
+        let (use_cases, task_id) = setup_use_cases().await;
+        let base = Utc::now() - chrono::Duration::days(10);
+
+        // 1日ごとに5件のエントリを追加
+        for day in 0..5 {
+            let start_time = base + chrono::Duration::days(day);
+            use_cases.add_manual_entry(AddManualEntryCommand {
+                task_id,
+                start_time,
+                end_time: start_time + chrono::Duration::hours(1),
+                note: None,
+            }).await.unwrap();
+        }
+
+        let mut command = GetTimeEntriesCommand {
+            start: base,
+            end: base + chrono::Duration::days(5),
+            project_id: None,
+            task_id: Some(task_id),
+            cursor: None,
+            limit: Some(2),
+        };
+
+        let mut collected = Vec::new();
+        loop {
+            let page = use_cases.get_entries_by_period(command.clone()).await.unwrap();
+            assert!(page.entries.len() <= 2);
+            collected.extend(page.entries);
+            match page.next_cursor {
+                Some(cursor) => command.cursor = Some(cursor),
+                None => break,
+            }
+        }
+
+        assert_eq!(collected.len(), 5);
+        assert!(collected.windows(2).all(|w| w[0].start_time() > w[1].start_time()));
+    }
+
+    #[tokio::test]
+    async fn 期間指定の時間エントリ取得をプロジェクトで絞り込めること() {
+        let (use_cases, task_id) = setup_use_cases().await;
+        let other_task_id = TaskId::new(2).unwrap();
+        use_cases
+            .task_repository
+            .save(&Task::new(other_task_id, ProjectId::new(2).unwrap(), "Other Task".to_string()).unwrap())
+            .await
+            .unwrap();
+        let base = Utc::now() - chrono::Duration::days(1);
+        for (index, task_id) in [task_id, other_task_id].into_iter().enumerate() {
+            let start_time = base + chrono::Duration::hours(index as i64 * 2);
+            use_cases.add_manual_entry(AddManualEntryCommand {
+                task_id,
+                start_time,
+                end_time: start_time + chrono::Duration::hours(1),
+                note: None,
+            }).await.unwrap();
+        }
+
+        let page = use_cases.get_entries_by_period(GetTimeEntriesCommand {
+            start: base,
+            end: base + chrono::Duration::days(1),
+            project_id: Some(2),
+            task_id: None,
+            cursor: None,
+            limit: None,
+        }).await.unwrap();
+
+        assert_eq!(page.entries.len(), 1);
+        assert_eq!(page.entries[0].task_id(), other_task_id);
+    }
+
+    #[tokio::test]
+    async fn 不正な期間の時間エントリ取得が失敗すること() {
+        let (use_cases, _) = setup_use_cases().await;
+        let now = Utc::now();
+
+        let result = use_cases.get_entries_by_period(GetTimeEntriesCommand {
+            start: now,
+            end: now,
+            project_id: None,
+            task_id: None,
+            cursor: None,
+            limit: None,
+        }).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Start time must be before end time"));
+    }
+    #[tokio::test]
+    async fn 時間エントリ訂正が正しく動作すること() {
+        let (use_cases, task_id) = setup_use_cases().await;
+        let start_time = Utc::now() - chrono::Duration::hours(3);
+        use_cases.add_manual_entry(AddManualEntryCommand {
+            task_id,
+            start_time,
+            end_time: start_time + chrono::Duration::hours(1),
+            note: None,
+        }).await.unwrap();
+        let entry = use_cases.get_task_entries(task_id).await.unwrap().remove(0);
+
+        let new_start = start_time - chrono::Duration::minutes(30);
+        let updated = use_cases.update_time_entry(UpdateTimeEntryCommand {
+            start_event_id: entry.start_event_id(),
+            start_time: Some(new_start),
+            end_time: None,
+        }).await.unwrap();
+
+        assert_eq!(updated.start_event_id(), entry.start_event_id());
+        assert_eq!(updated.start_time().timestamp(), new_start.timestamp());
+        assert_eq!(
+            updated.end_time().map(|t| t.timestamp()),
+            entry.end_time().map(|t| t.timestamp())
+        );
+    }
+
+    #[tokio::test]
+    async fn 存在しない時間エントリの訂正が失敗すること() {
+        let (use_cases, _) = setup_use_cases().await;
+
+        let result = use_cases.update_time_entry(UpdateTimeEntryCommand {
+            start_event_id: 999,
+            start_time: Some(Utc::now()),
+            end_time: None,
+        }).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Time entry not found"));
+    }
+    #[tokio::test]
+    async fn 時間エントリの削除と復元が正しく動作すること() {
+        let (use_cases, task_id) = setup_use_cases().await;
+
+        // 誤操作による短いエントリ
+        use_cases.start_timer(StartTimerCommand { task_id, at: None }).await.unwrap();
+        use_cases.stop_timer(StopTimerCommand { task_id, at: None }).await.unwrap();
+        let entry = use_cases.get_task_entries(task_id).await.unwrap().remove(0);
+
+        let void_event = use_cases.delete_time_entry(entry.start_event_id()).await.unwrap();
+        assert!(void_event.is_void());
+        assert!(use_cases.get_task_entries(task_id).await.unwrap().is_empty());
+        assert!(use_cases.get_recent_entries(10).await.unwrap().is_empty());
+
+        let restored = use_cases.restore_time_entry(entry.start_event_id()).await.unwrap();
+        assert_eq!(restored.start_event_id(), entry.start_event_id());
+        assert_eq!(use_cases.get_task_entries(task_id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn 時間エントリへのメモ追記が正しく動作すること() {
+        let (use_cases, task_id) = setup_use_cases().await;
+        let start_time = Utc::now() - chrono::Duration::hours(3);
+        use_cases.add_manual_entry(AddManualEntryCommand {
+            task_id,
+            start_time,
+            end_time: start_time + chrono::Duration::hours(1),
+            note: Some("設計".to_string()),
+        }).await.unwrap();
+        let entry = use_cases.get_task_entries(task_id).await.unwrap().remove(0);
+        assert_eq!(entry.notes(), Some("設計"));
+
+        let annotated = use_cases.annotate_time_entry(AnnotateTimeEntryCommand {
+            start_event_id: entry.start_event_id(),
+            note: "実装".to_string(),
+        }).await.unwrap();
+
+        assert_eq!(annotated.start_event_id(), entry.start_event_id());
+        assert_eq!(annotated.notes(), Some("設計\n実装"));
+    }
+
+    #[tokio::test]
+    async fn エントリ開始時点で付与されていたタグで絞り込まれること() {
+        let tag_repo = InMemoryTagRepository::new();
+        let time_entry_repo = InMemoryTimeEntryRepository::new().with_tag_repository(&tag_repo);
+        let task_repo = InMemoryTaskRepository::new();
+        let task_id = TaskId::new(1).unwrap();
+        task_repo
+            .save(&Task::new(task_id, ProjectId::new(1).unwrap(), "Test Task".to_string()).unwrap())
+            .await
+            .unwrap();
+        let use_cases = TimeTrackingUseCasesImpl::new(
+            time_entry_repo.clone(),
+            task_repo,
+            TimeTrackingServiceImpl::new(time_entry_repo),
+        );
+        let at = |hour: u32| Utc.with_ymd_and_hms(2025, 3, 3, hour, 0, 0).unwrap();
+
+        // 9時に付与、12時に剥奪：10時のエントリだけがタグ付き
+        let tag_id = TagId::new(1).unwrap();
+        tag_repo.save(&Tag::new(tag_id, "billable".to_string()).unwrap()).await.unwrap();
+        tag_repo.save_task_tag_event(&TaskTagEvent::add(task_id, tag_id).with_at(at(9))).await.unwrap();
+        tag_repo.save_task_tag_event(&TaskTagEvent::remove(task_id, tag_id).with_at(at(12))).await.unwrap();
+        for (start, end) in [(10, 11), (13, 15)] {
+            use_cases
+                .add_manual_entry(AddManualEntryCommand { task_id, start_time: at(start), end_time: at(end), note: None })
+                .await
+                .unwrap();
+        }
+
+        let entries = use_cases.get_tag_entries(tag_id).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].start_time(), at(10));
+        assert_eq!(use_cases.get_tag_entries_by_period(tag_id, at(0), at(23)).await.unwrap().len(), 1);
+        assert!(use_cases.get_tag_entries_by_period(tag_id, at(12), at(23)).await.unwrap().is_empty());
+        assert_eq!(use_cases.get_tag_total_duration(tag_id).await.unwrap(), 3600);
+    }
 }
@@ -0,0 +1,406 @@
+use crate::application::use_cases::{AddManualEntryCommand, TimeTrackingUseCases, UpdateTimeEntryCommand};
+use crate::domain::entities::TimeEntry;
+use crate::domain::repositories::{TaskRepository, TimeEntryRepository};
+use crate::domain::services::{Clock, LocalCalendar, TimesheetChange, TimesheetReconciler};
+use crate::domain::value_objects::{ProjectId, TaskId};
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate, NaiveTime};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// タイムシートのセル設定コマンド
+#[derive(Debug, Clone)]
+pub struct SetTimesheetCellCommand {
+    pub task_id: TaskId,
+    /// ローカル日付
+    pub date: NaiveDate,
+    pub duration_seconds: i64,
+}
+
+/// タイムシートの行（タスクごとの曜日別の合計）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimesheetRow {
+    pub task_id: TaskId,
+    pub task_name: String,
+    pub project_id: ProjectId,
+    /// 週の開始日からの7日分（秒）
+    pub daily_seconds: Vec<i64>,
+    pub total_seconds: i64,
+}
+
+/// 1週間分のタイムシート
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timesheet {
+    pub week_start: NaiveDate,
+    pub rows: Vec<TimesheetRow>,
+    /// 曜日ごとの全タスクの合計（秒）
+    pub daily_totals: Vec<i64>,
+    pub total_seconds: i64,
+}
+
+/// タイムシートユースケーストレイト
+#[async_trait]
+pub trait TimesheetUseCases: Send + Sync {
+    /// 指定日を含む週のタイムシートを取得（週の区切りはカレンダーの週の開始曜日）
+    async fn get_timesheet(&self, date: NaiveDate) -> anyhow::Result<Timesheet>;
+
+    /// セル（タスク×日）の合計を指定した時間にする（差分は手動エントリの追加・訂正・削除で調整する）
+    async fn set_timesheet_cell(&self, command: SetTimesheetCellCommand) -> anyhow::Result<Timesheet>;
+}
+
+/// タイムシートユースケース実装
+///
+/// 時間エントリの変更はタイムトラッキングユースケースを通して行い、重複チェックやイベントの発行を共通にする。
+pub struct TimesheetUseCasesImpl<T: TimeEntryRepository, K: TaskRepository> {
+    time_entry_repository: T,
+    task_repository: K,
+    time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>,
+    clock: Arc<dyn Clock>,
+    calendar: LocalCalendar,
+    /// 時間を増やすときの手動エントリをなるべくこのローカル時刻以降に置く（稼働時間の開始）
+    working_hours_start: NaiveTime,
+}
+
+impl<T: TimeEntryRepository, K: TaskRepository> TimesheetUseCasesImpl<T, K> {
+    pub fn new(
+        time_entry_repository: T,
+        task_repository: K,
+        time_tracking_use_cases: Arc<dyn TimeTrackingUseCases>,
+        clock: Arc<dyn Clock>,
+        calendar: LocalCalendar,
+        working_hours_start: NaiveTime,
+    ) -> Self {
+        Self {
+            time_entry_repository,
+            task_repository,
+            time_tracking_use_cases,
+            clock,
+            calendar,
+            working_hours_start,
+        }
+    }
+
+    /// セルの変更を適用する
+    /// 変更をすべて検証してから書き込む（途中で調整できないと分かった場合は何も変更しない）
+    async fn apply_changes(
+        &self,
+        task_id: TaskId,
+        reconciler: &TimesheetReconciler,
+        entries: &[TimeEntry],
+        changes: Vec<TimesheetChange>,
+    ) -> anyhow::Result<()> {
+        reconciler.validate(entries, &changes)?;
+
+        for change in changes {
+            match change {
+                TimesheetChange::Add { start_time, end_time } => {
+                    self.time_tracking_use_cases
+                        .add_manual_entry(AddManualEntryCommand {
+                            task_id,
+                            start_time,
+                            end_time,
+                            note: None,
+                        })
+                        .await?;
+                }
+                TimesheetChange::Adjust { start_event_id, start_time, end_time } => {
+                    self.time_tracking_use_cases
+                        .update_time_entry(UpdateTimeEntryCommand {
+                            start_event_id,
+                            start_time: Some(start_time),
+                            end_time: Some(end_time),
+                        })
+                        .await?;
+                }
+                TimesheetChange::Delete { start_event_id } => {
+                    self.time_tracking_use_cases.delete_time_entry(start_event_id).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// ローカル日付のセルの合計を求める・調整するための範囲
+    /// 時間を増やすときの手動エントリは、なるべく稼働時間の開始以降に置く
+    fn reconciler_for(&self, date: NaiveDate) -> TimesheetReconciler {
+        TimesheetReconciler::new(
+            self.calendar.start_of_day(date),
+            self.calendar.start_of_day(date + Duration::days(1)),
+            self.calendar.at_local_time(date, self.working_hours_start),
+        )
+    }
+}
+
+#[async_trait]
+impl<T: TimeEntryRepository, K: TaskRepository> TimesheetUseCases for TimesheetUseCasesImpl<T, K> {
+    async fn get_timesheet(&self, date: NaiveDate) -> anyhow::Result<Timesheet> {
+        let week_start = self.calendar.start_of_week(date);
+        let days: Vec<_> = (0..7).map(|i| week_start + Duration::days(i)).collect();
+        let now = self.clock.now();
+
+        let entries = self
+            .time_entry_repository
+            .find_entries_overlapping_period(
+                self.calendar.start_of_day(week_start),
+                self.calendar.start_of_day(week_start + Duration::days(7)),
+            )
+            .await?;
+
+        // タスクごとに曜日別の合計を求める
+        let mut seconds_by_task: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+        for entry in &entries {
+            let daily = seconds_by_task
+                .entry(i64::from(entry.task_id()))
+                .or_insert_with(|| vec![0; days.len()]);
+            for (i, day) in days.iter().enumerate() {
+                daily[i] += self.reconciler_for(*day).total_seconds(std::slice::from_ref(entry), now);
+            }
+        }
+
+        let mut rows = Vec::with_capacity(seconds_by_task.len());
+        for (task_id, daily_seconds) in seconds_by_task {
+            let task_id = TaskId::new(task_id)?;
+            let task = self
+                .task_repository
+                .find_by_id(task_id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+            rows.push(TimesheetRow {
+                task_id,
+                task_name: task.name().to_string(),
+                project_id: task.project_id(),
+                total_seconds: daily_seconds.iter().sum(),
+                daily_seconds,
+            });
+        }
+        rows.sort_by(|a, b| {
+            (i64::from(a.project_id), &a.task_name).cmp(&(i64::from(b.project_id), &b.task_name))
+        });
+
+        let daily_totals: Vec<i64> = (0..days.len())
+            .map(|i| rows.iter().map(|row| row.daily_seconds[i]).sum())
+            .collect();
+
+        Ok(Timesheet {
+            week_start,
+            total_seconds: daily_totals.iter().sum(),
+            daily_totals,
+            rows,
+        })
+    }
+
+    async fn set_timesheet_cell(&self, command: SetTimesheetCellCommand) -> anyhow::Result<Timesheet> {
+        // タスクの存在確認
+        self.task_repository
+            .find_by_id(command.task_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+
+        let reconciler = self.reconciler_for(command.date);
+        let entries = self
+            .time_entry_repository
+            .find_overlapping_entries(
+                command.task_id,
+                self.calendar.start_of_day(command.date),
+                self.calendar.start_of_day(command.date + Duration::days(1)),
+            )
+            .await?;
+
+        let changes = reconciler.reconcile(&entries, command.duration_seconds, self.clock.now())?;
+        self.apply_changes(command.task_id, &reconciler, &entries, changes).await?;
+
+        self.get_timesheet(command.date).await
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::TimeTrackingUseCasesImpl;
+    use crate::domain::entities::Task;
+    use crate::domain::repositories::{task_tests::InMemoryTaskRepository, time_entry_tests::InMemoryTimeEntryRepository};
+    use crate::domain::services::clock::tests::FixedClock;
+    use crate::domain::services::TimeTrackingServiceImpl;
+    use chrono::{DateTime, TimeZone, Utc, Weekday};
+
+    fn jst(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, day, hour, 0, 0).unwrap() - Duration::hours(9)
+    }
+
+    async fn setup() -> (TimesheetUseCasesImpl<InMemoryTimeEntryRepository, InMemoryTaskRepository>, Arc<dyn TimeTrackingUseCases>) {
+        setup_with_working_hours_start(NaiveTime::from_hms_opt(9, 0, 0).unwrap()).await
+    }
+
+    async fn setup_with_working_hours_start(
+        working_hours_start: NaiveTime,
+    ) -> (TimesheetUseCasesImpl<InMemoryTimeEntryRepository, InMemoryTaskRepository>, Arc<dyn TimeTrackingUseCases>) {
+        let time_entry_repo = InMemoryTimeEntryRepository::new();
+        let task_repo = InMemoryTaskRepository::new();
+        let project_id = ProjectId::new(1).unwrap();
+        for (id, name) in [(1, "Design"), (2, "Review")] {
+            task_repo
+                .save(&Task::new(TaskId::new(id).unwrap(), project_id, name.to_string()).unwrap())
+                .await
+                .unwrap();
+        }
+
+        let time_tracking_use_cases: Arc<dyn TimeTrackingUseCases> = Arc::new(TimeTrackingUseCasesImpl::new(
+            time_entry_repo.clone(),
+            task_repo.clone(),
+            TimeTrackingServiceImpl::new(time_entry_repo.clone()),
+        ));
+        let use_cases = TimesheetUseCasesImpl::new(
+            time_entry_repo,
+            task_repo,
+            time_tracking_use_cases.clone(),
+            Arc::new(FixedClock::new(jst(16, 12))),
+            LocalCalendar::from_name("Asia/Tokyo", Weekday::Mon).unwrap(),
+            working_hours_start,
+        );
+        (use_cases, time_tracking_use_cases)
+    }
+
+    async fn add_entry(use_cases: &Arc<dyn TimeTrackingUseCases>, task_id: i64, start: DateTime<Utc>, end: DateTime<Utc>) {
+        use_cases
+            .add_manual_entry(AddManualEntryCommand {
+                task_id: TaskId::new(task_id).unwrap(),
+                start_time: start,
+                end_time: end,
+                note: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn 週のタイムシートがローカル日付の曜日別に集計されること() {
+        let (use_cases, time_tracking) = setup().await;
+        // 2025-03-10 は月曜日。日曜の夜から月曜にまたがる区間は0時で分ける
+        add_entry(&time_tracking, 1, jst(9, 23), jst(10, 1)).await;
+        add_entry(&time_tracking, 1, jst(12, 9), jst(12, 11)).await;
+        add_entry(&time_tracking, 2, jst(16, 22), jst(17, 0)).await;
+
+        let timesheet = use_cases.get_timesheet(date(13)).await.unwrap();
+        assert_eq!(timesheet.week_start, date(10));
+        assert_eq!(timesheet.rows.len(), 2);
+        assert_eq!(timesheet.rows[0].task_name, "Design");
+        assert_eq!(timesheet.rows[0].daily_seconds, vec![3600, 0, 7200, 0, 0, 0, 0]);
+        assert_eq!(timesheet.rows[1].daily_seconds, vec![0, 0, 0, 0, 0, 0, 7200]);
+        assert_eq!(timesheet.daily_totals, vec![3600, 0, 7200, 0, 0, 0, 7200]);
+        assert_eq!(timesheet.total_seconds, 18000);
+    }
+
+    #[tokio::test]
+    async fn セルの設定で手動エントリが追加と訂正されること() {
+        let (use_cases, time_tracking) = setup().await;
+        add_entry(&time_tracking, 1, jst(11, 9), jst(11, 10)).await;
+        let task_id = TaskId::new(1).unwrap();
+
+        // 1時間 → 3時間: 10:00 から2時間の手動エントリを追加
+        let timesheet = use_cases
+            .set_timesheet_cell(SetTimesheetCellCommand { task_id, date: date(11), duration_seconds: 3 * 3600 })
+            .await
+            .unwrap();
+        assert_eq!(timesheet.rows[0].daily_seconds[1], 3 * 3600);
+        let entries = time_tracking.get_task_entries(task_id).await.unwrap();
+        assert!(entries.iter().any(|e| e.start_time() == jst(11, 10) && e.end_time() == Some(jst(11, 12))));
+
+        // 3時間 → 30分: 後の区間を削除し、前の区間を短縮する
+        let timesheet = use_cases
+            .set_timesheet_cell(SetTimesheetCellCommand { task_id, date: date(11), duration_seconds: 1800 })
+            .await
+            .unwrap();
+        assert_eq!(timesheet.rows[0].daily_seconds[1], 1800);
+        let entries = time_tracking.get_task_entries(task_id).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].end_time(), Some(jst(11, 9) + Duration::minutes(30)));
+    }
+
+    #[tokio::test]
+    async fn 追加する手動エントリが稼働時間の開始以降に置かれること() {
+        let (use_cases, time_tracking) = setup_with_working_hours_start(NaiveTime::from_hms_opt(13, 30, 0).unwrap()).await;
+        let task_id = TaskId::new(1).unwrap();
+
+        use_cases
+            .set_timesheet_cell(SetTimesheetCellCommand { task_id, date: date(11), duration_seconds: 3600 })
+            .await
+            .unwrap();
+        let entries = time_tracking.get_task_entries(task_id).await.unwrap();
+        assert_eq!(entries[0].start_time(), jst(11, 13) + Duration::minutes(30));
+    }
+
+    #[tokio::test]
+    async fn 今日と未来のセルは現在時刻より後に終わるエントリを追加しないこと() {
+        // 現在時刻は 3/16 12:00（JST）
+        let (use_cases, time_tracking) = setup().await;
+        let task_id = TaskId::new(1).unwrap();
+
+        // 9:00 から現在時刻までに収まらない4時間は日の始まりから置く
+        use_cases
+            .set_timesheet_cell(SetTimesheetCellCommand { task_id, date: date(16), duration_seconds: 4 * 3600 })
+            .await
+            .unwrap();
+        let entries = time_tracking.get_task_entries(task_id).await.unwrap();
+        assert_eq!(entries[0].start_time(), jst(16, 0));
+        assert_eq!(entries[0].end_time(), Some(jst(16, 4)));
+
+        // 現在時刻までの空きより長い時間や、未来の日には追加できない
+        let result = use_cases
+            .set_timesheet_cell(SetTimesheetCellCommand { task_id, date: date(16), duration_seconds: 13 * 3600 })
+            .await;
+        assert!(result.is_err());
+        let result = use_cases
+            .set_timesheet_cell(SetTimesheetCellCommand { task_id, date: date(17), duration_seconds: 3600 })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(time_tracking.get_task_entries(task_id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn 空き時間がないセルの設定は何も変更しないこと() {
+        let (use_cases, time_tracking) = setup().await;
+        let task_id = TaskId::new(1).unwrap();
+
+        let result = use_cases
+            .set_timesheet_cell(SetTimesheetCellCommand { task_id, date: date(11), duration_seconds: 25 * 3600 })
+            .await;
+        assert!(result.is_err());
+        assert!(time_tracking.get_task_entries(task_id).await.unwrap().is_empty());
+
+        let missing = use_cases
+            .set_timesheet_cell(SetTimesheetCellCommand { task_id: TaskId::new(99).unwrap(), date: date(11), duration_seconds: 3600 })
+            .await;
+        assert!(missing.is_err());
+    }
+
+    #[tokio::test]
+    async fn 途中の変更が適用できない場合は何も変更しないこと() {
+        let (use_cases, time_tracking) = setup().await;
+        add_entry(&time_tracking, 1, jst(11, 9), jst(11, 10)).await;
+        add_entry(&time_tracking, 1, jst(11, 11), jst(11, 12)).await;
+        add_entry(&time_tracking, 1, jst(11, 13), jst(11, 14)).await;
+        let task_id = TaskId::new(1).unwrap();
+        let entries = time_tracking.get_task_entries(task_id).await.unwrap();
+        let start_event_id = |start| entries.iter().find(|e| e.start_time() == start).unwrap().start_event_id();
+
+        // 1件目の削除は適用できるが、2件目の訂正は残る区間と重複する
+        let changes = vec![
+            TimesheetChange::Delete { start_event_id: start_event_id(jst(11, 13)) },
+            TimesheetChange::Adjust {
+                start_event_id: start_event_id(jst(11, 9)),
+                start_time: jst(11, 9),
+                end_time: jst(11, 12),
+            },
+        ];
+        let result = use_cases
+            .apply_changes(task_id, &use_cases.reconciler_for(date(11)), &entries, changes)
+            .await;
+        assert!(result.is_err());
+        assert_eq!(time_tracking.get_task_entries(task_id).await.unwrap(), entries);
+    }
+}
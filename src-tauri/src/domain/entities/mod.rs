@@ -3,8 +3,12 @@
 pub mod project;
 pub mod task;
 pub mod time_entry;
+pub mod tag;
+pub mod pomodoro;
 
 pub use project::*;
 pub use task::*;
 pub use time_entry::*;
+pub use tag::*;
+pub use pomodoro::*;
 
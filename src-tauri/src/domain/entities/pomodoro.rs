@@ -0,0 +1,373 @@
+use crate::domain::value_objects::TaskId;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// ポモドーロのフェーズ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PomodoroPhase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl PomodoroPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PomodoroPhase::Work => "work",
+            PomodoroPhase::ShortBreak => "short_break",
+            PomodoroPhase::LongBreak => "long_break",
+        }
+    }
+
+    pub fn is_work(&self) -> bool {
+        matches!(self, PomodoroPhase::Work)
+    }
+}
+
+/// ポモドーロの設定（各フェーズの長さと長い休憩の間隔）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PomodoroSettings {
+    work_seconds: i64,
+    short_break_seconds: i64,
+    long_break_seconds: i64,
+    long_break_interval: u32,
+}
+
+impl PomodoroSettings {
+    pub fn new(
+        work_seconds: i64,
+        short_break_seconds: i64,
+        long_break_seconds: i64,
+        long_break_interval: u32,
+    ) -> anyhow::Result<Self> {
+        if work_seconds <= 0 {
+            return Err(anyhow::anyhow!("Pomodoro work seconds must be positive"));
+        }
+        if short_break_seconds <= 0 || long_break_seconds <= 0 {
+            return Err(anyhow::anyhow!("Pomodoro break seconds must be positive"));
+        }
+        if long_break_interval == 0 {
+            return Err(anyhow::anyhow!("Pomodoro long break interval must be positive"));
+        }
+
+        Ok(Self {
+            work_seconds,
+            short_break_seconds,
+            long_break_seconds,
+            long_break_interval,
+        })
+    }
+
+    pub fn work_seconds(&self) -> i64 {
+        self.work_seconds
+    }
+
+    pub fn short_break_seconds(&self) -> i64 {
+        self.short_break_seconds
+    }
+
+    pub fn long_break_seconds(&self) -> i64 {
+        self.long_break_seconds
+    }
+
+    pub fn long_break_interval(&self) -> u32 {
+        self.long_break_interval
+    }
+
+    /// フェーズの長さ（秒）
+    pub fn phase_seconds(&self, phase: PomodoroPhase) -> i64 {
+        match phase {
+            PomodoroPhase::Work => self.work_seconds,
+            PomodoroPhase::ShortBreak => self.short_break_seconds,
+            PomodoroPhase::LongBreak => self.long_break_seconds,
+        }
+    }
+}
+
+impl Default for PomodoroSettings {
+    fn default() -> Self {
+        Self {
+            work_seconds: 25 * 60,
+            short_break_seconds: 5 * 60,
+            long_break_seconds: 15 * 60,
+            long_break_interval: 4,
+        }
+    }
+}
+
+/// ポモドーロのフェーズ遷移
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PomodoroPhaseChange {
+    pub task_id: TaskId,
+    pub from: PomodoroPhase,
+    pub to: PomodoroPhase,
+    /// 遷移元のフェーズを最後まで完了したか（スキップした場合はfalse）
+    pub completed: bool,
+    /// セッション内で完了した作業フェーズの数
+    pub completed_count: u32,
+    pub from_started_at: DateTime<Utc>,
+    pub at: DateTime<Utc>,
+}
+
+/// ポモドーロセッション（作業と休憩のサイクル）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PomodoroSession {
+    task_id: TaskId,
+    settings: PomodoroSettings,
+    phase: PomodoroPhase,
+    phase_started_at: DateTime<Utc>,
+    paused_at: Option<DateTime<Utc>>,
+    paused_seconds: i64,
+    completed_count: u32,
+}
+
+impl PomodoroSession {
+    /// 作業フェーズから新しいセッションを開始
+    pub fn start(task_id: TaskId, settings: PomodoroSettings, now: DateTime<Utc>) -> Self {
+        Self {
+            task_id,
+            settings,
+            phase: PomodoroPhase::Work,
+            phase_started_at: now,
+            paused_at: None,
+            paused_seconds: 0,
+            completed_count: 0,
+        }
+    }
+
+    // Getters
+    pub fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+
+    pub fn settings(&self) -> &PomodoroSettings {
+        &self.settings
+    }
+
+    pub fn phase(&self) -> PomodoroPhase {
+        self.phase
+    }
+
+    pub fn phase_started_at(&self) -> DateTime<Utc> {
+        self.phase_started_at
+    }
+
+    pub fn paused_at(&self) -> Option<DateTime<Utc>> {
+        self.paused_at
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    pub fn completed_count(&self) -> u32 {
+        self.completed_count
+    }
+
+    /// 現在のフェーズの経過秒数（一時停止していた時間を除く）
+    pub fn elapsed_seconds(&self, now: DateTime<Utc>) -> i64 {
+        let until = self.paused_at.unwrap_or(now);
+        ((until - self.phase_started_at).num_seconds() - self.paused_seconds).max(0)
+    }
+
+    /// 現在のフェーズの残り秒数
+    pub fn remaining_seconds(&self, now: DateTime<Utc>) -> i64 {
+        (self.settings.phase_seconds(self.phase) - self.elapsed_seconds(now)).max(0)
+    }
+
+    /// 現在のフェーズの終了予定時刻（一時停止中はNone）
+    pub fn phase_ends_at(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.is_paused() {
+            return None;
+        }
+        Some(now + Duration::seconds(self.remaining_seconds(now)))
+    }
+
+    /// 一時停止していた時間を含めた現在のフェーズの予定終了時刻
+    pub fn scheduled_phase_end(&self) -> DateTime<Utc> {
+        self.phase_started_at
+            + Duration::seconds(self.paused_seconds + self.settings.phase_seconds(self.phase))
+    }
+
+    /// 現在のフェーズが終了時刻に達したか
+    pub fn is_phase_complete(&self, now: DateTime<Utc>) -> bool {
+        !self.is_paused() && self.remaining_seconds(now) == 0
+    }
+
+    /// 一時停止する
+    pub fn pause(&mut self, now: DateTime<Utc>) -> anyhow::Result<()> {
+        if self.is_paused() {
+            return Err(anyhow::anyhow!("Pomodoro is already paused"));
+        }
+        self.paused_at = Some(now);
+        Ok(())
+    }
+
+    /// 再開する
+    pub fn resume(&mut self, now: DateTime<Utc>) -> anyhow::Result<()> {
+        let paused_at = self
+            .paused_at
+            .take()
+            .ok_or_else(|| anyhow::anyhow!("Pomodoro is not paused"))?;
+        self.paused_seconds += (now - paused_at).num_seconds().max(0);
+        Ok(())
+    }
+
+    /// 次のフェーズに進む
+    ///
+    /// 作業フェーズを完了した場合のみ完了数を加算し、完了数が長い休憩の間隔に達したら長い休憩に入る。
+    /// 作業フェーズをスキップした場合は短い休憩に入る。
+    pub fn advance(&mut self, now: DateTime<Utc>, completed: bool) -> PomodoroPhaseChange {
+        let from = self.phase;
+        let from_started_at = self.phase_started_at;
+
+        let to = match from {
+            PomodoroPhase::Work => {
+                if completed {
+                    self.completed_count += 1;
+                }
+                if completed && self.completed_count.is_multiple_of(self.settings.long_break_interval) {
+                    PomodoroPhase::LongBreak
+                } else {
+                    PomodoroPhase::ShortBreak
+                }
+            }
+            PomodoroPhase::ShortBreak | PomodoroPhase::LongBreak => PomodoroPhase::Work,
+        };
+
+        self.phase = to;
+        self.phase_started_at = now;
+        self.paused_at = None;
+        self.paused_seconds = 0;
+
+        PomodoroPhaseChange {
+            task_id: self.task_id,
+            from,
+            to,
+            completed,
+            completed_count: self.completed_count,
+            from_started_at,
+            at: now,
+        }
+    }
+}
+
+/// 完了したポモドーロ（作業フェーズ）の記録
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PomodoroCompletion {
+    id: Option<i64>,
+    task_id: TaskId,
+    started_at: DateTime<Utc>,
+    completed_at: DateTime<Utc>,
+    work_seconds: i64,
+}
+
+impl PomodoroCompletion {
+    pub fn new(task_id: TaskId, started_at: DateTime<Utc>, completed_at: DateTime<Utc>, work_seconds: i64) -> Self {
+        Self {
+            id: None,
+            task_id,
+            started_at,
+            completed_at,
+            work_seconds,
+        }
+    }
+
+    /// IDを設定（保存後に使用）
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i64> {
+        self.id
+    }
+
+    pub fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+
+    pub fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+
+    pub fn completed_at(&self) -> DateTime<Utc> {
+        self.completed_at
+    }
+
+    pub fn work_seconds(&self) -> i64 {
+        self.work_seconds
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, hour, minute, 0).unwrap()
+    }
+
+    fn settings() -> PomodoroSettings {
+        PomodoroSettings::new(25 * 60, 5 * 60, 15 * 60, 2).unwrap()
+    }
+
+    #[test]
+    fn 設定のバリデーションが動作すること() {
+        assert!(PomodoroSettings::new(0, 300, 900, 4).is_err());
+        assert!(PomodoroSettings::new(1500, 0, 900, 4).is_err());
+        assert!(PomodoroSettings::new(1500, 300, 900, 0).is_err());
+        assert_eq!(PomodoroSettings::default().phase_seconds(PomodoroPhase::LongBreak), 15 * 60);
+    }
+
+    #[test]
+    fn 作業と休憩のサイクルが進むこと() {
+        let task_id = TaskId::new(1).unwrap();
+        let mut session = PomodoroSession::start(task_id, settings(), at(9, 0));
+        assert_eq!(session.phase(), PomodoroPhase::Work);
+        assert!(!session.is_phase_complete(at(9, 24)));
+        assert!(session.is_phase_complete(at(9, 25)));
+
+        let change = session.advance(at(9, 25), true);
+        assert_eq!((change.from, change.to), (PomodoroPhase::Work, PomodoroPhase::ShortBreak));
+        assert_eq!(change.completed_count, 1);
+        assert_eq!(change.from_started_at, at(9, 0));
+
+        session.advance(at(9, 30), true);
+        assert_eq!(session.phase(), PomodoroPhase::Work);
+
+        // 間隔に達すると長い休憩
+        let change = session.advance(at(9, 55), true);
+        assert_eq!(change.to, PomodoroPhase::LongBreak);
+        assert_eq!(session.remaining_seconds(at(9, 55)), 15 * 60);
+    }
+
+    #[test]
+    fn スキップした作業は完了数に数えないこと() {
+        let mut session = PomodoroSession::start(TaskId::new(1).unwrap(), settings(), at(9, 0));
+
+        let change = session.advance(at(9, 10), false);
+        assert!(!change.completed);
+        assert_eq!(change.completed_count, 0);
+        assert_eq!(change.to, PomodoroPhase::ShortBreak);
+    }
+
+    #[test]
+    fn 一時停止中は残り時間が減らないこと() {
+        let mut session = PomodoroSession::start(TaskId::new(1).unwrap(), settings(), at(9, 0));
+
+        session.pause(at(9, 10)).unwrap();
+        assert!(session.pause(at(9, 11)).is_err());
+        assert_eq!(session.remaining_seconds(at(9, 40)), 15 * 60);
+        assert!(!session.is_phase_complete(at(10, 0)));
+        assert!(session.phase_ends_at(at(9, 40)).is_none());
+
+        session.resume(at(9, 40)).unwrap();
+        assert!(session.resume(at(9, 41)).is_err());
+        assert_eq!(session.phase_ends_at(at(9, 40)), Some(at(9, 55)));
+        assert!(session.is_phase_complete(at(9, 55)));
+    }
+}
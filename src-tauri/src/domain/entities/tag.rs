@@ -0,0 +1,221 @@
+use crate::domain::value_objects::{TagId, TaskId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// タグエンティティ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tag {
+    id: TagId,
+    name: String,
+}
+
+impl Tag {
+    /// 新しいタグを作成
+    pub fn new(id: TagId, name: String) -> anyhow::Result<Self> {
+        let name = Self::normalize_name(&name);
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("Tag name cannot be empty"));
+        }
+        if name.chars().count() > 255 {
+            return Err(anyhow::anyhow!("Tag name cannot exceed 255 characters"));
+        }
+
+        Ok(Self { id, name })
+    }
+
+    /// タグ名を正規化（前後空白と先頭の`#`を除去）
+    pub fn normalize_name(name: &str) -> String {
+        name.trim().trim_start_matches('#').trim().to_string()
+    }
+
+    // Getters
+    pub fn id(&self) -> TagId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// タスクタグイベントの種類
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TaskTagEventType {
+    Add,
+    Remove,
+}
+
+impl TaskTagEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskTagEventType::Add => "add",
+            TaskTagEventType::Remove => "remove",
+        }
+    }
+}
+
+impl FromStr for TaskTagEventType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "add" => Ok(TaskTagEventType::Add),
+            "remove" => Ok(TaskTagEventType::Remove),
+            _ => Err(anyhow::anyhow!("Invalid task tag event type: {}", s)),
+        }
+    }
+}
+
+/// タスクタグイベント（タグの付与/剥奪）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskTagEvent {
+    id: Option<i64>,
+    task_id: TaskId,
+    tag_id: TagId,
+    event_type: TaskTagEventType,
+    at: DateTime<Utc>,
+}
+
+impl TaskTagEvent {
+    /// タグ付与イベントを作成
+    pub fn add(task_id: TaskId, tag_id: TagId) -> Self {
+        Self::add_at(task_id, tag_id, Utc::now())
+    }
+
+    /// 指定日時のタグ付与イベントを作成（過去のエントリの取り込み用）
+    pub fn add_at(task_id: TaskId, tag_id: TagId, at: DateTime<Utc>) -> Self {
+        Self {
+            id: None,
+            task_id,
+            tag_id,
+            event_type: TaskTagEventType::Add,
+            at,
+        }
+    }
+
+    /// タグ剥奪イベントを作成
+    pub fn remove(task_id: TaskId, tag_id: TagId) -> Self {
+        Self {
+            id: None,
+            task_id,
+            tag_id,
+            event_type: TaskTagEventType::Remove,
+            at: Utc::now(),
+        }
+    }
+
+    /// 発生日時を指定（バックフィル用）
+    pub fn with_at(mut self, at: DateTime<Utc>) -> Self {
+        self.at = at;
+        self
+    }
+
+    /// IDを設定（保存後に使用）
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<i64> {
+        self.id
+    }
+
+    pub fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+
+    pub fn tag_id(&self) -> TagId {
+        self.tag_id
+    }
+
+    pub fn event_type(&self) -> &TaskTagEventType {
+        &self.event_type
+    }
+
+    pub fn at(&self) -> DateTime<Utc> {
+        self.at
+    }
+
+    pub fn is_add(&self) -> bool {
+        matches!(self.event_type, TaskTagEventType::Add)
+    }
+
+    pub fn is_remove(&self) -> bool {
+        matches!(self.event_type, TaskTagEventType::Remove)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn タグ作成ができること() {
+        let tag_id = TagId::new(1).unwrap();
+        let tag = Tag::new(tag_id, "meeting".to_string()).unwrap();
+
+        assert_eq!(tag.id(), tag_id);
+        assert_eq!(tag.name(), "meeting");
+    }
+
+    #[test]
+    fn タグ名が正規化されること() {
+        let tag_id = TagId::new(1).unwrap();
+        let tag = Tag::new(tag_id, "  #meeting ".to_string()).unwrap();
+
+        assert_eq!(tag.name(), "meeting");
+    }
+
+    #[test]
+    fn タグ名のバリデーションが機能すること() {
+        let tag_id = TagId::new(1).unwrap();
+
+        assert!(Tag::new(tag_id, "".to_string()).is_err());
+        assert!(Tag::new(tag_id, "   ".to_string()).is_err());
+        assert!(Tag::new(tag_id, "#".to_string()).is_err());
+        assert!(Tag::new(tag_id, "a".repeat(256)).is_err());
+        assert!(Tag::new(tag_id, "クライアントA".to_string()).is_ok());
+        // 上限はバイト数ではなく文字数で判定する
+        assert!(Tag::new(tag_id, "あ".repeat(255)).is_ok());
+        assert!(Tag::new(tag_id, "あ".repeat(256)).is_err());
+    }
+
+    #[test]
+    fn タグ付与イベント作成が正しく動作すること() {
+        let task_id = TaskId::new(1).unwrap();
+        let tag_id = TagId::new(2).unwrap();
+        let event = TaskTagEvent::add(task_id, tag_id);
+
+        assert_eq!(event.task_id(), task_id);
+        assert_eq!(event.tag_id(), tag_id);
+        assert!(event.is_add());
+        assert!(!event.is_remove());
+        assert!(event.id().is_none());
+    }
+
+    #[test]
+    fn タグ剥奪イベント作成が正しく動作すること() {
+        let task_id = TaskId::new(1).unwrap();
+        let tag_id = TagId::new(2).unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let event = TaskTagEvent::remove(task_id, tag_id).with_at(at).with_id(10);
+
+        assert!(event.is_remove());
+        assert_eq!(event.at(), at);
+        assert_eq!(event.id(), Some(10));
+    }
+
+    #[test]
+    fn タグイベントタイプ文字列変換が正しく動作すること() {
+        assert_eq!(TaskTagEventType::Add.as_str(), "add");
+        assert_eq!(TaskTagEventType::Remove.as_str(), "remove");
+
+        assert_eq!("add".parse::<TaskTagEventType>().unwrap(), TaskTagEventType::Add);
+        assert_eq!("remove".parse::<TaskTagEventType>().unwrap(), TaskTagEventType::Remove);
+        assert!("invalid".parse::<TaskTagEventType>().is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::domain::value_objects::TaskId;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// タイムエントリイベントの種類
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -8,6 +9,11 @@ pub enum TimeEntryEventType {
     Start,
     Stop,
     Annotate,
+    Adjust,
+    Void,
+    Unvoid,
+    Pause,
+    Resume,
 }
 
 impl TimeEntryEventType {
@@ -16,6 +22,11 @@ impl TimeEntryEventType {
             TimeEntryEventType::Start => "start",
             TimeEntryEventType::Stop => "stop",
             TimeEntryEventType::Annotate => "annotate",
+            TimeEntryEventType::Adjust => "adjust",
+            TimeEntryEventType::Void => "void",
+            TimeEntryEventType::Unvoid => "unvoid",
+            TimeEntryEventType::Pause => "pause",
+            TimeEntryEventType::Resume => "resume",
         }
     }
 
@@ -24,11 +35,91 @@ impl TimeEntryEventType {
             "start" => Ok(TimeEntryEventType::Start),
             "stop" => Ok(TimeEntryEventType::Stop),
             "annotate" => Ok(TimeEntryEventType::Annotate),
+            "adjust" => Ok(TimeEntryEventType::Adjust),
+            "void" => Ok(TimeEntryEventType::Void),
+            "unvoid" => Ok(TimeEntryEventType::Unvoid),
+            "pause" => Ok(TimeEntryEventType::Pause),
+            "resume" => Ok(TimeEntryEventType::Resume),
             _ => Err(anyhow::anyhow!("Invalid time entry event type: {}", s)),
         }
     }
 }
 
+/// 時間区間の訂正内容（adjustイベントのペイロード）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeEntryAdjustment {
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+impl TimeEntryAdjustment {
+    const DATETIME_FORMAT: &'static str = "%Y-%m-%dT%H:%M:%SZ";
+
+    /// JSON文字列に変換（時刻は秒精度のUTC）
+    pub fn to_payload(&self) -> String {
+        serde_json::json!({
+            "start_time": self.start_time.format(Self::DATETIME_FORMAT).to_string(),
+            "end_time": self.end_time.map(|t| t.format(Self::DATETIME_FORMAT).to_string()),
+        })
+        .to_string()
+    }
+
+    /// JSON文字列から復元
+    pub fn from_payload(payload: &str) -> anyhow::Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(payload)?;
+        let parse = |key: &str| -> anyhow::Result<Option<DateTime<Utc>>> {
+            match value.get(key).and_then(|v| v.as_str()) {
+                Some(s) => Ok(Some(DateTime::parse_from_rfc3339(s)?.with_timezone(&Utc))),
+                None => Ok(None),
+            }
+        };
+
+        Ok(Self {
+            start_time: parse("start_time")?
+                .ok_or_else(|| anyhow::anyhow!("Adjustment payload has no start_time"))?,
+            end_time: parse("end_time")?,
+        })
+    }
+}
+
+/// 整合性補正の理由（補正イベントの payload.reason）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RepairReason {
+    /// 重複STARTの直前区間を閉じる停止
+    ImplicitStop,
+    /// 孤立STOPに対応する開始の補完
+    BackfillStart,
+    /// 長時間実行の自動停止
+    AutoCutoff,
+    /// クラッシュ/スリープ後の復旧による停止
+    CrashRecovery,
+}
+
+impl RepairReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepairReason::ImplicitStop => "implicit_stop",
+            RepairReason::BackfillStart => "backfill_start",
+            RepairReason::AutoCutoff => "auto_cutoff",
+            RepairReason::CrashRecovery => "crash_recovery",
+        }
+    }
+}
+
+impl FromStr for RepairReason {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "implicit_stop" => Ok(RepairReason::ImplicitStop),
+            "backfill_start" => Ok(RepairReason::BackfillStart),
+            "auto_cutoff" => Ok(RepairReason::AutoCutoff),
+            "crash_recovery" => Ok(RepairReason::CrashRecovery),
+            _ => Err(anyhow::anyhow!("Invalid repair reason: {}", s)),
+        }
+    }
+}
+
 /// タイムエントリイベント
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TimeEntryEvent {
@@ -101,6 +192,101 @@ impl TimeEntryEvent {
         }
     }
 
+    /// 訂正イベントを作成（対象区間の開始・終了を上書き）
+    pub fn adjust(task_id: TaskId, start_event_id: i64, adjustment: TimeEntryAdjustment) -> Self {
+        Self {
+            id: None,
+            task_id,
+            event_type: TimeEntryEventType::Adjust,
+            at: Utc::now(),
+            start_event_id: Some(start_event_id),
+            payload: Some(adjustment.to_payload()),
+        }
+    }
+
+    /// 無効化イベントを作成（対象区間を集計から除外）
+    pub fn void(task_id: TaskId, start_event_id: i64) -> Self {
+        Self {
+            id: None,
+            task_id,
+            event_type: TimeEntryEventType::Void,
+            at: Utc::now(),
+            start_event_id: Some(start_event_id),
+            payload: None,
+        }
+    }
+
+    /// 無効化取り消しイベントを作成
+    pub fn unvoid(task_id: TaskId, start_event_id: i64) -> Self {
+        Self {
+            id: None,
+            task_id,
+            event_type: TimeEntryEventType::Unvoid,
+            at: Utc::now(),
+            start_event_id: Some(start_event_id),
+            payload: None,
+        }
+    }
+
+    /// 一時停止イベントを作成（区間は閉じずに計測を止める）
+    pub fn pause(task_id: TaskId, start_event_id: i64) -> Self {
+        Self::pause_at(task_id, start_event_id, Utc::now())
+    }
+
+    /// 指定時刻の一時停止イベントを作成
+    pub fn pause_at(task_id: TaskId, start_event_id: i64, at: DateTime<Utc>) -> Self {
+        Self {
+            id: None,
+            task_id,
+            event_type: TimeEntryEventType::Pause,
+            at,
+            start_event_id: Some(start_event_id),
+            payload: None,
+        }
+    }
+
+    /// 再開イベントを作成
+    pub fn resume(task_id: TaskId, start_event_id: i64) -> Self {
+        Self::resume_at(task_id, start_event_id, Utc::now())
+    }
+
+    /// 指定時刻の再開イベントを作成
+    pub fn resume_at(task_id: TaskId, start_event_id: i64, at: DateTime<Utc>) -> Self {
+        Self {
+            id: None,
+            task_id,
+            event_type: TimeEntryEventType::Resume,
+            at,
+            start_event_id: Some(start_event_id),
+            payload: None,
+        }
+    }
+
+    /// 保存済みの値からイベントを復元
+    pub fn from_stored(
+        id: i64,
+        task_id: TaskId,
+        event_type: TimeEntryEventType,
+        at: DateTime<Utc>,
+        start_event_id: Option<i64>,
+        payload: Option<String>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            task_id,
+            event_type,
+            at,
+            start_event_id,
+            payload,
+        }
+    }
+
+    /// 補正理由を設定（payload を `{"reason": ...}` に置き換える）
+    pub fn with_repair_reason(mut self, reason: RepairReason) -> Self {
+        self.payload = Some(serde_json::json!({ "reason": reason.as_str() }).to_string());
+        self
+    }
+
     /// IDを設定（保存後に使用）
     pub fn with_id(mut self, id: i64) -> Self {
         self.id = Some(id);
@@ -143,8 +329,53 @@ impl TimeEntryEvent {
     pub fn is_annotate(&self) -> bool {
         matches!(self.event_type, TimeEntryEventType::Annotate)
     }
+
+    pub fn is_adjust(&self) -> bool {
+        matches!(self.event_type, TimeEntryEventType::Adjust)
+    }
+
+    pub fn is_void(&self) -> bool {
+        matches!(self.event_type, TimeEntryEventType::Void)
+    }
+
+    pub fn is_unvoid(&self) -> bool {
+        matches!(self.event_type, TimeEntryEventType::Unvoid)
+    }
+
+    pub fn is_pause(&self) -> bool {
+        matches!(self.event_type, TimeEntryEventType::Pause)
+    }
+
+    pub fn is_resume(&self) -> bool {
+        matches!(self.event_type, TimeEntryEventType::Resume)
+    }
+
+    /// 補正理由を取得（補正で挿入された開始/停止イベント以外はNone）
+    pub fn repair_reason(&self) -> Option<RepairReason> {
+        if !(self.is_start() || self.is_stop()) {
+            return None;
+        }
+        let value: serde_json::Value = serde_json::from_str(self.payload.as_deref()?).ok()?;
+        value
+            .get("reason")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<RepairReason>().ok())
+    }
+
+    /// 訂正内容を取得（訂正イベント以外はNone）
+    pub fn adjustment(&self) -> Option<TimeEntryAdjustment> {
+        if !self.is_adjust() {
+            return None;
+        }
+        self.payload
+            .as_deref()
+            .and_then(|payload| TimeEntryAdjustment::from_payload(payload).ok())
+    }
 }
 
+/// 一時停止していた区間（一時停止時刻と再開時刻）
+pub type PauseInterval = (DateTime<Utc>, DateTime<Utc>);
+
 /// 時間区間（開始と終了の組み合わせ）
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TimeEntry {
@@ -153,6 +384,11 @@ pub struct TimeEntry {
     start_time: DateTime<Utc>,
     end_time: Option<DateTime<Utc>>,
     duration_in_seconds: Option<i64>,
+    notes: Option<String>,
+    paused_seconds: i64,
+    paused_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pause_intervals: Vec<PauseInterval>,
 }
 
 impl TimeEntry {
@@ -170,9 +406,38 @@ impl TimeEntry {
             start_time,
             end_time,
             duration_in_seconds,
+            notes: None,
+            paused_seconds: 0,
+            paused_at: None,
+            pause_intervals: Vec::new(),
         }
     }
 
+    /// メモを設定（annotateイベントを畳み込んだ内容）
+    pub fn with_notes(mut self, notes: Option<String>) -> Self {
+        self.notes = notes;
+        self
+    }
+
+    /// 一時停止の状態を設定（pause/resumeイベントを畳み込んだ内容）
+    ///
+    /// `paused_seconds` は再開済み（停止済みの区間では停止時刻まで）の一時停止時間の合計、
+    /// `paused_at` は実行中の区間が現在一時停止している場合の一時停止時刻
+    pub fn with_pause(mut self, paused_seconds: i64, paused_at: Option<DateTime<Utc>>) -> Self {
+        self.paused_seconds = paused_seconds;
+        self.paused_at = if self.is_running() { paused_at } else { None };
+        self.duration_in_seconds = self
+            .end_time
+            .map(|end| (end - self.start_time).num_seconds() - paused_seconds);
+        self
+    }
+
+    /// 一時停止していた区間を設定（再開済み、または停止時刻までの区間。区間内に切り詰め済み）
+    pub fn with_pause_intervals(mut self, pause_intervals: Vec<PauseInterval>) -> Self {
+        self.pause_intervals = pause_intervals;
+        self
+    }
+
     // Getters
     pub fn task_id(&self) -> TaskId {
         self.task_id
@@ -194,6 +459,26 @@ impl TimeEntry {
         self.duration_in_seconds
     }
 
+    pub fn notes(&self) -> Option<&str> {
+        self.notes.as_deref()
+    }
+
+    pub fn paused_seconds(&self) -> i64 {
+        self.paused_seconds
+    }
+
+    pub fn paused_at(&self) -> Option<DateTime<Utc>> {
+        self.paused_at
+    }
+
+    pub fn pause_intervals(&self) -> &[PauseInterval] {
+        &self.pause_intervals
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
     pub fn is_running(&self) -> bool {
         self.end_time.is_none()
     }
@@ -202,12 +487,42 @@ impl TimeEntry {
         self.end_time.is_some()
     }
 
-    /// 経過時間を秒単位で取得（実行中の場合は現在時刻まで）
+    /// 経過時間を秒単位で取得（実行中の場合は現在時刻まで、一時停止中の場合は一時停止時刻まで）
+    /// 一時停止していた時間は含まない
     pub fn elapsed_seconds(&self) -> i64 {
-        match self.end_time {
-            Some(end) => (end - self.start_time).num_seconds(),
-            None => (Utc::now() - self.start_time).num_seconds(),
+        let until = self.end_time.or(self.paused_at).unwrap_or_else(Utc::now);
+        (until - self.start_time).num_seconds() - self.paused_seconds
+    }
+
+    /// 指定範囲に含まれる作業時間を秒単位で取得（実行中の場合は now まで）
+    /// 一時停止していた時間は一時停止していた区間と範囲の重なりだけを差し引く
+    /// （区間が設定されていない場合は区間の長さに応じて按分する）
+    pub fn seconds_within(&self, start: DateTime<Utc>, end: DateTime<Utc>, now: DateTime<Utc>) -> i64 {
+        let until = self.end_time.or(self.paused_at).unwrap_or(now);
+        let from = self.start_time.max(start);
+        let to = until.min(end);
+        let span = (to - from).num_seconds();
+        if span <= 0 {
+            return 0;
+        }
+        if self.paused_seconds == 0 {
+            return span;
         }
+
+        if !self.pause_intervals.is_empty() {
+            let paused: i64 = self
+                .pause_intervals
+                .iter()
+                .map(|(pause_from, pause_to)| ((*pause_to).min(to) - (*pause_from).max(from)).num_seconds().max(0))
+                .sum();
+            return span - paused;
+        }
+
+        let total = (until - self.start_time).num_seconds();
+        if total <= 0 {
+            return span;
+        }
+        span - (self.paused_seconds as f64 * span as f64 / total as f64).round() as i64
     }
 
     /// 経過時間を時:分:秒の形式で取得
@@ -286,6 +601,18 @@ mod tests {
         assert_eq!(entry.duration_in_seconds(), Some(5400)); // 1.5時間 = 5400秒
         assert!(!entry.is_running());
         assert!(entry.is_completed());
+        assert!(entry.notes().is_none());
+    }
+
+    #[test]
+    fn タイムエントリにメモが設定できること() {
+        let task_id = TaskId::new(1).unwrap();
+        let start_time = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
+
+        let entry = TimeEntry::new(task_id, 123, start_time, None)
+            .with_notes(Some("仕様確認\nレビュー対応".to_string()));
+
+        assert_eq!(entry.notes(), Some("仕様確認\nレビュー対応"));
     }
 
     #[test]
@@ -311,10 +638,14 @@ mod tests {
         assert_eq!(TimeEntryEventType::Start.as_str(), "start");
         assert_eq!(TimeEntryEventType::Stop.as_str(), "stop");
         assert_eq!(TimeEntryEventType::Annotate.as_str(), "annotate");
+        assert_eq!(TimeEntryEventType::Adjust.as_str(), "adjust");
 
         assert_eq!(TimeEntryEventType::from_str("start").unwrap(), TimeEntryEventType::Start);
         assert_eq!(TimeEntryEventType::from_str("stop").unwrap(), TimeEntryEventType::Stop);
         assert_eq!(TimeEntryEventType::from_str("annotate").unwrap(), TimeEntryEventType::Annotate);
+        assert_eq!(TimeEntryEventType::from_str("adjust").unwrap(), TimeEntryEventType::Adjust);
+        assert_eq!(TimeEntryEventType::from_str("void").unwrap(), TimeEntryEventType::Void);
+        assert_eq!(TimeEntryEventType::from_str("unvoid").unwrap(), TimeEntryEventType::Unvoid);
         
         assert!(TimeEntryEventType::from_str("invalid").is_err());
     }
@@ -330,4 +661,116 @@ mod tests {
         let stop_event = TimeEntryEvent::stop_at(task_id, 123, specific_time);
         assert_eq!(stop_event.at(), specific_time);
     }
+    #[test]
+    fn 訂正イベント作成が正しく動作すること() {
+        let task_id = TaskId::new(1).unwrap();
+        let adjustment = TimeEntryAdjustment {
+            start_time: Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+            end_time: Some(Utc.with_ymd_and_hms(2024, 1, 1, 10, 15, 0).unwrap()),
+        };
+        let event = TimeEntryEvent::adjust(task_id, 123, adjustment);
+
+        assert!(event.is_adjust());
+        assert_eq!(event.start_event_id(), Some(123));
+        assert_eq!(event.adjustment(), Some(adjustment));
+        assert!(event.payload().unwrap().contains(r#""start_time":"2024-01-01T09:00:00Z""#));
+    }
+
+    #[test]
+    fn 終了時刻なしの訂正内容が復元できること() {
+        let adjustment = TimeEntryAdjustment {
+            start_time: Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+            end_time: None,
+        };
+
+        let restored = TimeEntryAdjustment::from_payload(&adjustment.to_payload()).unwrap();
+        assert_eq!(restored, adjustment);
+        assert!(TimeEntryAdjustment::from_payload("{}").is_err());
+    }
+    #[test]
+    fn 無効化イベント作成が正しく動作すること() {
+        let task_id = TaskId::new(1).unwrap();
+
+        let void_event = TimeEntryEvent::void(task_id, 123);
+        assert!(void_event.is_void());
+        assert!(!void_event.is_unvoid());
+        assert_eq!(void_event.start_event_id(), Some(123));
+
+        let unvoid_event = TimeEntryEvent::unvoid(task_id, 123);
+        assert!(unvoid_event.is_unvoid());
+        assert_eq!(unvoid_event.event_type().as_str(), "unvoid");
+    }
+
+    #[test]
+    fn 補正理由付きイベントが正しく動作すること() {
+        let task_id = TaskId::new(1).unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        let event = TimeEntryEvent::stop_at(task_id, 123, at).with_repair_reason(RepairReason::ImplicitStop);
+        assert_eq!(event.repair_reason(), Some(RepairReason::ImplicitStop));
+        assert_eq!(event.payload(), Some(r#"{"reason":"implicit_stop"}"#));
+
+        // 補正理由のないイベントや注釈イベントはNone
+        assert!(TimeEntryEvent::start_at(task_id, at).repair_reason().is_none());
+        assert!(TimeEntryEvent::annotate(task_id, 123, "メモ".to_string()).repair_reason().is_none());
+
+        assert_eq!("auto_cutoff".parse::<RepairReason>().unwrap(), RepairReason::AutoCutoff);
+        assert_eq!(RepairReason::BackfillStart.as_str(), "backfill_start");
+        assert!("invalid".parse::<RepairReason>().is_err());
+    }
+
+    #[test]
+    fn 一時停止時間が作業時間から除外されること() {
+        let task_id = TaskId::new(1).unwrap();
+        let start_time = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
+        let end_time = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        let entry = TimeEntry::new(task_id, 123, start_time, Some(end_time)).with_pause(15 * 60, None);
+        assert_eq!(entry.duration_in_seconds(), Some(105 * 60));
+        assert_eq!(entry.elapsed_seconds(), 105 * 60);
+        assert!(!entry.is_paused());
+
+        // 実行中に一時停止している場合は一時停止時刻で経過時間が止まる
+        let paused_at = Utc.with_ymd_and_hms(2024, 1, 1, 11, 0, 0).unwrap();
+        let entry = TimeEntry::new(task_id, 123, start_time, None).with_pause(10 * 60, Some(paused_at));
+        assert!(entry.is_paused());
+        assert_eq!(entry.paused_at(), Some(paused_at));
+        assert_eq!(entry.elapsed_seconds(), 50 * 60);
+        assert!(entry.duration_in_seconds().is_none());
+
+        let event = TimeEntryEvent::pause_at(task_id, 123, paused_at);
+        assert!(event.is_pause());
+        assert_eq!(event.start_event_id(), Some(123));
+        assert!(TimeEntryEvent::resume(task_id, 123).is_resume());
+        assert_eq!(TimeEntryEventType::from_str("resume").unwrap(), TimeEntryEventType::Resume);
+    }
+
+    #[test]
+    fn 指定範囲に含まれる作業時間が取得できること() {
+        let task_id = TaskId::new(1).unwrap();
+        let at = |day, hour| Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap();
+        let now = at(3, 12);
+
+        // 22時から翌2時までの区間は0時で2時間ずつに分かれる
+        let entry = TimeEntry::new(task_id, 1, at(1, 22), Some(at(2, 2)));
+        assert_eq!(entry.seconds_within(at(1, 0), at(2, 0), now), 2 * 3600);
+        assert_eq!(entry.seconds_within(at(2, 0), at(3, 0), now), 2 * 3600);
+        assert_eq!(entry.seconds_within(at(3, 0), at(4, 0), now), 0);
+
+        // 翌日の0時から1時までの一時停止は翌日からだけ差し引かれる
+        let paused = entry
+            .clone()
+            .with_pause(3600, None)
+            .with_pause_intervals(vec![(at(2, 0), at(2, 1))]);
+        assert_eq!(paused.seconds_within(at(1, 0), at(2, 0), now), 2 * 3600);
+        assert_eq!(paused.seconds_within(at(2, 0), at(3, 0), now), 3600);
+
+        // 一時停止の区間が設定されていない場合は区間の長さに応じて按分される
+        let paused = entry.clone().with_pause(3600, None);
+        assert_eq!(paused.seconds_within(at(1, 0), at(2, 0), now), 5400);
+
+        // 実行中の区間は now まで
+        let running = TimeEntry::new(task_id, 2, at(3, 10), None);
+        assert_eq!(running.seconds_within(at(3, 0), at(4, 0), now), 2 * 3600);
+    }
 }
@@ -0,0 +1,52 @@
+use crate::domain::entities::{PomodoroCompletion, TimeEntry};
+use crate::domain::value_objects::{ProjectId, TagId, TaskId};
+
+/// ドメインイベント（変更の保存に成功した後に発行される）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainEvent {
+    ProjectCreated { project_id: ProjectId },
+    ProjectRenamed { project_id: ProjectId },
+    /// プロジェクトをアーカイブした（with_tasks が true の場合は所属タスクもアーカイブ済み）
+    ProjectArchived { project_id: ProjectId, with_tasks: bool },
+    ProjectRestored { project_id: ProjectId },
+    TaskCreated { task_id: TaskId, project_id: ProjectId },
+    TaskRenamed { task_id: TaskId },
+    TaskMoved { task_id: TaskId, from_project_id: ProjectId, to_project_id: ProjectId },
+    TaskArchived { task_id: TaskId },
+    TaskRestored { task_id: TaskId },
+    TagAddedToTask { task_id: TaskId, tag_id: TagId },
+    TagRemovedFromTask { task_id: TaskId, tag_id: TagId },
+    /// タイマーを開始した（開始した実行中の時間エントリを保持）
+    TimerStarted(TimeEntry),
+    /// タイマーを停止した（停止した時間エントリを保持）
+    TimerStopped(TimeEntry),
+    TimerPaused { task_id: TaskId },
+    TimerResumed { task_id: TaskId },
+    EntryAdded { task_id: TaskId },
+    /// 時間エントリの開始・終了時刻を訂正した（訂正後の時間エントリを保持）
+    EntryCorrected(TimeEntry),
+    EntryAnnotated(TimeEntry),
+    EntryDeleted { task_id: TaskId, start_event_id: i64 },
+    EntryRestored(TimeEntry),
+    PomodoroCompleted(PomodoroCompletion),
+    /// フェーズの切り替えでタイマーを操作できなかったため、ポモドーロセッションを終了した
+    PomodoroEnded { task_id: TaskId, reason: String },
+}
+
+impl DomainEvent {
+    /// 時間エントリに変更があったタスク（時間エントリに関係しないイベントはNone）
+    pub fn entries_changed_task(&self) -> Option<TaskId> {
+        match self {
+            DomainEvent::TimerStarted(entry)
+            | DomainEvent::TimerStopped(entry)
+            | DomainEvent::EntryCorrected(entry)
+            | DomainEvent::EntryAnnotated(entry)
+            | DomainEvent::EntryRestored(entry) => Some(entry.task_id()),
+            DomainEvent::TimerPaused { task_id }
+            | DomainEvent::TimerResumed { task_id }
+            | DomainEvent::EntryAdded { task_id }
+            | DomainEvent::EntryDeleted { task_id, .. } => Some(*task_id),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+// ドメインイベント - ユースケースで永続化された変更を他のコンポーネントに伝える
+
+pub mod domain_event;
+
+pub use domain_event::*;
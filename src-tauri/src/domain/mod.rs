@@ -5,10 +5,12 @@ pub mod entities;
 pub mod value_objects;
 pub mod repositories;
 pub mod services;
+pub mod events;
 
 // ドメイン全体で使用される型やトレイトの再エクスポート
 pub use entities::*;
 pub use value_objects::*;
 pub use repositories::*;
 pub use services::*;
+pub use events::*;
 
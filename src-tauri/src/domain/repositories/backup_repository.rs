@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// バックアップ文書の形式名（バックアップ以外のJSONを取り込まないための目印）
+pub const BACKUP_FORMAT: &str = "time-tracker-go-backup";
+
+/// 1テーブル分のバックアップ（全行を列名→値のオブジェクトで保持）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupTable {
+    pub name: String,
+    pub rows: Vec<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// 全イベント履歴のバックアップ文書
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Backup {
+    pub format: String,
+    /// 書き出し元のスキーマバージョン（適用済みマイグレーションの最大バージョン）
+    pub schema_version: i64,
+    pub exported_at: DateTime<Utc>,
+    /// 外部キーの参照先が先に来る順序
+    pub tables: Vec<BackupTable>,
+}
+
+/// バックアップリポジトリトレイト
+#[async_trait]
+pub trait BackupRepository: Send + Sync {
+    /// 現在のスキーマバージョンを取得
+    async fn schema_version(&self) -> anyhow::Result<i64>;
+
+    /// 全テーブルの全行を取得（スキーマ管理用のテーブルは除く）
+    async fn export_tables(&self) -> anyhow::Result<Vec<BackupTable>>;
+
+    /// 空のデータベースに全テーブルの行を取り込む
+    ///
+    /// スキーマバージョンの不一致、空でないデータベース、テーブル・列の過不足、外部キー違反の
+    /// いずれかがあれば何も取り込まずにエラーを返す。
+    async fn import_tables(&self, schema_version: i64, tables: &[BackupTable]) -> anyhow::Result<()>;
+}
@@ -4,10 +4,22 @@
 pub mod project_repository;
 pub mod task_repository;
 pub mod time_entry_repository;
+pub mod tag_repository;
+pub mod pomodoro_repository;
+pub mod report_repository;
+pub mod timeline_repository;
+pub mod rounding_repository;
+pub mod backup_repository;
 
 pub use project_repository::{ProjectRepository};
 pub use task_repository::{TaskRepository};
-pub use time_entry_repository::{TimeEntryRepository};
+pub use time_entry_repository::{TimeEntryCursor, TimeEntryQuery, TimeEntryRepository};
+pub use tag_repository::{TagRepository};
+pub use pomodoro_repository::{PomodoroRepository};
+pub use report_repository::{ReportDimension, ReportGranularity, ReportKey, ReportPeriod, ReportQuery, ReportRepository, ReportRow};
+pub use timeline_repository::{TimelineEntry, TimelineRepository};
+pub use rounding_repository::{RoundingRepository};
+pub use backup_repository::{Backup, BackupRepository, BackupTable, BACKUP_FORMAT};
 
 #[cfg(test)]
 pub use project_repository::tests;
@@ -15,4 +27,10 @@ pub use project_repository::tests;
 pub use task_repository::tests as task_tests;
 #[cfg(test)]
 pub use time_entry_repository::tests as time_entry_tests;
+#[cfg(test)]
+pub use tag_repository::tests as tag_tests;
+#[cfg(test)]
+pub use pomodoro_repository::tests as pomodoro_tests;
+#[cfg(test)]
+pub use rounding_repository::tests as rounding_tests;
 
@@ -0,0 +1,48 @@
+use crate::domain::entities::PomodoroCompletion;
+use crate::domain::value_objects::TaskId;
+use async_trait::async_trait;
+
+/// ポモドーロリポジトリトレイト
+#[async_trait]
+pub trait PomodoroRepository: Send + Sync {
+    /// 完了したポモドーロを保存
+    async fn save_completion(&self, completion: &PomodoroCompletion) -> anyhow::Result<PomodoroCompletion>;
+
+    /// 指定タスクの完了したポモドーロ数を取得
+    async fn count_completions_by_task(&self, task_id: TaskId) -> anyhow::Result<usize>;
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+pub mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    // テスト用のインメモリリポジトリ実装
+    #[derive(Debug, Default, Clone)]
+    pub struct InMemoryPomodoroRepository {
+        completions: Arc<Mutex<Vec<PomodoroCompletion>>>,
+    }
+
+    impl InMemoryPomodoroRepository {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[async_trait]
+    impl PomodoroRepository for InMemoryPomodoroRepository {
+        async fn save_completion(&self, completion: &PomodoroCompletion) -> anyhow::Result<PomodoroCompletion> {
+            let mut completions = self.completions.lock().await;
+            let saved = completion.clone().with_id(completions.len() as i64 + 1);
+            completions.push(saved.clone());
+            Ok(saved)
+        }
+
+        async fn count_completions_by_task(&self, task_id: TaskId) -> anyhow::Result<usize> {
+            let completions = self.completions.lock().await;
+            Ok(completions.iter().filter(|c| c.task_id() == task_id).count())
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use crate::domain::services::{LocalCalendar, RoundingPolicy};
+use crate::domain::value_objects::{ProjectId, TagId, TaskId};
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, Utc};
+use std::str::FromStr;
+
+/// レポートの期間の単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportGranularity {
+    Day,
+    /// カレンダーの週の開始曜日から始まる週
+    Week,
+    Month,
+}
+
+impl ReportGranularity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportGranularity::Day => "day",
+            ReportGranularity::Week => "week",
+            ReportGranularity::Month => "month",
+        }
+    }
+}
+
+impl FromStr for ReportGranularity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "day" => Ok(ReportGranularity::Day),
+            "week" => Ok(ReportGranularity::Week),
+            "month" => Ok(ReportGranularity::Month),
+            _ => Err(anyhow::anyhow!("Invalid report granularity: {}", s)),
+        }
+    }
+}
+
+/// レポートのグループ化の軸
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportDimension {
+    Project,
+    Task,
+    Tag,
+}
+
+impl ReportDimension {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportDimension::Project => "project",
+            ReportDimension::Task => "task",
+            ReportDimension::Tag => "tag",
+        }
+    }
+}
+
+impl FromStr for ReportDimension {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "project" => Ok(ReportDimension::Project),
+            "task" => Ok(ReportDimension::Task),
+            "tag" => Ok(ReportDimension::Tag),
+            _ => Err(anyhow::anyhow!("Invalid report dimension: {}", s)),
+        }
+    }
+}
+
+/// レポートの集計条件
+///
+/// 時間エントリはローカル日付の区切りで分割して各期間に計上し、タグは開始時点で付与されていたものに計上する。
+/// 複数のタグが付与されたエントリはタグごとに計上されるため、タグ別の合計は全体の合計を超えることがある。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportQuery {
+    /// 集計期間の開始（この時刻を含む）
+    pub start: DateTime<Utc>,
+    /// 集計期間の終了（この時刻を含まない）
+    pub end: DateTime<Utc>,
+    pub granularity: ReportGranularity,
+    /// 期間の内側でグループ化する軸（指定順に入れ子にする）
+    pub group_by: Vec<ReportDimension>,
+    /// 空の場合は絞り込まない
+    pub project_ids: Vec<ProjectId>,
+    pub task_ids: Vec<TaskId>,
+    /// いずれかのタグが付与されていたエントリに絞り込む
+    pub tag_ids: Vec<TagId>,
+    /// アーカイブ済みのプロジェクト・タスクのエントリも含めるか
+    pub include_archived: bool,
+    /// 日・週・月の区切りに使うカレンダー
+    pub calendar: LocalCalendar,
+}
+
+impl ReportQuery {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.start >= self.end {
+            return Err(anyhow::anyhow!("Report start must be before end"));
+        }
+        for (i, dimension) in self.group_by.iter().enumerate() {
+            if self.group_by[..i].contains(dimension) {
+                return Err(anyhow::anyhow!("Duplicate report dimension: {}", dimension.as_str()));
+            }
+        }
+        Ok(())
+    }
+
+    /// 集計期間をローカルの日・週・月で区切る（最初と最後の期間は集計期間の開始・終了で切り詰める）
+    pub fn periods(&self) -> Vec<ReportPeriod> {
+        let first = self.calendar.date_of(self.start);
+        let mut date = match self.granularity {
+            ReportGranularity::Day => first,
+            ReportGranularity::Week => self.calendar.start_of_week(first),
+            ReportGranularity::Month => first.with_day(1).unwrap(),
+        };
+
+        let mut periods = Vec::new();
+        loop {
+            let next = match self.granularity {
+                ReportGranularity::Day => date + Duration::days(1),
+                ReportGranularity::Week => date + Duration::days(7),
+                ReportGranularity::Month => date + Months::new(1),
+            };
+            let start = self.calendar.start_of_day(date).max(self.start);
+            if start >= self.end {
+                break;
+            }
+            periods.push(ReportPeriod {
+                date,
+                start,
+                end: self.calendar.start_of_day(next).min(self.end),
+            });
+            date = next;
+        }
+        periods
+    }
+}
+
+/// レポートの期間（ローカルの開始日と、UTCでの範囲）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportPeriod {
+    pub date: NaiveDate,
+    pub start: DateTime<Utc>,
+    /// この時刻を含まない
+    pub end: DateTime<Utc>,
+}
+
+/// レポートのグループのキー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportKey {
+    /// 期間の開始日
+    Period(NaiveDate),
+    Project(ProjectId),
+    Task(TaskId),
+    /// None はタグの付与されていないエントリ
+    Tag(Option<TagId>),
+}
+
+/// 集計結果の1行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportRow {
+    /// 先頭は期間、以降は集計を指定した軸の順
+    pub keys: Vec<ReportKey>,
+    /// keys と同じ並びのグループ名（期間とタグなしはNone）
+    pub names: Vec<Option<String>>,
+    pub total_seconds: i64,
+    /// 丸め設定を適用した合計
+    pub rounded_seconds: i64,
+    pub entry_count: usize,
+}
+
+/// レポート集計リポジトリトレイト
+#[async_trait]
+pub trait ReportRepository: Send + Sync {
+    /// 期間と指定した軸でグループ化して集計する（実行中のエントリは now までの経過時間で計上する）
+    /// 期間をまたぐエントリは各期間に含まれる部分の時間で計上し、件数は各期間で数える
+    /// 丸めた合計は、エントリをローカル日付で分割した部分（日単位の規則ではタスクごとの1日の合計）ごとに丸めて求める
+    async fn aggregate(
+        &self,
+        query: &ReportQuery,
+        dimensions: &[ReportDimension],
+        rounding: &RoundingPolicy,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<ReportRow>>;
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Weekday};
+
+    fn query(granularity: ReportGranularity, calendar: LocalCalendar) -> ReportQuery {
+        ReportQuery {
+            start: Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+            granularity,
+            group_by: Vec::new(),
+            project_ids: Vec::new(),
+            task_ids: Vec::new(),
+            tag_ids: Vec::new(),
+            include_archived: false,
+            calendar,
+        }
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn 週の期間が週の開始曜日で区切られること() {
+        let calendar = LocalCalendar::from_name("UTC", Weekday::Sun).unwrap();
+        let periods = query(ReportGranularity::Week, calendar).periods();
+
+        // 2025-03-01 は土曜日なので、最初の週は 2/23（日）始まりで 3/1 から切り詰められる
+        assert_eq!(periods.len(), 6);
+        assert_eq!(periods[0].date, date(2, 23));
+        assert_eq!(periods[0].start, Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap());
+        assert_eq!(periods[0].end, Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap());
+        assert_eq!(periods[5].date, date(3, 30));
+        assert_eq!(periods[5].end, Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn 夏時間の切り替えをまたぐ日の期間が正しいこと() {
+        let calendar = LocalCalendar::from_name("America/New_York", Weekday::Mon).unwrap();
+        let mut query = query(ReportGranularity::Day, calendar);
+        query.start = calendar.start_of_day(date(3, 8));
+        query.end = calendar.start_of_day(date(3, 11));
+
+        let hours: Vec<_> = query
+            .periods()
+            .iter()
+            .map(|p| (p.date, (p.end - p.start).num_hours()))
+            .collect();
+        assert_eq!(hours, vec![(date(3, 8), 24), (date(3, 9), 23), (date(3, 10), 24)]);
+    }
+
+    #[test]
+    fn 月の期間がローカルの月初で区切られること() {
+        let calendar = LocalCalendar::from_name("Asia/Tokyo", Weekday::Mon).unwrap();
+        let periods = query(ReportGranularity::Month, calendar).periods();
+
+        // UTC 3/1 0:00 はJSTでは 3/1 9:00、UTC 4/1 0:00 はJSTでは 4/1 9:00
+        assert_eq!(periods.iter().map(|p| p.date).collect::<Vec<_>>(), vec![date(3, 1), date(4, 1)]);
+        assert_eq!(periods[0].end, Utc.with_ymd_and_hms(2025, 3, 31, 15, 0, 0).unwrap());
+        assert_eq!(periods[1].start, Utc.with_ymd_and_hms(2025, 3, 31, 15, 0, 0).unwrap());
+    }
+}
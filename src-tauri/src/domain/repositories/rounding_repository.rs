@@ -0,0 +1,61 @@
+use crate::domain::services::RoundingPolicy;
+use crate::domain::value_objects::{ProjectId, RoundingRule};
+use async_trait::async_trait;
+
+/// 丸め設定リポジトリトレイト
+#[async_trait]
+pub trait RoundingRepository: Send + Sync {
+    /// 丸め設定を取得（既定の規則が保存されていなければ丸めない）
+    async fn find_policy(&self) -> anyhow::Result<RoundingPolicy>;
+
+    /// 規則を保存（project_id が None の場合はワークスペース全体の既定、既存の規則は置き換える）
+    async fn save_rule(&self, project_id: Option<ProjectId>, rule: RoundingRule) -> anyhow::Result<()>;
+
+    /// プロジェクトの規則を削除（既定の規則が適用されるようになる）
+    async fn delete_project_rule(&self, project_id: ProjectId) -> anyhow::Result<()>;
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+pub mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    // テスト用のインメモリリポジトリ実装
+    #[derive(Debug, Default, Clone)]
+    pub struct InMemoryRoundingRepository {
+        policy: Arc<Mutex<RoundingPolicy>>,
+    }
+
+    impl InMemoryRoundingRepository {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[async_trait]
+    impl RoundingRepository for InMemoryRoundingRepository {
+        async fn find_policy(&self) -> anyhow::Result<RoundingPolicy> {
+            Ok(self.policy.lock().await.clone())
+        }
+
+        async fn save_rule(&self, project_id: Option<ProjectId>, rule: RoundingRule) -> anyhow::Result<()> {
+            let mut policy = self.policy.lock().await;
+            match project_id {
+                None => policy.default_rule = rule,
+                Some(project_id) => {
+                    policy.project_rules.retain(|(id, _)| *id != project_id);
+                    policy.project_rules.push((project_id, rule));
+                    policy.project_rules.sort_by_key(|(id, _)| i64::from(*id));
+                }
+            }
+            Ok(())
+        }
+
+        async fn delete_project_rule(&self, project_id: ProjectId) -> anyhow::Result<()> {
+            self.policy.lock().await.project_rules.retain(|(id, _)| *id != project_id);
+            Ok(())
+        }
+    }
+}
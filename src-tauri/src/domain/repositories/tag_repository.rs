@@ -0,0 +1,218 @@
+use crate::domain::entities::{Tag, TaskTagEvent};
+use crate::domain::value_objects::{TagId, TaskId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// タグリポジトリトレイト
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    /// タグを保存（新規作成）
+    async fn save(&self, tag: &Tag) -> anyhow::Result<()>;
+
+    /// タグIDで検索
+    async fn find_by_id(&self, id: TagId) -> anyhow::Result<Option<Tag>>;
+
+    /// タグ名で検索（完全一致）
+    async fn find_by_name(&self, name: &str) -> anyhow::Result<Option<Tag>>;
+
+    /// 全てのタグを取得（名前順）
+    async fn find_all(&self) -> anyhow::Result<Vec<Tag>>;
+
+    /// 次に使用可能なタグIDを生成
+    async fn next_id(&self) -> anyhow::Result<TagId>;
+
+    /// タスクタグイベントを保存
+    async fn save_task_tag_event(&self, event: &TaskTagEvent) -> anyhow::Result<TaskTagEvent>;
+
+    /// 指定タスクに現在付与されているタグを取得（名前順）
+    async fn find_by_task(&self, task_id: TaskId) -> anyhow::Result<Vec<Tag>>;
+
+    /// 指定時刻の時点で指定タスクに付与されていたタグを取得（名前順）
+    async fn find_by_task_at(&self, task_id: TaskId, at: DateTime<Utc>) -> anyhow::Result<Vec<Tag>>;
+
+    /// 指定タスクのタスクタグイベントを取得（発生日時順）
+    async fn find_events_by_task(&self, task_id: TaskId) -> anyhow::Result<Vec<TaskTagEvent>>;
+
+    /// 指定タグが現在付与されているタスクIDを取得
+    async fn find_task_ids_by_tag(&self, tag_id: TagId) -> anyhow::Result<Vec<TaskId>>;
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+pub mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    // テスト用のインメモリリポジトリ実装
+    #[derive(Debug, Default, Clone)]
+    pub struct InMemoryTagRepository {
+        tags: Arc<Mutex<HashMap<TagId, Tag>>>,
+        events: Arc<Mutex<Vec<TaskTagEvent>>>,
+        next_id: Arc<Mutex<i64>>,
+    }
+
+    impl InMemoryTagRepository {
+        pub fn new() -> Self {
+            Self {
+                tags: Arc::new(Mutex::new(HashMap::new())),
+                events: Arc::new(Mutex::new(Vec::new())),
+                next_id: Arc::new(Mutex::new(1)),
+            }
+        }
+
+        /// タスクタグイベントの共有ハンドル（インメモリの時間エントリリポジトリのタグ別クエリで使う）
+        pub fn task_tag_events(&self) -> Arc<Mutex<Vec<TaskTagEvent>>> {
+            self.events.clone()
+        }
+
+        /// 現在付与中の(タスク, タグ)の組を畳み込みで導出
+        async fn current_pairs(&self) -> Vec<(TaskId, TagId)> {
+            self.pairs_at(DateTime::<Utc>::MAX_UTC).await
+        }
+
+        /// 指定時刻の時点で付与されていた(タスク, タグ)の組を畳み込みで導出
+        async fn pairs_at(&self, at: DateTime<Utc>) -> Vec<(TaskId, TagId)> {
+            let events = self.events.lock().await;
+            let mut latest: HashMap<(TaskId, TagId), &TaskTagEvent> = HashMap::new();
+
+            for event in events.iter().filter(|event| event.at() <= at) {
+                let key = (event.task_id(), event.tag_id());
+                let replace = match latest.get(&key) {
+                    Some(current) => (event.at(), event.id()) >= (current.at(), current.id()),
+                    None => true,
+                };
+                if replace {
+                    latest.insert(key, event);
+                }
+            }
+
+            latest
+                .into_iter()
+                .filter(|(_, event)| event.is_add())
+                .map(|(key, _)| key)
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl TagRepository for InMemoryTagRepository {
+        async fn save(&self, tag: &Tag) -> anyhow::Result<()> {
+            let mut tags = self.tags.lock().await;
+            if tags.values().any(|t| t.name() == tag.name() && t.id() != tag.id()) {
+                return Err(anyhow::anyhow!("UNIQUE constraint failed: tags.name"));
+            }
+            tags.insert(tag.id(), tag.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: TagId) -> anyhow::Result<Option<Tag>> {
+            let tags = self.tags.lock().await;
+            Ok(tags.get(&id).cloned())
+        }
+
+        async fn find_by_name(&self, name: &str) -> anyhow::Result<Option<Tag>> {
+            let tags = self.tags.lock().await;
+            Ok(tags.values().find(|t| t.name() == name).cloned())
+        }
+
+        async fn find_all(&self) -> anyhow::Result<Vec<Tag>> {
+            let tags = self.tags.lock().await;
+            let mut result: Vec<Tag> = tags.values().cloned().collect();
+            result.sort_by(|a, b| a.name().cmp(b.name()));
+            Ok(result)
+        }
+
+        async fn next_id(&self) -> anyhow::Result<TagId> {
+            let mut next_id = self.next_id.lock().await;
+            let id = *next_id;
+            *next_id += 1;
+            TagId::new(id)
+        }
+
+        async fn save_task_tag_event(&self, event: &TaskTagEvent) -> anyhow::Result<TaskTagEvent> {
+            let mut events = self.events.lock().await;
+            let saved_event = event.clone().with_id(events.len() as i64 + 1);
+            events.push(saved_event.clone());
+            Ok(saved_event)
+        }
+
+        async fn find_by_task(&self, task_id: TaskId) -> anyhow::Result<Vec<Tag>> {
+            let pairs = self.current_pairs().await;
+            let tags = self.tags.lock().await;
+            let mut result: Vec<Tag> = pairs
+                .into_iter()
+                .filter(|(t, _)| *t == task_id)
+                .filter_map(|(_, tag_id)| tags.get(&tag_id).cloned())
+                .collect();
+            result.sort_by(|a, b| a.name().cmp(b.name()));
+            Ok(result)
+        }
+
+        async fn find_by_task_at(&self, task_id: TaskId, at: DateTime<Utc>) -> anyhow::Result<Vec<Tag>> {
+            let pairs = self.pairs_at(at).await;
+            let tags = self.tags.lock().await;
+            let mut result: Vec<Tag> = pairs
+                .into_iter()
+                .filter(|(t, _)| *t == task_id)
+                .filter_map(|(_, tag_id)| tags.get(&tag_id).cloned())
+                .collect();
+            result.sort_by(|a, b| a.name().cmp(b.name()));
+            Ok(result)
+        }
+
+        async fn find_events_by_task(&self, task_id: TaskId) -> anyhow::Result<Vec<TaskTagEvent>> {
+            let events = self.events.lock().await;
+            let mut result: Vec<TaskTagEvent> = events
+                .iter()
+                .filter(|event| event.task_id() == task_id)
+                .cloned()
+                .collect();
+            result.sort_by_key(|event| (event.at(), event.id()));
+            Ok(result)
+        }
+
+        async fn find_task_ids_by_tag(&self, tag_id: TagId) -> anyhow::Result<Vec<TaskId>> {
+            let pairs = self.current_pairs().await;
+            let mut result: Vec<TaskId> = pairs
+                .into_iter()
+                .filter(|(_, t)| *t == tag_id)
+                .map(|(task_id, _)| task_id)
+                .collect();
+            result.sort_by_key(|id| id.value());
+            Ok(result)
+        }
+    }
+
+    #[tokio::test]
+    async fn タグの保存と取得ができること() {
+        let repository = InMemoryTagRepository::new();
+        let tag_id = repository.next_id().await.unwrap();
+        let tag = Tag::new(tag_id, "meeting".to_string()).unwrap();
+
+        repository.save(&tag).await.unwrap();
+
+        let found = repository.find_by_id(tag_id).await.unwrap().unwrap();
+        assert_eq!(found.name(), "meeting");
+
+        let found_by_name = repository.find_by_name("meeting").await.unwrap().unwrap();
+        assert_eq!(found_by_name.id(), tag_id);
+    }
+
+    #[tokio::test]
+    async fn タグの付与と剥奪が畳み込まれること() {
+        let repository = InMemoryTagRepository::new();
+        let task_id = TaskId::new(1).unwrap();
+        let tag = Tag::new(repository.next_id().await.unwrap(), "client-a".to_string()).unwrap();
+        repository.save(&tag).await.unwrap();
+
+        repository.save_task_tag_event(&TaskTagEvent::add(task_id, tag.id())).await.unwrap();
+        assert_eq!(repository.find_by_task(task_id).await.unwrap().len(), 1);
+        assert_eq!(repository.find_task_ids_by_tag(tag.id()).await.unwrap(), vec![task_id]);
+
+        repository.save_task_tag_event(&TaskTagEvent::remove(task_id, tag.id())).await.unwrap();
+        assert!(repository.find_by_task(task_id).await.unwrap().is_empty());
+        assert!(repository.find_task_ids_by_tag(tag.id()).await.unwrap().is_empty());
+    }
+}
@@ -72,6 +72,11 @@ pub mod tests {
                 next_id: Arc::new(Mutex::new(1)),
             }
         }
+
+        /// タスクの版を共有する（時間区間リポジトリのプロジェクト別クエリ用）
+        pub fn tasks(&self) -> Arc<Mutex<HashMap<TaskId, Vec<Task>>>> {
+            self.tasks.clone()
+        }
     }
 
     #[async_trait]
@@ -1,14 +1,75 @@
 use crate::domain::entities::time_entry::{TimeEntry, TimeEntryEvent};
-use crate::domain::value_objects::TaskId;
+use crate::domain::value_objects::{TagId, TaskId};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
+/// 時間区間のページングカーソル（開始時刻の降順で最後に返した区間を指す）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeEntryCursor {
+    pub start_time: DateTime<Utc>,
+    pub start_event_id: i64,
+}
+
+impl TimeEntryCursor {
+    pub fn from_entry(entry: &TimeEntry) -> Self {
+        Self {
+            start_time: entry.start_time(),
+            start_event_id: entry.start_event_id(),
+        }
+    }
+}
+
+/// 時間区間の検索条件
+///
+/// 開始時刻が`[start, end)`に含まれる区間を、開始時刻の降順で返す
+#[derive(Debug, Clone)]
+pub struct TimeEntryQuery {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub project_id: Option<i64>,
+    pub task_id: Option<TaskId>,
+    pub after: Option<TimeEntryCursor>,
+    pub limit: Option<usize>,
+}
+
+impl TimeEntryQuery {
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self {
+            start,
+            end,
+            project_id: None,
+            task_id: None,
+            after: None,
+            limit: None,
+        }
+    }
+
+    /// 指定区間が検索条件に一致するか（プロジェクト条件は除く）
+    pub fn matches(&self, entry: &TimeEntry) -> bool {
+        entry.start_time() >= self.start
+            && entry.start_time() < self.end
+            && self.task_id.is_none_or(|task_id| entry.task_id() == task_id)
+            && self.after.is_none_or(|cursor| {
+                (entry.start_time(), entry.start_event_id()) < (cursor.start_time, cursor.start_event_id)
+            })
+    }
+}
+
 /// タイムエントリリポジトリトレイト
 #[async_trait]
 pub trait TimeEntryRepository: Send + Sync + Clone {
     /// イベントを保存
     async fn save_event(&self, event: &TimeEntryEvent) -> anyhow::Result<TimeEntryEvent>;
 
+    /// 全イベントを正準順序（at, id の昇順）で取得
+    async fn find_all_events(&self) -> anyhow::Result<Vec<TimeEntryEvent>>;
+
+    /// 実行中区間のハートビートを記録（既存の記録は上書き）
+    async fn save_heartbeat(&self, start_event_id: i64, at: DateTime<Utc>) -> anyhow::Result<()>;
+
+    /// 指定区間の最終ハートビートを取得
+    async fn find_heartbeat(&self, start_event_id: i64) -> anyhow::Result<Option<DateTime<Utc>>>;
+
     /// 実行中の時間区間を全て取得
     async fn find_running_entries(&self) -> anyhow::Result<Vec<TimeEntry>>;
 
@@ -44,6 +105,16 @@ pub trait TimeEntryRepository: Send + Sync + Clone {
         end: DateTime<Utc>,
     ) -> anyhow::Result<Vec<TimeEntry>>;
 
+    /// 指定期間に重複する全タスクの時間区間を取得（期間の前に開始した区間や実行中の区間も含む）
+    async fn find_entries_overlapping_period(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimeEntry>>;
+
+    /// 検索条件に一致する時間区間を取得（開始時刻の降順）
+    async fn find_entries_by_query(&self, query: &TimeEntryQuery) -> anyhow::Result<Vec<TimeEntry>>;
+
     /// 指定タスクの時間区間数を取得
     async fn count_entries_by_task(&self, task_id: TaskId) -> anyhow::Result<usize>;
 
@@ -53,19 +124,42 @@ pub trait TimeEntryRepository: Send + Sync + Clone {
     /// 指定プロジェクトの合計作業時間を取得（秒）
     async fn sum_duration_by_project(&self, project_id: i64) -> anyhow::Result<i64>;
 
+    /// 指定タグの全時間区間を取得（エントリ開始時点のタグ付与状態で判定）
+    async fn find_entries_by_tag(&self, tag_id: TagId) -> anyhow::Result<Vec<TimeEntry>>;
+
+    /// 指定期間・指定タグの時間区間を取得（エントリ開始時点のタグ付与状態で判定）
+    async fn find_entries_by_period_and_tag(
+        &self,
+        tag_id: TagId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimeEntry>>;
+
+    /// 指定タグの合計作業時間を取得（秒）
+    async fn sum_duration_by_tag(&self, tag_id: TagId) -> anyhow::Result<i64>;
+
     /// イベントIDで時間区間を取得
     async fn find_entry_by_start_event_id(&self, start_event_id: i64) -> anyhow::Result<Option<TimeEntry>>;
 
+    /// 無効化された時間区間をイベントIDで取得（無効化されていない場合はNone）
+    async fn find_voided_entry_by_start_event_id(&self, start_event_id: i64) -> anyhow::Result<Option<TimeEntry>>;
+
     /// 最新のN件の時間区間を取得
     async fn find_recent_entries(&self, limit: usize) -> anyhow::Result<Vec<TimeEntry>>;
 
     /// 指定タスクの最新のN件の時間区間を取得
     async fn find_recent_entries_by_task(&self, task_id: TaskId, limit: usize) -> anyhow::Result<Vec<TimeEntry>>;
+
+    /// 最後に停止した時間区間を取得（全タスク対象、終了時刻が最も遅いもの）
+    async fn find_latest_stopped_entry(&self) -> anyhow::Result<Option<TimeEntry>>;
 }
 
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::domain::entities::{Task, TaskTagEvent};
+    use crate::domain::repositories::tag_tests::InMemoryTagRepository;
+    use crate::domain::repositories::task_tests::InMemoryTaskRepository;
 
     use std::collections::HashMap;
     use std::sync::{Arc, Mutex};
@@ -74,17 +168,50 @@ pub mod tests {
     #[derive(Clone)]
     pub struct InMemoryTimeEntryRepository {
         events: Arc<Mutex<Vec<TimeEntryEvent>>>,
+        heartbeats: Arc<Mutex<HashMap<i64, DateTime<Utc>>>>,
         next_id: Arc<Mutex<i64>>,
+        task_tag_events: Arc<tokio::sync::Mutex<Vec<TaskTagEvent>>>,
+        tasks: Arc<tokio::sync::Mutex<HashMap<TaskId, Vec<Task>>>>,
     }
 
     impl InMemoryTimeEntryRepository {
         pub fn new() -> Self {
             Self {
                 events: Arc::new(Mutex::new(Vec::new())),
+                heartbeats: Arc::new(Mutex::new(HashMap::new())),
                 next_id: Arc::new(Mutex::new(1)),
+                task_tag_events: Arc::new(tokio::sync::Mutex::new(Vec::new())),
+                tasks: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
             }
         }
 
+        /// タスクリポジトリのタスクを共有し、プロジェクト別クエリに使う
+        pub fn with_task_repository(mut self, task_repository: &InMemoryTaskRepository) -> Self {
+            self.tasks = task_repository.tasks();
+            self
+        }
+
+        /// タグリポジトリのタスクタグイベントを共有し、タグ別クエリに使う
+        pub fn with_tag_repository(mut self, tag_repository: &InMemoryTagRepository) -> Self {
+            self.task_tag_events = tag_repository.task_tag_events();
+            self
+        }
+
+        /// エントリ開始時点で指定タグが付与されていた時間区間だけを残す
+        async fn filter_tagged_at_start(&self, entries: Vec<TimeEntry>, tag_id: TagId) -> Vec<TimeEntry> {
+            let tag_events = self.task_tag_events.lock().await;
+            entries
+                .into_iter()
+                .filter(|entry| {
+                    tag_events
+                        .iter()
+                        .filter(|e| e.task_id() == entry.task_id() && e.tag_id() == tag_id && e.at() <= entry.start_time())
+                        .max_by_key(|e| (e.at(), e.id()))
+                        .is_some_and(|e| e.is_add())
+                })
+                .collect()
+        }
+
         fn generate_id(&self) -> i64 {
             let mut id = self.next_id.lock().unwrap();
             let current = *id;
@@ -92,7 +219,8 @@ pub mod tests {
             current
         }
 
-        fn build_time_entries(&self) -> Vec<TimeEntry> {
+        /// 無効化済みを含む全時間区間を構築（無効化フラグ付き）
+        fn build_all_time_entries(&self) -> Vec<(TimeEntry, bool)> {
             let events = self.events.lock().unwrap();
             let mut entries = Vec::new();
             let mut start_events: HashMap<i64, &TimeEntryEvent> = HashMap::new();
@@ -112,18 +240,87 @@ pub mod tests {
                 });
 
                 let end_time = stop_event.map(|e| e.at());
+
+                // 最新の訂正イベントがあれば開始・終了を上書き
+                let adjustment = events
+                    .iter()
+                    .filter(|e| e.is_adjust() && e.start_event_id() == Some(start_id))
+                    .max_by_key(|e| e.id())
+                    .and_then(|e| e.adjustment());
+                let (start_time, end_time) = match adjustment {
+                    Some(adjustment) => (adjustment.start_time, adjustment.end_time.or(end_time)),
+                    None => (start_event.at(), end_time),
+                };
+
+                // 最新の無効化/無効化取り消しイベントで無効化状態を判定
+                let is_voided = events
+                    .iter()
+                    .filter(|e| (e.is_void() || e.is_unvoid()) && e.start_event_id() == Some(start_id))
+                    .max_by_key(|e| e.id())
+                    .is_some_and(|e| e.is_void());
+
+                // 注釈イベントを発生順に改行区切りで連結してメモとする
+                let mut annotations: Vec<&TimeEntryEvent> = events
+                    .iter()
+                    .filter(|e| e.is_annotate() && e.start_event_id() == Some(start_id) && e.payload().is_some())
+                    .collect();
+                annotations.sort_by_key(|e| (e.at(), e.id()));
+                let notes = if annotations.is_empty() {
+                    None
+                } else {
+                    Some(annotations.iter().filter_map(|e| e.payload()).collect::<Vec<_>>().join("\n"))
+                };
+
+                // 一時停止/再開イベントを発生順に畳み込み、区間内に切り詰めた一時停止時間を合計する
+                let mut pause_events: Vec<&TimeEntryEvent> = events
+                    .iter()
+                    .filter(|e| (e.is_pause() || e.is_resume()) && e.start_event_id() == Some(start_id))
+                    .collect();
+                pause_events.sort_by_key(|e| (e.at(), e.id()));
+                let mut pause_intervals = Vec::new();
+                let mut push_interval = |from: DateTime<Utc>, to: DateTime<Utc>| {
+                    let from = from.max(start_time);
+                    let to = end_time.map_or(to, |end| to.min(end));
+                    if to > from {
+                        pause_intervals.push((from, to));
+                    }
+                };
+                let mut paused_at = None;
+                for event in pause_events {
+                    if event.is_pause() {
+                        paused_at = paused_at.or(Some(event.at()));
+                    } else if let Some(from) = paused_at.take() {
+                        push_interval(from, event.at());
+                    }
+                }
+                if let (Some(from), Some(end)) = (paused_at, end_time) {
+                    push_interval(from, end);
+                }
+                let paused_seconds = pause_intervals.iter().map(|(from, to)| (*to - *from).num_seconds()).sum();
+
                 let entry = TimeEntry::new(
                     start_event.task_id(),
                     start_id,
-                    start_event.at(),
+                    start_time,
                     end_time,
-                );
-                entries.push(entry);
+                )
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at)
+                .with_pause_intervals(pause_intervals);
+                entries.push((entry, is_voided));
             }
 
-            entries.sort_by(|a, b| b.start_time().cmp(&a.start_time())); // 新しい順
+            entries.sort_by(|a, b| b.0.start_time().cmp(&a.0.start_time())); // 新しい順
             entries
         }
+
+        fn build_time_entries(&self) -> Vec<TimeEntry> {
+            self.build_all_time_entries()
+                .into_iter()
+                .filter(|(_, is_voided)| !is_voided)
+                .map(|(entry, _)| entry)
+                .collect()
+        }
     }
 
     #[async_trait]
@@ -135,6 +332,21 @@ pub mod tests {
             Ok(saved_event)
         }
 
+        async fn find_all_events(&self) -> anyhow::Result<Vec<TimeEntryEvent>> {
+            let mut events = self.events.lock().unwrap().clone();
+            events.sort_by_key(|e| (e.at(), e.id()));
+            Ok(events)
+        }
+
+        async fn save_heartbeat(&self, start_event_id: i64, at: DateTime<Utc>) -> anyhow::Result<()> {
+            self.heartbeats.lock().unwrap().insert(start_event_id, at);
+            Ok(())
+        }
+
+        async fn find_heartbeat(&self, start_event_id: i64) -> anyhow::Result<Option<DateTime<Utc>>> {
+            Ok(self.heartbeats.lock().unwrap().get(&start_event_id).copied())
+        }
+
         async fn find_running_entries(&self) -> anyhow::Result<Vec<TimeEntry>> {
             let entries = self.build_time_entries();
             Ok(entries.into_iter().filter(|e| e.is_running()).collect())
@@ -206,6 +418,54 @@ pub mod tests {
                 .collect())
         }
 
+        async fn find_entries_overlapping_period(
+            &self,
+            start: DateTime<Utc>,
+            end: DateTime<Utc>,
+        ) -> anyhow::Result<Vec<TimeEntry>> {
+            let entries = self.build_time_entries();
+            Ok(entries
+                .into_iter()
+                .filter(|e| e.start_time() < end && e.end_time().is_none_or(|et| et > start))
+                .collect())
+        }
+
+        async fn find_entries_by_query(&self, query: &TimeEntryQuery) -> anyhow::Result<Vec<TimeEntry>> {
+            // SQLite実装と同じく、タスクの現在の版のプロジェクトで絞り込む
+            let project_tasks: Option<Vec<TaskId>> = match query.project_id {
+                Some(project_id) => {
+                    let tasks = self.tasks.lock().await;
+                    Some(
+                        tasks
+                            .iter()
+                            .filter(|(_, versions)| {
+                                versions
+                                    .iter()
+                                    .max_by_key(|t| t.effective_at())
+                                    .is_some_and(|t| i64::from(t.project_id()) == project_id)
+                            })
+                            .map(|(task_id, _)| *task_id)
+                            .collect(),
+                    )
+                }
+                None => None,
+            };
+
+            let mut entries: Vec<TimeEntry> = self
+                .build_time_entries()
+                .into_iter()
+                .filter(|e| query.matches(e))
+                .filter(|e| project_tasks.as_ref().is_none_or(|ids| ids.contains(&e.task_id())))
+                .collect();
+            entries.sort_by(|a, b| {
+                (b.start_time(), b.start_event_id()).cmp(&(a.start_time(), a.start_event_id()))
+            });
+            if let Some(limit) = query.limit {
+                entries.truncate(limit);
+            }
+            Ok(entries)
+        }
+
         async fn count_entries_by_task(&self, task_id: TaskId) -> anyhow::Result<usize> {
             let entries = self.find_entries_by_task(task_id).await?;
             Ok(entries.len())
@@ -229,6 +489,34 @@ pub mod tests {
             Ok(total)
         }
 
+        async fn find_entries_by_tag(&self, tag_id: TagId) -> anyhow::Result<Vec<TimeEntry>> {
+            let entries = self.build_time_entries();
+            Ok(self.filter_tagged_at_start(entries, tag_id).await)
+        }
+
+        async fn find_entries_by_period_and_tag(
+            &self,
+            tag_id: TagId,
+            start: DateTime<Utc>,
+            end: DateTime<Utc>,
+        ) -> anyhow::Result<Vec<TimeEntry>> {
+            let entries = self
+                .build_time_entries()
+                .into_iter()
+                .filter(|e| e.start_time() >= start && e.start_time() <= end)
+                .collect();
+            Ok(self.filter_tagged_at_start(entries, tag_id).await)
+        }
+
+        async fn sum_duration_by_tag(&self, tag_id: TagId) -> anyhow::Result<i64> {
+            let entries = self.find_entries_by_tag(tag_id).await?;
+            let total = entries
+                .iter()
+                .filter_map(|e| e.duration_in_seconds())
+                .sum();
+            Ok(total)
+        }
+
         async fn find_entry_by_start_event_id(&self, start_event_id: i64) -> anyhow::Result<Option<TimeEntry>> {
             let entries = self.build_time_entries();
             Ok(entries
@@ -236,6 +524,14 @@ pub mod tests {
                 .find(|e| e.start_event_id() == start_event_id))
         }
 
+        async fn find_voided_entry_by_start_event_id(&self, start_event_id: i64) -> anyhow::Result<Option<TimeEntry>> {
+            let entries = self.build_all_time_entries();
+            Ok(entries
+                .into_iter()
+                .find(|(e, is_voided)| *is_voided && e.start_event_id() == start_event_id)
+                .map(|(e, _)| e))
+        }
+
         async fn find_recent_entries(&self, limit: usize) -> anyhow::Result<Vec<TimeEntry>> {
             let mut entries = self.build_time_entries();
             entries.truncate(limit);
@@ -247,6 +543,14 @@ pub mod tests {
             entries.truncate(limit);
             Ok(entries)
         }
+
+        async fn find_latest_stopped_entry(&self) -> anyhow::Result<Option<TimeEntry>> {
+            Ok(self
+                .build_time_entries()
+                .into_iter()
+                .filter(|e| e.end_time().is_some())
+                .max_by_key(|e| e.end_time()))
+        }
     }
 
     #[tokio::test]
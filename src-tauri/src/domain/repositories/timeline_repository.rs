@@ -0,0 +1,24 @@
+use crate::domain::entities::time_entry::TimeEntry;
+use crate::domain::value_objects::ProjectId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// タイムラインの時間区間（タスク・プロジェクトの現在の名前付き）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineEntry {
+    pub entry: TimeEntry,
+    pub task_name: String,
+    pub project_id: ProjectId,
+    pub project_name: String,
+}
+
+/// タイムライン取得リポジトリトレイト
+#[async_trait]
+pub trait TimelineRepository: Send + Sync {
+    /// 指定期間に重複する全タスクの時間区間を開始時刻順に取得（アーカイブ済みのタスクも含む）
+    async fn find_entries_overlapping_period(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimelineEntry>>;
+}
@@ -0,0 +1,165 @@
+use crate::domain::entities::time_entry::TimeEntry;
+use crate::domain::services::LocalCalendar;
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+
+/// 長時間実行タイマーの自動停止ポリシー
+/// 最大計測時間と日次の締め時刻のうち、早い方で区間を打ち切る
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoCutoffPolicy {
+    max_timer_seconds: Option<i64>,
+    daily_cutoff_time: Option<NaiveTime>,
+    calendar: LocalCalendar,
+}
+
+impl AutoCutoffPolicy {
+    /// 新しいポリシーを作成（daily_cutoff_time は calendar のローカル時刻）
+    pub fn new(
+        max_timer_seconds: Option<i64>,
+        daily_cutoff_time: Option<NaiveTime>,
+        calendar: LocalCalendar,
+    ) -> anyhow::Result<Self> {
+        if max_timer_seconds.is_some_and(|seconds| seconds <= 0) {
+            return Err(anyhow::anyhow!("Max timer seconds must be positive"));
+        }
+
+        Ok(Self {
+            max_timer_seconds,
+            daily_cutoff_time,
+            calendar,
+        })
+    }
+
+    /// 自動停止を行わないポリシー
+    pub fn disabled() -> Self {
+        Self {
+            max_timer_seconds: None,
+            daily_cutoff_time: None,
+            calendar: LocalCalendar::utc(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.max_timer_seconds.is_some() || self.daily_cutoff_time.is_some()
+    }
+
+    /// 実行中の区間の打ち切り時刻を取得（無効な場合はNone）
+    /// 最大計測時間は一時停止していた時間を除いた計測時間で判定する
+    pub fn cutoff_for(&self, entry: &TimeEntry) -> Option<DateTime<Utc>> {
+        let by_max = self
+            .max_timer_seconds
+            .map(|seconds| entry.start_time() + Duration::seconds(seconds + entry.paused_seconds()))
+            // 一時停止中は計測時間が増えないため、一時停止より前に上限に達していた場合だけ打ち切る
+            .filter(|cutoff| entry.paused_at().is_none_or(|paused_at| *cutoff <= paused_at));
+        let by_daily = self
+            .daily_cutoff_time
+            .map(|cutoff| self.next_daily_cutoff(entry.start_time(), cutoff));
+
+        match (by_max, by_daily) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// 開始時刻より後で最初に到来する締め時刻（夏時間の切り替えをまたぐ場合もローカル時刻で判定する）
+    fn next_daily_cutoff(&self, start_time: DateTime<Utc>, cutoff: NaiveTime) -> DateTime<Utc> {
+        let date = self.calendar.date_of(start_time);
+        let same_day = self.calendar.at_local_time(date, cutoff);
+
+        if same_day > start_time {
+            same_day
+        } else {
+            self.calendar.at_local_time(date + Duration::days(1), cutoff)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::TaskId;
+    use chrono::{TimeZone, Weekday};
+
+    fn utc() -> LocalCalendar {
+        LocalCalendar::utc()
+    }
+
+    fn running(start_time: DateTime<Utc>) -> TimeEntry {
+        TimeEntry::new(TaskId::new(1).unwrap(), 1, start_time, None)
+    }
+
+    #[test]
+    fn 最大計測時間で打ち切り時刻が決まること() {
+        let policy = AutoCutoffPolicy::new(Some(8 * 60 * 60), None, utc()).unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+
+        assert_eq!(policy.cutoff_for(&running(start)), Some(Utc.with_ymd_and_hms(2024, 1, 1, 17, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn 日次の締め時刻で打ち切り時刻が決まること() {
+        let cutoff = NaiveTime::from_hms_opt(23, 0, 0).unwrap();
+        let policy = AutoCutoffPolicy::new(None, Some(cutoff), utc()).unwrap();
+
+        // 締め時刻前の開始は当日の締め時刻
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        assert_eq!(policy.cutoff_for(&running(start)), Some(Utc.with_ymd_and_hms(2024, 1, 1, 23, 0, 0).unwrap()));
+
+        // 締め時刻後の開始は翌日の締め時刻
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 23, 30, 0).unwrap();
+        assert_eq!(policy.cutoff_for(&running(start)), Some(Utc.with_ymd_and_hms(2024, 1, 2, 23, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn 締め時刻がローカル時刻で解釈されること() {
+        // UTC+9 の 0:00 は UTC の前日 15:00
+        let jst = LocalCalendar::from_name("Asia/Tokyo", Weekday::Mon).unwrap();
+        let policy = AutoCutoffPolicy::new(None, Some(NaiveTime::from_hms_opt(0, 0, 0).unwrap()), jst).unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(); // JST 18:00
+
+        assert_eq!(policy.cutoff_for(&running(start)), Some(Utc.with_ymd_and_hms(2024, 1, 1, 15, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn 夏時間の切り替えをまたいでもローカルの締め時刻で打ち切ること() {
+        // 2024-03-10 に America/New_York は EST(UTC-5) から EDT(UTC-4) に切り替わる
+        let new_york = LocalCalendar::from_name("America/New_York", Weekday::Mon).unwrap();
+        let policy = AutoCutoffPolicy::new(None, Some(NaiveTime::from_hms_opt(22, 0, 0).unwrap()), new_york).unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 3, 10, 4, 0, 0).unwrap(); // EST 3/9 23:00
+
+        // EDT 3/10 22:00 は UTC 3/11 2:00
+        assert_eq!(policy.cutoff_for(&running(start)), Some(Utc.with_ymd_and_hms(2024, 3, 11, 2, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn 一時停止していた時間は最大計測時間に含めないこと() {
+        let policy = AutoCutoffPolicy::new(Some(8 * 60 * 60), None, utc()).unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+
+        // 1時間の一時停止を挟んだ区間は8時間の計測で 18:00 に打ち切る
+        let resumed = running(start).with_pause(60 * 60, None);
+        assert_eq!(policy.cutoff_for(&resumed), Some(Utc.with_ymd_and_hms(2024, 1, 1, 18, 0, 0).unwrap()));
+
+        // 上限に達する前から一時停止中の区間は打ち切らない
+        let paused = running(start).with_pause(0, Some(Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()));
+        assert!(policy.cutoff_for(&paused).is_none());
+    }
+
+    #[test]
+    fn 早い方の打ち切り時刻が採用されること() {
+        let cutoff = NaiveTime::from_hms_opt(18, 0, 0).unwrap();
+        let policy = AutoCutoffPolicy::new(Some(12 * 60 * 60), Some(cutoff), utc()).unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+
+        assert_eq!(policy.cutoff_for(&running(start)), Some(Utc.with_ymd_and_hms(2024, 1, 1, 18, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn 無効なポリシーでは打ち切らないこと() {
+        let policy = AutoCutoffPolicy::disabled();
+        assert!(!policy.is_enabled());
+        assert!(policy.cutoff_for(&running(Utc::now())).is_none());
+
+        assert!(AutoCutoffPolicy::new(Some(0), None, utc()).is_err());
+    }
+}
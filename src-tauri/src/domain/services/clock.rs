@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+
+/// 現在時刻の取得元（テストで時刻を差し替えるために注入する）
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// システム時刻を返す時計
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+pub mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // テスト用の固定時計（任意に進められる）
+    #[derive(Debug)]
+    pub struct FixedClock {
+        now: Mutex<DateTime<Utc>>,
+    }
+
+    impl FixedClock {
+        pub fn new(now: DateTime<Utc>) -> Self {
+            Self { now: Mutex::new(now) }
+        }
+
+        pub fn set(&self, now: DateTime<Utc>) {
+            *self.now.lock().unwrap() = now;
+        }
+
+        pub fn advance(&self, duration: chrono::Duration) {
+            let mut now = self.now.lock().unwrap();
+            *now += duration;
+        }
+    }
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.now.lock().unwrap()
+        }
+    }
+
+    #[test]
+    fn 固定時計を進められること() {
+        use chrono::TimeZone;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let clock = FixedClock::new(start);
+        assert_eq!(clock.now(), start);
+
+        clock.advance(chrono::Duration::hours(1));
+        assert_eq!(clock.now(), Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap());
+
+        clock.set(start);
+        assert_eq!(clock.now(), start);
+    }
+}
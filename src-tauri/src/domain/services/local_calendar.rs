@@ -0,0 +1,169 @@
+use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveTime, Offset, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+/// 日・週の区切りを決めるローカルカレンダー
+/// IANAタイムゾーンで日付を判定するため、夏時間の切り替え日は23時間・25時間の日になる
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalCalendar {
+    timezone: Tz,
+    week_start: Weekday,
+}
+
+impl LocalCalendar {
+    pub fn new(timezone: Tz, week_start: Weekday) -> Self {
+        Self { timezone, week_start }
+    }
+
+    /// IANAタイムゾーン名（例: Asia/Tokyo）から作成
+    pub fn from_name(timezone: &str, week_start: Weekday) -> anyhow::Result<Self> {
+        let timezone = timezone
+            .parse::<Tz>()
+            .map_err(|_| anyhow::anyhow!("Invalid timezone: {}", timezone))?;
+        Ok(Self::new(timezone, week_start))
+    }
+
+    /// UTC・月曜始まりのカレンダー
+    pub fn utc() -> Self {
+        Self::new(Tz::UTC, Weekday::Mon)
+    }
+
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    pub fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    /// 指定時刻のローカル日付
+    pub fn date_of(&self, at: DateTime<Utc>) -> NaiveDate {
+        at.with_timezone(&self.timezone).date_naive()
+    }
+
+    /// ローカル日付の開始時刻（0時が夏時間の開始で存在しない日は切り替えの時刻）
+    pub fn start_of_day(&self, date: NaiveDate) -> DateTime<Utc> {
+        self.at_local_time(date, NaiveTime::MIN)
+    }
+
+    /// ローカル日時をUTCに換算する
+    /// 夏時間の終了で重複する時刻は早い方、開始で存在しない時刻は切り替え前のオフセットで換算する
+    pub fn at_local_time(&self, date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+        let local = date.and_time(time);
+        match self.timezone.from_local_datetime(&local) {
+            LocalResult::Single(at) => at.with_timezone(&Utc),
+            LocalResult::Ambiguous(earliest, _) => earliest.with_timezone(&Utc),
+            LocalResult::None => {
+                let before = self
+                    .timezone
+                    .offset_from_utc_datetime(&(local - Duration::days(1)))
+                    .fix();
+                (local - Duration::seconds(before.local_minus_utc() as i64)).and_utc()
+            }
+        }
+    }
+
+    /// 指定日を含む週の開始日
+    pub fn start_of_week(&self, date: NaiveDate) -> NaiveDate {
+        let days = (7 + date.weekday().num_days_from_monday() - self.week_start.num_days_from_monday()) % 7;
+        date - Duration::days(days as i64)
+    }
+
+    /// 区間をローカル日付ごとに分割する（日付と、その日に含まれる部分の開始・終了）
+    pub fn split_by_day(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<(NaiveDate, DateTime<Utc>, DateTime<Utc>)> {
+        let mut parts = Vec::new();
+        let mut date = self.date_of(start);
+        let mut part_start = start;
+        while part_start < end {
+            let next_day = self.start_of_day(date + Duration::days(1));
+            let part_end = next_day.min(end);
+            parts.push((date, part_start, part_end));
+            part_start = part_end;
+            date += Duration::days(1);
+        }
+        parts
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn タイムゾーンのローカル日付で日の区切りが決まること() {
+        let calendar = LocalCalendar::from_name("Asia/Tokyo", Weekday::Mon).unwrap();
+
+        // JSTでは UTC 15:00 が翌日の0時
+        assert_eq!(calendar.date_of(Utc.with_ymd_and_hms(2025, 3, 10, 14, 59, 59).unwrap()), date(2025, 3, 10));
+        assert_eq!(calendar.date_of(Utc.with_ymd_and_hms(2025, 3, 10, 15, 0, 0).unwrap()), date(2025, 3, 11));
+        assert_eq!(calendar.start_of_day(date(2025, 3, 11)), Utc.with_ymd_and_hms(2025, 3, 10, 15, 0, 0).unwrap());
+
+        assert!(LocalCalendar::from_name("Mars/Olympus", Weekday::Mon).is_err());
+    }
+
+    #[test]
+    fn 夏時間の切り替え日の長さが正しいこと() {
+        let calendar = LocalCalendar::from_name("America/New_York", Weekday::Mon).unwrap();
+        let length = |d: NaiveDate| calendar.start_of_day(d + Duration::days(1)) - calendar.start_of_day(d);
+
+        // 2025-03-09 は夏時間の開始で23時間、2025-11-02 は終了で25時間
+        assert_eq!(calendar.start_of_day(date(2025, 3, 9)), Utc.with_ymd_and_hms(2025, 3, 9, 5, 0, 0).unwrap());
+        assert_eq!(calendar.start_of_day(date(2025, 3, 10)), Utc.with_ymd_and_hms(2025, 3, 10, 4, 0, 0).unwrap());
+        assert_eq!(length(date(2025, 3, 9)), Duration::hours(23));
+        assert_eq!(length(date(2025, 11, 2)), Duration::hours(25));
+        assert_eq!(length(date(2025, 11, 3)), Duration::hours(24));
+    }
+
+    #[test]
+    fn 夏時間の開始で0時が存在しない日は切り替えの時刻から始まること() {
+        // サンティアゴは 2025-09-07 0:00 に 1:00 へ進む
+        let calendar = LocalCalendar::from_name("America/Santiago", Weekday::Mon).unwrap();
+
+        let start = calendar.start_of_day(date(2025, 9, 7));
+        assert_eq!(start, Utc.with_ymd_and_hms(2025, 9, 7, 4, 0, 0).unwrap());
+        assert_eq!(calendar.date_of(start), date(2025, 9, 7));
+        assert_eq!(calendar.date_of(start - Duration::seconds(1)), date(2025, 9, 6));
+    }
+
+    #[test]
+    fn 夏時間の切り替えでローカル日時が換算されること() {
+        let calendar = LocalCalendar::from_name("America/New_York", Weekday::Mon).unwrap();
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        assert_eq!(calendar.at_local_time(date(2025, 3, 9), time(9, 0)), Utc.with_ymd_and_hms(2025, 3, 9, 13, 0, 0).unwrap());
+        // 存在しない 2:30 は 3:30 EDT、重複する 1:30 は早い方（EDT）
+        assert_eq!(calendar.at_local_time(date(2025, 3, 9), time(2, 30)), Utc.with_ymd_and_hms(2025, 3, 9, 7, 30, 0).unwrap());
+        assert_eq!(calendar.at_local_time(date(2025, 11, 2), time(1, 30)), Utc.with_ymd_and_hms(2025, 11, 2, 5, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn 週の開始曜日で週の開始日が決まること() {
+        // 2025-03-05 は水曜日
+        let wednesday = date(2025, 3, 5);
+        assert_eq!(LocalCalendar::utc().start_of_week(wednesday), date(2025, 3, 3));
+        assert_eq!(LocalCalendar::new(Tz::UTC, Weekday::Sun).start_of_week(wednesday), date(2025, 3, 2));
+        assert_eq!(LocalCalendar::new(Tz::UTC, Weekday::Wed).start_of_week(wednesday), wednesday);
+        assert_eq!(LocalCalendar::new(Tz::UTC, Weekday::Thu).start_of_week(wednesday), date(2025, 2, 27));
+    }
+
+    #[test]
+    fn 日をまたぐ区間がローカルの0時で分割されること() {
+        let calendar = LocalCalendar::from_name("America/New_York", Weekday::Mon).unwrap();
+        // 2025-11-01 22:00 EDT から 2025-11-03 01:00 EST まで（11/2 は25時間）
+        let start = Utc.with_ymd_and_hms(2025, 11, 2, 2, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 11, 3, 6, 0, 0).unwrap();
+
+        let parts = calendar.split_by_day(start, end);
+        let hours: Vec<_> = parts.iter().map(|(d, s, e)| (*d, (*e - *s).num_hours())).collect();
+        assert_eq!(hours, vec![(date(2025, 11, 1), 2), (date(2025, 11, 2), 25), (date(2025, 11, 3), 1)]);
+        assert!(calendar.split_by_day(end, end).is_empty());
+    }
+}
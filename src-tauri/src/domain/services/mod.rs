@@ -1,8 +1,26 @@
 // ドメインサービス - エンティティや値オブジェクトに属さないビジネスロジック
 
+pub mod auto_cutoff_policy;
+pub mod clock;
+pub mod local_calendar;
 pub mod project_management_service;
+pub mod rounding_policy;
 pub mod time_tracking_service;
+pub mod time_entry_integrity_service;
+pub mod timer_recovery_service;
+pub mod timer_exclusivity;
+pub mod timesheet_reconciler;
+pub mod working_hours;
 
+pub use auto_cutoff_policy::*;
+pub use clock::{Clock, SystemClock};
+pub use local_calendar::*;
 pub use project_management_service::*;
+pub use rounding_policy::*;
 pub use time_tracking_service::*;
+pub use time_entry_integrity_service::*;
+pub use timer_recovery_service::*;
+pub use timer_exclusivity::*;
+pub use timesheet_reconciler::*;
+pub use working_hours::*;
 
@@ -0,0 +1,107 @@
+use crate::domain::entities::time_entry::TimeEntry;
+use crate::domain::services::LocalCalendar;
+use crate::domain::value_objects::{ProjectId, RoundingRule, RoundingScope};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::BTreeMap;
+
+/// 丸め設定（ワークスペース全体の既定とプロジェクトごとの上書き）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoundingPolicy {
+    pub default_rule: RoundingRule,
+    /// プロジェクトごとの規則（プロジェクトID順）
+    pub project_rules: Vec<(ProjectId, RoundingRule)>,
+}
+
+impl RoundingPolicy {
+    /// プロジェクトに適用する規則（上書きがなければ既定）
+    pub fn rule_for(&self, project_id: ProjectId) -> RoundingRule {
+        self.project_rules
+            .iter()
+            .find(|(id, _)| *id == project_id)
+            .map(|(_, rule)| *rule)
+            .unwrap_or(self.default_rule)
+    }
+
+    /// 時間エントリの丸めた作業時間の合計を求める（entries は同じ規則が適用されるもの）
+    ///
+    /// エントリ単位の規則では日をまたぐエントリも全体を1回だけ丸め、日単位の規則ではエントリを
+    /// ローカル日付の区切りで分割してタスクごとの1日の合計ごとに丸める（レポートの集計と同じ単位）。
+    pub fn round_entries(rule: RoundingRule, entries: &[TimeEntry], calendar: &LocalCalendar, now: DateTime<Utc>) -> i64 {
+        let mut day_totals: BTreeMap<(NaiveDate, i64), i64> = BTreeMap::new();
+        let mut rounded = 0;
+        for entry in entries {
+            let until = entry.end_time().or(entry.paused_at()).unwrap_or(now);
+            match rule.scope() {
+                RoundingScope::Entry => rounded += rule.round(entry.seconds_within(entry.start_time(), until, now)),
+                RoundingScope::Day => {
+                    for (date, start, end) in calendar.split_by_day(entry.start_time(), until) {
+                        *day_totals.entry((date, i64::from(entry.task_id()))).or_default() +=
+                            entry.seconds_within(start, end, now);
+                    }
+                }
+            }
+        }
+        rounded + day_totals.values().map(|seconds| rule.round(*seconds)).sum::<i64>()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{RoundingMode, TaskId};
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, day, hour, minute, 0).unwrap()
+    }
+
+    fn entry(task_id: i64, id: i64, start: DateTime<Utc>, end: DateTime<Utc>) -> TimeEntry {
+        TimeEntry::new(TaskId::new(task_id).unwrap(), id, start, Some(end))
+    }
+
+    #[test]
+    fn プロジェクトの規則がなければ既定の規則が使われること() {
+        let up = RoundingRule::new(RoundingMode::Up, 360, RoundingScope::Entry).unwrap();
+        let policy = RoundingPolicy {
+            default_rule: RoundingRule::none(),
+            project_rules: vec![(ProjectId::new(2).unwrap(), up)],
+        };
+
+        assert_eq!(policy.rule_for(ProjectId::new(2).unwrap()), up);
+        assert_eq!(policy.rule_for(ProjectId::new(1).unwrap()), RoundingRule::none());
+    }
+
+    #[test]
+    fn エントリごとと日ごとで丸める単位が変わること() {
+        let calendar = LocalCalendar::utc();
+        let now = at(20, 0, 0);
+        // 10分 + 10分（3/10）、日をまたぐ 23:50〜0:10（3/10 と 3/11 に10分ずつ）
+        let entries = vec![
+            entry(1, 1, at(10, 9, 0), at(10, 9, 10)),
+            entry(1, 2, at(10, 10, 0), at(10, 10, 10)),
+            entry(1, 3, at(10, 23, 50), at(11, 0, 10)),
+        ];
+        let per_entry = RoundingRule::new(RoundingMode::Up, 15 * 60, RoundingScope::Entry).unwrap();
+        let per_day = RoundingRule::new(RoundingMode::Up, 15 * 60, RoundingScope::Day).unwrap();
+
+        // 15分 + 15分 + 日をまたぐエントリ全体の20分 → 30分
+        assert_eq!(RoundingPolicy::round_entries(per_entry, &entries, &calendar, now), 60 * 60);
+        // 3/10 は30分、3/11 は10分 → 30分 + 15分
+        assert_eq!(RoundingPolicy::round_entries(per_day, &entries, &calendar, now), 45 * 60);
+        assert_eq!(RoundingPolicy::round_entries(RoundingRule::none(), &entries, &calendar, now), 40 * 60);
+    }
+
+    #[test]
+    fn エントリ単位の規則では日をまたぐエントリを分割せずに丸めること() {
+        let calendar = LocalCalendar::utc();
+        let now = at(20, 0, 0);
+        // 23:55〜0:05 は日ごとには5分ずつだが、エントリ全体は10分
+        let entries = vec![entry(1, 1, at(10, 23, 55), at(11, 0, 5))];
+        let per_entry = RoundingRule::new(RoundingMode::Up, 15 * 60, RoundingScope::Entry).unwrap();
+        let per_day = RoundingRule::new(RoundingMode::Up, 15 * 60, RoundingScope::Day).unwrap();
+
+        assert_eq!(RoundingPolicy::round_entries(per_entry, &entries, &calendar, now), 15 * 60);
+        assert_eq!(RoundingPolicy::round_entries(per_day, &entries, &calendar, now), 2 * 15 * 60);
+    }
+}
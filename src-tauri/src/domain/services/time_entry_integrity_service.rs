@@ -0,0 +1,571 @@
+use crate::domain::entities::time_entry::{RepairReason, TimeEntry, TimeEntryEvent};
+use crate::domain::repositories::TimeEntryRepository;
+use crate::domain::value_objects::TaskId;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, HashSet};
+
+/// 整合性チェックの条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegrityCheckOptions {
+    /// 判定基準時刻（長時間実行の判定に使用）
+    pub now: DateTime<Utc>,
+    /// 長時間実行とみなす実行時間の閾値（秒）
+    pub max_running_seconds: i64,
+}
+
+impl IntegrityCheckOptions {
+    /// 長時間実行の既定閾値（12時間）
+    pub const DEFAULT_MAX_RUNNING_SECONDS: i64 = 12 * 60 * 60;
+
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now,
+            max_running_seconds: Self::DEFAULT_MAX_RUNNING_SECONDS,
+        }
+    }
+
+    pub fn with_max_running_seconds(mut self, max_running_seconds: i64) -> Self {
+        self.max_running_seconds = max_running_seconds;
+        self
+    }
+}
+
+/// time_entry_events の整合性異常
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeEntryAnomaly {
+    /// 停止されないまま次の開始が到来した区間（重複START）
+    DuplicateStart {
+        task_id: TaskId,
+        start_event_id: i64,
+        start_time: DateTime<Utc>,
+        next_start_event_id: i64,
+        next_start_time: DateTime<Utc>,
+    },
+    /// 対応する開始のない停止（孤立STOP）
+    /// backfill_start_time は補完する開始時刻（直前のイベント時刻、補完できない場合はNone）
+    OrphanStop {
+        task_id: TaskId,
+        stop_event_id: i64,
+        stop_time: DateTime<Utc>,
+        backfill_start_time: Option<DateTime<Utc>>,
+    },
+    /// 終了が開始より前になっている区間（逆行/負区間）
+    ReversedInterval {
+        task_id: TaskId,
+        start_event_id: i64,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    },
+    /// 閾値を超えて実行中の区間（長時間実行）
+    LongRunning {
+        task_id: TaskId,
+        start_event_id: i64,
+        start_time: DateTime<Utc>,
+        cutoff_time: DateTime<Utc>,
+    },
+}
+
+impl TimeEntryAnomaly {
+    /// 異常の種類を文字列で取得
+    pub fn kind(&self) -> &'static str {
+        match self {
+            TimeEntryAnomaly::DuplicateStart { .. } => "duplicate_start",
+            TimeEntryAnomaly::OrphanStop { .. } => "orphan_stop",
+            TimeEntryAnomaly::ReversedInterval { .. } => "reversed_interval",
+            TimeEntryAnomaly::LongRunning { .. } => "long_running",
+        }
+    }
+
+    pub fn task_id(&self) -> TaskId {
+        match self {
+            TimeEntryAnomaly::DuplicateStart { task_id, .. }
+            | TimeEntryAnomaly::OrphanStop { task_id, .. }
+            | TimeEntryAnomaly::ReversedInterval { task_id, .. }
+            | TimeEntryAnomaly::LongRunning { task_id, .. } => *task_id,
+        }
+    }
+
+    /// 補正に使う理由（自動補正できない異常はNone）
+    pub fn repair_reason(&self) -> Option<RepairReason> {
+        match self {
+            TimeEntryAnomaly::DuplicateStart { .. } => Some(RepairReason::ImplicitStop),
+            TimeEntryAnomaly::OrphanStop { backfill_start_time: Some(_), .. } => Some(RepairReason::BackfillStart),
+            TimeEntryAnomaly::OrphanStop { .. } => None,
+            TimeEntryAnomaly::ReversedInterval { .. } => None,
+            TimeEntryAnomaly::LongRunning { .. } => Some(RepairReason::AutoCutoff),
+        }
+    }
+
+    /// 異常を解消する補正イベントを作成（自動補正できない異常はNone）
+    pub fn repair_event(&self) -> Option<TimeEntryEvent> {
+        let reason = self.repair_reason()?;
+        let event = match self {
+            TimeEntryAnomaly::DuplicateStart {
+                task_id,
+                start_event_id,
+                next_start_time,
+                ..
+            } => TimeEntryEvent::stop_at(*task_id, *start_event_id, *next_start_time),
+            TimeEntryAnomaly::OrphanStop {
+                task_id,
+                backfill_start_time: Some(at),
+                ..
+            } => TimeEntryEvent::start_at(*task_id, *at),
+            TimeEntryAnomaly::LongRunning {
+                task_id,
+                start_event_id,
+                cutoff_time,
+                ..
+            } => TimeEntryEvent::stop_at(*task_id, *start_event_id, *cutoff_time),
+            _ => return None,
+        };
+        Some(event.with_repair_reason(reason))
+    }
+}
+
+/// 整合性チェック結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityReport {
+    pub checked_at: DateTime<Utc>,
+    pub event_count: usize,
+    pub anomalies: Vec<TimeEntryAnomaly>,
+}
+
+impl IntegrityReport {
+    pub fn is_clean(&self) -> bool {
+        self.anomalies.is_empty()
+    }
+
+    /// 自動補正できる異常の件数
+    pub fn repairable_count(&self) -> usize {
+        self.anomalies.iter().filter(|a| a.repair_reason().is_some()).count()
+    }
+}
+
+/// 計測時間（一時停止していた時間を除く）が上限に達する時刻（一時停止中で達していない場合はNone）
+/// pause_events は発生順に並んだ一時停止/再開イベント
+fn tracked_cutoff_time(
+    start_time: DateTime<Utc>,
+    pause_events: &[&TimeEntryEvent],
+    max_seconds: i64,
+) -> Option<DateTime<Utc>> {
+    let mut remaining = max_seconds;
+    let mut resumed_at = Some(start_time);
+    for event in pause_events {
+        match resumed_at {
+            Some(from) if event.is_pause() => {
+                let worked = (event.at() - from).num_seconds().max(0);
+                if worked >= remaining {
+                    return Some(from + Duration::seconds(remaining));
+                }
+                remaining -= worked;
+                resumed_at = None;
+            }
+            None if event.is_resume() => resumed_at = Some(event.at().max(start_time)),
+            _ => {}
+        }
+    }
+    resumed_at.map(|from| from + Duration::seconds(remaining))
+}
+
+/// イベント列を走査して異常を検出する
+/// 開始/停止の対応付けは time_entries_all_view と同じ規則で行う
+pub fn scan_time_entry_events(events: &[TimeEntryEvent], options: &IntegrityCheckOptions) -> Vec<TimeEntryAnomaly> {
+    let mut ordered: Vec<&TimeEntryEvent> = events.iter().filter(|e| e.id().is_some()).collect();
+    ordered.sort_by_key(|e| (e.at(), e.id()));
+
+    let start_ids: HashSet<i64> = ordered.iter().filter(|e| e.is_start()).filter_map(|e| e.id()).collect();
+
+    // 最新の訂正と無効化状態（id 最大のものを採用）
+    let mut latest_adjust: HashMap<i64, &TimeEntryEvent> = HashMap::new();
+    let mut latest_void: HashMap<i64, &TimeEntryEvent> = HashMap::new();
+    for event in &ordered {
+        let Some(start_event_id) = event.start_event_id() else { continue };
+        let target = if event.is_adjust() {
+            &mut latest_adjust
+        } else if event.is_void() || event.is_unvoid() {
+            &mut latest_void
+        } else {
+            continue;
+        };
+        if target.get(&start_event_id).is_none_or(|current| event.id() > current.id()) {
+            target.insert(start_event_id, event);
+        }
+    }
+
+    let mut task_ids: Vec<TaskId> = ordered.iter().map(|e| e.task_id()).collect();
+    task_ids.sort_by_key(|id| id.value());
+    task_ids.dedup();
+
+    let mut anomalies = Vec::new();
+    for task_id in task_ids {
+        let task_events: Vec<&TimeEntryEvent> = ordered.iter().copied().filter(|e| e.task_id() == task_id).collect();
+        let starts: Vec<&TimeEntryEvent> = task_events.iter().copied().filter(|e| e.is_start()).collect();
+        let stops: Vec<&TimeEntryEvent> = task_events.iter().copied().filter(|e| e.is_stop()).collect();
+        let mut paired_stop_ids: HashSet<i64> = HashSet::new();
+
+        for (index, start) in starts.iter().enumerate() {
+            let start_id = start.id().unwrap();
+            let next = starts.get(index + 1);
+            let stop = stops.iter().find(|stop| is_paired(start, next.copied(), stop));
+            if let Some(stop) = stop {
+                paired_stop_ids.insert(stop.id().unwrap());
+            }
+
+            let is_voided = latest_void.get(&start_id).is_some_and(|e| e.is_void());
+            if is_voided {
+                continue;
+            }
+
+            let adjustment = latest_adjust.get(&start_id).and_then(|e| e.adjustment());
+            let start_time = adjustment.map(|a| a.start_time).unwrap_or(start.at());
+            let end_time = adjustment
+                .and_then(|a| a.end_time)
+                .or(stop.map(|e| e.at()))
+                .or(next.map(|e| e.at()));
+
+            match (stop, next, end_time) {
+                (None, Some(next), _) if adjustment.and_then(|a| a.end_time).is_none() => {
+                    anomalies.push(TimeEntryAnomaly::DuplicateStart {
+                        task_id,
+                        start_event_id: start_id,
+                        start_time,
+                        next_start_event_id: next.id().unwrap(),
+                        next_start_time: next.at(),
+                    });
+                }
+                (_, _, None) => {
+                    // 一時停止していた時間は計測時間に含めない
+                    let pause_events: Vec<&TimeEntryEvent> = task_events
+                        .iter()
+                        .copied()
+                        .filter(|e| (e.is_pause() || e.is_resume()) && e.start_event_id() == Some(start_id))
+                        .collect();
+                    let cutoff_time = tracked_cutoff_time(start_time, &pause_events, options.max_running_seconds);
+                    if let Some(cutoff_time) = cutoff_time.filter(|cutoff| *cutoff < options.now) {
+                        anomalies.push(TimeEntryAnomaly::LongRunning {
+                            task_id,
+                            start_event_id: start_id,
+                            start_time,
+                            cutoff_time,
+                        });
+                    }
+                }
+                _ => {}
+            }
+
+            if let Some(end_time) = end_time {
+                if end_time < start_time {
+                    anomalies.push(TimeEntryAnomaly::ReversedInterval {
+                        task_id,
+                        start_event_id: start_id,
+                        start_time,
+                        end_time,
+                    });
+                }
+            }
+        }
+
+        for stop in &stops {
+            let stop_id = stop.id().unwrap();
+            if paired_stop_ids.contains(&stop_id) {
+                continue;
+            }
+
+            // 既存の開始を参照する停止（二重停止）は補完すると区間を捏造するため補正しない
+            let refers_existing_start = stop.start_event_id().is_some_and(|id| start_ids.contains(&id));
+            let backfill_start_time = if refers_existing_start {
+                None
+            } else {
+                task_events
+                    .iter()
+                    .filter(|e| (e.is_start() || e.is_stop()) && e.at() < stop.at())
+                    .map(|e| e.at())
+                    .next_back()
+            };
+
+            anomalies.push(TimeEntryAnomaly::OrphanStop {
+                task_id,
+                stop_event_id: stop_id,
+                stop_time: stop.at(),
+                backfill_start_time,
+            });
+        }
+    }
+
+    anomalies
+}
+
+/// 停止イベントが開始イベントに対応付くか（time_entries_all_view の対応付け規則）
+fn is_paired(start: &TimeEntryEvent, next: Option<&TimeEntryEvent>, stop: &TimeEntryEvent) -> bool {
+    let start_id = start.id().unwrap();
+    let refers_start_or_none = stop.start_event_id().is_none_or(|id| id == start_id);
+
+    let after_start = stop.at() > start.at()
+        || (stop.at() == start.at() && stop.id() > start.id() && refers_start_or_none);
+    let before_next = match next {
+        None => true,
+        Some(next) => {
+            stop.at() < next.at() || (stop.at() == next.at() && stop.start_event_id() == Some(start_id))
+        }
+    };
+
+    after_start && before_next
+}
+
+/// 時間区間の整合性サービス
+#[async_trait]
+pub trait TimeEntryIntegrityService: Send + Sync {
+    /// イベントログを走査して異常を検出する
+    async fn check(&self, options: &IntegrityCheckOptions) -> anyhow::Result<IntegrityReport>;
+
+    /// 指定した理由の補正イベントを追加する（INSERT-only）
+    async fn repair(
+        &self,
+        options: &IntegrityCheckOptions,
+        reasons: &[RepairReason],
+    ) -> anyhow::Result<Vec<TimeEntryEvent>>;
+
+    /// 指定区間の現在の時間エントリを取得
+    async fn find_entry(&self, start_event_id: i64) -> anyhow::Result<Option<TimeEntry>>;
+}
+
+/// 時間区間の整合性サービス実装
+pub struct TimeEntryIntegrityServiceImpl<R: TimeEntryRepository> {
+    repository: R,
+}
+
+impl<R: TimeEntryRepository> TimeEntryIntegrityServiceImpl<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: TimeEntryRepository> TimeEntryIntegrityService for TimeEntryIntegrityServiceImpl<R> {
+    async fn check(&self, options: &IntegrityCheckOptions) -> anyhow::Result<IntegrityReport> {
+        let events = self.repository.find_all_events().await?;
+        let anomalies = scan_time_entry_events(&events, options);
+
+        Ok(IntegrityReport {
+            checked_at: options.now,
+            event_count: events.len(),
+            anomalies,
+        })
+    }
+
+    async fn repair(
+        &self,
+        options: &IntegrityCheckOptions,
+        reasons: &[RepairReason],
+    ) -> anyhow::Result<Vec<TimeEntryEvent>> {
+        let report = self.check(options).await?;
+        let mut repair_events = Vec::new();
+
+        for anomaly in &report.anomalies {
+            let Some(reason) = anomaly.repair_reason() else { continue };
+            if !reasons.contains(&reason) {
+                continue;
+            }
+            if let Some(event) = anomaly.repair_event() {
+                let saved_event = self.repository.save_event(&event).await?;
+                repair_events.push(saved_event);
+            }
+        }
+
+        Ok(repair_events)
+    }
+
+    async fn find_entry(&self, start_event_id: i64) -> anyhow::Result<Option<TimeEntry>> {
+        self.repository.find_entry_by_start_event_id(start_event_id).await
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::time_entry_repository::tests::InMemoryTimeEntryRepository;
+    use chrono::TimeZone;
+
+    const ALL_REASONS: [RepairReason; 3] = [
+        RepairReason::ImplicitStop,
+        RepairReason::BackfillStart,
+        RepairReason::AutoCutoff,
+    ];
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, hour, minute, 0).unwrap()
+    }
+
+    fn options() -> IntegrityCheckOptions {
+        IntegrityCheckOptions::new(Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap())
+    }
+
+    async fn setup_service() -> TimeEntryIntegrityServiceImpl<InMemoryTimeEntryRepository> {
+        TimeEntryIntegrityServiceImpl::new(InMemoryTimeEntryRepository::new())
+    }
+
+    #[tokio::test]
+    async fn 正常なイベントログでは異常が検出されないこと() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let start = service.repository.save_event(&TimeEntryEvent::start_at(task_id, at(9, 0))).await.unwrap();
+        service.repository.save_event(&TimeEntryEvent::stop_at(task_id, start.id().unwrap(), at(10, 0))).await.unwrap();
+
+        let report = service.check(&options()).await.unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.event_count, 2);
+    }
+
+    #[tokio::test]
+    async fn 重複STARTが検出され暗黙STOPで補正されること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let first = service.repository.save_event(&TimeEntryEvent::start_at(task_id, at(9, 0))).await.unwrap();
+        let second = service.repository.save_event(&TimeEntryEvent::start_at(task_id, at(10, 0))).await.unwrap();
+        service.repository.save_event(&TimeEntryEvent::stop_at(task_id, second.id().unwrap(), at(11, 0))).await.unwrap();
+
+        let report = service.check(&options()).await.unwrap();
+        assert_eq!(report.anomalies, vec![TimeEntryAnomaly::DuplicateStart {
+            task_id,
+            start_event_id: first.id().unwrap(),
+            start_time: at(9, 0),
+            next_start_event_id: second.id().unwrap(),
+            next_start_time: at(10, 0),
+        }]);
+
+        let repairs = service.repair(&options(), &ALL_REASONS).await.unwrap();
+        assert_eq!(repairs.len(), 1);
+        assert!(repairs[0].is_stop());
+        assert_eq!(repairs[0].start_event_id(), first.id());
+        assert_eq!(repairs[0].at(), at(10, 0));
+        assert_eq!(repairs[0].repair_reason(), Some(RepairReason::ImplicitStop));
+
+        // 補正後は異常が解消される
+        assert!(service.check(&options()).await.unwrap().is_clean());
+    }
+
+    #[tokio::test]
+    async fn 孤立STOPが検出され開始の補完で補正されること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let start = service.repository.save_event(&TimeEntryEvent::start_at(task_id, at(9, 0))).await.unwrap();
+        service.repository.save_event(&TimeEntryEvent::stop_at(task_id, start.id().unwrap(), at(10, 0))).await.unwrap();
+        // 開始を参照しない停止（取り込みデータ等）
+        let orphan = service.repository.save_event(&TimeEntryEvent::stop_at(task_id, 999, at(11, 0))).await.unwrap();
+
+        let report = service.check(&options()).await.unwrap();
+        assert_eq!(report.anomalies, vec![TimeEntryAnomaly::OrphanStop {
+            task_id,
+            stop_event_id: orphan.id().unwrap(),
+            stop_time: at(11, 0),
+            backfill_start_time: Some(at(10, 0)),
+        }]);
+
+        let repairs = service.repair(&options(), &[RepairReason::BackfillStart]).await.unwrap();
+        assert_eq!(repairs.len(), 1);
+        assert!(repairs[0].is_start());
+        assert_eq!(repairs[0].at(), at(10, 0));
+        assert_eq!(repairs[0].repair_reason(), Some(RepairReason::BackfillStart));
+
+        assert!(service.check(&options()).await.unwrap().is_clean());
+    }
+
+    #[tokio::test]
+    async fn 二重停止は補正対象外として報告されること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let start = service.repository.save_event(&TimeEntryEvent::start_at(task_id, at(9, 0))).await.unwrap();
+        service.repository.save_event(&TimeEntryEvent::stop_at(task_id, start.id().unwrap(), at(10, 0))).await.unwrap();
+        service.repository.save_event(&TimeEntryEvent::stop_at(task_id, start.id().unwrap(), at(10, 5))).await.unwrap();
+
+        let report = service.check(&options()).await.unwrap();
+        assert_eq!(report.anomalies.len(), 1);
+        assert_eq!(report.anomalies[0].kind(), "orphan_stop");
+        assert_eq!(report.repairable_count(), 0);
+        assert!(service.repair(&options(), &ALL_REASONS).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn 長時間実行が検出され自動停止で補正されること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let start = service.repository.save_event(&TimeEntryEvent::start_at(task_id, at(9, 0))).await.unwrap();
+        let options = options().with_max_running_seconds(8 * 60 * 60);
+
+        let report = service.check(&options).await.unwrap();
+        assert_eq!(report.anomalies, vec![TimeEntryAnomaly::LongRunning {
+            task_id,
+            start_event_id: start.id().unwrap(),
+            start_time: at(9, 0),
+            cutoff_time: at(17, 0),
+        }]);
+
+        // 対象外の理由のみ指定した場合は補正しない
+        assert!(service.repair(&options, &[RepairReason::ImplicitStop]).await.unwrap().is_empty());
+
+        let repairs = service.repair(&options, &ALL_REASONS).await.unwrap();
+        assert_eq!(repairs[0].at(), at(17, 0));
+        assert_eq!(repairs[0].repair_reason(), Some(RepairReason::AutoCutoff));
+        assert!(service.check(&options).await.unwrap().is_clean());
+    }
+
+    #[test]
+    fn 一時停止していた時間は長時間実行の判定に含めないこと() {
+        let task_id = TaskId::new(1).unwrap();
+        let options = options().with_max_running_seconds(8 * 60 * 60);
+
+        // 12:00〜13:00 に一時停止した区間は 18:00 に計測時間が8時間に達する
+        let resumed = vec![
+            TimeEntryEvent::start_at(task_id, at(9, 0)).with_id(1),
+            TimeEntryEvent::pause_at(task_id, 1, at(12, 0)).with_id(2),
+            TimeEntryEvent::resume_at(task_id, 1, at(13, 0)).with_id(3),
+        ];
+        let anomalies = scan_time_entry_events(&resumed, &options);
+        assert_eq!(anomalies, vec![TimeEntryAnomaly::LongRunning {
+            task_id,
+            start_event_id: 1,
+            start_time: at(9, 0),
+            cutoff_time: at(18, 0),
+        }]);
+
+        // 上限に達する前から一時停止中の区間は報告しない
+        let paused = vec![
+            TimeEntryEvent::start_at(task_id, at(9, 0)).with_id(1),
+            TimeEntryEvent::pause_at(task_id, 1, at(12, 0)).with_id(2),
+        ];
+        assert!(scan_time_entry_events(&paused, &options).is_empty());
+    }
+
+    #[test]
+    fn 逆行区間が補正対象外として検出されること() {
+        let task_id = TaskId::new(1).unwrap();
+        let adjustment = crate::domain::entities::TimeEntryAdjustment {
+            start_time: at(10, 0),
+            end_time: Some(at(9, 0)),
+        };
+        let events = vec![
+            TimeEntryEvent::start_at(task_id, at(9, 0)).with_id(1),
+            TimeEntryEvent::stop_at(task_id, 1, at(10, 0)).with_id(2),
+            TimeEntryEvent::adjust(task_id, 1, adjustment).with_id(3),
+        ];
+
+        let anomalies = scan_time_entry_events(&events, &options());
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].kind(), "reversed_interval");
+        assert!(anomalies[0].repair_event().is_none());
+    }
+
+    #[test]
+    fn 無効化された区間は報告されないこと() {
+        let task_id = TaskId::new(1).unwrap();
+        let events = vec![
+            TimeEntryEvent::start_at(task_id, at(9, 0)).with_id(1),
+            TimeEntryEvent::void(task_id, 1).with_id(2),
+        ];
+
+        assert!(scan_time_entry_events(&events, &options()).is_empty());
+    }
+}
@@ -1,5 +1,6 @@
-use crate::domain::entities::time_entry::{TimeEntryEvent};
+use crate::domain::entities::time_entry::{RepairReason, TimeEntryAdjustment, TimeEntryEvent};
 use crate::domain::repositories::TimeEntryRepository;
+use crate::domain::services::{AutoCutoffPolicy, TimerExclusivity};
 use crate::domain::value_objects::TaskId;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -7,15 +8,21 @@ use chrono::{DateTime, Utc};
 /// タイムトラッキングサービス
 #[async_trait]
 pub trait TimeTrackingService: Send + Sync {
-    /// タイマーを開始する
+    /// タイマーを開始する（開始時刻を指定しない場合は現在時刻）
     /// 同一タスクで実行中の区間がある場合は、自動的に停止してから新しい区間を開始
-    async fn start_timer(&self, task_id: TaskId) -> anyhow::Result<TimeEntryEvent>;
+    async fn start_timer(&self, task_id: TaskId, at: Option<DateTime<Utc>>) -> anyhow::Result<TimeEntryEvent>;
 
-    /// タイマーを停止する
-    async fn stop_timer(&self, task_id: TaskId) -> anyhow::Result<Option<TimeEntryEvent>>;
+    /// タイマーを停止する（停止時刻を指定しない場合は現在時刻）
+    async fn stop_timer(&self, task_id: TaskId, at: Option<DateTime<Utc>>) -> anyhow::Result<Option<TimeEntryEvent>>;
 
-    /// 現在実行中のタスクを取得
-    async fn get_running_task(&self) -> anyhow::Result<Option<TaskId>>;
+    /// 実行中のタイマーを一時停止する（区間は閉じずに一時停止イベントを追加。時刻を指定しない場合は現在時刻）
+    async fn pause_timer(&self, task_id: TaskId, at: Option<DateTime<Utc>>) -> anyhow::Result<TimeEntryEvent>;
+
+    /// 一時停止中のタイマーを再開する（同じ区間に再開イベントを追加。時刻を指定しない場合は現在時刻）
+    async fn resume_timer(&self, task_id: TaskId, at: Option<DateTime<Utc>>) -> anyhow::Result<TimeEntryEvent>;
+
+    /// 現在実行中のタスクを全て取得（開始時刻の新しい順、排他モードでは最大1件）
+    async fn get_running_tasks(&self) -> anyhow::Result<Vec<TaskId>>;
 
     /// 指定タスクが実行中かどうか
     async fn is_task_running(&self, task_id: TaskId) -> anyhow::Result<bool>;
@@ -29,55 +36,132 @@ pub trait TimeTrackingService: Send + Sync {
         note: Option<String>,
     ) -> anyhow::Result<(TimeEntryEvent, TimeEntryEvent)>;
 
+    /// 既存の時間区間の開始・終了を訂正する（訂正イベントを追加）
+    async fn adjust_entry(
+        &self,
+        start_event_id: i64,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<TimeEntryEvent>;
+
+    /// 時間区間を無効化する（無効化イベントを追加）
+    async fn void_entry(&self, start_event_id: i64) -> anyhow::Result<TimeEntryEvent>;
+
+    /// 時間区間の無効化を取り消す（無効化取り消しイベントを追加）
+    async fn unvoid_entry(&self, start_event_id: i64) -> anyhow::Result<TimeEntryEvent>;
+
+    /// 時間区間にメモを追記する（注釈イベントを追加）
+    async fn annotate_entry(&self, start_event_id: i64, note: String) -> anyhow::Result<TimeEntryEvent>;
+
     /// 全ての実行中タイマーを停止
     async fn stop_all_timers(&self) -> anyhow::Result<Vec<TimeEntryEvent>>;
+
+    /// 打ち切り時刻を過ぎた実行中タイマーを打ち切り時刻で自動停止する
+    async fn apply_auto_cutoff(
+        &self,
+        policy: &AutoCutoffPolicy,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimeEntryEvent>>;
 }
 
 /// タイムトラッキングサービス実装
 pub struct TimeTrackingServiceImpl<R: TimeEntryRepository> {
     repository: R,
+    exclusivity: TimerExclusivity,
 }
 
 impl<R: TimeEntryRepository> TimeTrackingServiceImpl<R> {
     pub fn new(repository: R) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            exclusivity: TimerExclusivity::default(),
+        }
+    }
+
+    /// タイマーの排他ポリシーを設定
+    pub fn with_exclusivity(mut self, exclusivity: TimerExclusivity) -> Self {
+        self.exclusivity = exclusivity;
+        self
     }
 }
 
 #[async_trait]
 impl<R: TimeEntryRepository> TimeTrackingService for TimeTrackingServiceImpl<R> {
-    async fn start_timer(&self, task_id: TaskId) -> anyhow::Result<TimeEntryEvent> {
-        // Step 1: 他のタスクで実行中のタイマーがあれば停止
-        let running_entries = self.repository.find_running_entries().await?;
-        for entry in running_entries {
-            if entry.task_id() != task_id {
-                // 他のタスクのタイマーを停止
-                let stop_event = TimeEntryEvent::stop(entry.task_id(), entry.start_event_id());
-                self.repository.save_event(&stop_event).await?;
+    async fn start_timer(&self, task_id: TaskId, at: Option<DateTime<Utc>>) -> anyhow::Result<TimeEntryEvent> {
+        let now = Utc::now();
+        let start_time = at.unwrap_or(now);
+
+        // 停止する実行中の区間（同一タスクの区間は暗黙stop、排他モードでは他タスクの区間も停止）
+        let entries_to_stop: Vec<_> = self
+            .repository
+            .find_running_entries()
+            .await?
+            .into_iter()
+            .filter(|e| e.task_id() == task_id || self.exclusivity.is_exclusive())
+            .collect();
+
+        // 遡って開始する場合のバリデーション
+        if at.is_some() {
+            if start_time > now {
+                return Err(anyhow::anyhow!("Start time must not be in the future"));
+            }
+
+            // 実行中の区間は開始時刻で停止するため、それより前に開始していなければならない
+            if entries_to_stop.iter().any(|e| e.start_time() >= start_time) {
+                return Err(anyhow::anyhow!("Start time must be after the running timer's start time"));
+            }
+
+            // 直前に停止した区間と重複しないこと（同時実行モードでは同一タスクの区間のみ）
+            let overlaps = if self.exclusivity.is_exclusive() {
+                let previous = self.repository.find_latest_stopped_entry().await?;
+                previous.and_then(|e| e.end_time()).is_some_and(|end| end > start_time)
+            } else {
+                self.repository
+                    .find_overlapping_entries(task_id, start_time, now)
+                    .await?
+                    .iter()
+                    .any(|e| e.end_time().is_some())
+            };
+            if overlaps {
+                return Err(anyhow::anyhow!("Start time overlaps with the previous time entry"));
             }
         }
 
-        // Step 2: 同一タスクで実行中の区間があれば停止（暗黙stop）
-        let running_for_task = self.repository.find_running_entry_by_task(task_id).await?;
-        if let Some(running_entry) = running_for_task {
-            let stop_event = TimeEntryEvent::stop(task_id, running_entry.start_event_id());
+        // Step 1: 実行中の区間を開始時刻で停止
+        for entry in entries_to_stop {
+            let stop_event = TimeEntryEvent::stop_at(entry.task_id(), entry.start_event_id(), start_time);
             self.repository.save_event(&stop_event).await?;
         }
 
-        // Step 3: 新しい開始イベントを作成・保存
-        let start_event = TimeEntryEvent::start(task_id);
+        // Step 2: 新しい開始イベントを作成・保存
+        let start_event = TimeEntryEvent::start_at(task_id, start_time);
         let saved_event = self.repository.save_event(&start_event).await?;
+
+        // Step 3: 遡って開始した区間が復旧待ちと判定されないよう、開始操作の時刻でハートビートを記録
+        if let Some(start_event_id) = saved_event.id() {
+            self.repository.save_heartbeat(start_event_id, now).await?;
+        }
         
         Ok(saved_event)
     }
 
-    async fn stop_timer(&self, task_id: TaskId) -> anyhow::Result<Option<TimeEntryEvent>> {
+    async fn stop_timer(&self, task_id: TaskId, at: Option<DateTime<Utc>>) -> anyhow::Result<Option<TimeEntryEvent>> {
+        let now = Utc::now();
+        let stop_time = at.unwrap_or(now);
+        if stop_time > now {
+            return Err(anyhow::anyhow!("Stop time cannot be in the future"));
+        }
+
         // 実行中の区間を取得
         let running_entry = self.repository.find_running_entry_by_task(task_id).await?;
         
         match running_entry {
             Some(entry) => {
-                let stop_event = TimeEntryEvent::stop(task_id, entry.start_event_id());
+                if at.is_some() && stop_time <= entry.start_time() {
+                    return Err(anyhow::anyhow!("Stop time must be after start time"));
+                }
+
+                let stop_event = TimeEntryEvent::stop_at(task_id, entry.start_event_id(), stop_time);
                 let saved_event = self.repository.save_event(&stop_event).await?;
                 Ok(Some(saved_event))
             }
@@ -85,9 +169,62 @@ impl<R: TimeEntryRepository> TimeTrackingService for TimeTrackingServiceImpl<R>
         }
     }
 
-    async fn get_running_task(&self) -> anyhow::Result<Option<TaskId>> {
+    async fn pause_timer(&self, task_id: TaskId, at: Option<DateTime<Utc>>) -> anyhow::Result<TimeEntryEvent> {
+        let now = Utc::now();
+        let pause_time = at.unwrap_or(now);
+        if pause_time > now {
+            return Err(anyhow::anyhow!("Pause time cannot be in the future"));
+        }
+
+        let entry = self
+            .repository
+            .find_running_entry_by_task(task_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("No running timer for task"))?;
+
+        if entry.is_paused() {
+            return Err(anyhow::anyhow!("Timer is already paused"));
+        }
+        // 開始時刻と直前の再開時刻より前には一時停止できない
+        let resumed_at = entry.pause_intervals().last().map(|(_, resumed_at)| *resumed_at);
+        if pause_time < entry.start_time() || resumed_at.is_some_and(|resumed_at| pause_time < resumed_at) {
+            return Err(anyhow::anyhow!("Pause time must be after the timer's start or last resume time"));
+        }
+
+        let pause_event = TimeEntryEvent::pause_at(task_id, entry.start_event_id(), pause_time);
+        self.repository.save_event(&pause_event).await
+    }
+
+    async fn resume_timer(&self, task_id: TaskId, at: Option<DateTime<Utc>>) -> anyhow::Result<TimeEntryEvent> {
+        let now = Utc::now();
+        let resume_time = at.unwrap_or(now);
+        if resume_time > now {
+            return Err(anyhow::anyhow!("Resume time cannot be in the future"));
+        }
+
+        let entry = self
+            .repository
+            .find_running_entry_by_task(task_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("No running timer for task"))?;
+
+        let Some(paused_at) = entry.paused_at() else {
+            return Err(anyhow::anyhow!("Timer is not paused"));
+        };
+        if resume_time < paused_at {
+            return Err(anyhow::anyhow!("Resume time must be after the pause time"));
+        }
+
+        let resume_event = TimeEntryEvent::resume_at(task_id, entry.start_event_id(), resume_time);
+        let saved_event = self.repository.save_event(&resume_event).await?;
+        // 遡って再開した区間が復旧待ちと判定されないよう、再開操作の時刻でハートビートを記録
+        self.repository.save_heartbeat(entry.start_event_id(), now).await?;
+        Ok(saved_event)
+    }
+
+    async fn get_running_tasks(&self) -> anyhow::Result<Vec<TaskId>> {
         let running_entries = self.repository.find_running_entries().await?;
-        Ok(running_entries.first().map(|entry| entry.task_id()))
+        Ok(running_entries.iter().map(|entry| entry.task_id()).collect())
     }
 
     async fn is_task_running(&self, task_id: TaskId) -> anyhow::Result<bool> {
@@ -142,6 +279,117 @@ impl<R: TimeEntryRepository> TimeTrackingService for TimeTrackingServiceImpl<R>
         Ok((saved_start_event, saved_stop_event))
     }
 
+    async fn adjust_entry(
+        &self,
+        start_event_id: i64,
+        start_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<TimeEntryEvent> {
+        let entry = self
+            .repository
+            .find_entry_by_start_event_id(start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry not found"))?;
+
+        if start_time.is_none() && end_time.is_none() {
+            return Err(anyhow::anyhow!("No changes specified"));
+        }
+        // 実行中の区間は訂正で終了させず、停止イベント（stop_timer）で終了させる
+        if end_time.is_some() && entry.is_running() {
+            return Err(anyhow::anyhow!("Cannot set end time of a running entry; stop the timer instead"));
+        }
+
+        // 指定されなかった側は現在の値を引き継ぐ
+        let new_start = start_time.unwrap_or(entry.start_time());
+        let new_end = end_time.or(entry.end_time());
+
+        // バリデーション
+        match new_end {
+            Some(end) if new_start >= end => {
+                return Err(anyhow::anyhow!("Start time must be before end time"));
+            }
+            None if new_start > Utc::now() => {
+                return Err(anyhow::anyhow!("Start time must not be in the future"));
+            }
+            _ => {}
+        }
+
+        // 重複チェック（訂正対象自身を除く既存の区間と重複しないか）
+        let overlapping = self
+            .repository
+            .find_overlapping_entries(entry.task_id(), new_start, new_end.unwrap_or_else(Utc::now))
+            .await?;
+
+        if overlapping.iter().any(|e| e.start_event_id() != start_event_id) {
+            return Err(anyhow::anyhow!("Time entry overlaps with existing entries"));
+        }
+
+        let adjustment = TimeEntryAdjustment {
+            start_time: new_start,
+            end_time: new_end,
+        };
+        let adjust_event = TimeEntryEvent::adjust(entry.task_id(), start_event_id, adjustment);
+        self.repository.save_event(&adjust_event).await
+    }
+
+    async fn void_entry(&self, start_event_id: i64) -> anyhow::Result<TimeEntryEvent> {
+        let entry = self
+            .repository
+            .find_entry_by_start_event_id(start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry not found"))?;
+
+        // 実行中の区間を無効化するとタイマーだけが動き続けるため、先に停止させる
+        if entry.is_running() {
+            return Err(anyhow::anyhow!("Cannot delete a running time entry; stop the timer first"));
+        }
+
+        let void_event = TimeEntryEvent::void(entry.task_id(), start_event_id);
+        self.repository.save_event(&void_event).await
+    }
+
+    async fn unvoid_entry(&self, start_event_id: i64) -> anyhow::Result<TimeEntryEvent> {
+        let entry = self
+            .repository
+            .find_voided_entry_by_start_event_id(start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry is not voided"))?;
+
+        // 重複チェック（無効化中に追加された区間と重複しないか）
+        let overlapping = self
+            .repository
+            .find_overlapping_entries(
+                entry.task_id(),
+                entry.start_time(),
+                entry.end_time().unwrap_or_else(Utc::now),
+            )
+            .await?;
+
+        if !overlapping.is_empty() {
+            return Err(anyhow::anyhow!("Time entry overlaps with existing entries"));
+        }
+
+        let unvoid_event = TimeEntryEvent::unvoid(entry.task_id(), start_event_id);
+        self.repository.save_event(&unvoid_event).await
+    }
+
+    async fn annotate_entry(&self, start_event_id: i64, note: String) -> anyhow::Result<TimeEntryEvent> {
+        let note = note.trim().to_string();
+        if note.is_empty() {
+            return Err(anyhow::anyhow!("Note cannot be empty"));
+        }
+
+        // 実行中・終了済みのどちらの区間にも追記できる
+        let entry = self
+            .repository
+            .find_entry_by_start_event_id(start_event_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Time entry not found"))?;
+
+        let annotate_event = TimeEntryEvent::annotate(entry.task_id(), start_event_id, note);
+        self.repository.save_event(&annotate_event).await
+    }
+
     async fn stop_all_timers(&self) -> anyhow::Result<Vec<TimeEntryEvent>> {
         let running_entries = self.repository.find_running_entries().await?;
         let mut stop_events = Vec::new();
@@ -154,6 +402,29 @@ impl<R: TimeEntryRepository> TimeTrackingService for TimeTrackingServiceImpl<R>
 
         Ok(stop_events)
     }
+
+    async fn apply_auto_cutoff(
+        &self,
+        policy: &AutoCutoffPolicy,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimeEntryEvent>> {
+        let running_entries = self.repository.find_running_entries().await?;
+        let mut stop_events = Vec::new();
+
+        for entry in running_entries {
+            let Some(cutoff) = policy.cutoff_for(&entry) else { continue };
+            if cutoff > now {
+                continue;
+            }
+
+            let stop_event = TimeEntryEvent::stop_at(entry.task_id(), entry.start_event_id(), cutoff)
+                .with_repair_reason(RepairReason::AutoCutoff);
+            let saved_event = self.repository.save_event(&stop_event).await?;
+            stop_events.push(saved_event);
+        }
+
+        Ok(stop_events)
+    }
 }
 
 #[cfg(test)]
@@ -161,6 +432,7 @@ impl<R: TimeEntryRepository> TimeTrackingService for TimeTrackingServiceImpl<R>
 mod tests {
     use super::*;
     use crate::domain::repositories::time_entry_repository::tests::InMemoryTimeEntryRepository;
+    use crate::domain::services::LocalCalendar;
     use chrono::TimeZone;
 
     async fn setup_service() -> TimeTrackingServiceImpl<InMemoryTimeEntryRepository> {
@@ -173,7 +445,7 @@ mod tests {
         let service = setup_service().await;
         let task_id = TaskId::new(1).unwrap();
 
-        let start_event = service.start_timer(task_id).await.unwrap();
+        let start_event = service.start_timer(task_id, None).await.unwrap();
 
         assert_eq!(start_event.task_id(), task_id);
         assert!(start_event.is_start());
@@ -181,7 +453,7 @@ mod tests {
 
         // 実行中状態を確認
         assert!(service.is_task_running(task_id).await.unwrap());
-        assert_eq!(service.get_running_task().await.unwrap(), Some(task_id));
+        assert_eq!(service.get_running_tasks().await.unwrap(), vec![task_id]);
     }
 
     #[tokio::test]
@@ -190,10 +462,10 @@ mod tests {
         let task_id = TaskId::new(1).unwrap();
 
         // 開始
-        let start_event = service.start_timer(task_id).await.unwrap();
+        let start_event = service.start_timer(task_id, None).await.unwrap();
 
         // 停止
-        let stop_event = service.stop_timer(task_id).await.unwrap();
+        let stop_event = service.stop_timer(task_id, None).await.unwrap();
 
         assert!(stop_event.is_some());
         let stop_event = stop_event.unwrap();
@@ -203,7 +475,7 @@ mod tests {
 
         // 停止状態を確認
         assert!(!service.is_task_running(task_id).await.unwrap());
-        assert_eq!(service.get_running_task().await.unwrap(), None);
+        assert_eq!(service.get_running_tasks().await.unwrap(), Vec::<TaskId>::new());
     }
 
     #[tokio::test]
@@ -212,11 +484,11 @@ mod tests {
         let task_id = TaskId::new(1).unwrap();
 
         // 最初の開始
-        let first_start = service.start_timer(task_id).await.unwrap();
+        let first_start = service.start_timer(task_id, None).await.unwrap();
         assert!(service.is_task_running(task_id).await.unwrap());
 
         // 同じタスクで再度開始（暗黙停止 + 新規開始）
-        let second_start = service.start_timer(task_id).await.unwrap();
+        let second_start = service.start_timer(task_id, None).await.unwrap();
         assert!(service.is_task_running(task_id).await.unwrap());
 
         // 新しい開始イベントであることを確認
@@ -236,15 +508,87 @@ mod tests {
         let task2 = TaskId::new(2).unwrap();
 
         // タスク1で開始
-        service.start_timer(task1).await.unwrap();
+        service.start_timer(task1, None).await.unwrap();
         assert!(service.is_task_running(task1).await.unwrap());
-        assert_eq!(service.get_running_task().await.unwrap(), Some(task1));
+        assert_eq!(service.get_running_tasks().await.unwrap(), vec![task1]);
 
         // タスク2で開始（タスク1は自動停止される）
-        service.start_timer(task2).await.unwrap();
+        service.start_timer(task2, None).await.unwrap();
         assert!(!service.is_task_running(task1).await.unwrap());
         assert!(service.is_task_running(task2).await.unwrap());
-        assert_eq!(service.get_running_task().await.unwrap(), Some(task2));
+        assert_eq!(service.get_running_tasks().await.unwrap(), vec![task2]);
+    }
+
+    #[tokio::test]
+    async fn 同時実行モードでは他タスクのタイマーが継続すること() {
+        let service = TimeTrackingServiceImpl::new(InMemoryTimeEntryRepository::new())
+            .with_exclusivity(TimerExclusivity::Concurrent);
+        let task1 = TaskId::new(1).unwrap();
+        let task2 = TaskId::new(2).unwrap();
+        let now = Utc::now();
+
+        service.start_timer(task1, Some(now - chrono::Duration::hours(2))).await.unwrap();
+        // 他タスクの実行中区間と重なる遡り開始も可能
+        service.start_timer(task2, Some(now - chrono::Duration::hours(3))).await.unwrap();
+
+        let running = service.get_running_tasks().await.unwrap();
+        assert_eq!(running.len(), 2);
+        assert!(running.contains(&task1) && running.contains(&task2));
+
+        // 同一タスクの再開始は従来どおり暗黙停止
+        service.start_timer(task1, None).await.unwrap();
+        assert_eq!(service.get_running_tasks().await.unwrap().len(), 2);
+        assert_eq!(service.repository.find_entries_by_task(task1).await.unwrap().len(), 2);
+
+        // 同一タスクの停止済み区間との重複はエラー
+        service.stop_timer(task2, None).await.unwrap();
+        assert!(service.start_timer(task2, Some(now - chrono::Duration::hours(1))).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn 開始時刻を遡ってタイマーを開始できること() {
+        let service = setup_service().await;
+        let task1 = TaskId::new(1).unwrap();
+        let task2 = TaskId::new(2).unwrap();
+        let now = Utc::now();
+
+        service
+            .add_manual_entry(task1, now - chrono::Duration::hours(3), now - chrono::Duration::hours(2), None)
+            .await
+            .unwrap();
+        service.start_timer(task1, Some(now - chrono::Duration::minutes(90))).await.unwrap();
+
+        // 直前に停止した区間との重複や未来の開始時刻はエラー
+        let overlapping = service.start_timer(task2, Some(now - chrono::Duration::minutes(150))).await;
+        assert!(overlapping.is_err());
+        assert!(service.start_timer(task2, Some(now + chrono::Duration::minutes(5))).await.is_err());
+        // 実行中の区間の開始より前には遡れない
+        assert!(service.start_timer(task2, Some(now - chrono::Duration::minutes(100))).await.is_err());
+
+        // 実行中の他タスクは遡った開始時刻で停止される
+        let start_time = now - chrono::Duration::minutes(15);
+        let start_event = service.start_timer(task2, Some(start_time)).await.unwrap();
+        assert_eq!(start_event.at(), start_time);
+        let entries = service.repository.find_entries_by_task(task1).await.unwrap();
+        assert!(entries.iter().any(|e| e.end_time() == Some(start_time)));
+        assert_eq!(service.get_running_tasks().await.unwrap(), vec![task2]);
+    }
+
+    #[tokio::test]
+    async fn 停止時刻を指定してタイマーを停止できること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let now = Utc::now();
+        service.start_timer(task_id, Some(now - chrono::Duration::hours(1))).await.unwrap();
+
+        // 未来や開始以前の停止時刻はエラー
+        assert!(service.stop_timer(task_id, Some(now + chrono::Duration::minutes(5))).await.is_err());
+        assert!(service.stop_timer(task_id, Some(now - chrono::Duration::hours(2))).await.is_err());
+
+        let stop_time = now - chrono::Duration::minutes(30);
+        let stop_event = service.stop_timer(task_id, Some(stop_time)).await.unwrap().unwrap();
+        assert_eq!(stop_event.at(), stop_time);
+        assert!(!service.is_task_running(task_id).await.unwrap());
     }
 
     #[tokio::test]
@@ -291,10 +635,97 @@ mod tests {
         let service = setup_service().await;
         let task_id = TaskId::new(1).unwrap();
 
-        let stop_event = service.stop_timer(task_id).await.unwrap();
+        let stop_event = service.stop_timer(task_id, None).await.unwrap();
         assert!(stop_event.is_none());
     }
 
+    #[tokio::test]
+    async fn 一時停止と再開が同じ区間に記録されること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+
+        // 実行中でなければ一時停止できない
+        assert!(service.pause_timer(task_id, None).await.is_err());
+
+        let start_event = service.start_timer(task_id, None).await.unwrap();
+        let pause_event = service.pause_timer(task_id, None).await.unwrap();
+        assert!(pause_event.is_pause());
+        assert_eq!(pause_event.start_event_id(), start_event.id());
+        assert!(service.is_task_running(task_id).await.unwrap());
+
+        // 二重の一時停止・一時停止していない状態での再開はエラー
+        assert!(service.pause_timer(task_id, None).await.unwrap_err().to_string().contains("already paused"));
+        let resume_event = service.resume_timer(task_id, None).await.unwrap();
+        assert!(resume_event.is_resume());
+        assert_eq!(resume_event.start_event_id(), start_event.id());
+        assert!(service.resume_timer(task_id, None).await.unwrap_err().to_string().contains("not paused"));
+    }
+
+    #[tokio::test]
+    async fn 指定した時刻で一時停止と再開ができること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let start_time = Utc::now() - chrono::Duration::hours(3);
+        let at = |minutes| start_time + chrono::Duration::minutes(minutes);
+        service.start_timer(task_id, Some(start_time)).await.unwrap();
+
+        // 開始前・未来の時刻では一時停止できない
+        assert!(service.pause_timer(task_id, Some(at(-1))).await.is_err());
+        assert!(service.pause_timer(task_id, Some(Utc::now() + chrono::Duration::minutes(5))).await.is_err());
+
+        let pause_event = service.pause_timer(task_id, Some(at(60))).await.unwrap();
+        assert_eq!(pause_event.at(), at(60));
+
+        // 一時停止より前には再開できない
+        assert!(service.resume_timer(task_id, Some(at(59))).await.unwrap_err().to_string().contains("after the pause time"));
+        let resume_event = service.resume_timer(task_id, Some(at(90))).await.unwrap();
+        assert_eq!(resume_event.at(), at(90));
+
+        // 直前の再開より前には一時停止できない
+        assert!(service.pause_timer(task_id, Some(at(80))).await.is_err());
+        service.pause_timer(task_id, Some(at(120))).await.unwrap();
+
+        let entry = service.repository.find_running_entry_by_task(task_id).await.unwrap().unwrap();
+        assert_eq!(entry.paused_seconds(), 30 * 60);
+        assert_eq!(entry.paused_at(), Some(at(120)));
+        assert_eq!(entry.elapsed_seconds(), 90 * 60);
+    }
+
+    #[tokio::test]
+    async fn 一時停止時間が作業時間から除外されること() {
+        let repository = InMemoryTimeEntryRepository::new();
+        let service = TimeTrackingServiceImpl::new(repository.clone());
+        let task_id = TaskId::new(1).unwrap();
+        let start_time = Utc::now() - chrono::Duration::hours(3);
+
+        let start_event = repository.save_event(&TimeEntryEvent::start_at(task_id, start_time)).await.unwrap();
+        let start_event_id = start_event.id().unwrap();
+        repository
+            .save_event(&TimeEntryEvent::pause_at(task_id, start_event_id, start_time + chrono::Duration::minutes(60)))
+            .await
+            .unwrap();
+
+        // 一時停止中は経過時間が止まる
+        let entry = repository.find_running_entry_by_task(task_id).await.unwrap().unwrap();
+        assert!(entry.is_paused());
+        assert_eq!(entry.elapsed_seconds(), 60 * 60);
+
+        repository
+            .save_event(&TimeEntryEvent::resume_at(task_id, start_event_id, start_time + chrono::Duration::minutes(90)))
+            .await
+            .unwrap();
+        repository
+            .save_event(&TimeEntryEvent::stop_at(task_id, start_event_id, start_time + chrono::Duration::minutes(150)))
+            .await
+            .unwrap();
+
+        let entry = repository.find_entry_by_start_event_id(start_event_id).await.unwrap().unwrap();
+        assert!(!entry.is_paused());
+        assert_eq!(entry.paused_seconds(), 30 * 60);
+        assert_eq!(entry.duration_in_seconds(), Some(120 * 60));
+        assert!(!service.is_task_running(task_id).await.unwrap());
+    }
+
     #[tokio::test]
     async fn 全タイマー停止が正しく動作すること() {
         let service = setup_service().await;
@@ -302,8 +733,8 @@ mod tests {
         let task2 = TaskId::new(2).unwrap();
 
         // 複数のタスクでタイマーを開始（これは実際には排他制御により最後のもののみ実行中）
-        service.start_timer(task1).await.unwrap();
-        service.start_timer(task2).await.unwrap();
+        service.start_timer(task1, None).await.unwrap();
+        service.start_timer(task2, None).await.unwrap();
 
         let stop_events = service.stop_all_timers().await.unwrap();
         assert_eq!(stop_events.len(), 1); // 実行中のタスクは1つのみ
@@ -312,6 +743,190 @@ mod tests {
         // 全てのタスクが停止していることを確認
         assert!(!service.is_task_running(task1).await.unwrap());
         assert!(!service.is_task_running(task2).await.unwrap());
-        assert_eq!(service.get_running_task().await.unwrap(), None);
+        assert_eq!(service.get_running_tasks().await.unwrap(), Vec::<TaskId>::new());
+    }
+    #[tokio::test]
+    async fn 時間区間の訂正が正しく動作すること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let (start_event, _) = service
+            .add_manual_entry(
+                task_id,
+                Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 11, 0, 0).unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+        let start_event_id = start_event.id().unwrap();
+
+        // 終了時刻のみ訂正
+        let new_end = Utc.with_ymd_and_hms(2024, 1, 1, 11, 30, 0).unwrap();
+        let adjust_event = service
+            .adjust_entry(start_event_id, None, Some(new_end))
+            .await
+            .unwrap();
+        assert!(adjust_event.is_adjust());
+
+        let entry = service.repository.find_entry_by_start_event_id(start_event_id).await.unwrap().unwrap();
+        assert_eq!(entry.start_time(), Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap());
+        assert_eq!(entry.end_time(), Some(new_end));
+        assert_eq!(entry.duration_in_seconds(), Some(5400));
+    }
+
+    #[tokio::test]
+    async fn 実行中の区間の終了時刻は訂正できないこと() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let start_event = service.start_timer(task_id, None).await.unwrap();
+        let start_event_id = start_event.id().unwrap();
+
+        let result = service.adjust_entry(start_event_id, None, Some(Utc::now())).await;
+        assert!(result.unwrap_err().to_string().contains("stop the timer instead"));
+        assert!(service.is_task_running(task_id).await.unwrap());
+
+        // 開始時刻だけなら訂正できる
+        let new_start = start_event.at() - chrono::Duration::minutes(10);
+        service.adjust_entry(start_event_id, Some(new_start), None).await.unwrap();
+        let entry = service.repository.find_entry_by_start_event_id(start_event_id).await.unwrap().unwrap();
+        assert_eq!(entry.start_time().timestamp(), new_start.timestamp());
+        assert!(entry.is_running());
+    }
+
+    #[tokio::test]
+    async fn 重複する訂正が失敗すること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let (first, _) = service
+            .add_manual_entry(
+                task_id,
+                Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 11, 0, 0).unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+        service
+            .add_manual_entry(
+                task_id,
+                Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 13, 0, 0).unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let result = service
+            .adjust_entry(
+                first.id().unwrap(),
+                None,
+                Some(Utc.with_ymd_and_hms(2024, 1, 1, 12, 30, 0).unwrap()),
+            )
+            .await;
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("overlaps"));
+
+        let result = service
+            .adjust_entry(
+                first.id().unwrap(),
+                Some(Utc.with_ymd_and_hms(2024, 1, 1, 11, 30, 0).unwrap()),
+                None,
+            )
+            .await;
+        assert!(result.unwrap_err().to_string().contains("Start time must be before end time"));
+    }
+    #[tokio::test]
+    async fn 時間区間の無効化と取り消しが正しく動作すること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let (start_event, _) = service
+            .add_manual_entry(
+                task_id,
+                Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 3).unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+        let start_event_id = start_event.id().unwrap();
+
+        let void_event = service.void_entry(start_event_id).await.unwrap();
+        assert!(void_event.is_void());
+        assert!(service.repository.find_entries_by_task(task_id).await.unwrap().is_empty());
+        assert_eq!(service.repository.sum_duration_by_task(task_id).await.unwrap(), 0);
+
+        // 二重の無効化は失敗する
+        assert!(service.void_entry(start_event_id).await.is_err());
+
+        let unvoid_event = service.unvoid_entry(start_event_id).await.unwrap();
+        assert!(unvoid_event.is_unvoid());
+        assert_eq!(service.repository.find_entries_by_task(task_id).await.unwrap().len(), 1);
+
+        // 無効化されていない区間の取り消しは失敗する
+        let result = service.unvoid_entry(start_event_id).await;
+        assert!(result.unwrap_err().to_string().contains("Time entry is not voided"));
+    }
+
+    #[tokio::test]
+    async fn 実行中の区間は無効化できないこと() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let start_event = service.start_timer(task_id, None).await.unwrap();
+
+        let result = service.void_entry(start_event.id().unwrap()).await;
+        assert!(result.unwrap_err().to_string().contains("stop the timer first"));
+        assert!(service.is_task_running(task_id).await.unwrap());
+        assert_eq!(service.repository.find_entries_by_task(task_id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn 時間区間へのメモ追記が正しく動作すること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let start_event = service.start_timer(task_id, None).await.unwrap();
+        let start_event_id = start_event.id().unwrap();
+
+        // 実行中の区間に追記できる
+        service.annotate_entry(start_event_id, " 仕様確認 ".to_string()).await.unwrap();
+        service.stop_timer(task_id, None).await.unwrap();
+
+        // 終了済みの区間にも追記でき、発生順に連結される
+        let annotate_event = service.annotate_entry(start_event_id, "レビュー対応".to_string()).await.unwrap();
+        assert!(annotate_event.is_annotate());
+
+        let entry = service.repository.find_entry_by_start_event_id(start_event_id).await.unwrap().unwrap();
+        assert_eq!(entry.notes(), Some("仕様確認\nレビュー対応"));
+
+        // 空のメモや存在しない区間への追記は失敗する
+        let result = service.annotate_entry(start_event_id, "   ".to_string()).await;
+        assert!(result.unwrap_err().to_string().contains("Note cannot be empty"));
+        let result = service.annotate_entry(9999, "メモ".to_string()).await;
+        assert!(result.unwrap_err().to_string().contains("Time entry not found"));
+    }
+
+    #[tokio::test]
+    async fn 打ち切り時刻を過ぎたタイマーが自動停止されること() {
+        let service = setup_service().await;
+        let task_id = TaskId::new(1).unwrap();
+        let start_time = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let start_event = service.repository.save_event(&TimeEntryEvent::start_at(task_id, start_time)).await.unwrap();
+        let policy = AutoCutoffPolicy::new(Some(8 * 60 * 60), None, LocalCalendar::utc()).unwrap();
+
+        // 打ち切り時刻前は何もしない
+        let stopped = service
+            .apply_auto_cutoff(&policy, Utc.with_ymd_and_hms(2024, 1, 1, 16, 59, 0).unwrap())
+            .await
+            .unwrap();
+        assert!(stopped.is_empty());
+
+        let stopped = service
+            .apply_auto_cutoff(&policy, Utc.with_ymd_and_hms(2024, 1, 2, 8, 0, 0).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(stopped.len(), 1);
+        assert_eq!(stopped[0].start_event_id(), start_event.id());
+        assert_eq!(stopped[0].at(), Utc.with_ymd_and_hms(2024, 1, 1, 17, 0, 0).unwrap());
+        assert_eq!(stopped[0].repair_reason(), Some(RepairReason::AutoCutoff));
+        assert!(!service.is_task_running(task_id).await.unwrap());
     }
 }
@@ -0,0 +1,35 @@
+use std::str::FromStr;
+
+/// タイマーの排他ポリシー
+/// 排他モードでは開始時に他タスクの実行中タイマーを停止し、同時実行モードでは他タスクのタイマーを継続する
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimerExclusivity {
+    #[default]
+    Exclusive,
+    Concurrent,
+}
+
+impl TimerExclusivity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimerExclusivity::Exclusive => "exclusive",
+            TimerExclusivity::Concurrent => "concurrent",
+        }
+    }
+
+    pub fn is_exclusive(&self) -> bool {
+        matches!(self, TimerExclusivity::Exclusive)
+    }
+}
+
+impl FromStr for TimerExclusivity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "exclusive" => Ok(TimerExclusivity::Exclusive),
+            "concurrent" => Ok(TimerExclusivity::Concurrent),
+            _ => Err(anyhow::anyhow!("Invalid timer exclusivity: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use crate::domain::entities::time_entry::{RepairReason, TimeEntry, TimeEntryEvent};
+use crate::domain::repositories::TimeEntryRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+
+/// 復旧待ち区間の解決方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryResolution {
+    /// 計測を継続する
+    Keep,
+    /// 最終ハートビートの時刻で停止する
+    StopAtLastHeartbeat,
+    /// 指定した時刻で停止する
+    StopAt(DateTime<Utc>),
+}
+
+/// 復旧待ちの区間（最終ハートビートが古い実行中区間）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingRecovery {
+    pub entry: TimeEntry,
+    /// 最後に生存が確認できた時刻（ハートビート未記録の場合は開始時刻）
+    pub last_heartbeat: DateTime<Utc>,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl PendingRecovery {
+    pub fn start_event_id(&self) -> i64 {
+        self.entry.start_event_id()
+    }
+}
+
+/// タイマー復旧サービストレイト
+#[async_trait]
+pub trait TimerRecoveryService: Send + Sync {
+    /// 最終ハートビートが閾値以上古い実行中区間を検出する
+    async fn find_stale_entries(
+        &self,
+        now: DateTime<Utc>,
+        stale_after_seconds: i64,
+    ) -> anyhow::Result<Vec<PendingRecovery>>;
+
+    /// 実行中の区間にハートビートを記録する（除外指定の区間を除く）
+    async fn record_heartbeats(
+        &self,
+        now: DateTime<Utc>,
+        excluded_start_event_ids: &[i64],
+    ) -> anyhow::Result<usize>;
+
+    /// 指定区間がまだ実行中か
+    async fn is_running(&self, start_event_id: i64) -> anyhow::Result<bool>;
+
+    /// 指定区間の現在の時間エントリを取得
+    async fn find_entry(&self, start_event_id: i64) -> anyhow::Result<Option<TimeEntry>>;
+
+    /// 復旧待ちの区間を解決する（停止した場合は停止イベントを返す）
+    async fn resolve(
+        &self,
+        recovery: &PendingRecovery,
+        resolution: RecoveryResolution,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Option<TimeEntryEvent>>;
+}
+
+/// タイマー復旧サービス実装
+pub struct TimerRecoveryServiceImpl<R: TimeEntryRepository> {
+    repository: R,
+}
+
+impl<R: TimeEntryRepository> TimerRecoveryServiceImpl<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    async fn last_heartbeat(&self, entry: &TimeEntry) -> anyhow::Result<DateTime<Utc>> {
+        let heartbeat = self.repository.find_heartbeat(entry.start_event_id()).await?;
+        Ok(heartbeat.map_or(entry.start_time(), |at| at.max(entry.start_time())))
+    }
+}
+
+#[async_trait]
+impl<R: TimeEntryRepository> TimerRecoveryService for TimerRecoveryServiceImpl<R> {
+    async fn find_stale_entries(
+        &self,
+        now: DateTime<Utc>,
+        stale_after_seconds: i64,
+    ) -> anyhow::Result<Vec<PendingRecovery>> {
+        let running_entries = self.repository.find_running_entries().await?;
+        let mut recoveries = Vec::new();
+
+        for entry in running_entries {
+            let last_heartbeat = self.last_heartbeat(&entry).await?;
+            if now - last_heartbeat >= Duration::seconds(stale_after_seconds) {
+                recoveries.push(PendingRecovery {
+                    entry,
+                    last_heartbeat,
+                    detected_at: now,
+                });
+            }
+        }
+
+        Ok(recoveries)
+    }
+
+    async fn record_heartbeats(
+        &self,
+        now: DateTime<Utc>,
+        excluded_start_event_ids: &[i64],
+    ) -> anyhow::Result<usize> {
+        let running_entries = self.repository.find_running_entries().await?;
+        let mut count = 0;
+
+        for entry in running_entries {
+            if excluded_start_event_ids.contains(&entry.start_event_id()) {
+                continue;
+            }
+            self.repository.save_heartbeat(entry.start_event_id(), now).await?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    async fn is_running(&self, start_event_id: i64) -> anyhow::Result<bool> {
+        let entry = self.find_entry(start_event_id).await?;
+        Ok(entry.is_some_and(|e| e.is_running()))
+    }
+
+    async fn find_entry(&self, start_event_id: i64) -> anyhow::Result<Option<TimeEntry>> {
+        self.repository.find_entry_by_start_event_id(start_event_id).await
+    }
+
+    async fn resolve(
+        &self,
+        recovery: &PendingRecovery,
+        resolution: RecoveryResolution,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Option<TimeEntryEvent>> {
+        if !self.is_running(recovery.start_event_id()).await? {
+            return Err(anyhow::anyhow!("Time entry is no longer running"));
+        }
+
+        let stop_time = match resolution {
+            RecoveryResolution::Keep => {
+                self.repository.save_heartbeat(recovery.start_event_id(), now).await?;
+                return Ok(None);
+            }
+            RecoveryResolution::StopAtLastHeartbeat => recovery.last_heartbeat,
+            RecoveryResolution::StopAt(at) => {
+                if at < recovery.entry.start_time() {
+                    return Err(anyhow::anyhow!("Stop time must be after start time"));
+                }
+                if at > now {
+                    return Err(anyhow::anyhow!("Stop time cannot be in the future"));
+                }
+                at
+            }
+        };
+
+        let stop_event = TimeEntryEvent::stop_at(recovery.entry.task_id(), recovery.start_event_id(), stop_time)
+            .with_repair_reason(RepairReason::CrashRecovery);
+        let saved_event = self.repository.save_event(&stop_event).await?;
+
+        Ok(Some(saved_event))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::time_entry_repository::tests::InMemoryTimeEntryRepository;
+    use crate::domain::value_objects::TaskId;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, hour, minute, 0).unwrap()
+    }
+
+    async fn setup_running_entry() -> (TimerRecoveryServiceImpl<InMemoryTimeEntryRepository>, InMemoryTimeEntryRepository, i64) {
+        let repository = InMemoryTimeEntryRepository::new();
+        let start = repository
+            .save_event(&TimeEntryEvent::start_at(TaskId::new(1).unwrap(), at(9, 0)))
+            .await
+            .unwrap();
+        (TimerRecoveryServiceImpl::new(repository.clone()), repository, start.id().unwrap())
+    }
+
+    #[tokio::test]
+    async fn ハートビートが古い実行中区間を検出すること() {
+        let (service, repository, start_event_id) = setup_running_entry().await;
+
+        // ハートビート未記録の場合は開始時刻を基準にする
+        let stale = service.find_stale_entries(at(9, 10), 5 * 60).await.unwrap();
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].last_heartbeat, at(9, 0));
+
+        repository.save_heartbeat(start_event_id, at(9, 8)).await.unwrap();
+        assert!(service.find_stale_entries(at(9, 10), 5 * 60).await.unwrap().is_empty());
+
+        let stale = service.find_stale_entries(at(10, 0), 5 * 60).await.unwrap();
+        assert_eq!(stale[0].last_heartbeat, at(9, 8));
+        assert_eq!(stale[0].detected_at, at(10, 0));
+    }
+
+    #[tokio::test]
+    async fn 除外指定の区間にはハートビートを記録しないこと() {
+        let (service, repository, start_event_id) = setup_running_entry().await;
+
+        assert_eq!(service.record_heartbeats(at(9, 5), &[start_event_id]).await.unwrap(), 0);
+        assert!(repository.find_heartbeat(start_event_id).await.unwrap().is_none());
+
+        assert_eq!(service.record_heartbeats(at(9, 5), &[]).await.unwrap(), 1);
+        assert_eq!(repository.find_heartbeat(start_event_id).await.unwrap(), Some(at(9, 5)));
+    }
+
+    #[tokio::test]
+    async fn 最終ハートビートの時刻で停止できること() {
+        let (service, repository, start_event_id) = setup_running_entry().await;
+        repository.save_heartbeat(start_event_id, at(9, 30)).await.unwrap();
+        let recovery = service.find_stale_entries(at(12, 0), 5 * 60).await.unwrap().remove(0);
+
+        let stop_event = service
+            .resolve(&recovery, RecoveryResolution::StopAtLastHeartbeat, at(12, 0))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stop_event.at(), at(9, 30));
+        assert_eq!(stop_event.repair_reason(), Some(RepairReason::CrashRecovery));
+
+        let entry = repository.find_entry_by_start_event_id(start_event_id).await.unwrap().unwrap();
+        assert_eq!(entry.end_time(), Some(at(9, 30)));
+
+        // 既に停止済みの区間は解決できない
+        let result = service.resolve(&recovery, RecoveryResolution::Keep, at(12, 0)).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn 指定時刻での停止と継続ができること() {
+        let (service, repository, start_event_id) = setup_running_entry().await;
+        let recovery = service.find_stale_entries(at(12, 0), 5 * 60).await.unwrap().remove(0);
+
+        // 開始前や未来の時刻は指定できない
+        assert!(service.resolve(&recovery, RecoveryResolution::StopAt(at(8, 0)), at(12, 0)).await.is_err());
+        assert!(service.resolve(&recovery, RecoveryResolution::StopAt(at(13, 0)), at(12, 0)).await.is_err());
+
+        // 継続するとハートビートが更新される
+        let result = service.resolve(&recovery, RecoveryResolution::Keep, at(12, 0)).await.unwrap();
+        assert!(result.is_none());
+        assert_eq!(repository.find_heartbeat(start_event_id).await.unwrap(), Some(at(12, 0)));
+
+        let stop_event = service
+            .resolve(&recovery, RecoveryResolution::StopAt(at(11, 0)), at(12, 0))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stop_event.at(), at(11, 0));
+    }
+}
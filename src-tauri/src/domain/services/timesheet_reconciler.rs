@@ -0,0 +1,353 @@
+use crate::domain::entities::time_entry::TimeEntry;
+use crate::domain::services::free_intervals;
+use chrono::{DateTime, Duration, Utc};
+use std::cmp::Reverse;
+
+/// タイムシートのセルを目標の時間に合わせるための変更
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimesheetChange {
+    /// 手動エントリを追加する
+    Add {
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    },
+    /// 時間エントリの開始・終了を訂正する
+    Adjust {
+        start_event_id: i64,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    },
+    /// 時間エントリを削除する
+    Delete { start_event_id: i64 },
+}
+
+/// タイムシートのセル（1タスク×1日）の合計を目標の時間に合わせる変更を求める
+///
+/// 増やす場合は現在時刻までの空いている時間に手動エントリを1件追加し、減らす場合は遅く始まった区間から削除・短縮する。
+/// 実行中の区間は変更せず、一時停止を含む区間は削除のみ行う（短縮すると作業時間が実時間どおりに減らないため）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimesheetReconciler {
+    day_start: DateTime<Utc>,
+    day_end: DateTime<Utc>,
+    /// 追加するエントリはなるべくこの時刻以降に置く
+    preferred_start: DateTime<Utc>,
+}
+
+impl TimesheetReconciler {
+    pub fn new(day_start: DateTime<Utc>, day_end: DateTime<Utc>, preferred_start: DateTime<Utc>) -> Self {
+        Self {
+            day_start,
+            day_end,
+            preferred_start,
+        }
+    }
+
+    /// セルの現在の合計（秒）
+    pub fn total_seconds(&self, entries: &[TimeEntry], now: DateTime<Utc>) -> i64 {
+        entries
+            .iter()
+            .map(|entry| entry.seconds_within(self.day_start, self.day_end, now))
+            .sum()
+    }
+
+    /// 目標の合計（秒）にするための変更を求める（entries はその日に重複するタスクの時間区間）
+    pub fn reconcile(
+        &self,
+        entries: &[TimeEntry],
+        target_seconds: i64,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimesheetChange>> {
+        if target_seconds < 0 {
+            return Err(anyhow::anyhow!("Duration must not be negative"));
+        }
+
+        let current = self.total_seconds(entries, now);
+        if target_seconds > current {
+            Ok(vec![self.plan_add(entries, target_seconds - current, now)?])
+        } else if target_seconds < current {
+            self.plan_reduce(entries, current - target_seconds, now)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    /// 変更をすべて適用できるか検証する（entries はその日に重複するタスクの時間区間）
+    ///
+    /// 訂正・削除の対象が終了済みの区間であり、追加・訂正後の区間が他の区間と重複しないこと。
+    /// 変更は途中で失敗すると一部だけが適用されるため、書き込む前に検証する。
+    pub fn validate(&self, entries: &[TimeEntry], changes: &[TimesheetChange]) -> anyhow::Result<()> {
+        // (開始イベントID, 開始, 終了)。実行中の区間は終わりがないものとみなす
+        let mut intervals: Vec<(Option<i64>, DateTime<Utc>, DateTime<Utc>)> = entries
+            .iter()
+            .map(|entry| {
+                let end_time = entry.end_time().unwrap_or(DateTime::<Utc>::MAX_UTC);
+                (Some(entry.start_event_id()), entry.start_time(), end_time)
+            })
+            .collect();
+        let mut changed = Vec::new();
+
+        for change in changes {
+            let target = match change {
+                TimesheetChange::Add { .. } => None,
+                TimesheetChange::Adjust { start_event_id, .. } | TimesheetChange::Delete { start_event_id } => {
+                    let entry = entries
+                        .iter()
+                        .find(|entry| entry.start_event_id() == *start_event_id)
+                        .filter(|entry| entry.is_completed())
+                        .ok_or_else(|| anyhow::anyhow!("Time entry {} cannot be changed", start_event_id))?;
+                    intervals.retain(|(id, _, _)| *id != Some(entry.start_event_id()));
+                    Some(*start_event_id)
+                }
+            };
+
+            match *change {
+                TimesheetChange::Add { start_time, end_time } | TimesheetChange::Adjust { start_time, end_time, .. } => {
+                    if start_time >= end_time {
+                        return Err(anyhow::anyhow!("Start time must be before end time"));
+                    }
+                    intervals.push((target, start_time, end_time));
+                    changed.push(intervals.len() - 1);
+                }
+                TimesheetChange::Delete { .. } => {}
+            }
+        }
+
+        // 既存の区間どうしの重複は問わず、追加・訂正した区間についてだけ確かめる
+        for &i in &changed {
+            let (_, start_time, end_time) = intervals[i];
+            let overlaps = intervals
+                .iter()
+                .enumerate()
+                .any(|(j, (_, start, end))| j != i && *start < end_time && start_time < *end);
+            if overlaps {
+                return Err(anyhow::anyhow!("Time entry overlaps with existing entries"));
+            }
+        }
+        Ok(())
+    }
+
+    fn plan_add(&self, entries: &[TimeEntry], seconds: i64, now: DateTime<Utc>) -> anyhow::Result<TimesheetChange> {
+        // 追加するエントリは現在時刻より後に終わらないようにする
+        let until = self.day_end.min(now);
+        if until <= self.day_start {
+            return Err(anyhow::anyhow!("Cannot add time to a future date"));
+        }
+
+        // 実行中の区間は日の終わりまで埋まっているとみなす
+        let gaps = free_intervals(
+            self.day_start,
+            until,
+            entries
+                .iter()
+                .map(|entry| (entry.start_time(), entry.end_time().unwrap_or(until))),
+        );
+
+        // 既定の開始時刻以降に収まらなければ、日の始まりから探す
+        let needed = Duration::seconds(seconds);
+        let start_time = gaps
+            .iter()
+            .map(|(start, end)| ((*start).max(self.preferred_start), *end))
+            .chain(gaps.iter().copied())
+            .find(|(start, end)| *end - *start >= needed)
+            .map(|(start, _)| start)
+            .ok_or_else(|| anyhow::anyhow!("Not enough free time in the day to add {} seconds", seconds))?;
+
+        Ok(TimesheetChange::Add {
+            start_time,
+            end_time: start_time + needed,
+        })
+    }
+
+    fn plan_reduce(
+        &self,
+        entries: &[TimeEntry],
+        seconds: i64,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimesheetChange>> {
+        let mut completed: Vec<_> = entries.iter().filter(|entry| entry.is_completed()).collect();
+        completed.sort_by_key(|entry| Reverse(entry.start_time()));
+
+        let mut remaining = seconds;
+        let mut changes = Vec::new();
+        for entry in completed {
+            if remaining == 0 {
+                break;
+            }
+            let portion = entry.seconds_within(self.day_start, self.day_end, now);
+            if portion == 0 {
+                continue;
+            }
+
+            let start_event_id = entry.start_event_id();
+            let start_time = entry.start_time();
+            let end_time = entry.end_time().unwrap();
+            let within_day = start_time >= self.day_start && end_time <= self.day_end;
+            if within_day && portion <= remaining {
+                changes.push(TimesheetChange::Delete { start_event_id });
+                remaining -= portion;
+                continue;
+            }
+            if entry.paused_seconds() > 0 {
+                continue;
+            }
+
+            // その日に含まれる側だけを縮める（日全体にまたがる区間は途中を削れない）
+            let cut = Duration::seconds(remaining.min(portion));
+            if end_time <= self.day_end {
+                changes.push(TimesheetChange::Adjust {
+                    start_event_id,
+                    start_time,
+                    end_time: end_time - cut,
+                });
+            } else if start_time >= self.day_start {
+                changes.push(TimesheetChange::Adjust {
+                    start_event_id,
+                    start_time: start_time + cut,
+                    end_time,
+                });
+            } else {
+                continue;
+            }
+            remaining -= cut.num_seconds();
+        }
+
+        if remaining > 0 {
+            return Err(anyhow::anyhow!(
+                "Cannot reduce the day by {} seconds without changing running or paused entries",
+                remaining
+            ));
+        }
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::TaskId;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 10, hour, minute, 0).unwrap()
+    }
+
+    fn entry(id: i64, start: DateTime<Utc>, end: Option<DateTime<Utc>>) -> TimeEntry {
+        TimeEntry::new(TaskId::new(1).unwrap(), id, start, end)
+    }
+
+    fn reconciler() -> TimesheetReconciler {
+        TimesheetReconciler::new(at(0, 0), at(0, 0) + Duration::days(1), at(9, 0))
+    }
+
+    #[test]
+    fn 不足分が既定の開始時刻以降の空き時間に追加されること() {
+        let now = at(23, 0);
+        let entries = vec![entry(1, at(9, 0), Some(at(10, 0))), entry(2, at(10, 30), Some(at(12, 0)))];
+
+        // 30分なら 10:00 からの空きに収まる
+        let changes = reconciler().reconcile(&entries, 3 * 3600, now).unwrap();
+        assert_eq!(changes, vec![TimesheetChange::Add { start_time: at(10, 0), end_time: at(10, 30) }]);
+
+        // 1時間は 12:00 以降に置く
+        let changes = reconciler().reconcile(&entries, (2 * 60 + 30 + 60) * 60, now).unwrap();
+        assert_eq!(changes, vec![TimesheetChange::Add { start_time: at(12, 0), end_time: at(13, 0) }]);
+
+        // 同じ合計なら変更しない
+        assert!(reconciler().reconcile(&entries, 150 * 60, now).unwrap().is_empty());
+        assert!(reconciler().reconcile(&entries, -1, now).is_err());
+    }
+
+    #[test]
+    fn 追加するエントリが現在時刻より後に終わらないこと() {
+        let entries = vec![entry(1, at(9, 0), Some(at(10, 0)))];
+
+        // 9時より前の空きに置き、現在時刻以降には置かない
+        let now = at(10, 30);
+        let changes = reconciler().reconcile(&entries, 2 * 3600, now).unwrap();
+        assert_eq!(changes, vec![TimesheetChange::Add { start_time: at(0, 0), end_time: at(1, 0) }]);
+
+        let now = at(1, 0);
+        assert!(reconciler().reconcile(&[], 2 * 3600, now).is_err());
+
+        // 未来の日には追加できない
+        let now = at(0, 0) - Duration::hours(1);
+        let error = reconciler().reconcile(&[], 3600, now).unwrap_err();
+        assert!(error.to_string().contains("future"));
+    }
+
+    #[test]
+    fn 空き時間が足りない場合はエラーになること() {
+        // 実行中の区間は日の終わりまで埋まっているとみなす
+        let entries = vec![entry(1, at(1, 0), None)];
+        let now = at(2, 0);
+
+        let changes = reconciler().reconcile(&entries, 3600 + 3600, now).unwrap();
+        assert_eq!(changes, vec![TimesheetChange::Add { start_time: at(0, 0), end_time: at(1, 0) }]);
+        assert!(reconciler().reconcile(&entries, 3 * 3600, now).is_err());
+    }
+
+    #[test]
+    fn 超過分が遅い区間から削除と短縮で調整されること() {
+        let now = at(23, 0);
+        let entries = vec![
+            entry(1, at(9, 0), Some(at(11, 0))),
+            entry(2, at(13, 0), Some(at(13, 30))),
+            // 翌日にまたがる区間はその日の側（開始）を縮める
+            entry(3, at(23, 0), Some(at(23, 0) + Duration::hours(2))),
+        ];
+
+        let changes = reconciler().reconcile(&entries, 3 * 3600, now).unwrap();
+        assert_eq!(changes, vec![
+            TimesheetChange::Adjust { start_event_id: 3, start_time: at(23, 30), end_time: at(23, 0) + Duration::hours(2) },
+        ]);
+
+        let changes = reconciler().reconcile(&entries, 3600, now).unwrap();
+        assert_eq!(changes, vec![
+            TimesheetChange::Adjust { start_event_id: 3, start_time: at(0, 0) + Duration::days(1), end_time: at(23, 0) + Duration::hours(2) },
+            TimesheetChange::Delete { start_event_id: 2 },
+            TimesheetChange::Adjust { start_event_id: 1, start_time: at(9, 0), end_time: at(10, 0) },
+        ]);
+    }
+
+    #[test]
+    fn 実行中と一時停止を含む区間は短縮しないこと() {
+        let now = at(12, 0);
+        let entries = vec![
+            entry(1, at(8, 0), Some(at(10, 0))).with_pause(1800, None),
+            entry(2, at(11, 0), None),
+        ];
+
+        // 一時停止を含む区間（90分）は削除だけできる
+        let changes = reconciler().reconcile(&entries, 3600, now).unwrap();
+        assert_eq!(changes, vec![TimesheetChange::Delete { start_event_id: 1 }]);
+        assert!(reconciler().reconcile(&entries, 2 * 3600, now).is_err());
+    }
+
+    #[test]
+    fn 適用できない変更を含む計画が検証で拒否されること() {
+        let entries = vec![
+            entry(1, at(9, 0), Some(at(10, 0))),
+            entry(2, at(11, 0), Some(at(12, 0))),
+            entry(3, at(13, 0), None),
+        ];
+
+        let valid = vec![
+            TimesheetChange::Delete { start_event_id: 2 },
+            TimesheetChange::Adjust { start_event_id: 1, start_time: at(9, 0), end_time: at(9, 30) },
+            TimesheetChange::Add { start_time: at(10, 0), end_time: at(12, 30) },
+        ];
+        assert!(reconciler().validate(&entries, &valid).is_ok());
+
+        // 存在しない区間・実行中の区間・重複する区間への変更
+        for invalid in [
+            TimesheetChange::Delete { start_event_id: 4 },
+            TimesheetChange::Delete { start_event_id: 3 },
+            TimesheetChange::Adjust { start_event_id: 1, start_time: at(9, 0), end_time: at(13, 30) },
+            TimesheetChange::Add { start_time: at(12, 30), end_time: at(14, 0) },
+        ] {
+            let changes = vec![TimesheetChange::Delete { start_event_id: 2 }, invalid];
+            assert!(reconciler().validate(&entries, &changes).is_err());
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::domain::services::LocalCalendar;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc, Weekday};
+
+/// 稼働時間（記録されていない時間を空き時間として扱う範囲）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkingHours {
+    start: NaiveTime,
+    end: NaiveTime,
+    days: Vec<Weekday>,
+}
+
+impl WorkingHours {
+    /// 稼働日ごとのローカル時刻 start から end まで（日をまたぐ稼働時間は扱わない）
+    pub fn new(start: NaiveTime, end: NaiveTime, days: Vec<Weekday>) -> anyhow::Result<Self> {
+        if start >= end {
+            return Err(anyhow::anyhow!("Working hours must start before they end"));
+        }
+        Ok(Self { start, end, days })
+    }
+
+    pub fn start(&self) -> NaiveTime {
+        self.start
+    }
+
+    pub fn end(&self) -> NaiveTime {
+        self.end
+    }
+
+    pub fn days(&self) -> &[Weekday] {
+        &self.days
+    }
+
+    /// 指定日の稼働時間の範囲（稼働日でなければNone）
+    pub fn range_on(&self, calendar: &LocalCalendar, date: NaiveDate) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        if !self.days.contains(&date.weekday()) {
+            return None;
+        }
+        let start = calendar.at_local_time(date, self.start);
+        let end = calendar.at_local_time(date, self.end);
+        (start < end).then_some((start, end))
+    }
+}
+
+/// 範囲のうち、埋まっている区間のいずれにも含まれない部分を開始時刻順に求める（埋まっている区間は重なっていてもよい）
+pub fn free_intervals(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    busy: impl IntoIterator<Item = (DateTime<Utc>, DateTime<Utc>)>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut busy: Vec<_> = busy
+        .into_iter()
+        .map(|(busy_start, busy_end)| (busy_start.max(start), busy_end.min(end)))
+        .filter(|(busy_start, busy_end)| busy_start < busy_end)
+        .collect();
+    busy.sort();
+
+    let mut free = Vec::new();
+    let mut cursor = start;
+    for (busy_start, busy_end) in busy {
+        if busy_start > cursor {
+            free.push((cursor, busy_start));
+        }
+        cursor = cursor.max(busy_end);
+    }
+    if cursor < end {
+        free.push((cursor, end));
+    }
+    free
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 10, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn 稼働日のローカル時刻で稼働時間の範囲が決まること() {
+        let calendar = LocalCalendar::from_name("Asia/Tokyo", Weekday::Mon).unwrap();
+        let hours = WorkingHours::new(time(9, 0), time(18, 0), vec![Weekday::Mon, Weekday::Tue]).unwrap();
+
+        // 2025-03-10 は月曜日、2025-03-12 は水曜日
+        assert_eq!(
+            hours.range_on(&calendar, NaiveDate::from_ymd_opt(2025, 3, 10).unwrap()),
+            Some((at(0, 0), at(9, 0)))
+        );
+        assert_eq!(hours.range_on(&calendar, NaiveDate::from_ymd_opt(2025, 3, 12).unwrap()), None);
+        assert!(WorkingHours::new(time(18, 0), time(9, 0), vec![Weekday::Mon]).is_err());
+    }
+
+    #[test]
+    fn 重なる区間を除いた空き時間が求まること() {
+        let busy = vec![
+            (at(10, 0), at(11, 0)),
+            (at(10, 30), at(12, 0)),
+            (at(8, 0), at(9, 30)),
+            (at(17, 0), at(19, 0)),
+        ];
+
+        assert_eq!(free_intervals(at(9, 0), at(18, 0), busy), vec![(at(9, 30), at(10, 0)), (at(12, 0), at(17, 0))]);
+        assert_eq!(free_intervals(at(9, 0), at(18, 0), Vec::new()), vec![(at(9, 0), at(18, 0))]);
+    }
+}
@@ -2,9 +2,13 @@
 
 pub mod project_id;
 pub mod task_id;
+pub mod tag_id;
 pub mod status;
+pub mod rounding_rule;
 
 pub use project_id::*;
 pub use task_id::*;
+pub use tag_id::*;
 pub use status::*;
+pub use rounding_rule::*;
 
@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// 丸めの方向
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoundingMode {
+    /// 丸めない
+    #[default]
+    None,
+    /// 切り上げ
+    Up,
+    /// 切り捨て
+    Down,
+    /// 四捨五入（ちょうど半分は切り上げ）
+    Nearest,
+}
+
+impl RoundingMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoundingMode::None => "none",
+            RoundingMode::Up => "up",
+            RoundingMode::Down => "down",
+            RoundingMode::Nearest => "nearest",
+        }
+    }
+}
+
+impl FromStr for RoundingMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "none" => Ok(RoundingMode::None),
+            "up" => Ok(RoundingMode::Up),
+            "down" => Ok(RoundingMode::Down),
+            "nearest" => Ok(RoundingMode::Nearest),
+            _ => Err(anyhow::anyhow!("Invalid rounding mode: {}", s)),
+        }
+    }
+}
+
+/// 丸めを適用する単位
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoundingScope {
+    /// 時間エントリごと（日をまたぐエントリも全体を1回だけ丸め、開始日に計上する）
+    #[default]
+    Entry,
+    /// タスクごとの1日の合計
+    Day,
+}
+
+impl RoundingScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoundingScope::Entry => "entry",
+            RoundingScope::Day => "day",
+        }
+    }
+}
+
+impl FromStr for RoundingScope {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "entry" => Ok(RoundingScope::Entry),
+            "day" => Ok(RoundingScope::Day),
+            _ => Err(anyhow::anyhow!("Invalid rounding scope: {}", s)),
+        }
+    }
+}
+
+/// 作業時間の丸め規則値オブジェクト（請求用の集計にのみ使い、記録された時間は変更しない）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundingRule {
+    mode: RoundingMode,
+    increment_seconds: i64,
+    scope: RoundingScope,
+}
+
+impl RoundingRule {
+    pub fn new(mode: RoundingMode, increment_seconds: i64, scope: RoundingScope) -> anyhow::Result<Self> {
+        if mode != RoundingMode::None && increment_seconds <= 0 {
+            return Err(anyhow::anyhow!("Rounding increment must be positive"));
+        }
+        Ok(Self {
+            mode,
+            increment_seconds: increment_seconds.max(0),
+            scope,
+        })
+    }
+
+    /// 丸めない規則
+    pub fn none() -> Self {
+        Self {
+            mode: RoundingMode::None,
+            increment_seconds: 0,
+            scope: RoundingScope::Entry,
+        }
+    }
+
+    pub fn mode(&self) -> RoundingMode {
+        self.mode
+    }
+
+    pub fn increment_seconds(&self) -> i64 {
+        self.increment_seconds
+    }
+
+    pub fn scope(&self) -> RoundingScope {
+        self.scope
+    }
+
+    /// 秒数を丸める
+    pub fn round(&self, seconds: i64) -> i64 {
+        let increment = self.increment_seconds;
+        match self.mode {
+            RoundingMode::None => seconds,
+            RoundingMode::Up => (seconds + increment - 1).div_euclid(increment) * increment,
+            RoundingMode::Down => seconds.div_euclid(increment) * increment,
+            RoundingMode::Nearest => (seconds + increment / 2).div_euclid(increment) * increment,
+        }
+    }
+}
+
+impl Default for RoundingRule {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn 指定した単位で丸められること() {
+        let rule = |mode| RoundingRule::new(mode, 15 * 60, RoundingScope::Entry).unwrap();
+
+        assert_eq!(rule(RoundingMode::Up).round(61), 15 * 60);
+        assert_eq!(rule(RoundingMode::Up).round(15 * 60), 15 * 60);
+        assert_eq!(rule(RoundingMode::Up).round(0), 0);
+        assert_eq!(rule(RoundingMode::Down).round(29 * 60 + 59), 15 * 60);
+        assert_eq!(rule(RoundingMode::Nearest).round(7 * 60 + 29), 0);
+        assert_eq!(rule(RoundingMode::Nearest).round(7 * 60 + 30), 15 * 60);
+        assert_eq!(RoundingRule::none().round(61), 61);
+    }
+
+    #[test]
+    fn 不正な丸め規則はエラーになること() {
+        assert!(RoundingRule::new(RoundingMode::Up, 0, RoundingScope::Day).is_err());
+        assert!(RoundingRule::new(RoundingMode::None, 0, RoundingScope::Day).is_ok());
+        assert!("ceil".parse::<RoundingMode>().is_err());
+        assert_eq!("day".parse::<RoundingScope>().unwrap(), RoundingScope::Day);
+    }
+}
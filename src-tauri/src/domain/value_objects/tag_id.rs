@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// タグID値オブジェクト
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TagId(i64);
+
+impl TagId {
+    /// 新しいタグIDを作成
+    pub fn new(id: i64) -> anyhow::Result<Self> {
+        if id <= 0 {
+            return Err(anyhow::anyhow!("TagId must be positive"));
+        }
+        Ok(Self(id))
+    }
+
+    /// 内部値を取得
+    pub fn value(&self) -> i64 {
+        self.0
+    }
+}
+
+impl Display for TagId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<TagId> for i64 {
+    fn from(id: TagId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn タグID作成ができること() {
+        let id = TagId::new(1).unwrap();
+        assert_eq!(id.value(), 1);
+    }
+
+    #[test]
+    fn タグIDの正の値バリデーションが機能すること() {
+        assert!(TagId::new(1).is_ok());
+        assert!(TagId::new(0).is_err());
+        assert!(TagId::new(-1).is_err());
+    }
+
+    #[test]
+    fn タグIDの文字列表示が正しく動作すること() {
+        let id = TagId::new(7).unwrap();
+        assert_eq!(format!("{}", id), "7");
+    }
+
+    #[test]
+    fn タグIDの型変換が正しく動作すること() {
+        let id = TagId::new(12).unwrap();
+        let value: i64 = id.into();
+        assert_eq!(value, 12);
+    }
+}
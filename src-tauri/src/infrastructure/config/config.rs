@@ -1,9 +1,177 @@
+use crate::domain::services::{LocalCalendar, TimerExclusivity, WorkingHours};
+use chrono::{NaiveTime, Weekday};
+use chrono_tz::Tz;
 use std::path::PathBuf;
 
+/// タイマーの排他ポリシーを指定する環境変数（exclusive / concurrent）
+pub const TIMER_EXCLUSIVITY_ENV: &str = "TIME_TRACKER_TIMER_EXCLUSIVITY";
+
+/// 自動停止の最大計測時間（秒）を指定する環境変数
+pub const AUTO_CUTOFF_MAX_SECONDS_ENV: &str = "TIME_TRACKER_AUTO_CUTOFF_MAX_SECONDS";
+
+/// 自動停止の日次の締め時刻（ローカル時刻 HH:MM）を指定する環境変数
+pub const AUTO_CUTOFF_DAILY_TIME_ENV: &str = "TIME_TRACKER_AUTO_CUTOFF_DAILY_TIME";
+
+/// 日・週を区切るIANAタイムゾーン名を指定する環境変数
+pub const TIMEZONE_ENV: &str = "TIME_TRACKER_TIMEZONE";
+
+/// 週の開始曜日（mon / monday など）を指定する環境変数
+pub const WEEK_START_ENV: &str = "TIME_TRACKER_WEEK_START";
+
+/// 稼働時間の開始（ローカル時刻 HH:MM）を指定する環境変数
+pub const WORKING_HOURS_START_ENV: &str = "TIME_TRACKER_WORKING_HOURS_START";
+
+/// 稼働時間の終了（ローカル時刻 HH:MM）を指定する環境変数
+pub const WORKING_HOURS_END_ENV: &str = "TIME_TRACKER_WORKING_HOURS_END";
+
+/// 稼働日（カンマ区切りの曜日、例: mon,tue,wed）を指定する環境変数
+pub const WORKING_DAYS_ENV: &str = "TIME_TRACKER_WORKING_DAYS";
+
+/// タイムラインに返す空き時間の最小秒数を指定する環境変数
+pub const MIN_GAP_SECONDS_ENV: &str = "TIME_TRACKER_MIN_GAP_SECONDS";
+
 /// アプリケーション設定
 #[derive(Debug, Clone)]
 pub struct Config {
     pub database_path: PathBuf,
+    pub auto_cutoff: AutoCutoffConfig,
+    pub recovery: RecoveryConfig,
+    pub pomodoro: PomodoroConfig,
+    pub timer: TimerConfig,
+    pub calendar: CalendarConfig,
+    pub timeline: TimelineConfig,
+}
+
+/// 長時間実行タイマーの自動停止設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoCutoffConfig {
+    /// 最大計測時間（秒）。None の場合は時間による打ち切りを行わない
+    pub max_timer_seconds: Option<i64>,
+    /// 日次の締め時刻（ローカル時刻）。None の場合は締め時刻による打ち切りを行わない
+    pub daily_cutoff_time: Option<NaiveTime>,
+    /// 実行中タイマーを確認する間隔（秒）
+    pub check_interval_seconds: u64,
+}
+
+impl Default for AutoCutoffConfig {
+    fn default() -> Self {
+        Self {
+            max_timer_seconds: None,
+            daily_cutoff_time: None,
+            check_interval_seconds: 60,
+        }
+    }
+}
+
+/// クラッシュ/スリープ後の復旧設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryConfig {
+    /// 実行中タイマーのハートビートを記録する間隔（秒）
+    pub heartbeat_interval_seconds: u64,
+    /// 最終ハートビートからこの秒数以上経過した実行中タイマーを復旧待ちとする
+    pub stale_after_seconds: i64,
+}
+
+impl Default for RecoveryConfig {
+    fn default() -> Self {
+        Self {
+            heartbeat_interval_seconds: 60,
+            stale_after_seconds: 5 * 60,
+        }
+    }
+}
+
+/// タイマーの動作設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimerConfig {
+    /// 排他ポリシー（既定は排他。同時実行にすると他タスクのタイマーを止めずに開始する）
+    pub exclusivity: TimerExclusivity,
+    /// 実行中タイマーの経過時間をフロントエンドに通知する間隔（秒）
+    pub tick_interval_seconds: u64,
+}
+
+impl Default for TimerConfig {
+    fn default() -> Self {
+        Self {
+            exclusivity: TimerExclusivity::default(),
+            tick_interval_seconds: 1,
+        }
+    }
+}
+
+/// 日・週の区切りの設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarConfig {
+    /// IANAタイムゾーン名（例: Asia/Tokyo）
+    pub timezone: String,
+    /// 週の開始曜日
+    pub week_start: Weekday,
+}
+
+impl Default for CalendarConfig {
+    fn default() -> Self {
+        // システムのタイムゾーンを使い、取得できない場合はUTCにする
+        let timezone = iana_time_zone::get_timezone()
+            .ok()
+            .filter(|name| name.parse::<Tz>().is_ok())
+            .unwrap_or_else(|| "UTC".to_string());
+
+        Self {
+            timezone,
+            week_start: Weekday::Mon,
+        }
+    }
+}
+
+/// タイムラインの空き時間の設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineConfig {
+    /// 稼働時間の開始（ローカル時刻）
+    pub working_hours_start: NaiveTime,
+    /// 稼働時間の終了（ローカル時刻）
+    pub working_hours_end: NaiveTime,
+    /// 稼働日（それ以外の日は空き時間を求めない）
+    pub working_days: Vec<Weekday>,
+    /// これより短い空き時間は返さない（秒）
+    pub min_gap_seconds: i64,
+}
+
+impl Default for TimelineConfig {
+    fn default() -> Self {
+        Self {
+            working_hours_start: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            working_hours_end: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+            working_days: vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+            min_gap_seconds: 60,
+        }
+    }
+}
+
+/// ポモドーロの既定設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PomodoroConfig {
+    /// 作業フェーズの長さ（秒）
+    pub work_seconds: i64,
+    /// 短い休憩の長さ（秒）
+    pub short_break_seconds: i64,
+    /// 長い休憩の長さ（秒）
+    pub long_break_seconds: i64,
+    /// 長い休憩に入るまでの作業フェーズ数
+    pub long_break_interval: u32,
+    /// フェーズの終了を確認する間隔（秒）
+    pub tick_interval_seconds: u64,
+}
+
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        Self {
+            work_seconds: 25 * 60,
+            short_break_seconds: 5 * 60,
+            long_break_seconds: 15 * 60,
+            long_break_interval: 4,
+            tick_interval_seconds: 1,
+        }
+    }
 }
 
 impl Default for Config {
@@ -22,6 +190,12 @@ impl Default for Config {
 
         Self {
             database_path: data_dir.join("time_tracker.db"),
+            auto_cutoff: AutoCutoffConfig::default(),
+            recovery: RecoveryConfig::default(),
+            pomodoro: PomodoroConfig::default(),
+            timer: TimerConfig::default(),
+            calendar: CalendarConfig::default(),
+            timeline: TimelineConfig::default(),
         }
     }
 }
@@ -29,13 +203,99 @@ impl Default for Config {
 impl Config {
     /// 新しい設定を作成
     pub fn new(database_path: PathBuf) -> Self {
-        Self { database_path }
+        Self {
+            database_path,
+            auto_cutoff: AutoCutoffConfig::default(),
+            recovery: RecoveryConfig::default(),
+            pomodoro: PomodoroConfig::default(),
+            timer: TimerConfig::default(),
+            calendar: CalendarConfig::default(),
+            timeline: TimelineConfig::default(),
+        }
+    }
+
+    /// 環境変数で設定を上書きする（設定されていない項目は現在の値のまま）
+    pub fn with_env_overrides(self) -> anyhow::Result<Self> {
+        self.with_overrides_from(|key| std::env::var(key).ok())
+    }
+
+    /// キーから値を引く関数で設定を上書きする（値がない項目は現在の値のまま）
+    pub fn with_overrides_from(mut self, lookup: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        if let Some(value) = lookup(TIMER_EXCLUSIVITY_ENV) {
+            self.timer.exclusivity = value.trim().parse()?;
+        }
+        if let Some(value) = lookup(AUTO_CUTOFF_MAX_SECONDS_ENV) {
+            let seconds: i64 = value
+                .trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid {}: {}", AUTO_CUTOFF_MAX_SECONDS_ENV, value))?;
+            if seconds <= 0 {
+                return Err(anyhow::anyhow!("{} must be positive: {}", AUTO_CUTOFF_MAX_SECONDS_ENV, value));
+            }
+            self.auto_cutoff.max_timer_seconds = Some(seconds);
+        }
+        if let Some(value) = lookup(AUTO_CUTOFF_DAILY_TIME_ENV) {
+            let time = NaiveTime::parse_from_str(value.trim(), "%H:%M")
+                .map_err(|_| anyhow::anyhow!("Invalid {}: {}", AUTO_CUTOFF_DAILY_TIME_ENV, value))?;
+            self.auto_cutoff.daily_cutoff_time = Some(time);
+        }
+        if let Some(value) = lookup(TIMEZONE_ENV) {
+            self.calendar.timezone = value.trim().to_string();
+        }
+        if let Some(value) = lookup(WEEK_START_ENV) {
+            self.calendar.week_start = value
+                .trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid {}: {}", WEEK_START_ENV, value))?;
+        }
+        LocalCalendar::from_name(&self.calendar.timezone, self.calendar.week_start)?;
+
+        let parse_time = |key: &str, value: &str| {
+            NaiveTime::parse_from_str(value.trim(), "%H:%M").map_err(|_| anyhow::anyhow!("Invalid {}: {}", key, value))
+        };
+        if let Some(value) = lookup(WORKING_HOURS_START_ENV) {
+            self.timeline.working_hours_start = parse_time(WORKING_HOURS_START_ENV, &value)?;
+        }
+        if let Some(value) = lookup(WORKING_HOURS_END_ENV) {
+            self.timeline.working_hours_end = parse_time(WORKING_HOURS_END_ENV, &value)?;
+        }
+        if let Some(value) = lookup(WORKING_DAYS_ENV) {
+            // 空の場合は稼働日なし（空き時間を求めない）
+            self.timeline.working_days = value
+                .split(',')
+                .map(str::trim)
+                .filter(|day| !day.is_empty())
+                .map(|day| day.parse().map_err(|_| anyhow::anyhow!("Invalid {}: {}", WORKING_DAYS_ENV, value)))
+                .collect::<anyhow::Result<Vec<Weekday>>>()?;
+        }
+        if let Some(value) = lookup(MIN_GAP_SECONDS_ENV) {
+            let seconds: i64 = value
+                .trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid {}: {}", MIN_GAP_SECONDS_ENV, value))?;
+            if seconds < 0 {
+                return Err(anyhow::anyhow!("{} must not be negative: {}", MIN_GAP_SECONDS_ENV, value));
+            }
+            self.timeline.min_gap_seconds = seconds;
+        }
+        WorkingHours::new(
+            self.timeline.working_hours_start,
+            self.timeline.working_hours_end,
+            self.timeline.working_days.clone(),
+        )?;
+        Ok(self)
     }
 
     /// インメモリデータベース用の設定
     pub fn in_memory() -> Self {
         Self {
             database_path: PathBuf::from(":memory:"),
+            auto_cutoff: AutoCutoffConfig::default(),
+            recovery: RecoveryConfig::default(),
+            pomodoro: PomodoroConfig::default(),
+            timer: TimerConfig::default(),
+            calendar: CalendarConfig::default(),
+            timeline: TimelineConfig::default(),
         }
     }
 
@@ -49,6 +309,12 @@ impl Config {
         
         Self {
             database_path: temp_dir.join("test.db"),
+            auto_cutoff: AutoCutoffConfig::default(),
+            recovery: RecoveryConfig::default(),
+            pomodoro: PomodoroConfig::default(),
+            timer: TimerConfig::default(),
+            calendar: CalendarConfig::default(),
+            timeline: TimelineConfig::default(),
         }
     }
 }
@@ -63,6 +329,15 @@ mod tests {
         let config = Config::default();
         assert!(config.database_path.to_string_lossy().contains("time-tracker-go"));
         assert!(config.database_path.to_string_lossy().ends_with("time_tracker.db"));
+        assert!(config.auto_cutoff.max_timer_seconds.is_none());
+        assert!(config.auto_cutoff.daily_cutoff_time.is_none());
+        assert_eq!(config.recovery.stale_after_seconds, 5 * 60);
+        assert_eq!(config.pomodoro.work_seconds, 25 * 60);
+        assert_eq!(config.timer.exclusivity, TimerExclusivity::Exclusive);
+        assert!(config.calendar.timezone.parse::<Tz>().is_ok());
+        assert_eq!(config.calendar.week_start, Weekday::Mon);
+        assert_eq!(config.timeline.working_days.len(), 5);
+        assert!(config.timeline.working_hours_start < config.timeline.working_hours_end);
     }
 
     #[test]
@@ -77,6 +352,103 @@ mod tests {
         assert!(config.database_path.to_string_lossy().contains("test.db"));
     }
 
+    #[test]
+    fn 上書きでタイマーの排他ポリシーを設定できること() {
+        let lookup = |value: &'static str| {
+            move |key: &str| (key == TIMER_EXCLUSIVITY_ENV).then(|| value.to_string())
+        };
+
+        let config = Config::in_memory().with_overrides_from(lookup("concurrent")).unwrap();
+        assert_eq!(config.timer.exclusivity, TimerExclusivity::Concurrent);
+
+        assert!(Config::in_memory().with_overrides_from(lookup("parallel")).is_err());
+
+        let config = Config::in_memory().with_overrides_from(|_| None).unwrap();
+        assert_eq!(config.timer.exclusivity, TimerExclusivity::Exclusive);
+    }
+
+    #[test]
+    fn 上書きで自動停止を有効にできること() {
+        use crate::domain::services::{AutoCutoffPolicy, LocalCalendar};
+
+        let policy = |config: &Config| {
+            AutoCutoffPolicy::new(
+                config.auto_cutoff.max_timer_seconds,
+                config.auto_cutoff.daily_cutoff_time,
+                LocalCalendar::utc(),
+            )
+            .unwrap()
+        };
+        assert!(!policy(&Config::in_memory()).is_enabled());
+
+        let config = Config::in_memory()
+            .with_overrides_from(|key| (key == AUTO_CUTOFF_MAX_SECONDS_ENV).then(|| "28800".to_string()))
+            .unwrap();
+        assert_eq!(config.auto_cutoff.max_timer_seconds, Some(8 * 3600));
+        assert!(policy(&config).is_enabled());
+
+        let config = Config::in_memory()
+            .with_overrides_from(|key| (key == AUTO_CUTOFF_DAILY_TIME_ENV).then(|| "23:30".to_string()))
+            .unwrap();
+        assert_eq!(config.auto_cutoff.daily_cutoff_time, NaiveTime::from_hms_opt(23, 30, 0));
+        assert!(policy(&config).is_enabled());
+
+        for (key, value) in [
+            (AUTO_CUTOFF_MAX_SECONDS_ENV, "0"),
+            (AUTO_CUTOFF_MAX_SECONDS_ENV, "8h"),
+            (AUTO_CUTOFF_DAILY_TIME_ENV, "25:00"),
+        ] {
+            let result = Config::in_memory().with_overrides_from(|k| (k == key).then(|| value.to_string()));
+            assert!(result.is_err(), "{}={} should be rejected", key, value);
+        }
+    }
+
+    #[test]
+    fn 上書きでタイムゾーンと週の開始曜日を設定できること() {
+        let config = Config::in_memory()
+            .with_overrides_from(|key| match key {
+                TIMEZONE_ENV => Some("America/New_York".to_string()),
+                WEEK_START_ENV => Some("sunday".to_string()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(config.calendar.timezone, "America/New_York");
+        assert_eq!(config.calendar.week_start, Weekday::Sun);
+
+        for (key, value) in [(TIMEZONE_ENV, "Asia/Nowhere"), (WEEK_START_ENV, "weekend")] {
+            let result = Config::in_memory().with_overrides_from(|k| (k == key).then(|| value.to_string()));
+            assert!(result.is_err(), "{}={} should be rejected", key, value);
+        }
+    }
+
+    #[test]
+    fn 上書きで稼働時間と空き時間の最小秒数を設定できること() {
+        let config = Config::in_memory()
+            .with_overrides_from(|key| match key {
+                WORKING_HOURS_START_ENV => Some("08:30".to_string()),
+                WORKING_HOURS_END_ENV => Some("17:00".to_string()),
+                WORKING_DAYS_ENV => Some("mon, tue,Saturday".to_string()),
+                MIN_GAP_SECONDS_ENV => Some("300".to_string()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(config.timeline.working_hours_start, NaiveTime::from_hms_opt(8, 30, 0).unwrap());
+        assert_eq!(config.timeline.working_hours_end, NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+        assert_eq!(config.timeline.working_days, vec![Weekday::Mon, Weekday::Tue, Weekday::Sat]);
+        assert_eq!(config.timeline.min_gap_seconds, 300);
+
+        for (key, value) in [
+            (WORKING_HOURS_START_ENV, "9am"),
+            (WORKING_HOURS_START_ENV, "19:00"),
+            (WORKING_HOURS_END_ENV, "08:00"),
+            (WORKING_DAYS_ENV, "mon,someday"),
+            (MIN_GAP_SECONDS_ENV, "-1"),
+        ] {
+            let result = Config::in_memory().with_overrides_from(|k| (k == key).then(|| value.to_string()));
+            assert!(result.is_err(), "{}={} should be rejected", key, value);
+        }
+    }
+
     #[test]
     fn カスタム設定が作成されること() {
         let custom_path = PathBuf::from("/custom/path/db.sqlite");
@@ -1,7 +1,21 @@
 use anyhow::Result;
-use rusqlite::Connection;
+use chrono::Utc;
+use rusqlite::{params, Connection};
 use std::path::Path;
 
+/// バージョン管理される追加マイグレーション（適用済みバージョンは schema_migrations に記録）
+const VERSIONED_MIGRATIONS: &[(i64, &str)] = &[
+    (3, include_str!("../../../../database/migrations/003_time_entry_adjust.sql")),
+    (4, include_str!("../../../../database/migrations/004_time_entry_void.sql")),
+    (5, include_str!("../../../../database/migrations/005_time_entry_notes.sql")),
+    (6, include_str!("../../../../database/migrations/006_time_entry_stop_pairing.sql")),
+    (7, include_str!("../../../../database/migrations/007_timer_heartbeats.sql")),
+    (8, include_str!("../../../../database/migrations/008_time_entry_pause.sql")),
+    (9, include_str!("../../../../database/migrations/009_pomodoro_completions.sql")),
+    (10, include_str!("../../../../database/migrations/010_rounding_rules.sql")),
+    (11, include_str!("../../../../database/migrations/011_time_entry_pause_intervals.sql")),
+];
+
 /// データベース接続管理
 pub struct DatabaseConnection {
     connection: Connection,
@@ -93,6 +107,11 @@ impl DatabaseConnection {
             return Err(e);
         }
         
+        if let Err(e) = self.apply_versioned_migrations() {
+            tracing::error!("DatabaseConnection::run_migrations: Failed to apply versioned migrations: {}", e);
+            return Err(e);
+        }
+        
         if let Err(e) = self.load_sample_data() {
             tracing::error!("DatabaseConnection::run_migrations: Failed to load sample data: {}", e);
             return Err(e);
@@ -142,6 +161,36 @@ impl DatabaseConnection {
         }
     }
 
+    /// 未適用のバージョン管理マイグレーションを順に適用
+    fn apply_versioned_migrations(&self) -> Result<()> {
+        for (version, sql) in VERSIONED_MIGRATIONS {
+            let applied: bool = self.connection.query_row(
+                "SELECT EXISTS(SELECT 1 FROM schema_migrations WHERE version = ?1)",
+                params![version],
+                |row| row.get(0),
+            )?;
+            if applied {
+                tracing::debug!("DatabaseConnection::apply_versioned_migrations: Version {} already applied", version);
+                continue;
+            }
+
+            tracing::info!("DatabaseConnection::apply_versioned_migrations: Applying version {}", version);
+            let tx = self.connection.unchecked_transaction()?;
+            if let Err(e) = tx.execute_batch(sql) {
+                tracing::error!("DatabaseConnection::apply_versioned_migrations: Failed to apply version {}: {}", version, e);
+                return Err(e.into());
+            }
+            tx.execute(
+                "INSERT INTO schema_migrations (version, applied_at) VALUES (?1, ?2)",
+                params![version, Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()],
+            )?;
+            tx.commit()?;
+            tracing::info!("DatabaseConnection::apply_versioned_migrations: Version {} applied successfully", version);
+        }
+
+        Ok(())
+    }
+
     /// サンプルデータを読み込み
     fn load_sample_data(&self) -> Result<()> {
         tracing::info!("DatabaseConnection::load_sample_data: Checking if sample data should be loaded");
@@ -220,4 +269,23 @@ mod tests {
         assert!(views.contains(&"task_current_view".to_string()));
         Ok(())
     }
+    #[test]
+    fn バージョン管理マイグレーションが一度だけ適用されること() -> Result<()> {
+        let db = DatabaseConnection::new_in_memory()?;
+        db.run_migrations()?;
+        db.run_migrations()?;
+
+        let versions: Vec<i64> = db.connection()
+            .prepare("SELECT version FROM schema_migrations ORDER BY version")?
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(versions, vec![3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+        // 訂正イベントが保存できること
+        db.connection().execute(
+            "INSERT INTO time_entry_events (task_id, event_type, at, start_event_id, payload) VALUES (1, 'adjust', '2024-01-02T00:00:00Z', 1, '{}')",
+            [],
+        )?;
+        Ok(())
+    }
 }
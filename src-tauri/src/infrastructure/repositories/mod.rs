@@ -3,8 +3,20 @@
 pub mod sqlite_project_repository;
 pub mod sqlite_task_repository;
 pub mod sqlite_time_entry_repository;
+pub mod sqlite_tag_repository;
+pub mod sqlite_pomodoro_repository;
+pub mod sqlite_report_repository;
+pub mod sqlite_timeline_repository;
+pub mod sqlite_rounding_repository;
+pub mod sqlite_backup_repository;
 
 pub use sqlite_project_repository::*;
 pub use sqlite_task_repository::*;
 pub use sqlite_time_entry_repository::*;
+pub use sqlite_tag_repository::*;
+pub use sqlite_pomodoro_repository::*;
+pub use sqlite_report_repository::*;
+pub use sqlite_timeline_repository::*;
+pub use sqlite_rounding_repository::*;
+pub use sqlite_backup_repository::*;
 
@@ -0,0 +1,329 @@
+use crate::domain::repositories::{BackupRepository, BackupTable};
+use crate::infrastructure::database::DatabaseConnection;
+use async_trait::async_trait;
+use rusqlite::types::{Value as SqlValue, ValueRef};
+use rusqlite::{params_from_iter, Connection};
+use serde_json::Value as JsonValue;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// バックアップ対象のテーブル（外部キーの参照先が先に来る順序、schema_migrations は対象外）
+const BACKUP_TABLES: &[&str] = &[
+    "projects",
+    "project_versions",
+    "tasks",
+    "task_versions",
+    "tags",
+    "task_tag_events",
+    "time_entry_events",
+    "timer_heartbeats",
+    "pomodoro_completions",
+    "rounding_rules",
+];
+
+/// SQLiteバックアップリポジトリ実装
+#[derive(Clone)]
+pub struct SqliteBackupRepository {
+    db: Arc<Mutex<DatabaseConnection>>,
+}
+
+impl SqliteBackupRepository {
+    pub fn new(db: Arc<Mutex<DatabaseConnection>>) -> Self {
+        Self { db }
+    }
+
+    fn schema_version_of(conn: &Connection) -> anyhow::Result<i64> {
+        Ok(conn.query_row("SELECT COALESCE(MAX(version), 0) FROM schema_migrations", [], |row| row.get(0))?)
+    }
+
+    /// テーブルの列名を定義順に取得
+    fn table_columns(conn: &Connection, table: &str) -> anyhow::Result<Vec<String>> {
+        let mut stmt = conn.prepare(&format!("PRAGMA table_info(\"{}\")", table))?;
+        let columns = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(columns)
+    }
+
+    fn export_table(conn: &Connection, table: &str) -> anyhow::Result<BackupTable> {
+        let columns = Self::table_columns(conn, table)?;
+        let sql = format!(
+            "SELECT {} FROM \"{}\" ORDER BY rowid",
+            columns.iter().map(|c| format!("\"{}\"", c)).collect::<Vec<_>>().join(", "),
+            table
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let mut rows = stmt.query([])?;
+
+        let mut result = Vec::new();
+        while let Some(row) = rows.next()? {
+            let mut object = serde_json::Map::new();
+            for (i, column) in columns.iter().enumerate() {
+                object.insert(column.clone(), Self::to_json(row.get_ref(i)?, table, column)?);
+            }
+            result.push(object);
+        }
+
+        Ok(BackupTable {
+            name: table.to_string(),
+            rows: result,
+        })
+    }
+
+    fn export_all(conn: &Connection) -> anyhow::Result<Vec<BackupTable>> {
+        BACKUP_TABLES.iter().map(|table| Self::export_table(conn, table)).collect()
+    }
+
+    fn to_json(value: ValueRef<'_>, table: &str, column: &str) -> anyhow::Result<JsonValue> {
+        Ok(match value {
+            ValueRef::Null => JsonValue::Null,
+            ValueRef::Integer(i) => JsonValue::from(i),
+            ValueRef::Real(f) => serde_json::Number::from_f64(f)
+                .map(JsonValue::Number)
+                .ok_or_else(|| anyhow::anyhow!("Non-finite value in {}.{}", table, column))?,
+            ValueRef::Text(text) => JsonValue::String(String::from_utf8(text.to_vec())?),
+            ValueRef::Blob(_) => return Err(anyhow::anyhow!("Unsupported BLOB value in {}.{}", table, column)),
+        })
+    }
+
+    fn to_sql(value: &JsonValue, table: &str, column: &str) -> anyhow::Result<SqlValue> {
+        match value {
+            JsonValue::Null => Ok(SqlValue::Null),
+            JsonValue::Number(n) => match (n.as_i64(), n.as_f64()) {
+                (Some(i), _) => Ok(SqlValue::Integer(i)),
+                (None, Some(f)) => Ok(SqlValue::Real(f)),
+                _ => Err(anyhow::anyhow!("Invalid number in {}.{}", table, column)),
+            },
+            JsonValue::String(s) => Ok(SqlValue::Text(s.clone())),
+            _ => Err(anyhow::anyhow!("Invalid value in {}.{}", table, column)),
+        }
+    }
+
+    /// 初回起動時のデータベース（サンプルデータのみ）の内容
+    fn initial_tables() -> anyhow::Result<Vec<BackupTable>> {
+        let db = DatabaseConnection::new_in_memory()?;
+        db.run_migrations()?;
+        Self::export_all(db.connection())
+    }
+
+    /// 取り込み先が空であることを確認する（初回起動時のサンプルデータだけの場合は削除して空にする）
+    fn ensure_empty(conn: &Connection) -> anyhow::Result<()> {
+        let current = Self::export_all(conn)?;
+        if current.iter().all(|table| table.rows.is_empty()) {
+            return Ok(());
+        }
+        if current != Self::initial_tables()? {
+            return Err(anyhow::anyhow!("Backup can only be imported into an empty database"));
+        }
+
+        for table in BACKUP_TABLES.iter().rev() {
+            conn.execute(&format!("DELETE FROM \"{}\"", table), [])?;
+        }
+        Ok(())
+    }
+
+    fn import_table(conn: &Connection, table: &BackupTable) -> anyhow::Result<()> {
+        let columns = Self::table_columns(conn, &table.name)?;
+        let expected: BTreeSet<&str> = columns.iter().map(String::as_str).collect();
+        let sql = format!(
+            "INSERT INTO \"{}\" ({}) VALUES ({})",
+            table.name,
+            columns.iter().map(|c| format!("\"{}\"", c)).collect::<Vec<_>>().join(", "),
+            (1..=columns.len()).map(|i| format!("?{}", i)).collect::<Vec<_>>().join(", ")
+        );
+        let mut stmt = conn.prepare(&sql)?;
+
+        for (index, row) in table.rows.iter().enumerate() {
+            let actual: BTreeSet<&str> = row.keys().map(String::as_str).collect();
+            if actual != expected {
+                return Err(anyhow::anyhow!(
+                    "Row {} of {} does not match the table columns",
+                    index + 1,
+                    table.name
+                ));
+            }
+            let values = columns
+                .iter()
+                .map(|column| Self::to_sql(&row[column.as_str()], &table.name, column))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            stmt.execute(params_from_iter(values))
+                .map_err(|e| anyhow::anyhow!("Failed to import row {} of {}: {}", index + 1, table.name, e))?;
+        }
+        Ok(())
+    }
+
+    /// 外部キー違反があればエラー
+    fn check_foreign_keys(conn: &Connection) -> anyhow::Result<()> {
+        let mut stmt = conn.prepare("PRAGMA foreign_key_check")?;
+        let violation = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<i64>>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?
+            .next()
+            .transpose()?;
+
+        match violation {
+            Some((table, rowid, parent)) => Err(anyhow::anyhow!(
+                "Backup violates a foreign key: {} row {} references a missing {} row",
+                table,
+                rowid.unwrap_or_default(),
+                parent
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl BackupRepository for SqliteBackupRepository {
+    async fn schema_version(&self) -> anyhow::Result<i64> {
+        let db = self.db.lock().await;
+        Self::schema_version_of(db.connection())
+    }
+
+    async fn export_tables(&self) -> anyhow::Result<Vec<BackupTable>> {
+        let db = self.db.lock().await;
+        Self::export_all(db.connection())
+    }
+
+    async fn import_tables(&self, schema_version: i64, tables: &[BackupTable]) -> anyhow::Result<()> {
+        let db = self.db.lock().await;
+        // エラー時はトランザクションを破棄し、何も取り込まない
+        let tx = db.connection().unchecked_transaction()?;
+
+        let current_version = Self::schema_version_of(&tx)?;
+        if schema_version != current_version {
+            return Err(anyhow::anyhow!(
+                "Backup schema version {} does not match database schema version {}",
+                schema_version,
+                current_version
+            ));
+        }
+        if let Some(table) = tables.iter().find(|table| !BACKUP_TABLES.contains(&table.name.as_str())) {
+            return Err(anyhow::anyhow!("Unknown table in backup: {}", table.name));
+        }
+        let mut ordered = Vec::new();
+        for name in BACKUP_TABLES {
+            let mut matching = tables.iter().filter(|table| table.name == *name);
+            match (matching.next(), matching.next()) {
+                (Some(table), None) => ordered.push(table),
+                (None, _) => return Err(anyhow::anyhow!("Backup is missing table: {}", name)),
+                (Some(_), Some(_)) => return Err(anyhow::anyhow!("Duplicate table in backup: {}", name)),
+            }
+        }
+
+        Self::ensure_empty(&tx)?;
+
+        // 外部キーはコミット前にまとめて検証する
+        tx.pragma_update(None, "defer_foreign_keys", "ON")?;
+        for table in ordered {
+            Self::import_table(&tx, table)?;
+        }
+        Self::check_foreign_keys(&tx)?;
+
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    fn migrated_db() -> Arc<Mutex<DatabaseConnection>> {
+        let db = DatabaseConnection::new_in_memory().unwrap();
+        db.run_migrations().unwrap();
+        Arc::new(Mutex::new(db))
+    }
+
+    #[tokio::test]
+    async fn 全てのテーブルがバックアップ対象に含まれること() {
+        let db = migrated_db();
+        let db = db.lock().await;
+        let tables: Vec<String> = db
+            .connection()
+            .prepare("SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' AND name != 'schema_migrations' ORDER BY name")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let mut expected: Vec<String> = BACKUP_TABLES.iter().map(|t| t.to_string()).collect();
+        expected.sort();
+        assert_eq!(tables, expected);
+    }
+
+    #[tokio::test]
+    async fn 書き出した全行を初回起動時のデータベースに復元できること() {
+        let source = SqliteBackupRepository::new(migrated_db());
+        source
+            .db
+            .lock()
+            .await
+            .connection()
+            .execute_batch(
+                "
+                INSERT INTO projects (id) VALUES (931);
+                INSERT INTO project_versions (project_id, version, name, status, effective_at) VALUES
+                    (931, 1, 'Backup', 'active', '2025-03-01T00:00:00Z'),
+                    (931, 2, 'Backup \"renamed\"', 'archived', '2025-03-02T00:00:00Z');
+                INSERT INTO tasks (id) VALUES (931);
+                INSERT INTO task_versions (task_id, version, project_id, name, status, effective_at) VALUES
+                    (931, 1, 931, 'Restore', 'active', '2025-03-01T00:00:00Z');
+                INSERT INTO time_entry_events (id, task_id, event_type, at, start_event_id, payload) VALUES
+                    (9310, 931, 'start', '2025-03-03T09:00:00Z', NULL, NULL),
+                    (9311, 931, 'annotate', '2025-03-03T09:10:00Z', 9310, '改行を含む\n注釈');
+                INSERT INTO timer_heartbeats (start_event_id, at) VALUES (9310, '2025-03-03T09:30:00Z');
+                INSERT INTO pomodoro_completions (task_id, started_at, completed_at, work_seconds) VALUES
+                    (931, '2025-03-03T09:00:00Z', '2025-03-03T09:25:00Z', 1500);
+                INSERT INTO rounding_rules (project_id, mode, increment_seconds, scope, updated_at) VALUES
+                    (NULL, 'up', 900, 'entry', '2025-03-01T00:00:00Z'),
+                    (931, 'none', 0, 'day', '2025-03-01T00:00:00Z');
+                ",
+            )
+            .unwrap();
+        let version = source.schema_version().await.unwrap();
+        let tables = source.export_tables().await.unwrap();
+
+        // JSONを経由しても値が変わらない
+        let tables: Vec<BackupTable> = serde_json::from_str(&serde_json::to_string(&tables).unwrap()).unwrap();
+
+        let target = SqliteBackupRepository::new(migrated_db());
+        target.import_tables(version, &tables).await.unwrap();
+        assert_eq!(target.export_tables().await.unwrap(), tables);
+
+        // 取り込み済みのデータベースには再度取り込めない
+        let result = target.import_tables(version, &tables).await;
+        assert!(result.unwrap_err().to_string().contains("empty database"));
+    }
+
+    #[tokio::test]
+    async fn 不正なバックアップは一部も取り込まないこと() {
+        let source = SqliteBackupRepository::new(migrated_db());
+        let version = source.schema_version().await.unwrap();
+        let tables = source.export_tables().await.unwrap();
+        let target = SqliteBackupRepository::new(migrated_db());
+        let initial = target.export_tables().await.unwrap();
+
+        // スキーマバージョンの不一致
+        assert!(target.import_tables(version - 1, &tables).await.is_err());
+
+        // テーブルの不足
+        assert!(target.import_tables(version, &tables[1..]).await.is_err());
+
+        // 参照先のプロジェクトがない（後続のテーブルの取り込みまで進んでから失敗する）
+        let mut orphaned = tables.clone();
+        orphaned[0].rows.clear();
+        let result = target.import_tables(version, &orphaned).await;
+        assert!(result.unwrap_err().to_string().contains("foreign key"));
+
+        assert_eq!(target.export_tables().await.unwrap(), initial);
+    }
+}
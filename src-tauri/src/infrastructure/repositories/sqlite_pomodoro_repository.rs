@@ -0,0 +1,91 @@
+use crate::domain::entities::PomodoroCompletion;
+use crate::domain::repositories::PomodoroRepository;
+use crate::domain::value_objects::TaskId;
+use crate::infrastructure::database::DatabaseConnection;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// SQLiteポモドーロリポジトリ実装
+#[derive(Clone)]
+pub struct SqlitePomodoroRepository {
+    db: Arc<Mutex<DatabaseConnection>>,
+}
+
+impl SqlitePomodoroRepository {
+    pub fn new(db: Arc<Mutex<DatabaseConnection>>) -> Self {
+        Self { db }
+    }
+
+    fn format_datetime(dt: DateTime<Utc>) -> String {
+        dt.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+    }
+}
+
+#[async_trait]
+impl PomodoroRepository for SqlitePomodoroRepository {
+    async fn save_completion(&self, completion: &PomodoroCompletion) -> anyhow::Result<PomodoroCompletion> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            "INSERT INTO pomodoro_completions (task_id, started_at, completed_at, work_seconds) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                i64::from(completion.task_id()),
+                Self::format_datetime(completion.started_at()),
+                Self::format_datetime(completion.completed_at()),
+                completion.work_seconds()
+            ],
+        )?;
+
+        Ok(completion.clone().with_id(conn.last_insert_rowid()))
+    }
+
+    async fn count_completions_by_task(&self, task_id: TaskId) -> anyhow::Result<usize> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM pomodoro_completions WHERE task_id = ?1",
+            params![i64::from(task_id)],
+            |row| row.get(0),
+        )?;
+
+        Ok(count as usize)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[tokio::test]
+    async fn 完了したポモドーロがタスクごとに数えられること() {
+        let db = DatabaseConnection::new_in_memory().unwrap();
+        db.run_migrations().unwrap();
+        db.connection()
+            .execute_batch("INSERT INTO tasks (id) VALUES (901); INSERT INTO tasks (id) VALUES (902);")
+            .unwrap();
+        let repository = SqlitePomodoroRepository::new(Arc::new(Mutex::new(db)));
+
+        let task_id = TaskId::new(901).unwrap();
+        let started_at = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let completed_at = Utc.with_ymd_and_hms(2024, 1, 1, 9, 25, 0).unwrap();
+        let saved = repository
+            .save_completion(&PomodoroCompletion::new(task_id, started_at, completed_at, 1500))
+            .await
+            .unwrap();
+        assert!(saved.id().is_some());
+        repository
+            .save_completion(&PomodoroCompletion::new(task_id, completed_at, completed_at, 1500))
+            .await
+            .unwrap();
+
+        assert_eq!(repository.count_completions_by_task(task_id).await.unwrap(), 2);
+        assert_eq!(repository.count_completions_by_task(TaskId::new(902).unwrap()).await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,671 @@
+use crate::domain::repositories::{ReportDimension, ReportKey, ReportQuery, ReportRepository, ReportRow};
+use crate::domain::services::RoundingPolicy;
+use crate::domain::value_objects::{ProjectId, RoundingRule, TagId, TaskId};
+use crate::infrastructure::database::DatabaseConnection;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rusqlite::params;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// 集計対象のエントリ（期間・プロジェクト/タスク/タグ・アーカイブ状態で絞り込み済み）をローカル日付ごとに分割した部分
+///
+/// パラメータ: ?1 集計開始, ?2 集計終了, ?3 アーカイブ済みを含めるか, ?4 プロジェクトID（JSON配列）,
+/// ?5 タスクID（JSON配列）, ?6 タグID（JSON配列）, ?7 現在時刻, ?8 期間内の日（JSON配列）, ?9 丸め規則（JSON配列）
+const REPORT_ENTRIES: &str = r#"
+    WITH days AS (
+        SELECT
+            json_extract(value, '$.period') AS period,
+            json_extract(value, '$.date') AS day,
+            json_extract(value, '$.start') AS day_start,
+            json_extract(value, '$.end') AS day_end
+        FROM json_each(?8)
+    ), rules AS (
+        -- project_id が NULL の規則は既定
+        SELECT
+            json_extract(value, '$.project_id') AS project_id,
+            json_extract(value, '$.mode') AS mode,
+            json_extract(value, '$.increment') AS increment,
+            json_extract(value, '$.scope') AS scope
+        FROM json_each(?9)
+    ), entry_tags AS (
+        -- エントリ開始時点で付与されていたタグ
+        SELECT start_event_id, tag_id
+        FROM (
+            SELECT
+                tev.start_event_id,
+                tte.tag_id,
+                tte.event_type,
+                ROW_NUMBER() OVER (
+                    PARTITION BY tev.start_event_id, tte.tag_id
+                    ORDER BY tte.at DESC, tte.id DESC
+                ) AS rn
+            FROM time_entries_view tev
+            JOIN task_tag_events tte ON tte.task_id = tev.task_id AND tte.at <= tev.start_time
+            WHERE tev.start_time < ?2
+        )
+        WHERE rn = 1 AND event_type = 'add'
+    ), ranged AS (
+        -- 実行中のエントリは一時停止時刻または現在時刻までの区間とする
+        SELECT
+            tev.start_event_id,
+            tev.task_id,
+            tev.start_time,
+            COALESCE(tev.end_time, tev.paused_at, ?7) AS end_time,
+            tev.paused_seconds,
+            tcv.project_id,
+            tcv.name AS task_name,
+            pcv.name AS project_name
+        FROM time_entries_view tev
+        JOIN task_current_view tcv ON tcv.task_id = tev.task_id
+        JOIN project_current_view pcv ON pcv.project_id = tcv.project_id
+        WHERE tev.start_time < ?2
+          AND COALESCE(tev.end_time, tev.paused_at, ?7) > ?1
+          AND (?3 OR (tcv.status = 'active' AND pcv.status = 'active'))
+          AND (?4 IS NULL OR tcv.project_id IN (SELECT value FROM json_each(?4)))
+          AND (?5 IS NULL OR tev.task_id IN (SELECT value FROM json_each(?5)))
+          AND (?6 IS NULL OR EXISTS (
+              SELECT 1 FROM entry_tags et
+              WHERE et.start_event_id = tev.start_event_id
+                AND et.tag_id IN (SELECT value FROM json_each(?6))
+          ))
+    ), spans AS (
+        SELECT
+            r.*,
+            d.period,
+            d.day,
+            MAX(r.start_time, d.day_start) AS span_start,
+            MIN(r.end_time, d.day_end) AS span_end,
+            CAST(ROUND((julianday(MIN(r.end_time, d.day_end)) - julianday(MAX(r.start_time, d.day_start))) * 86400) AS INTEGER)
+                AS span_seconds,
+            CAST(ROUND((julianday(r.end_time) - julianday(r.start_time)) * 86400) AS INTEGER) AS total_span_seconds,
+            r.start_time >= d.day_start AND r.start_time < d.day_end AS is_start_day
+        FROM ranged r
+        JOIN days d ON r.start_time < d.day_end AND r.end_time > d.day_start
+    ), entries AS (
+        -- 一時停止の時間は一時停止していた区間と各日の重なりだけを差し引く
+        SELECT
+            s.*,
+            s.span_seconds - COALESCE((
+                SELECT SUM(CAST(ROUND((julianday(MIN(p.pause_to, s.span_end)) - julianday(MAX(p.pause_from, s.span_start))) * 86400) AS INTEGER))
+                FROM time_entry_pauses_view p
+                WHERE p.start_event_id = s.start_event_id
+                  AND p.pause_from < s.span_end
+                  AND p.pause_to > s.span_start
+            ), 0) AS seconds,
+            s.total_span_seconds - s.paused_seconds AS entry_seconds,
+            COALESCE(pr.mode, dr.mode, 'none') AS rounding_mode,
+            COALESCE(pr.increment, dr.increment, 0) AS rounding_increment,
+            COALESCE(pr.scope, dr.scope, 'entry') AS rounding_scope
+        FROM spans s
+        LEFT JOIN rules pr ON pr.project_id = s.project_id
+        LEFT JOIN rules dr ON dr.project_id IS NULL
+    )
+"#;
+
+/// 丸めの単位（units）ごとの合計を丸める式
+const ROUNDED_UNIT_SECONDS: &str = r#"
+    CASE u.rounding_mode
+        WHEN 'up' THEN (u.unit_seconds + u.rounding_increment - 1) / u.rounding_increment * u.rounding_increment
+        WHEN 'down' THEN u.unit_seconds / u.rounding_increment * u.rounding_increment
+        WHEN 'nearest' THEN (u.unit_seconds + u.rounding_increment / 2) / u.rounding_increment * u.rounding_increment
+        ELSE u.unit_seconds
+    END
+"#;
+
+/// SQLiteレポート集計リポジトリ実装
+#[derive(Clone)]
+pub struct SqliteReportRepository {
+    db: Arc<Mutex<DatabaseConnection>>,
+}
+
+impl SqliteReportRepository {
+    pub fn new(db: Arc<Mutex<DatabaseConnection>>) -> Self {
+        Self { db }
+    }
+
+    fn format_datetime(dt: DateTime<Utc>) -> String {
+        dt.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+    }
+
+    /// 期間内のローカル日付をJSON配列にする（各日にその日を含む期間の開始日を付ける）
+    fn days_json(query: &ReportQuery) -> anyhow::Result<String> {
+        let mut days = Vec::new();
+        for period in query.periods() {
+            for (date, start, end) in query.calendar.split_by_day(period.start, period.end) {
+                days.push(serde_json::json!({
+                    "period": period.date.format("%Y-%m-%d").to_string(),
+                    "date": date.format("%Y-%m-%d").to_string(),
+                    "start": Self::format_datetime(start),
+                    "end": Self::format_datetime(end),
+                }));
+            }
+        }
+        Ok(serde_json::to_string(&days)?)
+    }
+
+    /// 丸め規則をJSON配列にする
+    fn rules_json(rounding: &RoundingPolicy) -> anyhow::Result<String> {
+        let rule_json = |project_id: Option<i64>, rule: &RoundingRule| {
+            serde_json::json!({
+                "project_id": project_id,
+                "mode": rule.mode().as_str(),
+                "increment": rule.increment_seconds(),
+                "scope": rule.scope().as_str(),
+            })
+        };
+        let rules: Vec<_> = std::iter::once(rule_json(None, &rounding.default_rule))
+            .chain(
+                rounding
+                    .project_rules
+                    .iter()
+                    .map(|(project_id, rule)| rule_json(Some(i64::from(*project_id)), rule)),
+            )
+            .collect();
+        Ok(serde_json::to_string(&rules)?)
+    }
+
+    /// 絞り込みのIDをJSON配列にする（空の場合は絞り込まない）
+    fn ids_json(ids: impl ExactSizeIterator<Item = i64>) -> anyhow::Result<Option<String>> {
+        if ids.len() == 0 {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::to_string(&ids.collect::<Vec<_>>())?))
+    }
+
+    /// 軸ごとのキーと名前の列
+    fn dimension_columns(dimension: ReportDimension) -> (&'static str, &'static str) {
+        match dimension {
+            ReportDimension::Project => ("e.project_id", "e.project_name"),
+            ReportDimension::Task => ("e.task_id", "e.task_name"),
+            ReportDimension::Tag => ("et.tag_id", "tg.name"),
+        }
+    }
+
+    fn build_sql(dimensions: &[ReportDimension]) -> String {
+        let mut columns = vec!["e.period AS period".to_string()];
+        let mut outer_columns = vec!["u.period".to_string()];
+        let mut group_by = vec!["e.period".to_string()];
+        let mut outer_group_by = vec!["u.period".to_string()];
+        for (i, dimension) in dimensions.iter().enumerate() {
+            let (key, name) = Self::dimension_columns(*dimension);
+            columns.push(format!("{} AS key{}", key, i));
+            columns.push(format!("{} AS name{}", name, i));
+            outer_columns.push(format!("u.key{}", i));
+            outer_columns.push(format!("u.name{}", i));
+            group_by.push(key.to_string());
+            outer_group_by.push(format!("u.key{}", i));
+        }
+
+        let tag_join = if dimensions.contains(&ReportDimension::Tag) {
+            "LEFT JOIN entry_tags et ON et.start_event_id = e.start_event_id LEFT JOIN tags tg ON tg.id = et.tag_id"
+        } else {
+            ""
+        };
+
+        // 丸めの単位: エントリ単位の規則ではエントリ全体（開始日の行に計上）、日単位の規則ではグループ内の日・タスクごと
+        // 単位の合計を先頭の行にだけ計上することで、グループの合計を単位ごとに丸めた値の合計にする
+        format!(
+            "{}, units AS ( \
+                SELECT {}, e.start_event_id, e.seconds, e.rounding_mode, e.rounding_increment, \
+                    CASE WHEN e.rounding_scope = 'entry' THEN e.entry_seconds \
+                        ELSE SUM(e.seconds) OVER unit END AS unit_seconds, \
+                    CASE WHEN e.rounding_scope = 'entry' THEN CASE WHEN e.is_start_day THEN 1 ELSE 0 END \
+                        ELSE ROW_NUMBER() OVER unit END AS unit_row \
+                FROM entries e {} \
+                WINDOW unit AS (PARTITION BY {}, e.day, e.task_id) \
+            ) \
+            SELECT {}, SUM(u.seconds), SUM(CASE WHEN u.unit_row = 1 THEN {} ELSE 0 END), COUNT(DISTINCT u.start_event_id) \
+            FROM units u GROUP BY {} ORDER BY {}",
+            REPORT_ENTRIES,
+            columns.join(", "),
+            tag_join,
+            group_by.join(", "),
+            outer_columns.join(", "),
+            ROUNDED_UNIT_SECONDS,
+            outer_group_by.join(", "),
+            outer_group_by.join(", "),
+        )
+    }
+}
+
+#[async_trait]
+impl ReportRepository for SqliteReportRepository {
+    async fn aggregate(
+        &self,
+        query: &ReportQuery,
+        dimensions: &[ReportDimension],
+        rounding: &RoundingPolicy,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<ReportRow>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let sql = Self::build_sql(dimensions);
+        let mut stmt = conn.prepare(&sql)?;
+
+        let row_iter = stmt.query_map(
+            params![
+                Self::format_datetime(query.start),
+                Self::format_datetime(query.end),
+                query.include_archived,
+                Self::ids_json(query.project_ids.iter().map(|id| i64::from(*id)))?,
+                Self::ids_json(query.task_ids.iter().map(|id| i64::from(*id)))?,
+                Self::ids_json(query.tag_ids.iter().map(|id| i64::from(*id)))?,
+                Self::format_datetime(now),
+                Self::days_json(query)?,
+                Self::rules_json(rounding)?,
+            ],
+            |row| {
+                let period: String = row.get(0)?;
+                let mut keys = Vec::with_capacity(dimensions.len());
+                for i in 0..dimensions.len() {
+                    let key: Option<i64> = row.get(1 + i * 2)?;
+                    let name: Option<String> = row.get(2 + i * 2)?;
+                    keys.push((key, name));
+                }
+                let total_seconds: i64 = row.get(1 + dimensions.len() * 2)?;
+                let rounded_seconds: i64 = row.get(2 + dimensions.len() * 2)?;
+                let entry_count: i64 = row.get(3 + dimensions.len() * 2)?;
+
+                Ok((period, keys, total_seconds, rounded_seconds, entry_count))
+            },
+        )?;
+
+        let mut rows = Vec::new();
+        for row_result in row_iter {
+            let (period, dimension_keys, total_seconds, rounded_seconds, entry_count) = row_result?;
+            let period = NaiveDate::parse_from_str(&period, "%Y-%m-%d")?;
+
+            let mut keys = vec![ReportKey::Period(period)];
+            let mut names = vec![None];
+            for (dimension, (key, name)) in dimensions.iter().zip(dimension_keys) {
+                let key = match dimension {
+                    ReportDimension::Project => ReportKey::Project(ProjectId::new(
+                        key.ok_or_else(|| anyhow::anyhow!("Report row is missing project id"))?,
+                    )?),
+                    ReportDimension::Task => ReportKey::Task(TaskId::new(
+                        key.ok_or_else(|| anyhow::anyhow!("Report row is missing task id"))?,
+                    )?),
+                    ReportDimension::Tag => ReportKey::Tag(key.map(TagId::new).transpose()?),
+                };
+                keys.push(key);
+                names.push(name);
+            }
+
+            rows.push(ReportRow {
+                keys,
+                names,
+                total_seconds,
+                rounded_seconds,
+                entry_count: entry_count as usize,
+            });
+        }
+
+        Ok(rows)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::ReportGranularity;
+    use crate::domain::services::LocalCalendar;
+    use chrono::{TimeZone, Weekday};
+
+    // サンプルデータと重ならないID・期間でデータを作成する
+    async fn setup_repository() -> SqliteReportRepository {
+        let db = DatabaseConnection::new_in_memory().unwrap();
+        db.run_migrations().unwrap();
+        db.connection()
+            .execute_batch(
+                r#"
+                INSERT INTO projects (id) VALUES (901), (902);
+                INSERT INTO project_versions (project_id, version, name, status, effective_at) VALUES
+                  (901, 1, 'Report A', 'active', '2025-01-01T00:00:00Z'),
+                  (902, 1, 'Report B', 'archived', '2025-01-01T00:00:00Z');
+                INSERT INTO tasks (id) VALUES (901), (902), (903);
+                INSERT INTO task_versions (task_id, version, project_id, name, status, effective_at) VALUES
+                  (901, 1, 901, 'Design', 'active', '2025-01-01T00:00:00Z'),
+                  (902, 1, 901, 'Review', 'active', '2025-01-01T00:00:00Z'),
+                  (903, 1, 902, 'Old', 'archived', '2025-01-01T00:00:00Z');
+                INSERT INTO tags (id, name) VALUES (901, 'report-x'), (902, 'report-y');
+                INSERT INTO task_tag_events (task_id, tag_id, event_type, at) VALUES
+                  (901, 901, 'add', '2025-01-01T00:00:00Z'),
+                  (901, 902, 'add', '2025-01-01T00:00:00Z');
+                INSERT INTO time_entry_events (id, task_id, event_type, at, start_event_id) VALUES
+                  (9001, 901, 'start', '2025-03-03T09:00:00Z', NULL),
+                  (9002, 901, 'stop', '2025-03-03T10:00:00Z', 9001),
+                  (9003, 902, 'start', '2025-03-04T09:00:00Z', NULL),
+                  (9004, 902, 'stop', '2025-03-04T09:30:00Z', 9003),
+                  (9005, 901, 'start', '2025-03-10T23:30:00Z', NULL),
+                  (9006, 901, 'stop', '2025-03-11T00:00:00Z', 9005),
+                  (9007, 903, 'start', '2025-03-05T09:00:00Z', NULL),
+                  (9008, 903, 'stop', '2025-03-05T10:00:00Z', 9007);
+                "#,
+            )
+            .unwrap();
+        SqliteReportRepository::new(Arc::new(Mutex::new(db)))
+    }
+
+    fn query(granularity: ReportGranularity) -> ReportQuery {
+        ReportQuery {
+            start: Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+            granularity,
+            group_by: Vec::new(),
+            project_ids: Vec::new(),
+            task_ids: Vec::new(),
+            tag_ids: Vec::new(),
+            include_archived: false,
+            calendar: LocalCalendar::utc(),
+        }
+    }
+
+    fn date(month: u32, day: u32) -> ReportKey {
+        ReportKey::Period(NaiveDate::from_ymd_opt(2025, month, day).unwrap())
+    }
+
+    fn totals(rows: &[ReportRow]) -> Vec<(Vec<ReportKey>, i64)> {
+        rows.iter().map(|row| (row.keys.clone(), row.total_seconds)).collect()
+    }
+
+    #[tokio::test]
+    async fn 週単位でタスク別に集計されること() {
+        let repository = setup_repository().await;
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let task = |id| ReportKey::Task(TaskId::new(id).unwrap());
+
+        let rows = repository
+            .aggregate(&query(ReportGranularity::Week), &[ReportDimension::Task], &RoundingPolicy::default(), now)
+            .await
+            .unwrap();
+
+        // アーカイブ済みのタスクは含まれない
+        assert_eq!(totals(&rows), vec![
+            (vec![date(3, 3), task(901)], 3600),
+            (vec![date(3, 3), task(902)], 1800),
+            (vec![date(3, 10), task(901)], 1800),
+        ]);
+        assert_eq!(rows[0].names, vec![None, Some("Design".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn 同じ名前のタスクがキーごとに集計されること() {
+        let repository = setup_repository().await;
+        insert_events(
+            &repository,
+            r#"
+            INSERT INTO tasks (id) VALUES (904);
+            INSERT INTO task_versions (task_id, version, project_id, name, status, effective_at) VALUES
+              (904, 1, 902, 'Design', 'active', '2025-01-01T00:00:00Z');
+            INSERT INTO time_entry_events (id, task_id, event_type, at, start_event_id) VALUES
+              (9101, 904, 'start', '2025-03-05T09:00:00Z', NULL),
+              (9102, 904, 'stop', '2025-03-05T09:20:00Z', 9101);
+            "#,
+        )
+        .await;
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let task = |id| ReportKey::Task(TaskId::new(id).unwrap());
+        let mut query = query(ReportGranularity::Month);
+        query.include_archived = true;
+        query.task_ids = vec![TaskId::new(901).unwrap(), TaskId::new(904).unwrap()];
+
+        // 別プロジェクトの "Design" は1行にまとめられない
+        let rows = repository
+            .aggregate(&query, &[ReportDimension::Task], &RoundingPolicy::default(), now)
+            .await
+            .unwrap();
+        assert_eq!(totals(&rows), vec![
+            (vec![date(3, 1), task(901)], 5400),
+            (vec![date(3, 1), task(904)], 1200),
+        ]);
+        assert_eq!(rows[1].names, vec![None, Some("Design".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn タイムゾーンで日の区切りが変わること() {
+        let repository = setup_repository().await;
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let mut query = query(ReportGranularity::Day);
+        query.task_ids = vec![TaskId::new(901).unwrap()];
+
+        let rows = repository.aggregate(&query, &[], &RoundingPolicy::default(), now).await.unwrap();
+        assert_eq!(totals(&rows), vec![(vec![date(3, 3)], 3600), (vec![date(3, 10)], 1800)]);
+
+        // JSTでは 3/10 23:30 の開始は 3/11 に計上される
+        query.calendar = LocalCalendar::from_name("Asia/Tokyo", Weekday::Mon).unwrap();
+        let rows = repository.aggregate(&query, &[], &RoundingPolicy::default(), now).await.unwrap();
+        assert_eq!(totals(&rows), vec![(vec![date(3, 3)], 3600), (vec![date(3, 11)], 1800)]);
+    }
+
+    #[tokio::test]
+    async fn タグ別の集計と絞り込みができること() {
+        let repository = setup_repository().await;
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let tag = |id: Option<i64>| ReportKey::Tag(id.map(|id| TagId::new(id).unwrap()));
+
+        // 複数のタグが付与されたエントリはタグごとに計上され、タグのないエントリはNoneになる
+        let rows = repository
+            .aggregate(&query(ReportGranularity::Month), &[ReportDimension::Tag], &RoundingPolicy::default(), now)
+            .await
+            .unwrap();
+        assert_eq!(totals(&rows), vec![
+            (vec![date(3, 1), tag(None)], 1800),
+            (vec![date(3, 1), tag(Some(901))], 5400),
+            (vec![date(3, 1), tag(Some(902))], 5400),
+        ]);
+
+        let mut query = query(ReportGranularity::Month);
+        query.tag_ids = vec![TagId::new(902).unwrap()];
+        let rows = repository.aggregate(&query, &[], &RoundingPolicy::default(), now).await.unwrap();
+        assert_eq!(totals(&rows), vec![(vec![date(3, 1)], 5400)]);
+    }
+
+    #[tokio::test]
+    async fn アーカイブ済みを含めてプロジェクト別に集計できること() {
+        let repository = setup_repository().await;
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let project = |id| ReportKey::Project(ProjectId::new(id).unwrap());
+        let mut query = query(ReportGranularity::Month);
+        query.include_archived = true;
+        query.project_ids = vec![ProjectId::new(901).unwrap(), ProjectId::new(902).unwrap()];
+
+        let rows = repository.aggregate(&query, &[ReportDimension::Project], &RoundingPolicy::default(), now).await.unwrap();
+        assert_eq!(totals(&rows), vec![
+            (vec![date(3, 1), project(901)], 7200),
+            (vec![date(3, 1), project(902)], 3600),
+        ]);
+        assert_eq!(rows[1].names, vec![None, Some("Report B".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn 実行中のエントリは現在時刻までで計上されること() {
+        let repository = setup_repository().await;
+        {
+            let db = repository.db.lock().await;
+            db.connection()
+                .execute(
+                    "INSERT INTO time_entry_events (task_id, event_type, at) VALUES (902, 'start', '2025-03-20T09:00:00Z')",
+                    [],
+                )
+                .unwrap();
+        }
+        let mut query = query(ReportGranularity::Day);
+        query.task_ids = vec![TaskId::new(902).unwrap()];
+        let now = Utc.with_ymd_and_hms(2025, 3, 20, 9, 15, 0).unwrap();
+
+        let rows = repository.aggregate(&query, &[], &RoundingPolicy::default(), now).await.unwrap();
+        assert_eq!(totals(&rows), vec![(vec![date(3, 4)], 1800), (vec![date(3, 20)], 900)]);
+    }
+
+    async fn insert_events(repository: &SqliteReportRepository, sql: &str) {
+        let db = repository.db.lock().await;
+        db.connection().execute_batch(sql).unwrap();
+    }
+
+    #[tokio::test]
+    async fn 日をまたぐエントリがローカルの0時で分割されること() {
+        let repository = setup_repository().await;
+        insert_events(
+            &repository,
+            r#"
+            INSERT INTO time_entry_events (id, task_id, event_type, at, start_event_id) VALUES
+              (9101, 902, 'start', '2025-03-20T13:00:00Z', NULL),
+              (9102, 902, 'stop', '2025-03-20T17:00:00Z', 9101);
+            "#,
+        )
+        .await;
+        let mut query = query(ReportGranularity::Day);
+        query.task_ids = vec![TaskId::new(902).unwrap()];
+        query.calendar = LocalCalendar::from_name("Asia/Tokyo", Weekday::Mon).unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+
+        // JSTの 3/20 22:00 から 3/21 2:00 までは2時間ずつ計上され、件数は各日で数える
+        let rows = repository.aggregate(&query, &[], &RoundingPolicy::default(), now).await.unwrap();
+        assert_eq!(totals(&rows), vec![
+            (vec![date(3, 4)], 1800),
+            (vec![date(3, 20)], 7200),
+            (vec![date(3, 21)], 7200),
+        ]);
+        assert_eq!(rows[1].entry_count, 1);
+        assert_eq!(rows[2].entry_count, 1);
+
+        // 集計期間の外にはみ出した部分は計上しない
+        query.start = Utc.with_ymd_and_hms(2025, 3, 20, 16, 0, 0).unwrap();
+        let rows = repository.aggregate(&query, &[], &RoundingPolicy::default(), now).await.unwrap();
+        assert_eq!(totals(&rows), vec![(vec![date(3, 21)], 3600)]);
+    }
+
+    #[tokio::test]
+    async fn 夏時間の切り替え日は実際の経過時間で計上されること() {
+        let repository = setup_repository().await;
+        // ニューヨークの 3/8 22:00 EST から 3/10 1:00 EDT まで（3/9 は23時間）
+        insert_events(
+            &repository,
+            r#"
+            INSERT INTO time_entry_events (id, task_id, event_type, at, start_event_id) VALUES
+              (9101, 902, 'start', '2025-03-09T03:00:00Z', NULL),
+              (9102, 902, 'stop', '2025-03-10T05:00:00Z', 9101);
+            "#,
+        )
+        .await;
+        let mut query = query(ReportGranularity::Day);
+        query.start = Utc.with_ymd_and_hms(2025, 3, 8, 0, 0, 0).unwrap();
+        query.task_ids = vec![TaskId::new(902).unwrap()];
+        query.calendar = LocalCalendar::from_name("America/New_York", Weekday::Mon).unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+
+        let rows = repository.aggregate(&query, &[], &RoundingPolicy::default(), now).await.unwrap();
+        assert_eq!(totals(&rows), vec![
+            (vec![date(3, 8)], 2 * 3600),
+            (vec![date(3, 9)], 23 * 3600),
+            (vec![date(3, 10)], 3600),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn 日をまたぐエントリの一時停止は一時停止していた日から差し引かれること() {
+        let repository = setup_repository().await;
+        // 22:00 から 2:00 までのうち 23:00 から 0:00 まで一時停止（実時間3時間）
+        insert_events(
+            &repository,
+            r#"
+            INSERT INTO time_entry_events (id, task_id, event_type, at, start_event_id) VALUES
+              (9101, 902, 'start', '2025-03-20T22:00:00Z', NULL),
+              (9102, 902, 'pause', '2025-03-20T23:00:00Z', 9101),
+              (9103, 902, 'resume', '2025-03-21T00:00:00Z', 9101),
+              (9104, 902, 'stop', '2025-03-21T02:00:00Z', 9101);
+            "#,
+        )
+        .await;
+        let mut query = query(ReportGranularity::Day);
+        query.task_ids = vec![TaskId::new(902).unwrap()];
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+
+        let rows = repository.aggregate(&query, &[], &RoundingPolicy::default(), now).await.unwrap();
+        assert_eq!(totals(&rows), vec![
+            (vec![date(3, 4)], 1800),
+            (vec![date(3, 20)], 3600),
+            (vec![date(3, 21)], 7200),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn 丸め規則がエントリごとと日ごとに適用されること() {
+        use crate::domain::value_objects::{RoundingMode, RoundingScope};
+
+        let repository = setup_repository().await;
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let task = |id| ReportKey::Task(TaskId::new(id).unwrap());
+        let rounded = |rows: &[ReportRow]| -> Vec<(Vec<ReportKey>, i64)> {
+            rows.iter().map(|row| (row.keys.clone(), row.rounded_seconds)).collect()
+        };
+
+        // 既定: 40分単位の切り上げをエントリごと
+        let mut rounding = RoundingPolicy {
+            default_rule: RoundingRule::new(RoundingMode::Up, 2400, RoundingScope::Entry).unwrap(),
+            project_rules: Vec::new(),
+        };
+        let rows = repository
+            .aggregate(&query(ReportGranularity::Week), &[ReportDimension::Task], &rounding, now)
+            .await
+            .unwrap();
+        assert_eq!(rounded(&rows), vec![
+            (vec![date(3, 3), task(901)], 4800),
+            (vec![date(3, 3), task(902)], 2400),
+            (vec![date(3, 10), task(901)], 2400),
+        ]);
+        // 記録された時間は変わらない
+        assert_eq!(rows[0].total_seconds, 3600);
+
+        // プロジェクトの上書き: 1時間単位の四捨五入をタスクごとの1日の合計に
+        rounding.project_rules = vec![(
+            ProjectId::new(901).unwrap(),
+            RoundingRule::new(RoundingMode::Nearest, 3600, RoundingScope::Day).unwrap(),
+        )];
+        let rows = repository
+            .aggregate(&query(ReportGranularity::Month), &[], &rounding, now)
+            .await
+            .unwrap();
+        assert_eq!(rounded(&rows), vec![(vec![date(3, 1)], 3 * 3600)]);
+        assert_eq!(totals(&rows), vec![(vec![date(3, 1)], 7200)]);
+    }
+
+    #[tokio::test]
+    async fn エントリ単位の丸めは日をまたぐエントリを開始日に計上すること() {
+        use crate::domain::value_objects::{RoundingMode, RoundingScope};
+
+        let repository = setup_repository().await;
+        insert_events(
+            &repository,
+            r#"
+            INSERT INTO time_entry_events (id, task_id, event_type, at, start_event_id) VALUES
+              (9101, 902, 'start', '2025-03-20T23:55:00Z', NULL),
+              (9102, 902, 'stop', '2025-03-21T00:05:00Z', 9101);
+            "#,
+        )
+        .await;
+        let mut query = query(ReportGranularity::Day);
+        query.start = Utc.with_ymd_and_hms(2025, 3, 20, 0, 0, 0).unwrap();
+        query.task_ids = vec![TaskId::new(902).unwrap()];
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let rounding = |scope| RoundingPolicy {
+            default_rule: RoundingRule::new(RoundingMode::Up, 900, scope).unwrap(),
+            project_rules: Vec::new(),
+        };
+        let rounded = |rows: &[ReportRow]| -> Vec<(Vec<ReportKey>, i64)> {
+            rows.iter().map(|row| (row.keys.clone(), row.rounded_seconds)).collect()
+        };
+
+        // エントリ全体の10分を1回だけ切り上げ、開始日に計上する
+        let rows = repository.aggregate(&query, &[], &rounding(RoundingScope::Entry), now).await.unwrap();
+        assert_eq!(totals(&rows), vec![(vec![date(3, 20)], 300), (vec![date(3, 21)], 300)]);
+        assert_eq!(rounded(&rows), vec![(vec![date(3, 20)], 900), (vec![date(3, 21)], 0)]);
+
+        // 日単位の規則では日ごとの合計を丸める
+        let rows = repository.aggregate(&query, &[], &rounding(RoundingScope::Day), now).await.unwrap();
+        assert_eq!(rounded(&rows), vec![(vec![date(3, 20)], 900), (vec![date(3, 21)], 900)]);
+    }
+}
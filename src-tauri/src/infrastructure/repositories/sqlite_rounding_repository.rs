@@ -0,0 +1,115 @@
+use crate::domain::repositories::RoundingRepository;
+use crate::domain::services::RoundingPolicy;
+use crate::domain::value_objects::{ProjectId, RoundingMode, RoundingRule, RoundingScope};
+use crate::infrastructure::database::DatabaseConnection;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// SQLite丸め設定リポジトリ実装
+#[derive(Clone)]
+pub struct SqliteRoundingRepository {
+    db: Arc<Mutex<DatabaseConnection>>,
+}
+
+impl SqliteRoundingRepository {
+    pub fn new(db: Arc<Mutex<DatabaseConnection>>) -> Self {
+        Self { db }
+    }
+
+    fn format_datetime(dt: DateTime<Utc>) -> String {
+        dt.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+    }
+}
+
+#[async_trait]
+impl RoundingRepository for SqliteRoundingRepository {
+    async fn find_policy(&self) -> anyhow::Result<RoundingPolicy> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT project_id, mode, increment_seconds, scope FROM rounding_rules ORDER BY project_id",
+        )?;
+        let row_iter = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, Option<i64>>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+
+        let mut policy = RoundingPolicy::default();
+        for row in row_iter {
+            let (project_id, mode, increment_seconds, scope) = row?;
+            let rule = RoundingRule::new(mode.parse::<RoundingMode>()?, increment_seconds, scope.parse::<RoundingScope>()?)?;
+            match project_id {
+                None => policy.default_rule = rule,
+                Some(project_id) => policy.project_rules.push((ProjectId::new(project_id)?, rule)),
+            }
+        }
+
+        Ok(policy)
+    }
+
+    async fn save_rule(&self, project_id: Option<ProjectId>, rule: RoundingRule) -> anyhow::Result<()> {
+        let db = self.db.lock().await;
+        let tx = db.connection().unchecked_transaction()?;
+
+        let project_id = project_id.map(i64::from);
+        tx.execute("DELETE FROM rounding_rules WHERE project_id IS ?1", params![project_id])?;
+        tx.execute(
+            "INSERT INTO rounding_rules (project_id, mode, increment_seconds, scope, updated_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                project_id,
+                rule.mode().as_str(),
+                rule.increment_seconds(),
+                rule.scope().as_str(),
+                Self::format_datetime(Utc::now())
+            ],
+        )?;
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    async fn delete_project_rule(&self, project_id: ProjectId) -> anyhow::Result<()> {
+        let db = self.db.lock().await;
+        db.connection()
+            .execute("DELETE FROM rounding_rules WHERE project_id = ?1", params![i64::from(project_id)])?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn 既定とプロジェクトごとの規則が保存・置き換え・削除できること() {
+        let db = DatabaseConnection::new_in_memory().unwrap();
+        db.run_migrations().unwrap();
+        db.connection().execute_batch("INSERT INTO projects (id) VALUES (921);").unwrap();
+        let repository = SqliteRoundingRepository::new(Arc::new(Mutex::new(db)));
+        let project_id = ProjectId::new(921).unwrap();
+        let six_minutes = RoundingRule::new(RoundingMode::Up, 360, RoundingScope::Entry).unwrap();
+        let quarter_day = RoundingRule::new(RoundingMode::Nearest, 900, RoundingScope::Day).unwrap();
+
+        assert_eq!(repository.find_policy().await.unwrap(), RoundingPolicy::default());
+
+        repository.save_rule(None, six_minutes).await.unwrap();
+        repository.save_rule(Some(project_id), six_minutes).await.unwrap();
+        repository.save_rule(Some(project_id), quarter_day).await.unwrap();
+        let policy = repository.find_policy().await.unwrap();
+        assert_eq!(policy.default_rule, six_minutes);
+        assert_eq!(policy.project_rules, vec![(project_id, quarter_day)]);
+
+        repository.save_rule(None, RoundingRule::none()).await.unwrap();
+        repository.delete_project_rule(project_id).await.unwrap();
+        assert_eq!(repository.find_policy().await.unwrap(), RoundingPolicy::default());
+    }
+}
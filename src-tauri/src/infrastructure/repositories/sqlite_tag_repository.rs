@@ -0,0 +1,354 @@
+use crate::domain::entities::{Tag, TaskTagEvent, TaskTagEventType};
+use crate::domain::repositories::TagRepository;
+use crate::domain::value_objects::{TagId, TaskId};
+use crate::infrastructure::database::DatabaseConnection;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// SQLiteタグリポジトリ実装
+#[derive(Clone)]
+pub struct SqliteTagRepository {
+    db: Arc<Mutex<DatabaseConnection>>,
+}
+
+impl SqliteTagRepository {
+    pub fn new(db: Arc<Mutex<DatabaseConnection>>) -> Self {
+        Self { db }
+    }
+
+    fn format_datetime(dt: DateTime<Utc>) -> String {
+        dt.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+    }
+
+    fn parse_datetime(s: &str) -> anyhow::Result<DateTime<Utc>> {
+        Ok(DateTime::parse_from_rfc3339(s)?.with_timezone(&Utc))
+    }
+}
+
+#[async_trait]
+impl TagRepository for SqliteTagRepository {
+    async fn save(&self, tag: &Tag) -> anyhow::Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            "INSERT INTO tags (id, name) VALUES (?1, ?2)",
+            params![i64::from(tag.id()), tag.name()],
+        )?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: TagId) -> anyhow::Result<Option<Tag>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let result = conn.query_row(
+            "SELECT id, name FROM tags WHERE id = ?1",
+            params![i64::from(id)],
+            |row| {
+                let id: i64 = row.get(0)?;
+                let name: String = row.get(1)?;
+                Ok((id, name))
+            },
+        );
+
+        match result {
+            Ok((id, name)) => Ok(Some(Tag::new(TagId::new(id)?, name)?)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn find_by_name(&self, name: &str) -> anyhow::Result<Option<Tag>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let result = conn.query_row(
+            "SELECT id, name FROM tags WHERE name = ?1",
+            params![name],
+            |row| {
+                let id: i64 = row.get(0)?;
+                let name: String = row.get(1)?;
+                Ok((id, name))
+            },
+        );
+
+        match result {
+            Ok((id, name)) => Ok(Some(Tag::new(TagId::new(id)?, name)?)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn find_all(&self) -> anyhow::Result<Vec<Tag>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare("SELECT id, name FROM tags ORDER BY name")?;
+
+        let tag_iter = stmt.query_map([], |row| {
+            let id: i64 = row.get(0)?;
+            let name: String = row.get(1)?;
+            Ok((id, name))
+        })?;
+
+        let mut tags = Vec::new();
+        for tag_result in tag_iter {
+            let (id, name) = tag_result?;
+            tags.push(Tag::new(TagId::new(id)?, name)?);
+        }
+
+        Ok(tags)
+    }
+
+    async fn next_id(&self) -> anyhow::Result<TagId> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let next_id: i64 = conn.query_row(
+            "SELECT COALESCE(MAX(id), 0) + 1 FROM tags",
+            [],
+            |row| row.get(0),
+        )?;
+
+        TagId::new(next_id)
+    }
+
+    async fn save_task_tag_event(&self, event: &TaskTagEvent) -> anyhow::Result<TaskTagEvent> {
+        tracing::info!("SqliteTagRepository::save_task_tag_event: task_id: {}, tag_id: {}, event_type: {:?}",
+            event.task_id(), event.tag_id(), event.event_type());
+
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let id = conn.query_row(
+            r#"
+            INSERT INTO task_tag_events (task_id, tag_id, event_type, at)
+            VALUES (?1, ?2, ?3, ?4)
+            RETURNING id
+            "#,
+            params![
+                i64::from(event.task_id()),
+                i64::from(event.tag_id()),
+                event.event_type().as_str(),
+                Self::format_datetime(event.at()),
+            ],
+            |row| row.get::<_, i64>(0),
+        )?;
+
+        Ok(event.clone().with_id(id))
+    }
+
+    async fn find_by_task(&self, task_id: TaskId) -> anyhow::Result<Vec<Tag>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT tg.id, tg.name
+            FROM task_tags_current ttc
+            JOIN tags tg ON tg.id = ttc.tag_id
+            WHERE ttc.task_id = ?1
+            ORDER BY tg.name
+            "#,
+        )?;
+
+        let tag_iter = stmt.query_map(params![i64::from(task_id)], |row| {
+            let id: i64 = row.get(0)?;
+            let name: String = row.get(1)?;
+            Ok((id, name))
+        })?;
+
+        let mut tags = Vec::new();
+        for tag_result in tag_iter {
+            let (id, name) = tag_result?;
+            tags.push(Tag::new(TagId::new(id)?, name)?);
+        }
+
+        Ok(tags)
+    }
+
+    async fn find_by_task_at(&self, task_id: TaskId, at: DateTime<Utc>) -> anyhow::Result<Vec<Tag>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        // 指定時刻までの最新イベントが add のタグを付与中とみなす（レポート・絞り込みと同じ判定）
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT tg.id, tg.name
+            FROM tags tg
+            WHERE (
+                SELECT tte.event_type
+                FROM task_tag_events tte
+                WHERE tte.task_id = ?1
+                  AND tte.tag_id = tg.id
+                  AND tte.at <= ?2
+                ORDER BY tte.at DESC, tte.id DESC
+                LIMIT 1
+            ) = 'add'
+            ORDER BY tg.name
+            "#,
+        )?;
+
+        let tag_iter = stmt.query_map(params![i64::from(task_id), Self::format_datetime(at)], |row| {
+            let id: i64 = row.get(0)?;
+            let name: String = row.get(1)?;
+            Ok((id, name))
+        })?;
+
+        let mut tags = Vec::new();
+        for tag_result in tag_iter {
+            let (id, name) = tag_result?;
+            tags.push(Tag::new(TagId::new(id)?, name)?);
+        }
+
+        Ok(tags)
+    }
+
+    async fn find_events_by_task(&self, task_id: TaskId) -> anyhow::Result<Vec<TaskTagEvent>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, tag_id, event_type, at
+            FROM task_tag_events
+            WHERE task_id = ?1
+            ORDER BY at, id
+            "#,
+        )?;
+
+        let event_iter = stmt.query_map(params![i64::from(task_id)], |row| {
+            let id: i64 = row.get(0)?;
+            let tag_id: i64 = row.get(1)?;
+            let event_type: String = row.get(2)?;
+            let at: String = row.get(3)?;
+            Ok((id, tag_id, event_type, at))
+        })?;
+
+        let mut events = Vec::new();
+        for event_result in event_iter {
+            let (id, tag_id, event_type, at) = event_result?;
+            let tag_id = TagId::new(tag_id)?;
+            let at = Self::parse_datetime(&at)?;
+            let event = match event_type.parse::<TaskTagEventType>()? {
+                TaskTagEventType::Add => TaskTagEvent::add_at(task_id, tag_id, at),
+                TaskTagEventType::Remove => TaskTagEvent::remove(task_id, tag_id).with_at(at),
+            };
+            events.push(event.with_id(id));
+        }
+
+        Ok(events)
+    }
+
+    async fn find_task_ids_by_tag(&self, tag_id: TagId) -> anyhow::Result<Vec<TaskId>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT task_id FROM task_tags_current WHERE tag_id = ?1 ORDER BY task_id",
+        )?;
+
+        let id_iter = stmt.query_map(params![i64::from(tag_id)], |row| row.get::<_, i64>(0))?;
+
+        let mut task_ids = Vec::new();
+        for id_result in id_iter {
+            task_ids.push(TaskId::new(id_result?)?);
+        }
+
+        Ok(task_ids)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    async fn setup_repository() -> SqliteTagRepository {
+        let db = DatabaseConnection::new_in_memory().unwrap();
+        db.run_migrations().unwrap();
+        SqliteTagRepository::new(Arc::new(Mutex::new(db)))
+    }
+
+    #[tokio::test]
+    async fn タグの保存と名前検索ができること() {
+        let repository = setup_repository().await;
+        let tag_id = repository.next_id().await.unwrap();
+        let tag = Tag::new(tag_id, "meeting".to_string()).unwrap();
+
+        repository.save(&tag).await.unwrap();
+
+        let found = repository.find_by_name("meeting").await.unwrap().unwrap();
+        assert_eq!(found.id(), tag_id);
+        assert!(repository.find_all().await.unwrap().iter().any(|t| t.name() == "meeting"));
+    }
+
+    #[tokio::test]
+    async fn 現在タグ集合ビューから付与中のタグが取得できること() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(1).unwrap();
+        let tag = Tag::new(repository.next_id().await.unwrap(), "meeting".to_string()).unwrap();
+        repository.save(&tag).await.unwrap();
+
+        repository.save_task_tag_event(&TaskTagEvent::add(task_id, tag.id())).await.unwrap();
+        let tags = repository.find_by_task(task_id).await.unwrap();
+        assert!(tags.iter().any(|t| t.id() == tag.id()));
+        assert!(repository.find_task_ids_by_tag(tag.id()).await.unwrap().contains(&task_id));
+
+        let removed_at = Utc::now() + chrono::Duration::seconds(1);
+        repository
+            .save_task_tag_event(&TaskTagEvent::remove(task_id, tag.id()).with_at(removed_at))
+            .await
+            .unwrap();
+        let tags = repository.find_by_task(task_id).await.unwrap();
+        assert!(!tags.iter().any(|t| t.id() == tag.id()));
+    }
+
+    #[tokio::test]
+    async fn 指定時刻の時点で付与されていたタグが取得できること() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(1).unwrap();
+        let tag = Tag::new(repository.next_id().await.unwrap(), "client-a".to_string()).unwrap();
+        repository.save(&tag).await.unwrap();
+        let added_at = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let removed_at = Utc.with_ymd_and_hms(2025, 3, 10, 9, 0, 0).unwrap();
+
+        repository
+            .save_task_tag_event(&TaskTagEvent::add(task_id, tag.id()).with_at(added_at))
+            .await
+            .unwrap();
+        repository
+            .save_task_tag_event(&TaskTagEvent::remove(task_id, tag.id()).with_at(removed_at))
+            .await
+            .unwrap();
+
+        let has_tag = |tags: Vec<Tag>| tags.iter().any(|t| t.id() == tag.id());
+        assert!(!has_tag(
+            repository
+                .find_by_task_at(task_id, added_at - chrono::Duration::seconds(1))
+                .await
+                .unwrap()
+        ));
+        assert!(has_tag(repository.find_by_task_at(task_id, added_at).await.unwrap()));
+        assert!(!has_tag(repository.find_by_task_at(task_id, removed_at).await.unwrap()));
+
+        let events: Vec<TaskTagEvent> = repository
+            .find_events_by_task(task_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|event| event.tag_id() == tag.id())
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert!(events[0].is_add());
+        assert_eq!(events[0].at(), added_at);
+        assert!(events[1].is_remove());
+        assert_eq!(events[1].at(), removed_at);
+    }
+}
@@ -1,10 +1,11 @@
-use crate::domain::entities::time_entry::{TimeEntry, TimeEntryEvent};
-use crate::domain::value_objects::TaskId;
-use crate::domain::repositories::TimeEntryRepository;
+use crate::domain::entities::time_entry::{PauseInterval, TimeEntry, TimeEntryEvent, TimeEntryEventType};
+use crate::domain::value_objects::{TagId, TaskId};
+use crate::domain::repositories::{TimeEntryQuery, TimeEntryRepository};
 use crate::infrastructure::database::DatabaseConnection;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use rusqlite::{params, Connection};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -40,6 +41,21 @@ impl DatabaseOperationLogger for Connection {
     }
 }
 
+/// タグ所属条件（`tev`の開始時点で`?1`のタグが付与されていたか）
+///
+/// 現在のタグ集合ではなく、エントリ開始時刻以前の最新のタグイベントで判定する
+const TAG_MEMBERSHIP_AT_START: &str = r#"
+    (
+        SELECT tte.event_type
+        FROM task_tag_events tte
+        WHERE tte.task_id = tev.task_id
+          AND tte.tag_id = ?1
+          AND tte.at <= tev.start_time
+        ORDER BY tte.at DESC, tte.id DESC
+        LIMIT 1
+    ) = 'add'
+"#;
+
 /// SQLiteタイムエントリリポジトリ実装
 #[derive(Clone)]
 pub struct SqliteTimeEntryRepository {
@@ -58,6 +74,54 @@ impl SqliteTimeEntryRepository {
     fn parse_datetime(s: &str) -> anyhow::Result<DateTime<Utc>> {
         Ok(DateTime::parse_from_rfc3339(s)?.with_timezone(&Utc))
     }
+
+    /// 一時停止のある時間区間について、一時停止していた区間を開始イベントIDごとに取得する（1回のクエリで取得）
+    pub(crate) fn find_pause_intervals<'a>(
+        conn: &Connection,
+        entries: impl IntoIterator<Item = &'a TimeEntry>,
+    ) -> anyhow::Result<HashMap<i64, Vec<PauseInterval>>> {
+        let start_event_ids: Vec<i64> = entries
+            .into_iter()
+            .filter(|entry| entry.paused_seconds() > 0)
+            .map(|entry| entry.start_event_id())
+            .collect();
+        let mut intervals: HashMap<i64, Vec<_>> = HashMap::new();
+        if start_event_ids.is_empty() {
+            return Ok(intervals);
+        }
+
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT start_event_id, pause_from, pause_to
+            FROM time_entry_pauses_view
+            WHERE start_event_id IN (SELECT value FROM json_each(?1))
+            ORDER BY start_event_id, pause_from
+            "#,
+        )?;
+        let rows = stmt.query_map(params![serde_json::to_string(&start_event_ids)?], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+        })?;
+        for row in rows {
+            let (start_event_id, pause_from, pause_to) = row?;
+            intervals
+                .entry(start_event_id)
+                .or_default()
+                .push((Self::parse_datetime(&pause_from)?, Self::parse_datetime(&pause_to)?));
+        }
+        Ok(intervals)
+    }
+
+    /// 時間区間に一時停止していた区間を設定する
+    fn attach_pause_intervals(conn: &Connection, entries: Vec<TimeEntry>) -> anyhow::Result<Vec<TimeEntry>> {
+        let mut intervals = Self::find_pause_intervals(conn, &entries)?;
+        Ok(entries
+            .into_iter()
+            .map(|entry| {
+                let pause_intervals = intervals.remove(&entry.start_event_id()).unwrap_or_default();
+                entry.with_pause_intervals(pause_intervals)
+            })
+            .collect())
+    }
 }
 
 #[async_trait]
@@ -118,13 +182,85 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
         Ok(result)
     }
 
+    async fn find_all_events(&self) -> anyhow::Result<Vec<TimeEntryEvent>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, task_id, event_type, at, start_event_id, payload
+            FROM time_entry_events
+            ORDER BY at, id
+            "#,
+        )?;
+
+        let event_iter = stmt.query_map([], |row| {
+            let id: i64 = row.get(0)?;
+            let task_id: i64 = row.get(1)?;
+            let event_type: String = row.get(2)?;
+            let at_str: String = row.get(3)?;
+            let start_event_id: Option<i64> = row.get(4)?;
+            let payload: Option<String> = row.get(5)?;
+
+            Ok((id, task_id, event_type, at_str, start_event_id, payload))
+        })?;
+
+        let mut events = Vec::new();
+        for event_result in event_iter {
+            let (id, task_id, event_type, at_str, start_event_id, payload) = event_result?;
+            events.push(TimeEntryEvent::from_stored(
+                id,
+                TaskId::new(task_id)?,
+                TimeEntryEventType::from_str(&event_type)?,
+                Self::parse_datetime(&at_str)?,
+                start_event_id,
+                payload,
+            ));
+        }
+
+        Ok(events)
+    }
+
+    async fn save_heartbeat(&self, start_event_id: i64, at: DateTime<Utc>) -> anyhow::Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            r#"
+            INSERT INTO timer_heartbeats (start_event_id, at)
+            VALUES (?1, ?2)
+            ON CONFLICT(start_event_id) DO UPDATE SET at = excluded.at
+            "#,
+            params![start_event_id, Self::format_datetime(at)],
+        )?;
+
+        Ok(())
+    }
+
+    async fn find_heartbeat(&self, start_event_id: i64) -> anyhow::Result<Option<DateTime<Utc>>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let result = conn.query_row(
+            "SELECT at FROM timer_heartbeats WHERE start_event_id = ?1",
+            params![start_event_id],
+            |row| row.get::<_, String>(0),
+        );
+
+        match result {
+            Ok(at_str) => Ok(Some(Self::parse_datetime(&at_str)?)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     async fn find_running_entries(&self) -> anyhow::Result<Vec<TimeEntry>> {
         let db = self.db.lock().await;
         let conn = db.connection();
 
         let mut stmt = conn.prepare(
             r#"
-            SELECT task_id, start_event_id, start_time, end_time, duration_in_seconds
+            SELECT task_id, start_event_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
             FROM time_entries_view
             WHERE end_time IS NULL OR duration_in_seconds IS NULL
             ORDER BY start_time DESC
@@ -137,24 +273,33 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
             let start_time_str: String = row.get(2)?;
             let end_time_str: Option<String> = row.get(3)?;
             let _duration: Option<i64> = row.get(4)?;
+            let notes: Option<String> = row.get(5)?;
+            let paused_seconds: i64 = row.get(6)?;
+            let paused_at_str: Option<String> = row.get(7)?;
 
-            Ok((task_id, start_event_id, start_time_str, end_time_str))
+            Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
         })?;
 
         let mut entries = Vec::new();
         for entry_result in entry_iter {
-            let (task_id, start_event_id, start_time_str, end_time_str) = entry_result?;
+            let (task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
             let task_id = TaskId::new(task_id)?;
             let start_time = Self::parse_datetime(&start_time_str)?;
             let end_time = end_time_str
                 .map(|s| Self::parse_datetime(&s))
                 .transpose()?;
 
-            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time);
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
             entries.push(entry);
         }
 
-        Ok(entries)
+        Self::attach_pause_intervals(conn, entries)
     }
 
     async fn find_running_entry_by_task(&self, task_id: TaskId) -> anyhow::Result<Option<TimeEntry>> {
@@ -163,7 +308,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
 
         let result = conn.query_row(
             r#"
-            SELECT start_event_id, start_time, end_time, duration_in_seconds
+            SELECT start_event_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
             FROM time_entries_view
             WHERE task_id = ?1 AND (end_time IS NULL OR duration_in_seconds IS NULL)
             ORDER BY start_time DESC
@@ -175,20 +320,29 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
                 let start_time_str: String = row.get(1)?;
                 let end_time_str: Option<String> = row.get(2)?;
                 let _duration: Option<i64> = row.get(3)?;
+                let notes: Option<String> = row.get(4)?;
+                let paused_seconds: i64 = row.get(5)?;
+                let paused_at_str: Option<String> = row.get(6)?;
 
-                Ok((start_event_id, start_time_str, end_time_str))
+                Ok((start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
             },
         );
 
         match result {
-            Ok((start_event_id, start_time_str, end_time_str)) => {
+            Ok((start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str)) => {
                 let start_time = Self::parse_datetime(&start_time_str)?;
                 let end_time = end_time_str
                     .map(|s| Self::parse_datetime(&s))
                     .transpose()?;
 
-                let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time);
-                Ok(Some(entry))
+                let paused_at = paused_at_str
+                    .map(|s| Self::parse_datetime(&s))
+                    .transpose()?;
+
+                let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                    .with_notes(notes)
+                    .with_pause(paused_seconds, paused_at);
+                Ok(Self::attach_pause_intervals(conn, vec![entry])?.pop())
             }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
             Err(e) => Err(e.into()),
@@ -201,7 +355,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
 
         let mut stmt = conn.prepare(
             r#"
-            SELECT start_event_id, start_time, end_time, duration_in_seconds
+            SELECT start_event_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
             FROM time_entries_view
             WHERE task_id = ?1
             ORDER BY start_time DESC
@@ -213,23 +367,32 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
             let start_time_str: String = row.get(1)?;
             let end_time_str: Option<String> = row.get(2)?;
             let _duration: Option<i64> = row.get(3)?;
+            let notes: Option<String> = row.get(4)?;
+            let paused_seconds: i64 = row.get(5)?;
+            let paused_at_str: Option<String> = row.get(6)?;
 
-            Ok((start_event_id, start_time_str, end_time_str))
+            Ok((start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
         })?;
 
         let mut entries = Vec::new();
         for entry_result in entry_iter {
-            let (start_event_id, start_time_str, end_time_str) = entry_result?;
+            let (start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
             let start_time = Self::parse_datetime(&start_time_str)?;
             let end_time = end_time_str
                 .map(|s| Self::parse_datetime(&s))
                 .transpose()?;
 
-            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time);
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
             entries.push(entry);
         }
 
-        Ok(entries)
+        Self::attach_pause_intervals(conn, entries)
     }
 
     async fn find_entries_by_task_and_period(
@@ -243,7 +406,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
 
         let mut stmt = conn.prepare(
             r#"
-            SELECT start_event_id, start_time, end_time, duration_in_seconds
+            SELECT start_event_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
             FROM time_entries_view
             WHERE task_id = ?1 AND start_time >= ?2 AND start_time <= ?3
             ORDER BY start_time DESC
@@ -261,24 +424,33 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
                 let start_time_str: String = row.get(1)?;
                 let end_time_str: Option<String> = row.get(2)?;
                 let _duration: Option<i64> = row.get(3)?;
+                let notes: Option<String> = row.get(4)?;
+                let paused_seconds: i64 = row.get(5)?;
+                let paused_at_str: Option<String> = row.get(6)?;
 
-                Ok((start_event_id, start_time_str, end_time_str))
+                Ok((start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
             },
         )?;
 
         let mut entries = Vec::new();
         for entry_result in entry_iter {
-            let (start_event_id, start_time_str, end_time_str) = entry_result?;
+            let (start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
             let start_time = Self::parse_datetime(&start_time_str)?;
             let end_time = end_time_str
                 .map(|s| Self::parse_datetime(&s))
                 .transpose()?;
 
-            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time);
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
             entries.push(entry);
         }
 
-        Ok(entries)
+        Self::attach_pause_intervals(conn, entries)
     }
 
     async fn find_overlapping_entries(
@@ -292,7 +464,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
 
         let mut stmt = conn.prepare(
             r#"
-            SELECT start_event_id, start_time, end_time, duration_in_seconds
+            SELECT start_event_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
             FROM time_entries_view
             WHERE task_id = ?1 
               AND start_time < ?3 
@@ -312,24 +484,33 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
                 let start_time_str: String = row.get(1)?;
                 let end_time_str: Option<String> = row.get(2)?;
                 let _duration: Option<i64> = row.get(3)?;
+                let notes: Option<String> = row.get(4)?;
+                let paused_seconds: i64 = row.get(5)?;
+                let paused_at_str: Option<String> = row.get(6)?;
 
-                Ok((start_event_id, start_time_str, end_time_str))
+                Ok((start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
             },
         )?;
 
         let mut entries = Vec::new();
         for entry_result in entry_iter {
-            let (start_event_id, start_time_str, end_time_str) = entry_result?;
+            let (start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
             let start_time = Self::parse_datetime(&start_time_str)?;
             let end_time = end_time_str
                 .map(|s| Self::parse_datetime(&s))
                 .transpose()?;
 
-            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time);
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
             entries.push(entry);
         }
 
-        Ok(entries)
+        Self::attach_pause_intervals(conn, entries)
     }
 
     async fn find_entries_by_project(&self, project_id: i64) -> anyhow::Result<Vec<TimeEntry>> {
@@ -338,7 +519,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
 
         let mut stmt = conn.prepare(
             r#"
-            SELECT tev.task_id, tev.start_event_id, tev.start_time, tev.end_time, tev.duration_in_seconds
+            SELECT tev.task_id, tev.start_event_id, tev.start_time, tev.end_time, tev.duration_in_seconds, tev.notes, tev.paused_seconds, tev.paused_at
             FROM time_entries_view tev
             JOIN task_current_view tcv ON tev.task_id = tcv.task_id
             WHERE tcv.project_id = ?1
@@ -352,24 +533,33 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
             let start_time_str: String = row.get(2)?;
             let end_time_str: Option<String> = row.get(3)?;
             let _duration: Option<i64> = row.get(4)?;
+            let notes: Option<String> = row.get(5)?;
+            let paused_seconds: i64 = row.get(6)?;
+            let paused_at_str: Option<String> = row.get(7)?;
 
-            Ok((task_id, start_event_id, start_time_str, end_time_str))
+            Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
         })?;
 
         let mut entries = Vec::new();
         for entry_result in entry_iter {
-            let (task_id, start_event_id, start_time_str, end_time_str) = entry_result?;
+            let (task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
             let task_id = TaskId::new(task_id)?;
             let start_time = Self::parse_datetime(&start_time_str)?;
             let end_time = end_time_str
                 .map(|s| Self::parse_datetime(&s))
                 .transpose()?;
 
-            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time);
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
             entries.push(entry);
         }
 
-        Ok(entries)
+        Self::attach_pause_intervals(conn, entries)
     }
 
     async fn find_entries_by_period(
@@ -382,7 +572,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
 
         let mut stmt = conn.prepare(
             r#"
-            SELECT task_id, start_event_id, start_time, end_time, duration_in_seconds
+            SELECT task_id, start_event_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
             FROM time_entries_view
             WHERE start_time >= ?1 AND start_time <= ?2
             ORDER BY start_time DESC
@@ -400,25 +590,158 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
                 let start_time_str: String = row.get(2)?;
                 let end_time_str: Option<String> = row.get(3)?;
                 let _duration: Option<i64> = row.get(4)?;
+                let notes: Option<String> = row.get(5)?;
+                let paused_seconds: i64 = row.get(6)?;
+                let paused_at_str: Option<String> = row.get(7)?;
 
-                Ok((task_id, start_event_id, start_time_str, end_time_str))
+                Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
             },
         )?;
 
         let mut entries = Vec::new();
         for entry_result in entry_iter {
-            let (task_id, start_event_id, start_time_str, end_time_str) = entry_result?;
+            let (task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
             let task_id = TaskId::new(task_id)?;
             let start_time = Self::parse_datetime(&start_time_str)?;
             let end_time = end_time_str
                 .map(|s| Self::parse_datetime(&s))
                 .transpose()?;
 
-            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time);
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
             entries.push(entry);
         }
 
-        Ok(entries)
+        Self::attach_pause_intervals(conn, entries)
+    }
+
+    async fn find_entries_overlapping_period(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimeEntry>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT task_id, start_event_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
+            FROM time_entries_view
+            WHERE start_time < ?2 AND (end_time IS NULL OR end_time > ?1)
+            ORDER BY start_time
+            "#,
+        )?;
+
+        let entry_iter = stmt.query_map(
+            params![
+                Self::format_datetime(start),
+                Self::format_datetime(end)
+            ],
+            |row| {
+                let task_id: i64 = row.get(0)?;
+                let start_event_id: i64 = row.get(1)?;
+                let start_time_str: String = row.get(2)?;
+                let end_time_str: Option<String> = row.get(3)?;
+                let _duration: Option<i64> = row.get(4)?;
+                let notes: Option<String> = row.get(5)?;
+                let paused_seconds: i64 = row.get(6)?;
+                let paused_at_str: Option<String> = row.get(7)?;
+
+                Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
+            },
+        )?;
+
+        let mut entries = Vec::new();
+        for entry_result in entry_iter {
+            let (task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
+            let task_id = TaskId::new(task_id)?;
+            let start_time = Self::parse_datetime(&start_time_str)?;
+            let end_time = end_time_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
+            entries.push(entry);
+        }
+
+        Self::attach_pause_intervals(conn, entries)
+    }
+
+    async fn find_entries_by_query(&self, query: &TimeEntryQuery) -> anyhow::Result<Vec<TimeEntry>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT tev.task_id, tev.start_event_id, tev.start_time, tev.end_time, tev.duration_in_seconds, tev.notes, tev.paused_seconds, tev.paused_at
+            FROM time_entries_view tev
+            LEFT JOIN task_current_view tcv ON tev.task_id = tcv.task_id
+            WHERE tev.start_time >= ?1 AND tev.start_time < ?2
+              AND (?3 IS NULL OR tcv.project_id = ?3)
+              AND (?4 IS NULL OR tev.task_id = ?4)
+              AND (?5 IS NULL OR tev.start_time < ?5 OR (tev.start_time = ?5 AND tev.start_event_id < ?6))
+            ORDER BY tev.start_time DESC, tev.start_event_id DESC
+            LIMIT ?7
+            "#,
+        )?;
+
+        // SQLiteではLIMITに負数を指定すると無制限になる
+        let limit = query.limit.map_or(-1, |limit| limit as i64);
+        let entry_iter = stmt.query_map(
+            params![
+                Self::format_datetime(query.start),
+                Self::format_datetime(query.end),
+                query.project_id,
+                query.task_id.map(i64::from),
+                query.after.map(|cursor| Self::format_datetime(cursor.start_time)),
+                query.after.map(|cursor| cursor.start_event_id),
+                limit,
+            ],
+            |row| {
+                let task_id: i64 = row.get(0)?;
+                let start_event_id: i64 = row.get(1)?;
+                let start_time_str: String = row.get(2)?;
+                let end_time_str: Option<String> = row.get(3)?;
+                let _duration: Option<i64> = row.get(4)?;
+                let notes: Option<String> = row.get(5)?;
+                let paused_seconds: i64 = row.get(6)?;
+                let paused_at_str: Option<String> = row.get(7)?;
+
+                Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
+            },
+        )?;
+
+        let mut entries = Vec::new();
+        for entry_result in entry_iter {
+            let (task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
+            let task_id = TaskId::new(task_id)?;
+            let start_time = Self::parse_datetime(&start_time_str)?;
+            let end_time = end_time_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
+            entries.push(entry);
+        }
+
+        Self::attach_pause_intervals(conn, entries)
     }
 
     async fn count_entries_by_task(&self, task_id: TaskId) -> anyhow::Result<usize> {
@@ -465,13 +788,144 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
         Ok(total.unwrap_or(0))
     }
 
+    async fn find_entries_by_tag(&self, tag_id: TagId) -> anyhow::Result<Vec<TimeEntry>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let sql = format!(
+            r#"
+            SELECT tev.task_id, tev.start_event_id, tev.start_time, tev.end_time, tev.duration_in_seconds, tev.notes, tev.paused_seconds, tev.paused_at
+            FROM time_entries_view tev
+            WHERE {}
+            ORDER BY tev.start_time DESC
+            "#,
+            TAG_MEMBERSHIP_AT_START
+        );
+        let mut stmt = conn.prepare(&sql)?;
+
+        let entry_iter = stmt.query_map(params![i64::from(tag_id)], |row| {
+            let task_id: i64 = row.get(0)?;
+            let start_event_id: i64 = row.get(1)?;
+            let start_time_str: String = row.get(2)?;
+            let end_time_str: Option<String> = row.get(3)?;
+            let _duration: Option<i64> = row.get(4)?;
+            let notes: Option<String> = row.get(5)?;
+            let paused_seconds: i64 = row.get(6)?;
+            let paused_at_str: Option<String> = row.get(7)?;
+
+            Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
+        })?;
+
+        let mut entries = Vec::new();
+        for entry_result in entry_iter {
+            let (task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
+            let task_id = TaskId::new(task_id)?;
+            let start_time = Self::parse_datetime(&start_time_str)?;
+            let end_time = end_time_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
+            entries.push(entry);
+        }
+
+        Self::attach_pause_intervals(conn, entries)
+    }
+
+    async fn find_entries_by_period_and_tag(
+        &self,
+        tag_id: TagId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimeEntry>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let sql = format!(
+            r#"
+            SELECT tev.task_id, tev.start_event_id, tev.start_time, tev.end_time, tev.duration_in_seconds, tev.notes, tev.paused_seconds, tev.paused_at
+            FROM time_entries_view tev
+            WHERE tev.start_time >= ?2 AND tev.start_time <= ?3
+              AND {}
+            ORDER BY tev.start_time DESC
+            "#,
+            TAG_MEMBERSHIP_AT_START
+        );
+        let mut stmt = conn.prepare(&sql)?;
+
+        let entry_iter = stmt.query_map(
+            params![
+                i64::from(tag_id),
+                Self::format_datetime(start),
+                Self::format_datetime(end)
+            ],
+            |row| {
+                let task_id: i64 = row.get(0)?;
+                let start_event_id: i64 = row.get(1)?;
+                let start_time_str: String = row.get(2)?;
+                let end_time_str: Option<String> = row.get(3)?;
+                let _duration: Option<i64> = row.get(4)?;
+                let notes: Option<String> = row.get(5)?;
+                let paused_seconds: i64 = row.get(6)?;
+                let paused_at_str: Option<String> = row.get(7)?;
+
+                Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
+            },
+        )?;
+
+        let mut entries = Vec::new();
+        for entry_result in entry_iter {
+            let (task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
+            let task_id = TaskId::new(task_id)?;
+            let start_time = Self::parse_datetime(&start_time_str)?;
+            let end_time = end_time_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
+            entries.push(entry);
+        }
+
+        Self::attach_pause_intervals(conn, entries)
+    }
+
+    async fn sum_duration_by_tag(&self, tag_id: TagId) -> anyhow::Result<i64> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let sql = format!(
+            r#"
+            SELECT SUM(tev.duration_in_seconds)
+            FROM time_entries_view tev
+            WHERE tev.duration_in_seconds IS NOT NULL
+              AND {}
+            "#,
+            TAG_MEMBERSHIP_AT_START
+        );
+        let total: Option<i64> = conn.query_row(&sql, params![i64::from(tag_id)], |row| row.get(0))?;
+
+        Ok(total.unwrap_or(0))
+    }
+
     async fn find_entry_by_start_event_id(&self, start_event_id: i64) -> anyhow::Result<Option<TimeEntry>> {
         let db = self.db.lock().await;
         let conn = db.connection();
 
         let result = conn.query_row(
             r#"
-            SELECT task_id, start_time, end_time, duration_in_seconds
+            SELECT task_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
             FROM time_entries_view
             WHERE start_event_id = ?1
             "#,
@@ -481,21 +935,76 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
                 let start_time_str: String = row.get(1)?;
                 let end_time_str: Option<String> = row.get(2)?;
                 let _duration: Option<i64> = row.get(3)?;
+                let notes: Option<String> = row.get(4)?;
+                let paused_seconds: i64 = row.get(5)?;
+                let paused_at_str: Option<String> = row.get(6)?;
+
+                Ok((task_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
+            },
+        );
+
+        match result {
+            Ok((task_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str)) => {
+                let task_id = TaskId::new(task_id)?;
+                let start_time = Self::parse_datetime(&start_time_str)?;
+                let end_time = end_time_str
+                    .map(|s| Self::parse_datetime(&s))
+                    .transpose()?;
+
+                let paused_at = paused_at_str
+                    .map(|s| Self::parse_datetime(&s))
+                    .transpose()?;
+
+                let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                    .with_notes(notes)
+                    .with_pause(paused_seconds, paused_at);
+                Ok(Self::attach_pause_intervals(conn, vec![entry])?.pop())
+            }
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn find_voided_entry_by_start_event_id(&self, start_event_id: i64) -> anyhow::Result<Option<TimeEntry>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let result = conn.query_row(
+            r#"
+            SELECT task_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
+            FROM time_entries_all_view
+            WHERE start_event_id = ?1 AND is_voided = 1
+            "#,
+            params![start_event_id],
+            |row| {
+                let task_id: i64 = row.get(0)?;
+                let start_time_str: String = row.get(1)?;
+                let end_time_str: Option<String> = row.get(2)?;
+                let _duration: Option<i64> = row.get(3)?;
+                let notes: Option<String> = row.get(4)?;
+                let paused_seconds: i64 = row.get(5)?;
+                let paused_at_str: Option<String> = row.get(6)?;
 
-                Ok((task_id, start_time_str, end_time_str))
+                Ok((task_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
             },
         );
 
         match result {
-            Ok((task_id, start_time_str, end_time_str)) => {
+            Ok((task_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str)) => {
                 let task_id = TaskId::new(task_id)?;
                 let start_time = Self::parse_datetime(&start_time_str)?;
                 let end_time = end_time_str
                     .map(|s| Self::parse_datetime(&s))
                     .transpose()?;
 
-                let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time);
-                Ok(Some(entry))
+                let paused_at = paused_at_str
+                    .map(|s| Self::parse_datetime(&s))
+                    .transpose()?;
+
+                let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                    .with_notes(notes)
+                    .with_pause(paused_seconds, paused_at);
+                Ok(Self::attach_pause_intervals(conn, vec![entry])?.pop())
             }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
             Err(e) => Err(e.into()),
@@ -514,7 +1023,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
         tracing::debug!("SqliteTimeEntryRepository::find_recent_entries: Preparing SQL statement");
         let mut stmt = match conn.prepare(
             r#"
-            SELECT task_id, start_event_id, start_time, end_time, duration_in_seconds
+            SELECT task_id, start_event_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
             FROM time_entries_view
             ORDER BY start_time DESC
             LIMIT ?1
@@ -526,7 +1035,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
             },
             Err(e) => {
                 tracing::error!("SqliteTimeEntryRepository::find_recent_entries: Failed to prepare SQL statement: {}", e);
-                tracing::error!("SqliteTimeEntryRepository::find_recent_entries: SQL: SELECT task_id, start_event_id, start_time, end_time, duration_in_seconds FROM time_entries_view ORDER BY start_time DESC LIMIT ?1");
+                tracing::error!("SqliteTimeEntryRepository::find_recent_entries: SQL: SELECT task_id, start_event_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at FROM time_entries_view ORDER BY start_time DESC LIMIT ?1");
                 return Err(e.into());
             }
         };
@@ -538,8 +1047,11 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
             let start_time_str: String = row.get(2)?;
             let end_time_str: Option<String> = row.get(3)?;
             let _duration: Option<i64> = row.get(4)?;
+            let notes: Option<String> = row.get(5)?;
+            let paused_seconds: i64 = row.get(6)?;
+            let paused_at_str: Option<String> = row.get(7)?;
 
-            Ok((task_id, start_event_id, start_time_str, end_time_str))
+            Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
         }) {
             Ok(iter) => {
                 tracing::debug!("SqliteTimeEntryRepository::find_recent_entries: Query executed successfully");
@@ -562,7 +1074,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
             tracing::debug!("SqliteTimeEntryRepository::find_recent_entries: Processing row {}", row_count);
             
             match entry_result {
-                Ok((task_id, start_event_id, start_time_str, end_time_str)) => {
+                Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str)) => {
                     tracing::debug!("SqliteTimeEntryRepository::find_recent_entries: Row {} raw data - task_id: {}, start_event_id: {}, start_time: {:?}, end_time: {:?}", 
                         row_count, task_id, start_event_id, start_time_str, end_time_str);
                     
@@ -595,7 +1107,13 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
                                     
                                     match end_time {
                                         Ok(end_time) => {
-                                            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time);
+                                            let paused_at = paused_at_str
+                                                .map(|s| Self::parse_datetime(&s))
+                                                .transpose()?;
+
+                                            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                                                .with_notes(notes)
+                                                .with_pause(paused_seconds, paused_at);
                                             entries.push(entry);
                                             tracing::debug!("SqliteTimeEntryRepository::find_recent_entries: Row {} - TimeEntry created successfully", row_count);
                                         },
@@ -627,7 +1145,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
         tracing::info!("SqliteTimeEntryRepository::find_recent_entries: Processing completed - rows processed: {}, entries created: {}", 
             row_count, entries.len());
         tracing::debug!("SqliteTimeEntryRepository::find_recent_entries: Successfully returning {} entries", entries.len());
-        Ok(entries)
+        Self::attach_pause_intervals(conn, entries)
     }
 
     async fn find_recent_entries_by_task(&self, task_id: TaskId, limit: usize) -> anyhow::Result<Vec<TimeEntry>> {
@@ -636,7 +1154,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
 
         let mut stmt = conn.prepare(
             r#"
-            SELECT start_event_id, start_time, end_time, duration_in_seconds
+            SELECT start_event_id, start_time, end_time, duration_in_seconds, notes, paused_seconds, paused_at
             FROM time_entries_view
             WHERE task_id = ?1
             ORDER BY start_time DESC
@@ -649,22 +1167,456 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
             let start_time_str: String = row.get(1)?;
             let end_time_str: Option<String> = row.get(2)?;
             let _duration: Option<i64> = row.get(3)?;
+            let notes: Option<String> = row.get(4)?;
+            let paused_seconds: i64 = row.get(5)?;
+            let paused_at_str: Option<String> = row.get(6)?;
 
-            Ok((start_event_id, start_time_str, end_time_str))
+            Ok((start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
         })?;
 
         let mut entries = Vec::new();
         for entry_result in entry_iter {
-            let (start_event_id, start_time_str, end_time_str) = entry_result?;
+            let (start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str) = entry_result?;
             let start_time = Self::parse_datetime(&start_time_str)?;
             let end_time = end_time_str
                 .map(|s| Self::parse_datetime(&s))
                 .transpose()?;
 
-            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time);
+            let paused_at = paused_at_str
+                .map(|s| Self::parse_datetime(&s))
+                .transpose()?;
+
+            let entry = TimeEntry::new(task_id, start_event_id, start_time, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
             entries.push(entry);
         }
 
-        Ok(entries)
+        Self::attach_pause_intervals(conn, entries)
+    }
+
+    async fn find_latest_stopped_entry(&self) -> anyhow::Result<Option<TimeEntry>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let result = conn.query_row(
+            r#"
+            SELECT task_id, start_event_id, start_time, end_time, notes, paused_seconds, paused_at
+            FROM time_entries_view
+            WHERE end_time IS NOT NULL AND duration_in_seconds IS NOT NULL
+            ORDER BY end_time DESC
+            LIMIT 1
+            "#,
+            [],
+            |row| {
+                let task_id: i64 = row.get(0)?;
+                let start_event_id: i64 = row.get(1)?;
+                let start_time_str: String = row.get(2)?;
+                let end_time_str: String = row.get(3)?;
+                let notes: Option<String> = row.get(4)?;
+                let paused_seconds: i64 = row.get(5)?;
+                let paused_at_str: Option<String> = row.get(6)?;
+
+                Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str))
+            },
+        );
+
+        match result {
+            Ok((task_id, start_event_id, start_time_str, end_time_str, notes, paused_seconds, paused_at_str)) => {
+                let start_time = Self::parse_datetime(&start_time_str)?;
+                let end_time = Self::parse_datetime(&end_time_str)?;
+                let paused_at = paused_at_str
+                    .map(|s| Self::parse_datetime(&s))
+                    .transpose()?;
+
+                let entry = TimeEntry::new(TaskId::new(task_id)?, start_event_id, start_time, Some(end_time))
+                    .with_notes(notes)
+                    .with_pause(paused_seconds, paused_at);
+                Ok(Self::attach_pause_intervals(conn, vec![entry])?.pop())
+            }
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::TimeEntryCursor;
+    use chrono::TimeZone;
+
+    async fn setup_repository() -> SqliteTimeEntryRepository {
+        let db = DatabaseConnection::new_in_memory().unwrap();
+        db.run_migrations().unwrap();
+        SqliteTimeEntryRepository::new(Arc::new(Mutex::new(db)))
+    }
+
+    async fn insert_tag_event(repository: &SqliteTimeEntryRepository, task_id: i64, tag_id: i64, event_type: &str, at: &str) {
+        let db = repository.db.lock().await;
+        db.connection()
+            .execute(
+                "INSERT INTO task_tag_events (task_id, tag_id, event_type, at) VALUES (?1, ?2, ?3, ?4)",
+                params![task_id, tag_id, event_type, at],
+            )
+            .unwrap();
+    }
+
+    async fn add_entry(repository: &SqliteTimeEntryRepository, task_id: TaskId, start: DateTime<Utc>, end: DateTime<Utc>) {
+        let start_event = repository
+            .save_event(&TimeEntryEvent::start_at(task_id, start))
+            .await
+            .unwrap();
+        repository
+            .save_event(&TimeEntryEvent::stop_at(task_id, start_event.id().unwrap(), end))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn タグ別の合計作業時間が取得できること() {
+        let repository = setup_repository().await;
+
+        // サンプルデータ: タスク1にタグ1、タスク2にタグ2が付与されている
+        let task1_total = repository.sum_duration_by_task(TaskId::new(1).unwrap()).await.unwrap();
+        let task2_total = repository.sum_duration_by_task(TaskId::new(2).unwrap()).await.unwrap();
+        assert!(task1_total > 0);
+        assert_eq!(repository.sum_duration_by_tag(TagId::new(1).unwrap()).await.unwrap(), task1_total);
+        assert_eq!(repository.sum_duration_by_tag(TagId::new(2).unwrap()).await.unwrap(), task2_total);
+        assert_eq!(repository.sum_duration_by_tag(TagId::new(3).unwrap()).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn エントリ開始時点のタグ付与状態で集計されること() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(3).unwrap();
+        let tag_id = TagId::new(3).unwrap();
+
+        // タグ付与前のエントリ
+        add_entry(
+            &repository,
+            task_id,
+            Utc.with_ymd_and_hms(2024, 2, 1, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 2, 1, 10, 0, 0).unwrap(),
+        ).await;
+        insert_tag_event(&repository, 3, 3, "add", "2024-02-02T00:00:00Z").await;
+        // タグ付与中のエントリ
+        add_entry(
+            &repository,
+            task_id,
+            Utc.with_ymd_and_hms(2024, 2, 3, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 2, 3, 9, 30, 0).unwrap(),
+        ).await;
+        insert_tag_event(&repository, 3, 3, "remove", "2024-02-04T00:00:00Z").await;
+        // タグ剥奪後のエントリ
+        add_entry(
+            &repository,
+            task_id,
+            Utc.with_ymd_and_hms(2024, 2, 5, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 2, 5, 11, 0, 0).unwrap(),
+        ).await;
+
+        let entries = repository.find_entries_by_tag(tag_id).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].start_time(), Utc.with_ymd_and_hms(2024, 2, 3, 9, 0, 0).unwrap());
+        assert_eq!(
+            repository.sum_duration_by_tag(tag_id).await.unwrap(),
+            entries[0].duration_in_seconds().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn 期間とタグで時間区間が取得できること() {
+        let repository = setup_repository().await;
+        let tag_id = TagId::new(1).unwrap();
+
+        let entries = repository
+            .find_entries_by_period_and_tag(
+                tag_id,
+                Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 23, 59, 59).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(i64::from(entries[0].task_id()), 1);
+
+        let entries = repository
+            .find_entries_by_period_and_tag(
+                tag_id,
+                Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 2, 23, 59, 59).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(entries.is_empty());
+    }
+    #[tokio::test]
+    async fn 期間に重複する時間区間が取得できること() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(1).unwrap();
+        let at = |day, hour| Utc.with_ymd_and_hms(2025, 3, day, hour, 0, 0).unwrap();
+        add_entry(&repository, task_id, at(9, 22), at(10, 2)).await;
+        add_entry(&repository, task_id, at(10, 9), at(10, 10)).await;
+        add_entry(&repository, task_id, at(11, 0), at(11, 1)).await;
+        repository.save_event(&TimeEntryEvent::start_at(TaskId::new(2).unwrap(), at(10, 23))).await.unwrap();
+
+        // 前日から続く区間と実行中の区間も含み、期間の終了時刻に開始した区間は含まない
+        let entries = repository.find_entries_overlapping_period(at(10, 0), at(11, 0)).await.unwrap();
+        let starts: Vec<_> = entries.iter().map(|e| e.start_time()).collect();
+        assert_eq!(starts, vec![at(9, 22), at(10, 9), at(10, 23)]);
+    }
+
+    #[tokio::test]
+    async fn 検索条件で時間区間がページングできること() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(3).unwrap();
+        for day in 1..=5 {
+            add_entry(
+                &repository,
+                task_id,
+                Utc.with_ymd_and_hms(2024, 3, day, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 3, day, 10, 0, 0).unwrap(),
+            ).await;
+        }
+
+        let mut query = TimeEntryQuery::new(
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap(),
+        );
+        query.task_id = Some(task_id);
+        query.limit = Some(3);
+
+        let first_page = repository.find_entries_by_query(&query).await.unwrap();
+        assert_eq!(first_page.len(), 3);
+        assert_eq!(first_page[0].start_time(), Utc.with_ymd_and_hms(2024, 3, 4, 9, 0, 0).unwrap());
+
+        query.after = Some(TimeEntryCursor::from_entry(first_page.last().unwrap()));
+        let second_page = repository.find_entries_by_query(&query).await.unwrap();
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].start_time(), Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap());
+    }
+
+    #[tokio::test]
+    async fn プロジェクトで時間区間が絞り込めること() {
+        let repository = setup_repository().await;
+        let mut query = TimeEntryQuery::new(
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(),
+        );
+
+        // サンプルデータ: 2024-01-01にプロジェクト1のタスク1・タスク2の区間が存在する
+        assert_eq!(repository.find_entries_by_query(&query).await.unwrap().len(), 2);
+
+        query.project_id = Some(1);
+        assert_eq!(repository.find_entries_by_query(&query).await.unwrap().len(), 2);
+
+        query.project_id = Some(2);
+        assert!(repository.find_entries_by_query(&query).await.unwrap().is_empty());
+    }
+    #[tokio::test]
+    async fn 訂正イベントが時間区間に反映されること() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(3).unwrap();
+        add_entry(
+            &repository,
+            task_id,
+            Utc.with_ymd_and_hms(2024, 4, 1, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 4, 1, 10, 0, 0).unwrap(),
+        ).await;
+        let entry = repository.find_entries_by_task(task_id).await.unwrap().remove(0);
+
+        // 2回訂正した場合は最新の訂正が有効
+        for end_minute in [15, 45] {
+            let adjustment = crate::domain::entities::TimeEntryAdjustment {
+                start_time: Utc.with_ymd_and_hms(2024, 4, 1, 8, 30, 0).unwrap(),
+                end_time: Some(Utc.with_ymd_and_hms(2024, 4, 1, 10, end_minute, 0).unwrap()),
+            };
+            repository
+                .save_event(&TimeEntryEvent::adjust(task_id, entry.start_event_id(), adjustment))
+                .await
+                .unwrap();
+        }
+
+        let adjusted = repository
+            .find_entry_by_start_event_id(entry.start_event_id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(adjusted.start_time(), Utc.with_ymd_and_hms(2024, 4, 1, 8, 30, 0).unwrap());
+        assert_eq!(adjusted.end_time(), Some(Utc.with_ymd_and_hms(2024, 4, 1, 10, 45, 0).unwrap()));
+        assert_eq!(repository.sum_duration_by_task(task_id).await.unwrap(), 8100);
+        assert_eq!(repository.count_entries_by_task(task_id).await.unwrap(), 1);
+    }
+    #[tokio::test]
+    async fn 無効化された時間区間が集計から除外されること() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(3).unwrap();
+        add_entry(
+            &repository,
+            task_id,
+            Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 3).unwrap(),
+        ).await;
+        let entry = repository.find_entries_by_task(task_id).await.unwrap().remove(0);
+
+        repository
+            .save_event(&TimeEntryEvent::void(task_id, entry.start_event_id()))
+            .await
+            .unwrap();
+        assert!(repository.find_entries_by_task(task_id).await.unwrap().is_empty());
+        assert_eq!(repository.sum_duration_by_task(task_id).await.unwrap(), 0);
+        assert!(repository.find_entry_by_start_event_id(entry.start_event_id()).await.unwrap().is_none());
+        assert!(repository.find_voided_entry_by_start_event_id(entry.start_event_id()).await.unwrap().is_some());
+
+        // 無効化取り消しで元に戻る
+        repository
+            .save_event(&TimeEntryEvent::unvoid(task_id, entry.start_event_id()))
+            .await
+            .unwrap();
+        assert_eq!(repository.sum_duration_by_task(task_id).await.unwrap(), 3);
+        assert!(repository.find_voided_entry_by_start_event_id(entry.start_event_id()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn 注釈イベントがメモとして畳み込まれること() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(3).unwrap();
+        add_entry(
+            &repository,
+            task_id,
+            Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 6, 1, 10, 0, 0).unwrap(),
+        ).await;
+        let entry = repository.find_entries_by_task(task_id).await.unwrap().remove(0);
+        assert!(entry.notes().is_none());
+
+        for note in ["仕様確認", "レビュー対応"] {
+            repository
+                .save_event(&TimeEntryEvent::annotate(task_id, entry.start_event_id(), note.to_string()))
+                .await
+                .unwrap();
+        }
+
+        let annotated = repository
+            .find_entry_by_start_event_id(entry.start_event_id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(annotated.notes(), Some("仕様確認\nレビュー対応"));
+
+        let entries = repository.find_entries_by_project(2).await.unwrap();
+        assert_eq!(entries[0].notes(), Some("仕様確認\nレビュー対応"));
+    }
+
+    #[tokio::test]
+    async fn 暗黙STOPの補正が次の区間を閉じないこと() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(3).unwrap();
+        let first = repository
+            .save_event(&TimeEntryEvent::start_at(task_id, Utc.with_ymd_and_hms(2024, 7, 1, 9, 0, 0).unwrap()))
+            .await
+            .unwrap();
+        let second = repository
+            .save_event(&TimeEntryEvent::start_at(task_id, Utc.with_ymd_and_hms(2024, 7, 1, 10, 0, 0).unwrap()))
+            .await
+            .unwrap();
+
+        // 次の開始と同時刻に直前区間の停止を補正挿入
+        let repair = TimeEntryEvent::stop_at(task_id, first.id().unwrap(), second.at())
+            .with_repair_reason(crate::domain::entities::RepairReason::ImplicitStop);
+        repository.save_event(&repair).await.unwrap();
+
+        let first_entry = repository.find_entry_by_start_event_id(first.id().unwrap()).await.unwrap().unwrap();
+        assert_eq!(first_entry.end_time(), Some(second.at()));
+        let second_entry = repository.find_entry_by_start_event_id(second.id().unwrap()).await.unwrap().unwrap();
+        assert!(second_entry.is_running());
+
+        // 補正イベントも正準順序で取得できる
+        let events: Vec<TimeEntryEvent> = repository
+            .find_all_events()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|e| e.task_id() == task_id)
+            .collect();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[2].repair_reason(), Some(crate::domain::entities::RepairReason::ImplicitStop));
+    }
+
+    #[tokio::test]
+    async fn ハートビートが上書き保存されること() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(3).unwrap();
+        let start = repository
+            .save_event(&TimeEntryEvent::start_at(task_id, Utc.with_ymd_and_hms(2024, 7, 1, 9, 0, 0).unwrap()))
+            .await
+            .unwrap();
+        let start_event_id = start.id().unwrap();
+        assert!(repository.find_heartbeat(start_event_id).await.unwrap().is_none());
+
+        let first = Utc.with_ymd_and_hms(2024, 7, 1, 9, 1, 0).unwrap();
+        let second = Utc.with_ymd_and_hms(2024, 7, 1, 9, 2, 0).unwrap();
+        repository.save_heartbeat(start_event_id, first).await.unwrap();
+        repository.save_heartbeat(start_event_id, second).await.unwrap();
+
+        assert_eq!(repository.find_heartbeat(start_event_id).await.unwrap(), Some(second));
+    }
+
+    #[tokio::test]
+    async fn 一時停止時間がビューの作業時間から除外されること() {
+        let repository = setup_repository().await;
+        let task_id = TaskId::new(3).unwrap();
+        let at = |hour, minute| Utc.with_ymd_and_hms(2024, 7, 2, hour, minute, 0).unwrap();
+        let start = repository.save_event(&TimeEntryEvent::start_at(task_id, at(9, 0))).await.unwrap();
+        let start_event_id = start.id().unwrap();
+        repository.save_event(&TimeEntryEvent::pause_at(task_id, start_event_id, at(10, 0))).await.unwrap();
+
+        // 一時停止中の実行中区間
+        let entry = repository.find_running_entry_by_task(task_id).await.unwrap().unwrap();
+        assert_eq!(entry.paused_at(), Some(at(10, 0)));
+        assert_eq!(entry.paused_seconds(), 0);
+
+        repository.save_event(&TimeEntryEvent::resume_at(task_id, start_event_id, at(10, 15))).await.unwrap();
+        repository.save_event(&TimeEntryEvent::pause_at(task_id, start_event_id, at(11, 0))).await.unwrap();
+        // 一時停止中に停止した場合は停止時刻までを一時停止時間とする
+        repository.save_event(&TimeEntryEvent::stop_at(task_id, start_event_id, at(11, 30))).await.unwrap();
+
+        let entry = repository.find_entry_by_start_event_id(start_event_id).await.unwrap().unwrap();
+        assert!(!entry.is_paused());
+        assert_eq!(entry.paused_seconds(), 45 * 60);
+        assert_eq!(entry.duration_in_seconds(), Some(105 * 60));
+        assert_eq!(entry.pause_intervals(), &[(at(10, 0), at(10, 15)), (at(11, 0), at(11, 30))]);
+
+        let db = repository.db.lock().await;
+        let duration: i64 = db
+            .connection()
+            .query_row(
+                "SELECT duration_in_seconds FROM time_entries_view WHERE start_event_id = ?1",
+                params![start_event_id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(duration, 105 * 60);
+    }
+
+    #[tokio::test]
+    async fn 最後に停止した区間が取得できること() {
+        let repository = setup_repository().await;
+        let at = |hour, minute| Utc.with_ymd_and_hms(2024, 7, 3, hour, minute, 0).unwrap();
+        let task1 = TaskId::new(1).unwrap();
+        let task2 = TaskId::new(2).unwrap();
+        let first = repository.save_event(&TimeEntryEvent::start_at(task1, at(9, 0))).await.unwrap();
+        repository.save_event(&TimeEntryEvent::stop_at(task1, first.id().unwrap(), at(12, 0))).await.unwrap();
+        let second = repository.save_event(&TimeEntryEvent::start_at(task2, at(10, 0))).await.unwrap();
+        repository.save_event(&TimeEntryEvent::stop_at(task2, second.id().unwrap(), at(11, 0))).await.unwrap();
+        // 実行中の区間は対象外
+        repository.save_event(&TimeEntryEvent::start_at(task2, at(13, 0))).await.unwrap();
+
+        let latest = repository.find_latest_stopped_entry().await.unwrap().unwrap();
+        assert_eq!(latest.start_event_id(), first.id().unwrap());
+        assert_eq!(latest.task_id(), task1);
+        assert_eq!(latest.end_time(), Some(at(12, 0)));
     }
 }
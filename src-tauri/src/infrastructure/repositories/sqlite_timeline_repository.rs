@@ -0,0 +1,155 @@
+use crate::domain::entities::time_entry::TimeEntry;
+use crate::domain::repositories::{TimelineEntry, TimelineRepository};
+use crate::domain::value_objects::{ProjectId, TaskId};
+use crate::infrastructure::database::DatabaseConnection;
+use crate::infrastructure::repositories::SqliteTimeEntryRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// SQLiteタイムライン取得リポジトリ実装
+#[derive(Clone)]
+pub struct SqliteTimelineRepository {
+    db: Arc<Mutex<DatabaseConnection>>,
+}
+
+impl SqliteTimelineRepository {
+    pub fn new(db: Arc<Mutex<DatabaseConnection>>) -> Self {
+        Self { db }
+    }
+
+    fn format_datetime(dt: DateTime<Utc>) -> String {
+        dt.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+    }
+
+    fn parse_datetime(s: &str) -> anyhow::Result<DateTime<Utc>> {
+        Ok(DateTime::parse_from_rfc3339(s)?.with_timezone(&Utc))
+    }
+}
+
+#[async_trait]
+impl TimelineRepository for SqliteTimelineRepository {
+    async fn find_entries_overlapping_period(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<TimelineEntry>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        // タスク・プロジェクトの名前を結合して1回のクエリで取得する
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT tev.task_id, tev.start_event_id, tev.start_time, tev.end_time, tev.notes, tev.paused_seconds, tev.paused_at,
+                   tcv.name, tcv.project_id, pcv.name
+            FROM time_entries_view tev
+            JOIN task_current_view tcv ON tcv.task_id = tev.task_id
+            JOIN project_current_view pcv ON pcv.project_id = tcv.project_id
+            WHERE tev.start_time < ?2 AND (tev.end_time IS NULL OR tev.end_time > ?1)
+            ORDER BY tev.start_time, tev.start_event_id
+            "#,
+        )?;
+
+        let row_iter = stmt.query_map(
+            params![Self::format_datetime(start), Self::format_datetime(end)],
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, i64>(5)?,
+                    row.get::<_, Option<String>>(6)?,
+                    row.get::<_, String>(7)?,
+                    row.get::<_, i64>(8)?,
+                    row.get::<_, String>(9)?,
+                ))
+            },
+        )?;
+
+        let mut entries = Vec::new();
+        for row in row_iter {
+            let (task_id, start_event_id, start_time, end_time, notes, paused_seconds, paused_at, task_name, project_id, project_name) =
+                row?;
+            let end_time = end_time.map(|s| Self::parse_datetime(&s)).transpose()?;
+            let paused_at = paused_at.map(|s| Self::parse_datetime(&s)).transpose()?;
+
+            let entry = TimeEntry::new(TaskId::new(task_id)?, start_event_id, Self::parse_datetime(&start_time)?, end_time)
+                .with_notes(notes)
+                .with_pause(paused_seconds, paused_at);
+            entries.push(TimelineEntry {
+                entry,
+                task_name,
+                project_id: ProjectId::new(project_id)?,
+                project_name,
+            });
+        }
+
+        // 日ごとの作業時間で一時停止を実際の日から差し引くため、一時停止していた区間を設定する
+        let mut intervals = SqliteTimeEntryRepository::find_pause_intervals(conn, entries.iter().map(|item| &item.entry))?;
+        for item in &mut entries {
+            if let Some(pause_intervals) = intervals.remove(&item.entry.start_event_id()) {
+                item.entry = item.entry.clone().with_pause_intervals(pause_intervals);
+            }
+        }
+
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[tokio::test]
+    async fn 期間に重複する区間が名前付きで開始時刻順に取得できること() {
+        let db = DatabaseConnection::new_in_memory().unwrap();
+        db.run_migrations().unwrap();
+        db.connection()
+            .execute_batch(
+                r#"
+                INSERT INTO projects (id) VALUES (911);
+                INSERT INTO project_versions (project_id, version, name, status, effective_at) VALUES
+                  (911, 1, 'Timeline', 'active', '2025-01-01T00:00:00Z');
+                INSERT INTO tasks (id) VALUES (911), (912);
+                INSERT INTO task_versions (task_id, version, project_id, name, status, effective_at) VALUES
+                  (911, 1, 911, 'Design', 'active', '2025-01-01T00:00:00Z'),
+                  (911, 2, 911, 'Design v2', 'active', '2025-02-01T00:00:00Z'),
+                  (912, 1, 911, 'Old', 'archived', '2025-01-01T00:00:00Z');
+                INSERT INTO time_entry_events (id, task_id, event_type, at, start_event_id) VALUES
+                  (9101, 911, 'start', '2025-03-09T23:00:00Z', NULL),
+                  (9102, 911, 'stop', '2025-03-10T01:00:00Z', 9101),
+                  (9103, 912, 'start', '2025-03-10T09:00:00Z', NULL),
+                  (9104, 912, 'stop', '2025-03-10T10:00:00Z', 9103),
+                  (9105, 911, 'start', '2025-03-10T08:00:00Z', NULL),
+                  (9106, 911, 'stop', '2025-03-10T08:30:00Z', 9105),
+                  (9107, 911, 'start', '2025-03-11T09:00:00Z', NULL),
+                  (9108, 911, 'stop', '2025-03-11T10:00:00Z', 9107),
+                  (9109, 912, 'start', '2025-03-10T22:00:00Z', NULL);
+                "#,
+            )
+            .unwrap();
+        let repository = SqliteTimelineRepository::new(Arc::new(Mutex::new(db)));
+
+        let entries = repository
+            .find_entries_overlapping_period(
+                Utc.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 3, 11, 0, 0, 0).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // 前日から続く区間・アーカイブ済みのタスク・実行中の区間を含み、翌日の区間は含まない
+        let ids: Vec<_> = entries.iter().map(|e| e.entry.start_event_id()).collect();
+        assert_eq!(ids, vec![9101, 9105, 9103, 9109]);
+        assert_eq!(entries[0].task_name, "Design v2");
+        assert_eq!(entries[0].project_name, "Timeline");
+        assert_eq!(entries[0].project_id, ProjectId::new(911).unwrap());
+        assert!(entries[3].entry.is_running());
+    }
+}
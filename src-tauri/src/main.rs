@@ -6,11 +6,15 @@
 )]
 
 use time_tracker_go::application::services::ApplicationService;
+use time_tracker_go::domain::services::SystemClock;
 use time_tracker_go::infrastructure::config::Config;
 use time_tracker_go::presentation::commands::*;
+use time_tracker_go::presentation::events::TauriNotifier;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use tracing_appender::rolling;
 use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::Manager;
 
 #[tokio::main]
 async fn main() {
@@ -20,8 +24,14 @@ async fn main() {
     tracing::info!("main: Logging system initialized");
 
     tracing::info!("main: Loading application configuration");
-    // 設定を読み込み
-    let config = Config::default();
+    // 設定を読み込み（環境変数で上書きできる項目は上書きする）
+    let config = match Config::default().with_env_overrides() {
+        Ok(config) => config,
+        Err(e) => {
+            tracing::error!("main: Invalid configuration: {}", e);
+            std::process::exit(1);
+        }
+    };
     tracing::info!("main: Configuration loaded: {:?}", config);
 
     // アプリケーションサービスを初期化
@@ -41,6 +51,21 @@ async fn main() {
     tracing::info!("main: Setting up Tauri application");
     tauri::Builder::default()
         .manage(app_service)
+        .setup(|app| {
+            // タイマーや時間エントリの変更をフロントエンドへ通知する
+            let notifier = Arc::new(TauriNotifier::new(app.handle().clone()));
+            let app_service = app.state::<ApplicationService>();
+            app_service.attach_notifier(notifier.clone());
+
+            // 長時間実行タイマーの自動停止タスク、ハートビート記録タスク、ポモドーロのフェーズ進行タスク、経過時間の通知タスクを起動
+            if let Err(e) = app_service.start_auto_cutoff(notifier.clone(), Arc::new(SystemClock)) {
+                tracing::error!("main: Failed to start auto cutoff task: {}", e);
+            }
+            app_service.start_heartbeat(notifier.clone());
+            app_service.start_pomodoro(notifier.clone());
+            app_service.start_timer_tick(notifier);
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             // プロジェクト管理コマンド
             create_project,
@@ -65,19 +90,64 @@ async fn main() {
             // タイムトラッキング管理コマンド
             start_timer,
             stop_timer,
+            pause_timer,
+            resume_timer,
             get_current_timer,
             get_timer_status,
             add_manual_entry,
+            update_time_entry,
+            delete_time_entry,
+            restore_time_entry,
+            annotate_time_entry,
             get_task_entries,
             get_recent_task_entries,
             get_project_entries,
             get_recent_entries,
             get_time_entries,
+            get_time_entries_page,
             get_global_timer_status,
             get_task_time_summary,
             get_project_time_summary,
+            get_tag_time_summary,
             stop_all_timers,
             is_task_running,
+            // タグ管理コマンド
+            add_tag_to_task,
+            remove_tag_from_task,
+            get_task_tags,
+            list_tags,
+            // 整合性チェックコマンド
+            check_time_entry_integrity,
+            repair_time_entries,
+            // 復旧コマンド
+            get_pending_recovery,
+            resolve_recovery,
+            // ポモドーロコマンド
+            start_pomodoro,
+            stop_pomodoro,
+            pause_pomodoro,
+            resume_pomodoro,
+            skip_pomodoro_phase,
+            get_pomodoro_session,
+            // レポートコマンド
+            generate_report,
+            // タイムシートコマンド
+            get_timesheet,
+            set_timesheet_cell,
+            // タイムラインコマンド
+            get_timeline,
+            // 丸め設定コマンド
+            get_rounding_settings,
+            set_rounding_rule,
+            // エクスポートコマンド
+            export_time_entries_csv,
+            export_time_entries_ics,
+            // バックアップコマンド
+            export_backup,
+            import_backup,
+            // 取り込みコマンド
+            import_toggl_csv,
+            import_time_entries_csv,
             // ログ出力コマンド
             log_to_file,
         ])
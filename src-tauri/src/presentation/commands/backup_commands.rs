@@ -0,0 +1,37 @@
+use crate::application::dto::{parse_backup_path, BackupResponse};
+use crate::application::services::ApplicationService;
+use tauri::State;
+
+/// 全イベント履歴をバージョン付きのJSONファイルに書き出す
+#[tauri::command]
+pub async fn export_backup(
+    app_service: State<'_, ApplicationService>,
+    path: String,
+) -> Result<BackupResponse, String> {
+    let path = parse_backup_path(&path).map_err(|e| e.to_string())?;
+
+    match app_service.backup_service().export_backup(path).await {
+        Ok(summary) => Ok(BackupResponse::from(summary)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to export backup");
+            Err(e.to_string())
+        }
+    }
+}
+
+/// JSONファイルのバックアップを空のデータベースに復元する
+#[tauri::command]
+pub async fn import_backup(
+    app_service: State<'_, ApplicationService>,
+    path: String,
+) -> Result<BackupResponse, String> {
+    let path = parse_backup_path(&path).map_err(|e| e.to_string())?;
+
+    match app_service.backup_service().import_backup(path).await {
+        Ok(summary) => Ok(BackupResponse::from(summary)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to import backup");
+            Err(e.to_string())
+        }
+    }
+}
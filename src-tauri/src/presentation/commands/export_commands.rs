@@ -0,0 +1,37 @@
+use crate::application::dto::{ExportResponse, ExportTimeEntriesCsvRequest, ExportTimeEntriesIcsRequest};
+use crate::application::services::ApplicationService;
+use tauri::State;
+
+/// 期間内の時間エントリを指定パスのCSVファイルに書き出す
+#[tauri::command]
+pub async fn export_time_entries_csv(
+    app_service: State<'_, ApplicationService>,
+    request: ExportTimeEntriesCsvRequest,
+) -> Result<ExportResponse, String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    match app_service.export_service().export_time_entries_csv(command).await {
+        Ok(summary) => Ok(ExportResponse::from(summary)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to export time entries as CSV");
+            Err(e.to_string())
+        }
+    }
+}
+
+/// 期間内の時間エントリを指定パスのiCalendar（.ics）ファイルに書き出す
+#[tauri::command]
+pub async fn export_time_entries_ics(
+    app_service: State<'_, ApplicationService>,
+    request: ExportTimeEntriesIcsRequest,
+) -> Result<ExportResponse, String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    match app_service.export_service().export_time_entries_ics(command).await {
+        Ok(summary) => Ok(ExportResponse::from(summary)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to export time entries as iCalendar");
+            Err(e.to_string())
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::application::dto::{ImportResponse, ImportTimeEntriesCsvRequest, ImportTogglCsvRequest};
+use crate::application::services::ApplicationService;
+use tauri::State;
+
+/// Togglの詳細レポートCSVを取り込む（dry_run の場合は作成されるものと取り込めない行だけを返す）
+#[tauri::command]
+pub async fn import_toggl_csv(
+    app_service: State<'_, ApplicationService>,
+    request: ImportTogglCsvRequest,
+) -> Result<ImportResponse, String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    match app_service.import_service().import_toggl_csv(command).await {
+        Ok(report) => Ok(ImportResponse::from(report)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to import Toggl CSV");
+            Err(e.to_string())
+        }
+    }
+}
+
+/// 列の対応付けに従ってCSVを取り込む（取り込めない行は行番号と理由を返し、残りの行を取り込む）
+#[tauri::command]
+pub async fn import_time_entries_csv(
+    app_service: State<'_, ApplicationService>,
+    request: ImportTimeEntriesCsvRequest,
+) -> Result<ImportResponse, String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    match app_service.import_service().import_time_entries_csv(command).await {
+        Ok(report) => Ok(ImportResponse::from(report)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to import CSV");
+            Err(e.to_string())
+        }
+    }
+}
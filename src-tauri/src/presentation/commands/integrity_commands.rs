@@ -0,0 +1,43 @@
+use crate::application::dto::{
+    CheckTimeEntryIntegrityRequest, IntegrityReportResponse, RepairTimeEntriesRequest,
+    RepairTimeEntriesResponse,
+};
+use crate::application::services::ApplicationService;
+use tauri::State;
+
+/// 時間区間のイベントログの整合性をチェックする
+#[tauri::command]
+pub async fn check_time_entry_integrity(
+    app_service: State<'_, ApplicationService>,
+    request: Option<CheckTimeEntryIntegrityRequest>,
+) -> Result<IntegrityReportResponse, String> {
+    let command = request
+        .unwrap_or_default()
+        .to_command()
+        .map_err(|e| e.to_string())?;
+
+    match app_service.integrity_use_cases().check_time_entry_integrity(command).await {
+        Ok(report) => Ok(IntegrityReportResponse::from(report)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 補正可能な異常に補正イベントを追加する
+#[tauri::command]
+pub async fn repair_time_entries(
+    app_service: State<'_, ApplicationService>,
+    request: Option<RepairTimeEntriesRequest>,
+) -> Result<RepairTimeEntriesResponse, String> {
+    let command = request
+        .unwrap_or_default()
+        .to_command()
+        .map_err(|e| e.to_string())?;
+
+    match app_service.integrity_use_cases().repair_time_entries(command).await {
+        Ok(result) => Ok(RepairTimeEntriesResponse::from(result)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to repair time entries");
+            Err(e.to_string())
+        }
+    }
+}
@@ -3,10 +3,32 @@
 pub mod project_commands;
 pub mod task_commands;
 pub mod time_tracking_commands;
+pub mod tag_commands;
+pub mod integrity_commands;
+pub mod recovery_commands;
+pub mod pomodoro_commands;
+pub mod report_commands;
+pub mod timesheet_commands;
+pub mod timeline_commands;
+pub mod rounding_commands;
+pub mod export_commands;
+pub mod backup_commands;
+pub mod import_commands;
 pub mod logging_commands;
 
 pub use project_commands::*;
 pub use task_commands::*;
 pub use time_tracking_commands::*;
+pub use tag_commands::*;
+pub use integrity_commands::*;
+pub use recovery_commands::*;
+pub use pomodoro_commands::*;
+pub use report_commands::*;
+pub use timesheet_commands::*;
+pub use timeline_commands::*;
+pub use rounding_commands::*;
+pub use export_commands::*;
+pub use backup_commands::*;
+pub use import_commands::*;
 pub use logging_commands::*;
 
@@ -0,0 +1,81 @@
+use crate::application::dto::{PomodoroPhaseChangeResponse, PomodoroSessionResponse, StartPomodoroRequest};
+use crate::application::services::{AppNotification, ApplicationService, Notifier};
+use crate::presentation::events::TauriNotifier;
+use chrono::Utc;
+use tauri::{AppHandle, State};
+
+/// ポモドーロセッションを開始する
+#[tauri::command]
+pub async fn start_pomodoro(
+    app_service: State<'_, ApplicationService>,
+    request: StartPomodoroRequest,
+) -> Result<PomodoroSessionResponse, String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    match app_service.pomodoro_use_cases().start_pomodoro(command).await {
+        Ok(session) => Ok(PomodoroSessionResponse::new(&session, Utc::now())),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to start pomodoro");
+            Err(e.to_string())
+        }
+    }
+}
+
+/// ポモドーロセッションを終了する
+#[tauri::command]
+pub async fn stop_pomodoro(
+    app_service: State<'_, ApplicationService>,
+) -> Result<Option<PomodoroSessionResponse>, String> {
+    match app_service.pomodoro_use_cases().stop_pomodoro().await {
+        Ok(session) => Ok(session.map(|s| PomodoroSessionResponse::new(&s, Utc::now()))),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// ポモドーロセッションを一時停止する
+#[tauri::command]
+pub async fn pause_pomodoro(
+    app_service: State<'_, ApplicationService>,
+) -> Result<PomodoroSessionResponse, String> {
+    match app_service.pomodoro_use_cases().pause_pomodoro().await {
+        Ok(session) => Ok(PomodoroSessionResponse::new(&session, Utc::now())),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 一時停止中のポモドーロセッションを再開する
+#[tauri::command]
+pub async fn resume_pomodoro(
+    app_service: State<'_, ApplicationService>,
+) -> Result<PomodoroSessionResponse, String> {
+    match app_service.pomodoro_use_cases().resume_pomodoro().await {
+        Ok(session) => Ok(PomodoroSessionResponse::new(&session, Utc::now())),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 現在のフェーズをスキップする（フェーズ切り替えイベントも送信する）
+#[tauri::command]
+pub async fn skip_pomodoro_phase(
+    app_handle: AppHandle,
+    app_service: State<'_, ApplicationService>,
+) -> Result<PomodoroPhaseChangeResponse, String> {
+    match app_service.pomodoro_use_cases().skip_pomodoro_phase().await {
+        Ok(change) => {
+            TauriNotifier::new(app_handle).notify(AppNotification::PomodoroPhaseChanged(change.clone()));
+            Ok(PomodoroPhaseChangeResponse::from(change))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 現在のポモドーロセッションを取得する
+#[tauri::command]
+pub async fn get_pomodoro_session(
+    app_service: State<'_, ApplicationService>,
+) -> Result<Option<PomodoroSessionResponse>, String> {
+    match app_service.pomodoro_use_cases().get_pomodoro_session().await {
+        Ok(session) => Ok(session.map(|s| PomodoroSessionResponse::new(&s, Utc::now()))),
+        Err(e) => Err(e.to_string()),
+    }
+}
@@ -0,0 +1,31 @@
+use crate::application::dto::{PendingRecoveryResponse, ResolveRecoveryRequest, TimeEntryEventResponse};
+use crate::application::services::ApplicationService;
+use tauri::State;
+
+/// クラッシュ/スリープ後の復旧待ちの区間を取得する
+#[tauri::command]
+pub async fn get_pending_recovery(
+    app_service: State<'_, ApplicationService>,
+) -> Result<Vec<PendingRecoveryResponse>, String> {
+    match app_service.recovery_use_cases().get_pending_recoveries().await {
+        Ok(recoveries) => Ok(recoveries.into_iter().map(PendingRecoveryResponse::from).collect()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 復旧待ちの区間を解決する（停止した場合は停止イベントを返す）
+#[tauri::command]
+pub async fn resolve_recovery(
+    app_service: State<'_, ApplicationService>,
+    request: ResolveRecoveryRequest,
+) -> Result<Option<TimeEntryEventResponse>, String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    match app_service.recovery_use_cases().resolve_recovery(command).await {
+        Ok(stop_event) => Ok(stop_event.map(TimeEntryEventResponse::from)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to resolve recovery");
+            Err(e.to_string())
+        }
+    }
+}
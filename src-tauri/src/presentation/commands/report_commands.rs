@@ -0,0 +1,20 @@
+use crate::application::dto::{GenerateReportRequest, ReportResponse};
+use crate::application::services::ApplicationService;
+use tauri::State;
+
+/// 期間・プロジェクト・タスク・タグごとの合計時間のレポートを作成する
+#[tauri::command]
+pub async fn generate_report(
+    app_service: State<'_, ApplicationService>,
+    request: GenerateReportRequest,
+) -> Result<ReportResponse, String> {
+    let query = request.to_query(app_service.calendar()).map_err(|e| e.to_string())?;
+
+    match app_service.report_service().generate_report(query).await {
+        Ok(report) => Ok(ReportResponse::from(report)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to generate report");
+            Err(e.to_string())
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::application::dto::{RoundingSettingsResponse, SetRoundingRuleRequest};
+use crate::application::services::ApplicationService;
+use tauri::State;
+
+/// 丸め設定（ワークスペース全体の既定とプロジェクトごとの上書き）を取得する
+#[tauri::command]
+pub async fn get_rounding_settings(
+    app_service: State<'_, ApplicationService>,
+) -> Result<RoundingSettingsResponse, String> {
+    match app_service.rounding_use_cases().get_rounding_policy().await {
+        Ok(policy) => Ok(RoundingSettingsResponse::from(policy)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to get rounding settings");
+            Err(e.to_string())
+        }
+    }
+}
+
+/// 丸め規則を設定し、更新後の丸め設定を返す
+#[tauri::command]
+pub async fn set_rounding_rule(
+    app_service: State<'_, ApplicationService>,
+    request: SetRoundingRuleRequest,
+) -> Result<RoundingSettingsResponse, String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    match app_service.rounding_use_cases().set_rounding_rule(command).await {
+        Ok(policy) => Ok(RoundingSettingsResponse::from(policy)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to set rounding rule");
+            Err(e.to_string())
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::application::dto::{AddTagToTaskRequest, RemoveTagFromTaskRequest, TagDto};
+use crate::application::services::ApplicationService;
+use crate::domain::value_objects::TaskId;
+use tauri::State;
+
+/// タスクへのタグ付与コマンド
+#[tauri::command]
+pub async fn add_tag_to_task(
+    app_service: State<'_, ApplicationService>,
+    request: AddTagToTaskRequest,
+) -> Result<TagDto, String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    match app_service.tag_use_cases().add_tag_to_task(command).await {
+        Ok(tag) => Ok(TagDto::from(tag)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// タスクからのタグ剥奪コマンド
+#[tauri::command]
+pub async fn remove_tag_from_task(
+    app_service: State<'_, ApplicationService>,
+    request: RemoveTagFromTaskRequest,
+) -> Result<(), String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    match app_service.tag_use_cases().remove_tag_from_task(command).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// タスクのタグ取得コマンド
+#[tauri::command]
+pub async fn get_task_tags(
+    app_service: State<'_, ApplicationService>,
+    task_id: i64,
+) -> Result<Vec<TagDto>, String> {
+    let task_id = TaskId::new(task_id).map_err(|e| e.to_string())?;
+
+    match app_service.tag_use_cases().get_task_tags(task_id).await {
+        Ok(tags) => Ok(tags.into_iter().map(TagDto::from).collect()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 全タグ取得コマンド
+#[tauri::command]
+pub async fn list_tags(
+    app_service: State<'_, ApplicationService>,
+) -> Result<Vec<TagDto>, String> {
+    match app_service.tag_use_cases().list_tags().await {
+        Ok(tags) => Ok(tags.into_iter().map(TagDto::from).collect()),
+        Err(e) => Err(e.to_string()),
+    }
+}
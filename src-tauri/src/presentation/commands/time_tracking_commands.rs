@@ -1,10 +1,12 @@
 use crate::application::dto::{
-    AddManualEntryRequest, CurrentTimerResponse, ProjectTimeSummaryResponse, StartTimerRequest,
-    StopTimerRequest, TaskTimeSummaryResponse, TimeEntryEventResponse, TimeEntryResponse,
-    TimerStatusResponse,
+    AddManualEntryRequest, AnnotateTimeEntryRequest, CurrentTimerResponse, GetTimeEntriesRequest, PauseTimerRequest,
+    ProjectTimeSummaryResponse, ResumeTimerRequest, RunningTimerResponse,
+    StartTimerRequest, StopTimerRequest, TagTimeSummaryRequest, TagTimeSummaryResponse,
+    TaskTimeSummaryResponse, TimeEntryEventResponse, TimeEntryPageResponse, TimeEntryResponse,
+    TimerStatusResponse, UpdateTimeEntryRequest,
 };
 use crate::application::services::ApplicationService;
-use crate::domain::value_objects::TaskId;
+use crate::domain::value_objects::{ProjectId, TaskId};
 use tauri::State;
 
 /// タイマーを開始する
@@ -75,30 +77,76 @@ pub async fn stop_timer(
     Ok(event.map(TimeEntryEventResponse::from))
 }
 
-/// 現在実行中のタイマーを取得する
+/// タイマーを一時停止する（同じ時間エントリのまま計測を止める）
 #[tauri::command]
-pub async fn get_current_timer(
+pub async fn pause_timer(
     app_service: State<'_, ApplicationService>,
-) -> Result<CurrentTimerResponse, String> {
-    let current_task_id = app_service
+    request: PauseTimerRequest,
+) -> Result<TimeEntryEventResponse, String> {
+    tracing::info!(task_id = request.task_id, "Timer pause requested");
+
+    let task_id = request.task_id; // コピーしておく
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    let event = app_service
         .time_tracking_use_cases()
-        .get_current_timer()
+        .pause_timer(command)
         .await
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| {
+            tracing::error!(task_id = task_id, error = %e, "Failed to pause timer");
+            e.to_string()
+        })?;
 
-    // 実行中の場合は経過時間も取得
-    let elapsed_seconds = if let Some(task_id) = current_task_id {
-        let status = app_service
-            .time_tracking_use_cases()
-            .get_timer_status(task_id)
-            .await
-            .map_err(|e| e.to_string())?;
-        status.elapsed_seconds
-    } else {
-        None
-    };
+    tracing::info!(task_id = task_id, event_id = ?event.id(), "Timer paused successfully");
+
+    Ok(TimeEntryEventResponse::from(event))
+}
 
-    Ok(CurrentTimerResponse::new(current_task_id, elapsed_seconds))
+/// 一時停止中のタイマーを再開する
+#[tauri::command]
+pub async fn resume_timer(
+    app_service: State<'_, ApplicationService>,
+    request: ResumeTimerRequest,
+) -> Result<TimeEntryEventResponse, String> {
+    tracing::info!(task_id = request.task_id, "Timer resume requested");
+
+    let task_id = request.task_id; // コピーしておく
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    let event = app_service
+        .time_tracking_use_cases()
+        .resume_timer(command)
+        .await
+        .map_err(|e| {
+            tracing::error!(task_id = task_id, error = %e, "Failed to resume timer");
+            e.to_string()
+        })?;
+
+    tracing::info!(task_id = task_id, event_id = ?event.id(), "Timer resumed successfully");
+
+    Ok(TimeEntryEventResponse::from(event))
+}
+
+/// 現在実行中のタイマーを取得する（同時実行モードでは全ての実行中タイマーを含む）
+#[tauri::command]
+pub async fn get_current_timer(
+    app_service: State<'_, ApplicationService>,
+) -> Result<CurrentTimerResponse, String> {
+    let timers = load_running_timers(&app_service).await.map_err(|e| e.to_string())?;
+    Ok(CurrentTimerResponse::from_timers(timers))
+}
+
+/// 実行中の全タイマーと経過時間を取得
+async fn load_running_timers(app_service: &ApplicationService) -> anyhow::Result<Vec<RunningTimerResponse>> {
+    let task_ids = app_service.time_tracking_use_cases().get_current_timers().await?;
+
+    let mut timers = Vec::with_capacity(task_ids.len());
+    for task_id in task_ids {
+        let status = app_service.time_tracking_use_cases().get_timer_status(task_id).await?;
+        timers.push(RunningTimerResponse::new(task_id, status.elapsed_seconds));
+    }
+
+    Ok(timers)
 }
 
 /// 指定タスクのタイマー状態を取得する
@@ -139,6 +187,77 @@ pub async fn add_manual_entry(
     Ok(())
 }
 
+/// 既存の時間エントリを訂正する
+#[tauri::command]
+pub async fn update_time_entry(
+    app_service: State<'_, ApplicationService>,
+    request: UpdateTimeEntryRequest,
+) -> Result<TimeEntryResponse, String> {
+    let start_event_id = request.start_event_id;
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    let entry = app_service
+        .time_tracking_use_cases()
+        .update_time_entry(command)
+        .await
+        .map_err(|e| {
+            tracing::error!(start_event_id = start_event_id, error = %e, "Failed to update time entry");
+            e.to_string()
+        })?;
+
+    Ok(TimeEntryResponse::from(entry))
+}
+
+/// 時間エントリを削除する（無効化イベントを追加）
+#[tauri::command]
+pub async fn delete_time_entry(
+    app_service: State<'_, ApplicationService>,
+    start_event_id: i64,
+) -> Result<TimeEntryEventResponse, String> {
+    let event = app_service
+        .time_tracking_use_cases()
+        .delete_time_entry(start_event_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(start_event_id = start_event_id, error = %e, "Failed to delete time entry");
+            e.to_string()
+        })?;
+
+    Ok(TimeEntryEventResponse::from(event))
+}
+
+/// 削除した時間エントリを復元する
+#[tauri::command]
+pub async fn restore_time_entry(
+    app_service: State<'_, ApplicationService>,
+    start_event_id: i64,
+) -> Result<TimeEntryResponse, String> {
+    let entry = app_service
+        .time_tracking_use_cases()
+        .restore_time_entry(start_event_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(TimeEntryResponse::from(entry))
+}
+
+/// 時間エントリにメモを追記する（実行中・終了済みのどちらも可）
+#[tauri::command]
+pub async fn annotate_time_entry(
+    app_service: State<'_, ApplicationService>,
+    request: AnnotateTimeEntryRequest,
+) -> Result<TimeEntryResponse, String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    let entry = app_service
+        .time_tracking_use_cases()
+        .annotate_time_entry(command)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(TimeEntryResponse::from(entry))
+}
+
 /// 指定タスクの時間エントリ一覧を取得する
 #[tauri::command]
 pub async fn get_task_entries(
@@ -219,6 +338,13 @@ pub async fn get_task_time_summary(
         .await
         .map_err(|e| e.to_string())?;
 
+    // 丸め設定を適用した合計時間を取得
+    let rounded_duration = app_service
+        .rounding_use_cases()
+        .get_task_rounded_duration(task_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
     // エントリ数を取得
     let entries = app_service
         .time_tracking_use_cases()
@@ -234,11 +360,20 @@ pub async fn get_task_time_summary(
         .await
         .map_err(|e| e.to_string())?;
 
+    // 完了したポモドーロ数を取得
+    let completed_pomodoros = app_service
+        .pomodoro_use_cases()
+        .count_completed_pomodoros(task_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(TaskTimeSummaryResponse::new(
         task_id,
         total_duration,
+        rounded_duration,
         entry_count,
         status.is_running,
+        completed_pomodoros,
     ))
 }
 
@@ -255,6 +390,13 @@ pub async fn get_project_time_summary(
         .await
         .map_err(|e| e.to_string())?;
 
+    // 丸め設定を適用した合計時間を取得
+    let rounded_duration = app_service
+        .rounding_use_cases()
+        .get_project_rounded_duration(ProjectId::new(project_id).map_err(|e| e.to_string())?)
+        .await
+        .map_err(|e| e.to_string())?;
+
     // エントリ数を取得
     let entries = app_service
         .time_tracking_use_cases()
@@ -266,6 +408,54 @@ pub async fn get_project_time_summary(
     Ok(ProjectTimeSummaryResponse::new(
         project_id,
         total_duration,
+        rounded_duration,
+        entry_count,
+    ))
+}
+
+/// 指定タグの時間サマリーを取得する（期間指定時はその期間に開始したエントリのみ集計）
+#[tauri::command]
+pub async fn get_tag_time_summary(
+    app_service: State<'_, ApplicationService>,
+    request: TagTimeSummaryRequest,
+) -> Result<TagTimeSummaryResponse, String> {
+    let tag_id = request.tag_id().map_err(|e| e.to_string())?;
+    let period = request.period().map_err(|e| e.to_string())?;
+
+    let (total_duration, entry_count) = match period {
+        Some((start, end)) => {
+            let entries = app_service
+                .time_tracking_use_cases()
+                .get_tag_entries_by_period(tag_id, start, end)
+                .await
+                .map_err(|e| e.to_string())?;
+            let total_duration = entries
+                .iter()
+                .filter_map(|e| e.duration_in_seconds())
+                .sum();
+            (total_duration, entries.len())
+        }
+        None => {
+            // 合計時間を取得
+            let total_duration = app_service
+                .time_tracking_use_cases()
+                .get_tag_total_duration(tag_id)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            // エントリ数を取得
+            let entries = app_service
+                .time_tracking_use_cases()
+                .get_tag_entries(tag_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            (total_duration, entries.len())
+        }
+    };
+
+    Ok(TagTimeSummaryResponse::new(
+        tag_id,
+        total_duration,
         entry_count,
     ))
 }
@@ -301,84 +491,65 @@ pub async fn is_task_running(
     Ok(status.is_running)
 }
 
-/// 指定期間の時間エントリーを取得する（新規追加）
+/// 指定期間の時間エントリーを取得する（期間内の全件）
 #[tauri::command]
 pub async fn get_time_entries(
     app_service: State<'_, ApplicationService>,
-    _start_date: String,
+    start_date: String,
+    end_date: Option<String>,
+    project_id: Option<i64>,
+    task_id: Option<i64>,
 ) -> Result<Vec<TimeEntryResponse>, String> {
-    tracing::info!("get_time_entries: Command called with start_date: {}", _start_date);
-    tracing::info!("get_time_entries: Command execution started");
-    tracing::info!("get_time_entries: About to call app_service.time_tracking_use_cases().get_recent_entries(100)");
-    
-    // 簡単な実装：最新のエントリーを取得
-    tracing::debug!("get_time_entries: Calling app_service.time_tracking_use_cases().get_recent_entries(100)");
-    
-    match app_service
-        .time_tracking_use_cases()
-        .get_recent_entries(100) // 最新100件を取得
-        .await
-    {
-        Ok(entries) => {
-            tracing::info!("get_time_entries: Use case call successful - {} entries returned", entries.len());
-            
-            tracing::debug!("get_time_entries: Converting entries to TimeEntryResponse");
-            let responses: Vec<TimeEntryResponse> = entries.into_iter().map(TimeEntryResponse::from).collect();
-            
-            tracing::info!("get_time_entries: Successfully converted {} entries to TimeEntryResponse", responses.len());
-            
-            // レスポンスの詳細をログ出力
-            for (i, response) in responses.iter().enumerate() {
-                tracing::debug!("get_time_entries: Response {} - task_id: {}, start_time: {:?}, end_time: {:?}, duration: {:?}", 
-                    i + 1, 
-                    response.task_id, 
-                    response.start_time, 
-                    response.end_time,
-                    response.duration_in_seconds
-                );
-            }
-            
-            tracing::info!("get_time_entries: Command completed successfully - returning {} entries", responses.len());
-            Ok(responses)
-        },
-        Err(e) => {
-            tracing::error!("get_time_entries: Use case call failed: {}", e);
-            tracing::error!("get_time_entries: Error type: {}", std::any::type_name_of_val(&*e));
-            tracing::error!("get_time_entries: Error details: {:?}", e);
-            
-            // エラーの種類に応じた詳細情報
-            if let Some(db_error) = e.downcast_ref::<rusqlite::Error>() {
-                tracing::error!("get_time_entries: SQLite error: {:?}", db_error);
-                match db_error {
-                    rusqlite::Error::QueryReturnedNoRows => {
-                        tracing::warn!("get_time_entries: No rows found in database");
-                    },
-                    rusqlite::Error::InvalidPath(_) => {
-                        tracing::error!("get_time_entries: Invalid database path");
-                    },
-                    rusqlite::Error::SqliteFailure(code, message) => {
-                        tracing::error!("get_time_entries: SQLite failure - code: {}, message: {:?}", code, message);
-                    },
-                    _ => {
-                        tracing::error!("get_time_entries: Other SQLite error: {:?}", db_error);
-                    }
-                }
-            }
-            
-            if let Some(anyhow_error) = e.downcast_ref::<anyhow::Error>() {
-                tracing::error!("get_time_entries: Anyhow error: {:?}", anyhow_error);
-            }
-            
-            // データが存在しない場合は空の配列を返す
-            if e.to_string().contains("no rows") {
-                tracing::info!("get_time_entries: No rows found, returning empty array");
-                Ok(Vec::new())
-            } else {
-                tracing::error!("get_time_entries: Returning error to frontend: {}", e);
-                Err(e.to_string())
-            }
+    tracing::info!("get_time_entries: Command called with start_date: {}, end_date: {:?}", start_date, end_date);
+
+    let request = GetTimeEntriesRequest {
+        start_date,
+        end_date,
+        project_id,
+        task_id,
+        cursor: None,
+        limit: Some(GetTimeEntriesRequest::MAX_LIMIT),
+    };
+    let mut command = request.to_command(&app_service.calendar()).map_err(|e| e.to_string())?;
+
+    // ページを辿って期間内の全エントリを取得
+    let mut responses = Vec::new();
+    loop {
+        let page = app_service
+            .time_tracking_use_cases()
+            .get_entries_by_period(command.clone())
+            .await
+            .map_err(|e| {
+                tracing::error!("get_time_entries: Use case call failed: {}", e);
+                e.to_string()
+            })?;
+
+        responses.extend(page.entries.into_iter().map(TimeEntryResponse::from));
+        match page.next_cursor {
+            Some(cursor) => command.cursor = Some(cursor),
+            None => break,
         }
     }
+
+    tracing::info!("get_time_entries: Command completed successfully - returning {} entries", responses.len());
+    Ok(responses)
+}
+
+/// 指定期間の時間エントリーをページ単位で取得する
+#[tauri::command]
+pub async fn get_time_entries_page(
+    app_service: State<'_, ApplicationService>,
+    request: GetTimeEntriesRequest,
+) -> Result<TimeEntryPageResponse, String> {
+    let command = request.to_command(&app_service.calendar()).map_err(|e| e.to_string())?;
+
+    let page = app_service
+        .time_tracking_use_cases()
+        .get_entries_by_period(command)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(TimeEntryPageResponse::from(page))
 }
 
 /// 全体のタイマーステータスを取得する（パラメータ不要版）
@@ -388,33 +559,13 @@ pub async fn get_global_timer_status(
 ) -> Result<CurrentTimerResponse, String> {
     tracing::debug!("get_global_timer_status called");
     
-    let current_task_id = app_service
-        .time_tracking_use_cases()
-        .get_current_timer()
-        .await
-        .map_err(|e| {
-            tracing::error!("get_global_timer_status: get_current_timer failed: {}", e);
-            e.to_string()
-        })?;
-
-    let (task_id, elapsed_seconds) = if let Some(task_id) = current_task_id {
-        tracing::debug!("get_global_timer_status: current task found: {}", task_id);
-        let elapsed = app_service
-            .time_tracking_use_cases()
-            .get_timer_status(task_id)
-            .await
-            .map_err(|e| {
-                tracing::error!("get_global_timer_status: get_timer_status failed: {}", e);
-                e.to_string()
-            })?;
-        (Some(task_id.into()), elapsed.elapsed_seconds)
-    } else {
-        tracing::debug!("get_global_timer_status: no current task");
-        (None, None)
-    };
+    let timers = load_running_timers(&app_service).await.map_err(|e| {
+        tracing::error!("get_global_timer_status: failed to load running timers: {}", e);
+        e.to_string()
+    })?;
 
-    tracing::debug!("get_global_timer_status success: task_id={:?}, elapsed={:?}", task_id, elapsed_seconds);
-    Ok(CurrentTimerResponse::new(task_id, elapsed_seconds))
+    tracing::debug!("get_global_timer_status success: running timers={}", timers.len());
+    Ok(CurrentTimerResponse::from_timers(timers))
 }
 
 #[cfg(test)]
@@ -462,7 +613,7 @@ mod tests {
         let (_, task_id) = create_test_task(&app_service).await;
 
         // タイマー開始
-        let start_request = StartTimerRequest { task_id };
+        let start_request = StartTimerRequest { task_id, at: None };
         let start_response = start_timer(tauri::State::from(&app_service), start_request)
             .await
             .unwrap();
@@ -477,7 +628,7 @@ mod tests {
         assert!(status.is_running);
 
         // タイマー停止
-        let stop_request = StopTimerRequest { task_id };
+        let stop_request = StopTimerRequest { task_id, at: None };
         let stop_response = stop_timer(tauri::State(&app_service), stop_request)
             .await
             .unwrap();
@@ -504,7 +655,7 @@ mod tests {
         assert!(current.task_id.is_none());
 
         // タイマーを開始
-        let start_request = StartTimerRequest { task_id };
+        let start_request = StartTimerRequest { task_id, at: None };
         start_timer(tauri::State(&app_service), start_request)
             .await
             .unwrap();
@@ -576,7 +727,7 @@ mod tests {
         assert!(!is_running);
 
         // タイマーを開始
-        let start_request = StartTimerRequest { task_id };
+        let start_request = StartTimerRequest { task_id, at: None };
         start_timer(tauri::State(&app_service), start_request)
             .await
             .unwrap();
@@ -593,7 +744,7 @@ mod tests {
         let app_service = setup_app_service().await;
         let invalid_task_id = 999999;
 
-        let start_request = StartTimerRequest { task_id: invalid_task_id };
+        let start_request = StartTimerRequest { task_id: invalid_task_id, at: None };
         let result = start_timer(tauri::State(&app_service), start_request).await;
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Task not found"));
@@ -605,7 +756,7 @@ mod tests {
         let (_, task_id) = create_test_task(&app_service).await;
 
         // タイマーを開始
-        let start_request = StartTimerRequest { task_id };
+        let start_request = StartTimerRequest { task_id, at: None };
         start_timer(tauri::State(&app_service), start_request)
             .await
             .unwrap();
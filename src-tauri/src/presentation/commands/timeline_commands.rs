@@ -0,0 +1,20 @@
+use crate::application::dto::{GetTimelineRequest, TimelineResponse};
+use crate::application::services::ApplicationService;
+use tauri::State;
+
+/// 指定日（または週）の全タスクの時間区間と稼働時間内の空き時間を時系列で取得する
+#[tauri::command]
+pub async fn get_timeline(
+    app_service: State<'_, ApplicationService>,
+    request: GetTimelineRequest,
+) -> Result<TimelineResponse, String> {
+    let (date, span) = request.to_query().map_err(|e| e.to_string())?;
+
+    match app_service.timeline_service().get_timeline(date, span).await {
+        Ok(timeline) => Ok(TimelineResponse::from(timeline)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to get timeline");
+            Err(e.to_string())
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::application::dto::{parse_timesheet_week, SetTimesheetCellRequest, TimesheetResponse};
+use crate::application::services::ApplicationService;
+use tauri::State;
+
+/// 指定日を含む週のタイムシート（タスクごとの日別合計）を取得する
+#[tauri::command]
+pub async fn get_timesheet(
+    app_service: State<'_, ApplicationService>,
+    week_start: String,
+) -> Result<TimesheetResponse, String> {
+    let date = parse_timesheet_week(&week_start).map_err(|e| e.to_string())?;
+
+    match app_service.timesheet_use_cases().get_timesheet(date).await {
+        Ok(timesheet) => Ok(TimesheetResponse::from(timesheet)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to get timesheet");
+            Err(e.to_string())
+        }
+    }
+}
+
+/// タイムシートのセル（タスク×日）の合計を指定の時間に合わせ、更新後のタイムシートを返す
+#[tauri::command]
+pub async fn set_timesheet_cell(
+    app_service: State<'_, ApplicationService>,
+    request: SetTimesheetCellRequest,
+) -> Result<TimesheetResponse, String> {
+    let command = request.to_command().map_err(|e| e.to_string())?;
+
+    match app_service.timesheet_use_cases().set_timesheet_cell(command).await {
+        Ok(timesheet) => Ok(TimesheetResponse::from(timesheet)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to set timesheet cell");
+            Err(e.to_string())
+        }
+    }
+}
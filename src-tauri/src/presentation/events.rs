@@ -0,0 +1,76 @@
+use crate::application::dto::{
+    CurrentTimerResponse, EntriesChangedResponse, PendingRecoveryResponse, PomodoroEndedResponse,
+    PomodoroPhaseChangeResponse, TimeEntryEventResponse, TimeEntryResponse,
+};
+use crate::application::services::{AppNotification, Notifier};
+use tauri::{AppHandle, Emitter};
+
+/// 長時間実行タイマーの自動停止イベント名
+pub const TIMER_AUTO_CUTOFF_EVENT: &str = "timer-auto-cutoff";
+
+/// 復旧待ちのタイマー検出イベント名
+pub const TIMER_RECOVERY_PENDING_EVENT: &str = "timer-recovery-pending";
+
+/// ポモドーロのフェーズ切り替えイベント名
+pub const POMODORO_PHASE_CHANGED_EVENT: &str = "pomodoro-phase-changed";
+
+/// ポモドーロセッション終了イベント名
+pub const POMODORO_ENDED_EVENT: &str = "pomodoro-ended";
+
+/// タイマー開始イベント名
+pub const TIMER_STARTED_EVENT: &str = "timer-started";
+
+/// タイマー停止イベント名
+pub const TIMER_STOPPED_EVENT: &str = "timer-stopped";
+
+/// 実行中タイマーの経過時間イベント名
+pub const TIMER_TICK_EVENT: &str = "timer-tick";
+
+/// 時間エントリ変更イベント名
+pub const ENTRIES_CHANGED_EVENT: &str = "entries-changed";
+
+/// Tauriイベントでフロントエンドに通知する
+pub struct TauriNotifier {
+    app_handle: AppHandle,
+}
+
+impl TauriNotifier {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+impl Notifier for TauriNotifier {
+    fn notify(&self, notification: AppNotification) {
+        let result = match notification {
+            AppNotification::TimerAutoCutoff(event) => self
+                .app_handle
+                .emit(TIMER_AUTO_CUTOFF_EVENT, TimeEntryEventResponse::from(event)),
+            AppNotification::TimerRecoveryPending(recovery) => self
+                .app_handle
+                .emit(TIMER_RECOVERY_PENDING_EVENT, PendingRecoveryResponse::from(recovery)),
+            AppNotification::PomodoroPhaseChanged(change) => self
+                .app_handle
+                .emit(POMODORO_PHASE_CHANGED_EVENT, PomodoroPhaseChangeResponse::from(change)),
+            AppNotification::PomodoroEnded { task_id, reason } => self
+                .app_handle
+                .emit(POMODORO_ENDED_EVENT, PomodoroEndedResponse::new(task_id, reason)),
+            AppNotification::TimerStarted(entry) => self
+                .app_handle
+                .emit(TIMER_STARTED_EVENT, TimeEntryResponse::from(entry)),
+            AppNotification::TimerStopped(entry) => self
+                .app_handle
+                .emit(TIMER_STOPPED_EVENT, TimeEntryResponse::from(entry)),
+            AppNotification::TimerTick(entries) => self
+                .app_handle
+                .emit(TIMER_TICK_EVENT, CurrentTimerResponse::from_entries(&entries)),
+            AppNotification::EntriesChanged(task_id) => self
+                .app_handle
+                .emit(ENTRIES_CHANGED_EVENT, EntriesChangedResponse::new(task_id)),
+        };
+
+        if let Err(e) = result {
+            tracing::error!(error = %e, "Failed to emit event to frontend");
+        }
+    }
+}
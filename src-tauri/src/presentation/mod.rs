@@ -1,6 +1,8 @@
 // プレゼンテーション層 - Tauriコマンドとフロントエンドとの橋渡し
 
 pub mod commands;
+pub mod events;
 
 pub use commands::*;
+pub use events::*;
 
@@ -6,7 +6,7 @@ import { ProjectSidebar } from './ProjectSidebar'
 import { ProjectEditModal } from '../projects/ProjectEditModal'
 import { ProjectDetailView } from '../projects/ProjectDetailView'
 import { Project, TimeEntry, TimerStatus, Task } from '@/types'
-import { Logger } from '@/utils'
+import { Logger, toLocalDateString } from '@/utils'
 
 export function Dashboard() {
   console.log('[Dashboard] Component rendering')
@@ -70,7 +70,14 @@ export function Dashboard() {
       // 時間エントリを取得
       try {
         Logger.debug('Dashboard', 'Calling get_time_entries');
-        entriesData = await invoke<TimeEntry[]>('get_time_entries', { startDate: new Date().toISOString().split('T')[0] }) || [];
+        // 終了日を省略すると開始日の1日分になるため、今日を含む直近7日間を明示する
+        const endDate = new Date();
+        const startDate = new Date(endDate);
+        startDate.setDate(startDate.getDate() - 6);
+        entriesData = await invoke<TimeEntry[]>('get_time_entries', {
+          startDate: toLocalDateString(startDate),
+          endDate: toLocalDateString(endDate),
+        }) || [];
         Logger.debug('Dashboard', 'get_time_entries success', { count: entriesData.length });
       } catch (e) {
         Logger.error('Dashboard', 'get_time_entries failed', { error: String(e) });
@@ -13,37 +13,40 @@ export function GlobalTimer() {
   const intervalRef = useRef<number | null>(null)
   const startTimeRef = useRef<Date | null>(null)
 
+  // タイマー情報を画面に反映
+  const applyCurrentTimer = (timer: CurrentTimer) => {
+    if (timer.task_id && timer.elapsed_seconds !== undefined) {
+      setCurrentTimer(timer)
+      setElapsedSeconds(timer.elapsed_seconds)
+      startTimeRef.current = new Date(Date.now() - timer.elapsed_seconds * 1000)
+      setIsVisible(true)
+      
+      // タスク情報も取得（バックエンドにAPIがあると仮定）
+      // 実際の実装では、TaskApiから取得する必要があります
+      // const task = await TaskApi.getTask(timer.task_id)
+      // setCurrentTask(task)
+      
+      // 仮のタスク情報
+      setCurrentTask({
+        id: timer.task_id,
+        project_id: 1,
+        name: `タスク ${timer.task_id}`,
+        status: 'active',
+        effective_at: new Date().toISOString()
+      })
+    } else {
+      setCurrentTimer(null)
+      setCurrentTask(null)
+      setIsVisible(false)
+      setElapsedSeconds(0)
+      startTimeRef.current = null
+    }
+  }
+
   // 現在のタイマー情報を取得
   const loadCurrentTimer = async () => {
     try {
-      const timer = await TimeTrackingApi.getCurrentTimer()
-      
-      if (timer.task_id && timer.elapsed_seconds !== undefined) {
-        setCurrentTimer(timer)
-        setElapsedSeconds(timer.elapsed_seconds)
-        startTimeRef.current = new Date(Date.now() - timer.elapsed_seconds * 1000)
-        setIsVisible(true)
-        
-        // タスク情報も取得（バックエンドにAPIがあると仮定）
-        // 実際の実装では、TaskApiから取得する必要があります
-        // const task = await TaskApi.getTask(timer.task_id)
-        // setCurrentTask(task)
-        
-        // 仮のタスク情報
-        setCurrentTask({
-          id: timer.task_id,
-          project_id: 1,
-          name: `タスク ${timer.task_id}`,
-          status: 'active',
-          effective_at: new Date().toISOString()
-        })
-      } else {
-        setCurrentTimer(null)
-        setCurrentTask(null)
-        setIsVisible(false)
-        setElapsedSeconds(0)
-        startTimeRef.current = null
-      }
+      applyCurrentTimer(await TimeTrackingApi.getCurrentTimer())
     } catch (err) {
       console.error('Failed to load current timer:', err)
       setIsVisible(false)
@@ -69,15 +72,20 @@ export function GlobalTimer() {
     }
   }, [currentTimer])
 
-  // 初期読み込みと定期的な更新
+  // 初期読み込みとバックエンドからのイベント購読
   useEffect(() => {
     loadCurrentTimer()
-    
-    // 30秒ごとにサーバーから状態を再確認
-    const updateInterval = setInterval(loadCurrentTimer, 30000)
+
+    // 開始・停止は他のウィンドウからの操作も含めて通知されるので、その都度状態を取り直す
+    // 経過時間は timer-tick イベントで補正する
+    const unlisteners = Promise.all([
+      TimeTrackingApi.onTimerStarted(() => loadCurrentTimer()),
+      TimeTrackingApi.onTimerStopped(() => loadCurrentTimer()),
+      TimeTrackingApi.onTimerTick(applyCurrentTimer),
+    ])
     
     return () => {
-      clearInterval(updateInterval)
+      unlisteners.then((fns) => fns.forEach((unlisten) => unlisten()))
       if (intervalRef.current) {
         clearInterval(intervalRef.current)
       }
@@ -9,7 +9,7 @@ interface TimerButtonProps {
 }
 
 export function TimerButton({ task, onTimerStateChanged, disabled = false }: TimerButtonProps) {
-  const [status, setStatus] = useState<TimerStatus>({ is_running: false })
+  const [status, setStatus] = useState<TimerStatus>({ is_running: false, is_paused: false })
   const [loading, setLoading] = useState(false)
   const [error, setError] = useState<string | null>(null)
 
@@ -0,0 +1,18 @@
+import { invoke } from '@tauri-apps/api/core'
+import type { BackupResult } from '@/types'
+
+export const backupService = {
+  /**
+   * 全イベント履歴をバージョン付きのJSONファイルに書き出す
+   */
+  async exportBackup(path: string): Promise<BackupResult> {
+    return await invoke('export_backup', { path })
+  },
+
+  /**
+   * JSONファイルのバックアップを空のデータベースに復元する（不正な場合は何も取り込まない）
+   */
+  async importBackup(path: string): Promise<BackupResult> {
+    return await invoke('import_backup', { path })
+  },
+}
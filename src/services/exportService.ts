@@ -0,0 +1,18 @@
+import { invoke } from '@tauri-apps/api/core'
+import type { ExportResult, ExportTimeEntriesCsvRequest, ExportTimeEntriesIcsRequest } from '@/types'
+
+export const exportService = {
+  /**
+   * 期間内の時間エントリを指定パスのCSVファイルに書き出す
+   */
+  async exportTimeEntriesCsv(request: ExportTimeEntriesCsvRequest): Promise<ExportResult> {
+    return await invoke('export_time_entries_csv', { request })
+  },
+
+  /**
+   * 期間内の時間エントリを指定パスのiCalendar（.ics）ファイルに書き出す
+   */
+  async exportTimeEntriesIcs(request: ExportTimeEntriesIcsRequest): Promise<ExportResult> {
+    return await invoke('export_time_entries_ics', { request })
+  },
+}
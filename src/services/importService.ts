@@ -0,0 +1,18 @@
+import { invoke } from '@tauri-apps/api/core'
+import type { ImportResult, ImportTimeEntriesCsvRequest, ImportTogglCsvRequest } from '@/types'
+
+export const importService = {
+  /**
+   * Togglの詳細レポートCSVを取り込む（dry_run の場合は作成されるものと取り込めない行だけを返す）
+   */
+  async importTogglCsv(request: ImportTogglCsvRequest): Promise<ImportResult> {
+    return await invoke('import_toggl_csv', { request })
+  },
+
+  /**
+   * 列の対応付けに従ってCSVを取り込む（取り込めない行は行番号と理由を返し、残りの行を取り込む）
+   */
+  async importTimeEntriesCsv(request: ImportTimeEntriesCsvRequest): Promise<ImportResult> {
+    return await invoke('import_time_entries_csv', { request })
+  },
+}
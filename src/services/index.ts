@@ -5,4 +5,10 @@ export * from './taskService';
 export * from './timeTrackingApi';
 export * from './tagService';
 export * from './reportService';
+export * from './timesheetService';
+export * from './timelineService';
+export * from './roundingService';
+export * from './exportService';
+export * from './backupService';
+export * from './importService';
 
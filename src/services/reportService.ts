@@ -1,2 +1,11 @@
-// TODO: Implement report service
-export const reportService = {}
+import { invoke } from '@tauri-apps/api/core'
+import type { GenerateReportRequest, Report } from '@/types'
+
+export const reportService = {
+  /**
+   * 期間ごと（さらにプロジェクト・タスク・タグごと）の合計時間を集計する
+   */
+  async generateReport(request: GenerateReportRequest): Promise<Report> {
+    return await invoke('generate_report', { request })
+  },
+}
@@ -0,0 +1,18 @@
+import { invoke } from '@tauri-apps/api/core'
+import type { RoundingSettings, SetRoundingRuleRequest } from '@/types'
+
+export const roundingService = {
+  /**
+   * 丸め設定（ワークスペース全体の既定とプロジェクトごとの上書き）を取得する
+   */
+  async getRoundingSettings(): Promise<RoundingSettings> {
+    return await invoke('get_rounding_settings')
+  },
+
+  /**
+   * 丸め規則を設定し、更新後の丸め設定を返す
+   */
+  async setRoundingRule(request: SetRoundingRuleRequest): Promise<RoundingSettings> {
+    return await invoke('set_rounding_rule', { request })
+  },
+}
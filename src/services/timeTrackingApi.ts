@@ -1,12 +1,44 @@
 import { invoke } from '@tauri-apps/api/core'
-import type { TimeEntry, TimerStatus, CurrentTimer, TaskTimeSummary } from '@/types'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
+import type { TimeEntry, TimerStatus, CurrentTimer, TaskTimeSummary, PomodoroSession, PomodoroPhaseChange, PomodoroEnded, EntriesChanged } from '@/types'
+
+/**
+ * バックエンドから通知されるタイマー関連のイベント名
+ */
+export const TimeTrackingEvents = {
+  TIMER_STARTED: 'timer-started',
+  TIMER_STOPPED: 'timer-stopped',
+  TIMER_TICK: 'timer-tick',
+  ENTRIES_CHANGED: 'entries-changed',
+  POMODORO_ENDED: 'pomodoro-ended',
+} as const
 
 export interface StartTimerRequest {
   task_id: number
+  at?: string
 }
 
 export interface StopTimerRequest {
   task_id: number
+  at?: string
+}
+
+export interface PauseTimerRequest {
+  task_id: number
+  at?: string
+}
+
+export interface ResumeTimerRequest {
+  task_id: number
+  at?: string
+}
+
+export interface StartPomodoroRequest {
+  task_id: number
+  work_seconds?: number
+  short_break_seconds?: number
+  long_break_seconds?: number
+  long_break_interval?: number
 }
 
 export interface AddManualEntryRequest {
@@ -30,21 +62,79 @@ export interface TimeEntryEventResponse {
  */
 export class TimeTrackingApi {
   /**
-   * タイマーを開始する
+   * タイマーを開始する（at を指定すると過去に遡って開始する）
    */
-  static async startTimer(taskId: number): Promise<TimeEntryEventResponse> {
-    const request: StartTimerRequest = { task_id: taskId }
+  static async startTimer(taskId: number, at?: string): Promise<TimeEntryEventResponse> {
+    const request: StartTimerRequest = { task_id: taskId, at }
     return await invoke('start_timer', { request })
   }
 
   /**
-   * タイマーを停止する
+   * タイマーを停止する（at を指定するとその時刻で停止する）
    */
-  static async stopTimer(taskId: number): Promise<TimeEntryEventResponse | null> {
-    const request: StopTimerRequest = { task_id: taskId }
+  static async stopTimer(taskId: number, at?: string): Promise<TimeEntryEventResponse | null> {
+    const request: StopTimerRequest = { task_id: taskId, at }
     return await invoke('stop_timer', { request })
   }
 
+  /**
+   * タイマーを一時停止する（同じ時間エントリのまま計測を止める。at を指定するとその時刻で一時停止する）
+   */
+  static async pauseTimer(taskId: number, at?: string): Promise<TimeEntryEventResponse> {
+    const request: PauseTimerRequest = { task_id: taskId, at }
+    return await invoke('pause_timer', { request })
+  }
+
+  /**
+   * 一時停止中のタイマーを再開する（at を指定するとその時刻で再開する）
+   */
+  static async resumeTimer(taskId: number, at?: string): Promise<TimeEntryEventResponse> {
+    const request: ResumeTimerRequest = { task_id: taskId, at }
+    return await invoke('resume_timer', { request })
+  }
+
+  /**
+   * ポモドーロセッションを開始する（作業フェーズのタイマーを開始）
+   */
+  static async startPomodoro(request: StartPomodoroRequest): Promise<PomodoroSession> {
+    return await invoke('start_pomodoro', { request })
+  }
+
+  /**
+   * ポモドーロセッションを終了する
+   */
+  static async stopPomodoro(): Promise<PomodoroSession | null> {
+    return await invoke('stop_pomodoro')
+  }
+
+  /**
+   * ポモドーロセッションを一時停止する
+   */
+  static async pausePomodoro(): Promise<PomodoroSession> {
+    return await invoke('pause_pomodoro')
+  }
+
+  /**
+   * 一時停止中のポモドーロセッションを再開する
+   */
+  static async resumePomodoro(): Promise<PomodoroSession> {
+    return await invoke('resume_pomodoro')
+  }
+
+  /**
+   * 現在のフェーズをスキップする
+   */
+  static async skipPomodoroPhase(): Promise<PomodoroPhaseChange> {
+    return await invoke('skip_pomodoro_phase')
+  }
+
+  /**
+   * 現在のポモドーロセッションを取得する
+   */
+  static async getPomodoroSession(): Promise<PomodoroSession | null> {
+    return await invoke('get_pomodoro_session')
+  }
+
   /**
    * 現在実行中のタイマーを取得する
    */
@@ -114,6 +204,41 @@ export class TimeTrackingApi {
   static async stopAllTimers(): Promise<TimeEntryEventResponse[]> {
     return await invoke('stop_all_timers')
   }
+
+  /**
+   * タイマーの開始を購読する（開始した時間エントリが渡される）
+   */
+  static async onTimerStarted(handler: (entry: TimeEntry) => void): Promise<UnlistenFn> {
+    return await listen<TimeEntry>(TimeTrackingEvents.TIMER_STARTED, (event) => handler(event.payload))
+  }
+
+  /**
+   * タイマーの停止を購読する（停止した時間エントリが渡される）
+   */
+  static async onTimerStopped(handler: (entry: TimeEntry) => void): Promise<UnlistenFn> {
+    return await listen<TimeEntry>(TimeTrackingEvents.TIMER_STOPPED, (event) => handler(event.payload))
+  }
+
+  /**
+   * 実行中タイマーの経過時間を購読する（実行中のタイマーがある間だけ通知される）
+   */
+  static async onTimerTick(handler: (timer: CurrentTimer) => void): Promise<UnlistenFn> {
+    return await listen<CurrentTimer>(TimeTrackingEvents.TIMER_TICK, (event) => handler(event.payload))
+  }
+
+  /**
+   * 時間エントリの変更を購読する
+   */
+  static async onEntriesChanged(handler: (change: EntriesChanged) => void): Promise<UnlistenFn> {
+    return await listen<EntriesChanged>(TimeTrackingEvents.ENTRIES_CHANGED, (event) => handler(event.payload))
+  }
+
+  /**
+   * タイマーを操作できずにポモドーロセッションが終了したことを購読する
+   */
+  static async onPomodoroEnded(handler: (ended: PomodoroEnded) => void): Promise<UnlistenFn> {
+    return await listen<PomodoroEnded>(TimeTrackingEvents.POMODORO_ENDED, (event) => handler(event.payload))
+  }
 }
 
 /**
@@ -0,0 +1,11 @@
+import { invoke } from '@tauri-apps/api/core'
+import type { GetTimelineRequest, Timeline } from '@/types'
+
+export const timelineService = {
+  /**
+   * 指定日（または週）の全タスクの時間区間と稼働時間内の空き時間を取得する
+   */
+  async getTimeline(request: GetTimelineRequest): Promise<Timeline> {
+    return await invoke('get_timeline', { request })
+  },
+}
@@ -0,0 +1,18 @@
+import { invoke } from '@tauri-apps/api/core'
+import type { SetTimesheetCellRequest, Timesheet } from '@/types'
+
+export const timesheetService = {
+  /**
+   * 指定日（YYYY-MM-DD）を含む週のタスクごとの日別合計を取得する
+   */
+  async getTimesheet(weekStart: string): Promise<Timesheet> {
+    return await invoke('get_timesheet', { weekStart })
+  },
+
+  /**
+   * セル（タスク×日）の合計を指定の時間に合わせ、更新後のタイムシートを返す
+   */
+  async setTimesheetCell(request: SetTimesheetCellRequest): Promise<Timesheet> {
+    return await invoke('set_timesheet_cell', { request })
+  },
+}
@@ -12,6 +12,10 @@ vi.mock('@tauri-apps/api/core', () => ({
   invoke: vi.fn(),
 }))
 
+vi.mock('@tauri-apps/api/event', () => ({
+  listen: vi.fn(() => Promise.resolve(() => {})),
+}))
+
 // グローバル設定
 Object.defineProperty(window, 'matchMedia', {
   writable: true,
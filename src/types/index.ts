@@ -27,6 +27,9 @@ export interface TimeEntry {
   elapsed_duration: string
   is_running: boolean
   is_completed: boolean
+  notes?: string // 注釈を発生順に改行区切りで連結したメモ
+  is_paused?: boolean
+  paused_seconds?: number // 一時停止していた秒数（duration_in_seconds からは除外済み）
   description?: string // Toggl風のタスク説明
   tags?: string[] // Toggl風のタグ
   // Toggl風UIで使いやすいエイリアス
@@ -36,26 +39,302 @@ export interface TimeEntry {
 
 export interface TimerStatus {
   is_running: boolean
+  is_paused: boolean
+  paused_at?: string
   current_entry?: TimeEntry
   elapsed_seconds?: number
   elapsed_duration?: string
 }
 
+export type PomodoroPhase = 'work' | 'short_break' | 'long_break'
+
+export interface PomodoroSession {
+  task_id: number
+  phase: PomodoroPhase
+  is_paused: boolean
+  phase_started_at: string
+  phase_ends_at?: string
+  remaining_seconds: number
+  completed_count: number
+  work_seconds: number
+  short_break_seconds: number
+  long_break_seconds: number
+  long_break_interval: number
+}
+
+export interface PomodoroPhaseChange {
+  task_id: number
+  from_phase: PomodoroPhase
+  to_phase: PomodoroPhase
+  completed: boolean
+  completed_count: number
+  at: string
+}
+
+// pomodoro-ended イベントのペイロード
+export interface PomodoroEnded {
+  task_id: number
+  reason: string
+}
+
+export interface RunningTimer {
+  task_id: number
+  elapsed_seconds?: number
+  elapsed_duration?: string
+}
+
 export interface CurrentTimer {
   task_id?: number
   elapsed_seconds?: number
   elapsed_duration?: string
+  timers: RunningTimer[]
+}
+
+// entries-changed イベントのペイロード
+export interface EntriesChanged {
+  task_id: number
 }
 
 export interface TaskTimeSummary {
   task_id: number
   total_duration_seconds: number
   total_duration_formatted: string
+  rounded_duration_seconds: number
+  rounded_duration_formatted: string
   entry_count: number
   is_running: boolean
+  completed_pomodoros: number
 }
 
 // API リクエスト型定義
+export type ReportGranularity = 'day' | 'week' | 'month'
+
+export type ReportDimension = 'project' | 'task' | 'tag'
+
+export interface GenerateReportRequest {
+  start_time: string
+  end_time: string
+  granularity: ReportGranularity
+  group_by?: ReportDimension[]
+  project_ids?: number[]
+  task_ids?: number[]
+  tag_ids?: number[]
+  include_archived?: boolean
+}
+
+export interface ReportGroup {
+  dimension: ReportGranularity | ReportDimension
+  period_start?: string
+  id?: number
+  name?: string
+  total_seconds: number
+  total_duration_formatted: string
+  rounded_seconds: number
+  rounded_duration_formatted: string
+  entry_count: number
+  children: ReportGroup[]
+}
+
+export interface Report {
+  start_time: string
+  end_time: string
+  granularity: ReportGranularity
+  group_by: ReportDimension[]
+  total_seconds: number
+  total_duration_formatted: string
+  rounded_seconds: number
+  rounded_duration_formatted: string
+  entry_count: number
+  groups: ReportGroup[]
+}
+
+export interface TimesheetRow {
+  task_id: number
+  task_name: string
+  project_id: number
+  daily_seconds: number[]
+  total_seconds: number
+  total_duration_formatted: string
+}
+
+export interface Timesheet {
+  week_start: string
+  days: string[]
+  rows: TimesheetRow[]
+  daily_totals: number[]
+  total_seconds: number
+  total_duration_formatted: string
+}
+
+export interface SetTimesheetCellRequest {
+  task_id: number
+  day: string
+  duration_seconds: number
+}
+
+export type TimelineSpan = 'day' | 'week'
+
+export interface GetTimelineRequest {
+  date: string
+  span?: TimelineSpan
+}
+
+export interface TimelineEntry {
+  start_event_id: number
+  task_id: number
+  task_name: string
+  project_id: number
+  project_name: string
+  start_time: string
+  end_time?: string
+  duration_in_seconds?: number
+  elapsed_duration: string
+  is_running: boolean
+  is_paused: boolean
+  notes?: string
+}
+
+// start_time / end_time はそのまま手動エントリの追加に使える
+export interface TimelineGap {
+  start_time: string
+  end_time: string
+  duration_seconds: number
+  duration_formatted: string
+}
+
+export interface TimelineDay {
+  date: string
+  entries: TimelineEntry[]
+  gaps: TimelineGap[]
+  tracked_seconds: number
+  tracked_duration_formatted: string
+}
+
+export interface Timeline {
+  span: TimelineSpan
+  start_date: string
+  days: TimelineDay[]
+}
+
+export type RoundingMode = 'none' | 'up' | 'down' | 'nearest'
+
+export type RoundingScope = 'entry' | 'day'
+
+export interface RoundingRule {
+  mode: RoundingMode
+  increment_seconds: number
+  scope: RoundingScope
+}
+
+export interface ProjectRoundingRule {
+  project_id: number
+  rule: RoundingRule
+}
+
+export interface RoundingSettings {
+  default_rule: RoundingRule
+  project_rules: ProjectRoundingRule[]
+}
+
+// project_id 未指定はワークスペース全体の既定、プロジェクトで rule 未指定は上書きの解除
+export interface SetRoundingRuleRequest {
+  project_id?: number
+  rule?: RoundingRule
+}
+
+// 日付は timezone（未指定時は設定のカレンダー）のローカル日付、end_date を含む
+export interface ExportTimeEntriesCsvRequest {
+  start_date: string
+  end_date?: string
+  project_id?: number
+  task_id?: number
+  tag_id?: number
+  path: string
+  delimiter?: string
+  always_quote?: boolean
+  escape_formulas?: boolean
+  timezone?: string
+}
+
+// 実行中の時間エントリは include_running 指定時のみ TENTATIVE の予定として含める
+export interface ExportTimeEntriesIcsRequest {
+  start_date: string
+  end_date?: string
+  project_id?: number
+  task_id?: number
+  tag_id?: number
+  path: string
+  include_running?: boolean
+}
+
+export interface ExportResult {
+  path: string
+  row_count: number
+  total_seconds: number
+  total_duration_formatted: string
+}
+
+export interface BackupTable {
+  name: string
+  row_count: number
+}
+
+export interface BackupResult {
+  path: string
+  schema_version: number
+  tables: BackupTable[]
+  total_rows: number
+}
+
+export interface ImportTogglCsvRequest {
+  path: string
+  timezone?: string
+  dry_run?: boolean
+}
+
+export interface CsvColumnMapping {
+  project: string
+  task: string
+  start: string
+  start_time?: string
+  end?: string
+  end_time?: string
+  duration?: string
+  notes?: string
+  tags?: string
+}
+
+export interface ImportTimeEntriesCsvRequest {
+  path: string
+  mapping: CsvColumnMapping
+  delimiter?: string
+  datetime_formats?: string[]
+  timezone?: string
+  dry_run?: boolean
+}
+
+export interface ImportedTask {
+  project_name: string
+  task_name: string
+}
+
+export interface SkippedRow {
+  line: number
+  message: string
+}
+
+export interface ImportResult {
+  path: string
+  dry_run: boolean
+  row_count: number
+  imported_count: number
+  imported_seconds: number
+  created_projects: string[]
+  created_tasks: ImportedTask[]
+  created_tags: string[]
+  skipped_rows: SkippedRow[]
+}
+
 export interface CreateProjectRequest {
   name: string
 }
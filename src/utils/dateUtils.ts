@@ -39,3 +39,13 @@ export function formatRelativeDate(date: Date): string {
   }
 }
 
+
+/**
+ * ローカル日付を YYYY-MM-DD 形式にする（日付単位のコマンドに渡す）
+ */
+export function toLocalDateString(date: Date): string {
+  const year = date.getFullYear()
+  const month = String(date.getMonth() + 1).padStart(2, '0')
+  const day = String(date.getDate()).padStart(2, '0')
+  return `${year}-${month}-${day}`
+}